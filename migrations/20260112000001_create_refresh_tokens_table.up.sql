@@ -0,0 +1,18 @@
+CREATE TABLE refresh_tokens (
+    id BIGSERIAL PRIMARY KEY,
+    user_id BIGINT NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    family_id UUID NOT NULL,
+    token_hash VARCHAR(64) NOT NULL UNIQUE,
+    expires_at TIMESTAMPTZ NOT NULL,
+    revoked_at TIMESTAMPTZ NULL,
+    replaced_by BIGINT NULL REFERENCES refresh_tokens (id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- indexing
+CREATE INDEX idx_refresh_tokens_family
+    ON refresh_tokens (family_id);
+
+CREATE INDEX idx_refresh_tokens_user_active
+    ON refresh_tokens (user_id)
+    WHERE revoked_at IS NULL;
@@ -0,0 +1,17 @@
+-- Individually revoked access tokens, kept until the token would have expired anyway
+CREATE TABLE revoked_tokens (
+    jti VARCHAR(64) PRIMARY KEY,
+    user_id BIGINT NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    expires_at TIMESTAMPTZ NOT NULL,
+    revoked_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- "Log out of all devices": every token issued before revoked_before is rejected
+CREATE TABLE user_token_revocations (
+    user_id BIGINT PRIMARY KEY REFERENCES users (id) ON DELETE CASCADE,
+    revoked_before TIMESTAMPTZ NOT NULL
+);
+
+-- indexing
+CREATE INDEX idx_revoked_tokens_expires_at
+    ON revoked_tokens (expires_at);
@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS idx_users_activation_key;
+
+ALTER TABLE users
+    DROP COLUMN IF EXISTS activation_key_expires_at;
@@ -0,0 +1,6 @@
+ALTER TABLE users
+    ADD COLUMN activation_key_expires_at TIMESTAMPTZ NULL;
+
+CREATE INDEX idx_users_activation_key
+    ON users (activation_key)
+    WHERE activation_key IS NOT NULL;
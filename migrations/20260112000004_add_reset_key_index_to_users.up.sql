@@ -0,0 +1,3 @@
+CREATE INDEX idx_users_reset_key
+    ON users (reset_key)
+    WHERE reset_key IS NOT NULL;
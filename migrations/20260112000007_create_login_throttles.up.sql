@@ -0,0 +1,11 @@
+-- Failed login counters keyed by account ("user:<id>") or client address ("ip:<addr>").
+CREATE TABLE login_throttles (
+    key VARCHAR(255) PRIMARY KEY,
+    failed_attempts INT NOT NULL DEFAULT 0,
+    lockout_count INT NOT NULL DEFAULT 0,
+    window_started_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    locked_until TIMESTAMPTZ NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_login_throttles_updated_at ON login_throttles (updated_at);
@@ -0,0 +1,6 @@
+DROP INDEX IF EXISTS idx_users_email_change_key;
+
+ALTER TABLE users
+    DROP COLUMN IF EXISTS email_change_key_expires_at,
+    DROP COLUMN IF EXISTS email_change_key,
+    DROP COLUMN IF EXISTS pending_email;
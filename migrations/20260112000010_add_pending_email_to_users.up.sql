@@ -0,0 +1,8 @@
+ALTER TABLE users
+    ADD COLUMN pending_email VARCHAR(255) NULL,
+    ADD COLUMN email_change_key VARCHAR(255) NULL,
+    ADD COLUMN email_change_key_expires_at TIMESTAMPTZ NULL;
+
+CREATE INDEX idx_users_email_change_key
+    ON users (email_change_key)
+    WHERE email_change_key IS NOT NULL;
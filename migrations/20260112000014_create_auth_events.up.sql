@@ -0,0 +1,16 @@
+-- Audit trail of authentication activity. Rows are never updated.
+CREATE TABLE auth_events (
+    id BIGSERIAL PRIMARY KEY,
+    event_type VARCHAR(50) NOT NULL,
+    outcome VARCHAR(20) NOT NULL,
+    user_id BIGINT NULL REFERENCES users (id) ON DELETE SET NULL,
+    actor_id BIGINT NULL REFERENCES users (id) ON DELETE SET NULL,
+    ip_address VARCHAR(45) NULL,
+    user_agent VARCHAR(512) NULL,
+    reason VARCHAR(255) NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- indexing
+CREATE INDEX idx_auth_events_created_at ON auth_events (created_at DESC);
+CREATE INDEX idx_auth_events_user ON auth_events (user_id, created_at DESC);
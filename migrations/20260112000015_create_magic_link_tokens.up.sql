@@ -0,0 +1,14 @@
+-- Single-use passwordless login links. Only the SHA-256 of the token is stored.
+CREATE TABLE magic_link_tokens (
+    id BIGSERIAL PRIMARY KEY,
+    user_id BIGINT NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    token_hash VARCHAR(64) NOT NULL UNIQUE,
+    expires_at TIMESTAMPTZ NOT NULL,
+    used_at TIMESTAMPTZ NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- indexing
+CREATE INDEX idx_magic_link_tokens_user
+    ON magic_link_tokens (user_id)
+    WHERE used_at IS NULL;
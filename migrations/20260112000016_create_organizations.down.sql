@@ -0,0 +1,6 @@
+ALTER TABLE products DROP COLUMN IF EXISTS organization_id;
+ALTER TABLE categories DROP COLUMN IF EXISTS organization_id;
+ALTER TABLE sessions DROP COLUMN IF EXISTS organization_id;
+DROP TABLE IF EXISTS organization_member_roles;
+DROP TABLE IF EXISTS organization_members;
+DROP TABLE IF EXISTS organizations;
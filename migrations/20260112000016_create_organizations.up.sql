@@ -0,0 +1,50 @@
+-- Tenants. Every tenant-owned row references one of these.
+CREATE TABLE organizations (
+    id BIGSERIAL PRIMARY KEY,
+    slug VARCHAR(63) NOT NULL UNIQUE,
+    name VARCHAR(255) NOT NULL,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE organization_members (
+    organization_id BIGINT NOT NULL REFERENCES organizations (id) ON DELETE CASCADE,
+    user_id BIGINT NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (organization_id, user_id)
+);
+
+-- Roles a member holds inside one organization, on top of their global roles.
+CREATE TABLE organization_member_roles (
+    organization_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    role_id BIGINT NOT NULL REFERENCES roles (id) ON DELETE CASCADE,
+    PRIMARY KEY (organization_id, user_id, role_id),
+    FOREIGN KEY (organization_id, user_id)
+        REFERENCES organization_members (organization_id, user_id)
+        ON DELETE CASCADE
+);
+
+-- Active tenant of a signed-in device, kept across refreshes.
+ALTER TABLE sessions
+    ADD COLUMN organization_id BIGINT NULL REFERENCES organizations (id) ON DELETE SET NULL;
+
+-- Catalog rows belong to a tenant. Rows created before tenancy have none
+-- and are not visible to any tenant.
+ALTER TABLE categories
+    ADD COLUMN organization_id BIGINT NULL REFERENCES organizations (id) ON DELETE RESTRICT;
+
+ALTER TABLE products
+    ADD COLUMN organization_id BIGINT NULL REFERENCES organizations (id) ON DELETE RESTRICT;
+
+-- indexing
+CREATE INDEX idx_organization_members_user ON organization_members (user_id);
+
+CREATE INDEX idx_categories_organization_active
+    ON categories (organization_id)
+    WHERE deleted_at IS NULL;
+
+CREATE INDEX idx_products_organization_active
+    ON products (organization_id)
+    WHERE deleted_at IS NULL;
@@ -0,0 +1,20 @@
+-- Admin invitations to create an account. Only the SHA-256 of the token is stored.
+CREATE TABLE user_invitations (
+    id BIGSERIAL PRIMARY KEY,
+    email VARCHAR(255) NOT NULL,
+    role_id BIGINT NOT NULL REFERENCES roles (id) ON DELETE CASCADE,
+    invited_by BIGINT NULL REFERENCES users (id) ON DELETE SET NULL,
+    token_hash VARCHAR(64) NOT NULL UNIQUE,
+    expires_at TIMESTAMPTZ NOT NULL,
+    accepted_at TIMESTAMPTZ NULL,
+    revoked_at TIMESTAMPTZ NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- indexing
+CREATE INDEX idx_user_invitations_email_open
+    ON user_invitations (email)
+    WHERE accepted_at IS NULL AND revoked_at IS NULL;
+
+CREATE INDEX idx_user_invitations_created_at
+    ON user_invitations (created_at DESC);
@@ -0,0 +1,22 @@
+-- Needed for the password policy's maximum age. Existing accounts count
+-- from their last reset, or from when they were created.
+ALTER TABLE users
+    ADD COLUMN password_changed_at TIMESTAMPTZ NULL;
+
+UPDATE users SET password_changed_at = COALESCE(reset_date, created_at);
+
+ALTER TABLE users
+    ALTER COLUMN password_changed_at SET NOT NULL,
+    ALTER COLUMN password_changed_at SET DEFAULT NOW();
+
+-- Previous password hashes, checked so old passwords are not reused.
+CREATE TABLE password_history (
+    id BIGSERIAL PRIMARY KEY,
+    user_id BIGINT NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    password_hash VARCHAR(255) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- indexing
+CREATE INDEX idx_password_history_user_id
+    ON password_history (user_id, created_at DESC);
@@ -0,0 +1,8 @@
+-- Irreversible: once tenants share a code or a product has no category, the
+-- global unique constraints and NOT NULL cannot come back without deleting
+-- data, so fail loudly instead of half-way through.
+DO $$
+BEGIN
+    RAISE EXCEPTION 'migration 20260112000019_scope_catalog_codes_to_tenants cannot be reverted';
+END
+$$;
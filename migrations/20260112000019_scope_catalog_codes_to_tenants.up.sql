@@ -0,0 +1,18 @@
+-- Product and category codes only need to be unique within their tenant;
+-- a global constraint would let one tenant probe another's codes.
+ALTER TABLE products DROP CONSTRAINT products_code_key;
+ALTER TABLE categories DROP CONSTRAINT categories_code_key;
+
+CREATE UNIQUE INDEX uq_products_organization_code
+    ON products (organization_id, code)
+    WHERE deleted_at IS NULL;
+
+CREATE UNIQUE INDEX uq_categories_organization_code
+    ON categories (organization_id, code)
+    WHERE deleted_at IS NULL;
+
+-- Products can be created before they are filed under a category. The
+-- column was declared BIGSERIAL, which gave it a sequence default.
+ALTER TABLE products ALTER COLUMN category_id DROP DEFAULT;
+ALTER TABLE products ALTER COLUMN category_id DROP NOT NULL;
+DROP SEQUENCE IF EXISTS products_category_id_seq;
@@ -0,0 +1,38 @@
+use crate::domain::entity::user::User;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// A user as seen by administrators, including account state.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AdminUserResponse {
+    pub id: i64,
+    pub code: String,
+    pub email: String,
+    pub username: String,
+    pub full_name: Option<String>,
+    pub is_verified: bool,
+    pub is_active: bool,
+    pub status: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub last_login_at: Option<DateTime<Utc>>,
+}
+
+impl From<User> for AdminUserResponse {
+    fn from(user: User) -> Self {
+        Self {
+            id: user.id.unwrap(),
+            code: user.code,
+            email: user.email.value().to_string(),
+            username: user.username,
+            full_name: user.full_name,
+            is_verified: user.is_verified,
+            is_active: user.is_active,
+            status: user.status,
+            created_at: user.created_at,
+            updated_at: user.updated_at,
+            last_login_at: user.last_login_at,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateApiKeyRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+
+    /// Permissions the key may use, a subset of your own. Empty means the
+    /// key only identifies you.
+    #[serde(default)]
+    pub scopes: Vec<String>,
+
+    /// Omit for a key that does not expire.
+    #[validate(range(min = 1, max = 3650))]
+    pub expires_in_days: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateApiKeyRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub name: Option<String>,
+
+    pub scopes: Option<Vec<String>>,
+}
@@ -0,0 +1,41 @@
+use crate::domain::AuthEventFilter;
+use chrono::{DateTime, Utc};
+use rust_reborn_contracts::{AppError, Result};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListAuthEventsQuery {
+    pub user_id: Option<i64>,
+    /// `login`, `register`, `change_password`, `reset_password`, `logout`,
+    /// `logout_all`, `deactivate`, `delete`, `impersonation_start` or
+    /// `impersonation_end`.
+    pub event_type: Option<String>,
+    /// `success` or `failure`.
+    pub outcome: Option<String>,
+    /// Events at or after this instant (RFC 3339).
+    pub from: Option<DateTime<Utc>>,
+    /// Events before this instant (RFC 3339).
+    pub to: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<ListAuthEventsQuery> for AuthEventFilter {
+    type Error = AppError;
+
+    fn try_from(query: ListAuthEventsQuery) -> Result<Self> {
+        if let (Some(from), Some(to)) = (query.from, query.to) {
+            if from >= to {
+                return Err(AppError::bad_request("`from` must be before `to`"));
+            }
+        }
+
+        Ok(Self {
+            user_id: query.user_id,
+            event_type: query.event_type.as_deref().map(str::parse).transpose()?,
+            outcome: query.outcome.as_deref().map(str::parse).transpose()?,
+            from: query.from,
+            to: query.to,
+        })
+    }
+}
@@ -0,0 +1,34 @@
+use crate::domain::entity::AuthEvent;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AuthEventResponse {
+    pub id: i64,
+    pub event_type: String,
+    pub outcome: String,
+    pub user_id: Option<i64>,
+    /// Set when someone other than the user caused the event, e.g. an admin.
+    pub actor_id: Option<i64>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub reason: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<AuthEvent> for AuthEventResponse {
+    fn from(event: AuthEvent) -> Self {
+        Self {
+            id: event.id.unwrap_or_default(),
+            event_type: event.event_type.as_str().to_string(),
+            outcome: event.outcome.as_str().to_string(),
+            user_id: event.user_id,
+            actor_id: event.actor_id,
+            ip_address: event.ip_address,
+            user_agent: event.user_agent,
+            reason: event.reason,
+            created_at: event.created_at,
+        }
+    }
+}
@@ -7,4 +7,8 @@ use utoipa::ToSchema;
 pub struct AuthResponse {
     pub user: UserResponse,
     pub token: TokenResponse,
+    /// The password is older than the policy's maximum age; the frontend
+    /// should ask for a new one.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub password_expired: bool,
 }
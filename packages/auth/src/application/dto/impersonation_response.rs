@@ -0,0 +1,15 @@
+use crate::application::dto::UserResponse;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Access token for acting as another user. There is no refresh token: the
+/// impersonation ends when the token expires or is ended explicitly.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ImpersonationResponse {
+    pub access_token: String,
+    pub token_type: String,
+    pub expires_in: i64,
+    /// The administrator the token was issued to.
+    pub actor_id: i64,
+    pub user: UserResponse,
+}
@@ -0,0 +1,28 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateInvitationRequest {
+    #[validate(email)]
+    pub email: String,
+
+    /// Role the new account gets.
+    #[validate(length(min = 1))]
+    pub role: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct AcceptInvitationRequest {
+    #[validate(length(min = 1))]
+    pub token: String,
+
+    #[validate(length(min = 3, max = 50))]
+    pub username: String,
+
+    #[validate(length(min = 1))]
+    pub password: String,
+
+    #[validate(length(max = 100))]
+    pub full_name: Option<String>,
+}
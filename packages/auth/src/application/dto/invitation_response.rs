@@ -0,0 +1,32 @@
+use crate::domain::entity::Invitation;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct InvitationResponse {
+    pub id: i64,
+    pub email: String,
+    pub role: String,
+    pub invited_by: Option<i64>,
+    /// `pending`, `accepted`, `revoked` or `expired`.
+    pub status: String,
+    pub expires_at: DateTime<Utc>,
+    pub accepted_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Invitation> for InvitationResponse {
+    fn from(invitation: Invitation) -> Self {
+        Self {
+            id: invitation.id.unwrap_or_default(),
+            status: invitation.status().to_string(),
+            email: invitation.email,
+            role: invitation.role,
+            invited_by: invitation.invited_by,
+            expires_at: invitation.expires_at,
+            accepted_at: invitation.accepted_at,
+            created_at: invitation.created_at,
+        }
+    }
+}
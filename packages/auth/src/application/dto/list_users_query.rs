@@ -0,0 +1,26 @@
+use crate::domain::UserFilter;
+use serde::Deserialize;
+use utoipa::IntoParams;
+use validator::Validate;
+
+#[derive(Debug, Default, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListUsersQuery {
+    pub status: Option<i32>,
+    pub is_verified: Option<bool>,
+    pub is_active: Option<bool>,
+    /// Matches part of the email or username, case-insensitive.
+    #[validate(length(min = 1, max = 100))]
+    pub search: Option<String>,
+}
+
+impl From<ListUsersQuery> for UserFilter {
+    fn from(query: ListUsersQuery) -> Self {
+        Self {
+            status: query.status,
+            is_verified: query.is_verified,
+            is_active: query.is_active,
+            search: query.search,
+        }
+    }
+}
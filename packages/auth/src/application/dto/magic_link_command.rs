@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct MagicLinkRequest {
+    #[validate(email)]
+    pub email: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct VerifyMagicLinkRequest {
+    #[validate(length(min = 1))]
+    pub token: String,
+}
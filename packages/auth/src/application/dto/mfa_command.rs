@@ -0,0 +1,19 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ConfirmTotpRequest {
+    #[validate(length(min = 6, max = 6))]
+    pub code: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct MfaLoginRequest {
+    #[validate(length(min = 1))]
+    pub mfa_token: String,
+
+    /// A 6-digit TOTP code or an unused recovery code.
+    #[validate(length(min = 6, max = 32))]
+    pub code: String,
+}
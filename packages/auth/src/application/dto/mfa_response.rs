@@ -0,0 +1,31 @@
+use super::auth_response::AuthResponse;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TotpEnrollmentResponse {
+    pub secret: String,
+    pub otpauth_uri: String,
+    /// The otpauth URI rendered as an SVG QR code.
+    pub qr_svg: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RecoveryCodesResponse {
+    pub recovery_codes: Vec<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MfaChallengeResponse {
+    pub mfa_required: bool,
+    pub mfa_token: String,
+    pub expires_in: i64,
+}
+
+/// Login either completes or asks for a second factor.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum LoginResponse {
+    Authenticated(AuthResponse),
+    MfaRequired(MfaChallengeResponse),
+}
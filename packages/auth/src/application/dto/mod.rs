@@ -1,15 +1,69 @@
+pub mod admin_user_response;
+pub mod api_key_command;
+pub mod api_key_response;
+pub mod auth_event_query;
+pub mod auth_event_response;
 pub mod auth_response;
+pub mod change_password_command;
+pub mod impersonation_response;
+pub mod invitation_command;
+pub mod invitation_response;
+pub mod list_users_query;
 pub mod login_command;
+pub mod logout_command;
+pub mod magic_link_command;
+pub mod mfa_command;
+pub mod mfa_response;
+pub mod oauth_client_command;
+pub mod oauth_client_response;
+pub mod oidc_command;
+pub mod organization_command;
+pub mod organization_response;
+pub mod password_reset_command;
+pub mod profile_command;
 pub mod refresh_token_command;
 pub mod register_command;
 pub mod register_response;
+pub mod session_response;
 pub mod token_response;
 pub mod user_response;
+pub mod verify_email_command;
 
+pub use admin_user_response::AdminUserResponse;
+pub use api_key_command::{CreateApiKeyRequest, UpdateApiKeyRequest};
+pub use api_key_response::{ApiKeyResponse, CreatedApiKeyResponse};
+pub use auth_event_query::ListAuthEventsQuery;
+pub use auth_event_response::AuthEventResponse;
 pub use auth_response::AuthResponse;
+pub use change_password_command::ChangePasswordRequest;
+pub use impersonation_response::ImpersonationResponse;
+pub use invitation_command::{AcceptInvitationRequest, CreateInvitationRequest};
+pub use invitation_response::InvitationResponse;
+pub use list_users_query::ListUsersQuery;
 pub use login_command::LoginRequest;
+pub use logout_command::LogoutRequest;
+pub use magic_link_command::{MagicLinkRequest, VerifyMagicLinkRequest};
+pub use mfa_command::{ConfirmTotpRequest, MfaLoginRequest};
+pub use mfa_response::{
+    LoginResponse, MfaChallengeResponse, RecoveryCodesResponse, TotpEnrollmentResponse,
+};
+pub use oauth_client_command::{ClientCredentialsRequest, RegisterOAuthClientRequest};
+pub use oauth_client_response::{
+    ClientTokenResponse, OAuthClientResponse, OAuthErrorResponse, RegisteredOAuthClientResponse,
+};
+pub use oidc_command::{OidcAuthorizationResponse, OidcCallbackRequest};
+pub use organization_command::{
+    CreateOrganizationRequest, SetMemberRolesRequest, SwitchOrganizationRequest,
+};
+pub use organization_response::{
+    MembershipResponse, OrganizationResponse, UserOrganizationResponse,
+};
+pub use password_reset_command::{ForgotPasswordRequest, ResetPasswordRequest};
+pub use profile_command::{ChangeEmailRequest, ConfirmEmailChangeRequest, UpdateProfileRequest};
 pub use refresh_token_command::RefreshTokenRequest;
 pub use register_command::RegisterRequest;
 pub use register_response::RegisterResponse;
+pub use session_response::SessionResponse;
 pub use token_response::TokenResponse;
 pub use user_response::UserResponse;
+pub use verify_email_command::{ResendVerificationRequest, VerifyEmailRequest};
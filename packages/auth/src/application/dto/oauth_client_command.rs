@@ -0,0 +1,24 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct RegisterOAuthClientRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+
+    /// Permissions the client may request in its tokens.
+    #[serde(default)]
+    pub scopes: Vec<String>,
+}
+
+/// Form body of `POST /oauth/token`. Client credentials may come here or
+/// in an HTTP Basic `Authorization` header.
+#[derive(Debug, Deserialize)]
+pub struct ClientCredentialsRequest {
+    pub grant_type: String,
+    /// Space-separated subset of the client's scopes; all of them when omitted.
+    pub scope: Option<String>,
+    pub client_id: Option<String>,
+    pub client_secret: Option<String>,
+}
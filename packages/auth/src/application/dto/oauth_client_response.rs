@@ -0,0 +1,53 @@
+use crate::domain::entity::OAuthClient;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OAuthClientResponse {
+    pub id: i64,
+    pub client_id: String,
+    pub name: String,
+    pub scopes: Vec<String>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<OAuthClient> for OAuthClientResponse {
+    fn from(client: OAuthClient) -> Self {
+        Self {
+            id: client.id.unwrap_or_default(),
+            client_id: client.client_id,
+            name: client.name,
+            scopes: client.scopes,
+            is_active: client.is_active,
+            created_at: client.created_at,
+            last_used_at: client.last_used_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RegisteredOAuthClientResponse {
+    /// Only shown once; store it in the service's secret store.
+    pub client_secret: String,
+    #[serde(flatten)]
+    pub client: OAuthClientResponse,
+}
+
+/// Successful token response (RFC 6749 section 5.1).
+#[derive(Debug, Serialize)]
+pub struct ClientTokenResponse {
+    pub access_token: String,
+    pub token_type: &'static str,
+    pub expires_in: i64,
+    pub scope: String,
+}
+
+/// Error response of the token endpoint (RFC 6749 section 5.2).
+#[derive(Debug, Serialize)]
+pub struct OAuthErrorResponse {
+    pub error: &'static str,
+    pub error_description: String,
+}
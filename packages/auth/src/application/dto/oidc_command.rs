@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OidcAuthorizationResponse {
+    /// Send the browser here to sign in at the provider.
+    pub authorization_url: String,
+    /// Comes back on the redirect; pass it to the callback unchanged.
+    pub state: String,
+    pub expires_in: i64,
+}
+
+/// Query parameters the provider appended to the redirect URI.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct OidcCallbackRequest {
+    #[validate(length(min = 1))]
+    pub code: String,
+
+    #[validate(length(min = 1))]
+    pub state: String,
+}
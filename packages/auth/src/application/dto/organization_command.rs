@@ -0,0 +1,26 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateOrganizationRequest {
+    /// Lowercase letters, digits and hyphens; used in `X-Tenant` and as subdomain.
+    #[validate(length(min = 1, max = 63))]
+    pub slug: String,
+
+    #[validate(length(min = 1, max = 255))]
+    pub name: String,
+}
+
+/// Replaces the member's roles in the organization, adding the member if needed.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetMemberRolesRequest {
+    #[serde(default)]
+    pub roles: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SwitchOrganizationRequest {
+    /// `null` leaves the current organization.
+    pub organization_id: Option<i64>,
+}
@@ -0,0 +1,53 @@
+use crate::domain::entity::{Membership, Organization};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrganizationResponse {
+    pub id: i64,
+    pub slug: String,
+    pub name: String,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Organization> for OrganizationResponse {
+    fn from(organization: Organization) -> Self {
+        Self {
+            id: organization.id.unwrap_or_default(),
+            slug: organization.slug,
+            name: organization.name,
+            is_active: organization.is_active,
+            created_at: organization.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MembershipResponse {
+    pub organization_id: i64,
+    pub user_id: i64,
+    /// Roles held in this organization only.
+    pub roles: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Membership> for MembershipResponse {
+    fn from(membership: Membership) -> Self {
+        Self {
+            organization_id: membership.organization_id,
+            user_id: membership.user_id,
+            roles: membership.role_names(),
+            created_at: membership.created_at,
+        }
+    }
+}
+
+/// An organization the current user belongs to.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UserOrganizationResponse {
+    #[serde(flatten)]
+    pub organization: OrganizationResponse,
+    pub roles: Vec<String>,
+}
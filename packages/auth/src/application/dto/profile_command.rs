@@ -0,0 +1,28 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// Omitted fields are left unchanged.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateProfileRequest {
+    #[validate(length(max = 100))]
+    pub full_name: Option<String>,
+
+    #[validate(length(min = 3, max = 50))]
+    pub username: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ChangeEmailRequest {
+    #[validate(email)]
+    pub new_email: String,
+
+    #[validate(length(min = 1))]
+    pub current_password: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ConfirmEmailChangeRequest {
+    #[validate(length(min = 1))]
+    pub token: String,
+}
@@ -1,6 +1,9 @@
 use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct RefreshTokenRequest {
+    #[validate(length(min = 1))]
     pub refresh_token: String,
 }
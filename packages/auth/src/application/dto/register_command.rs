@@ -9,7 +9,7 @@ pub struct RegisterRequest {
     #[validate(length(min = 3, max = 50))]
     pub username: String,
 
-    #[validate(length(min = 8))]
+    #[validate(length(min = 1))]
     pub password: String,
 
     #[validate(length(max = 100))]
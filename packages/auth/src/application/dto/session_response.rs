@@ -0,0 +1,29 @@
+use crate::domain::entity::session::Session;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SessionResponse {
+    pub id: Uuid,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+    /// True for the session the request was made from.
+    pub current: bool,
+}
+
+impl SessionResponse {
+    pub fn from_session(session: Session, current_session: Option<Uuid>) -> Self {
+        Self {
+            current: current_session == Some(session.id),
+            id: session.id,
+            user_agent: session.user_agent,
+            ip_address: session.ip_address,
+            created_at: session.created_at,
+            last_seen_at: session.last_seen_at,
+        }
+    }
+}
@@ -1,10 +1,24 @@
+use crate::domain::value_objects::Token;
 use serde::Serialize;
 use utoipa::ToSchema;
 
 #[derive(Debug, Serialize, ToSchema)]
 pub struct TokenResponse {
+    /// Empty, and left out, when the token was set as a cookie instead.
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub access_token: String,
     pub token_type: String,
     pub expires_in: i64,
     pub refresh_token: Option<String>,
 }
+
+impl From<Token> for TokenResponse {
+    fn from(token: Token) -> Self {
+        Self {
+            access_token: token.access_token,
+            token_type: token.token_type,
+            expires_in: token.expires_in,
+            refresh_token: token.refresh_token,
+        }
+    }
+}
@@ -10,6 +10,9 @@ pub struct UserResponse {
     pub username: String,
     pub full_name: Option<String>,
     pub is_verified: bool,
+    /// New address waiting for confirmation, if an email change is in progress.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_email: Option<String>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -21,6 +24,7 @@ impl From<User> for UserResponse {
             username: user.username,
             full_name: user.full_name,
             is_verified: user.is_verified,
+            pending_email: user.pending_email.map(|e| e.value().to_string()),
             created_at: user.created_at,
         }
     }
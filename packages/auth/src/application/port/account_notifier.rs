@@ -0,0 +1,27 @@
+use crate::domain::entity::{Invitation, User};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_reborn_contracts::Result;
+
+/// Delivers account-related messages (verification, password reset, email
+/// change, login links and invitations) to the user.
+#[async_trait]
+pub trait AccountNotifier: Send + Sync {
+    async fn send_email_verification(&self, user: &User, token: &str) -> Result<()>;
+    async fn send_password_reset(&self, user: &User, token: &str) -> Result<()>;
+    /// Goes to `user.pending_email`, proving the user controls the new address.
+    async fn send_email_change(&self, user: &User, token: &str) -> Result<()>;
+    async fn send_magic_link(
+        &self,
+        user: &User,
+        token: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<()>;
+    /// Goes to `invitation.email`, which has no account yet.
+    async fn send_invitation(
+        &self,
+        invitation: &Invitation,
+        inviter: &User,
+        token: &str,
+    ) -> Result<()>;
+}
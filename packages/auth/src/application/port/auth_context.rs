@@ -1,3 +1,80 @@
+use uuid::Uuid;
+
+/// Who a request acts as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Principal<'a> {
+    Anonymous,
+    User(i64),
+    /// An OAuth client authenticated with the client-credentials grant.
+    Service(&'a str),
+}
+
 pub trait AuthContext: Send + Sync {
     fn user_id(&self) -> Option<i64>;
+    fn roles(&self) -> &[String];
+    fn permissions(&self) -> &[String];
+
+    /// The session the access token belongs to, when it was issued with one.
+    fn session_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    /// Set when the request authenticated with an API key instead of a token.
+    fn api_key_id(&self) -> Option<i64> {
+        None
+    }
+
+    /// Client id of a service principal; such requests have no `user_id`.
+    fn client_id(&self) -> Option<&str> {
+        None
+    }
+
+    /// Organization the request acts in, once the tenant resolver has
+    /// checked the membership.
+    fn tenant_id(&self) -> Option<i64> {
+        None
+    }
+
+    /// Roles the user holds in the active organization. They are not part
+    /// of `roles()`, so they never grant platform-wide access.
+    fn tenant_roles(&self) -> &[String] {
+        &[]
+    }
+
+    fn tenant_permissions(&self) -> &[String] {
+        &[]
+    }
+
+    /// The administrator acting as `user_id` when the request uses an
+    /// impersonation token.
+    fn actor_id(&self) -> Option<i64> {
+        None
+    }
+
+    fn is_impersonating(&self) -> bool {
+        self.actor_id().is_some()
+    }
+
+    fn principal(&self) -> Principal<'_> {
+        match (self.user_id(), self.client_id()) {
+            (Some(user_id), _) => Principal::User(user_id),
+            (None, Some(client_id)) => Principal::Service(client_id),
+            (None, None) => Principal::Anonymous,
+        }
+    }
+
+    fn has_role(&self, role: &str) -> bool {
+        self.roles().iter().any(|r| r == role)
+    }
+
+    /// Checks global permissions only.
+    fn has_permission(&self, permission: &str) -> bool {
+        self.permissions().iter().any(|p| p == permission)
+    }
+
+    /// For resources owned by the active organization: a global permission
+    /// or one granted in that organization.
+    fn has_tenant_permission(&self, permission: &str) -> bool {
+        self.has_permission(permission) || self.tenant_permissions().iter().any(|p| p == permission)
+    }
 }
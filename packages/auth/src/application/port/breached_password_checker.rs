@@ -0,0 +1,8 @@
+use async_trait::async_trait;
+use rust_reborn_contracts::Result;
+
+/// Tells whether a password is known from a data breach, using local data only.
+#[async_trait]
+pub trait BreachedPasswordChecker: Send + Sync {
+    async fn is_breached(&self, raw: &str) -> Result<bool>;
+}
@@ -1,3 +1,5 @@
+pub mod account_notifier;
 pub mod auth_context;
+pub mod breached_password_checker;
 pub mod password_hasher;
 pub mod token_generator;
@@ -3,4 +3,10 @@ use rust_reborn_contracts::Result;
 pub trait PasswordHasher: Send + Sync {
     fn hash(&self, raw: &str) -> Result<String>;
     fn verify(&self, raw: &str, hashed: &str) -> Result<bool>;
+
+    /// Whether a stored hash uses an outdated algorithm or cost and should be
+    /// replaced the next time the raw password is known.
+    fn needs_rehash(&self, _hashed: &str) -> bool {
+        false
+    }
 }
@@ -0,0 +1,205 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::dto::ListUsersQuery;
+    use crate::application::services::test_fakes::{test_token_issued_at, TestDeps};
+    use crate::application::use_case::deactivate_user::DeactivateUserUseCase;
+    use crate::application::use_case::delete_user::DeleteUserUseCase;
+    use crate::application::use_case::list_users::ListUsersUseCase;
+    use crate::application::use_case::reactivate_user::ReactivateUserUseCase;
+    use crate::domain::entity::{AuthEventType, User};
+    use crate::domain::value_objects::{ClientInfo, Email, HashedPassword};
+    use crate::domain::UserRepository;
+    use rust_reborn_contracts::common::PaginationParams;
+    use rust_reborn_contracts::AppError;
+
+    const ADMIN_ID: i64 = 1;
+
+    struct Fixture {
+        deps: TestDeps,
+        list: ListUsersUseCase,
+        deactivate: DeactivateUserUseCase,
+        reactivate: ReactivateUserUseCase,
+        delete: DeleteUserUseCase,
+    }
+
+    fn user(id: i64, username: &str, verified: bool) -> User {
+        let mut user = User::new(
+            format!("USR-{}", id),
+            Email::new(format!("{}@example.com", username)).unwrap(),
+            username.to_string(),
+            HashedPassword::from("hashed_password".to_string()),
+            None,
+        );
+        user.id = Some(id);
+        user.is_verified = verified;
+        user.created_at += chrono::Duration::seconds(id);
+        user
+    }
+
+    fn setup() -> Fixture {
+        let deps = TestDeps::default().with_users(vec![
+            user(ADMIN_ID, "admin", true),
+            user(2, "alice", true),
+            user(3, "bob", false),
+            user(4, "alicia", false),
+        ]);
+
+        Fixture {
+            list: ListUsersUseCase::new(deps.users.clone()),
+            deactivate: DeactivateUserUseCase::new(
+                deps.users.clone(),
+                deps.logout_all(),
+                deps.recorder(),
+            ),
+            reactivate: ReactivateUserUseCase::new(deps.users.clone()),
+            delete: DeleteUserUseCase::new(deps.users.clone(), deps.logout_all(), deps.recorder()),
+            deps,
+        }
+    }
+
+    fn page(page: u32, per_page: u32) -> PaginationParams {
+        PaginationParams { page, per_page }
+    }
+
+    #[tokio::test]
+    async fn test_list_paginates_newest_first() {
+        let f = setup();
+
+        let first = f
+            .list
+            .execute(ListUsersQuery::default(), page(1, 3))
+            .await
+            .unwrap();
+        let second = f
+            .list
+            .execute(ListUsersQuery::default(), page(2, 3))
+            .await
+            .unwrap();
+
+        let usernames: Vec<_> = first.data.iter().map(|u| u.username.as_str()).collect();
+        assert_eq!(usernames, vec!["alicia", "bob", "alice"]);
+        assert_eq!(second.data.len(), 1);
+        assert_eq!(first.pagination.total_items, 4);
+        assert_eq!(first.pagination.total_pages, 2);
+    }
+
+    #[tokio::test]
+    async fn test_list_filters_and_searches() {
+        let f = setup();
+        let query = ListUsersQuery {
+            is_verified: Some(true),
+            search: Some("ALI".to_string()),
+            ..Default::default()
+        };
+
+        let result = f.list.execute(query, page(1, 10)).await.unwrap();
+
+        assert_eq!(result.data.len(), 1);
+        assert_eq!(result.data[0].username, "alice");
+        assert_eq!(result.pagination.total_items, 1);
+    }
+
+    #[tokio::test]
+    async fn test_deactivate_blocks_login_and_revokes_tokens() {
+        let f = setup();
+        let token = test_token_issued_at(
+            &f.deps.users.get(2),
+            chrono::Utc::now() - chrono::Duration::minutes(1),
+        );
+
+        let response = f
+            .deactivate
+            .execute(ADMIN_ID, 2, ClientInfo::default())
+            .await
+            .unwrap();
+
+        assert!(!response.is_active);
+        assert!(!f.deps.users.get(2).can_login());
+        assert!(f.deps.verify_token().execute(&token).await.is_err());
+
+        let inactive = ListUsersQuery {
+            is_active: Some(false),
+            ..Default::default()
+        };
+        let result = f.list.execute(inactive, page(1, 10)).await.unwrap();
+        assert_eq!(result.data.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_reactivate_restores_login() {
+        let f = setup();
+        f.deactivate
+            .execute(ADMIN_ID, 2, ClientInfo::default())
+            .await
+            .unwrap();
+
+        let response = f.reactivate.execute(2).await.unwrap();
+
+        assert!(response.is_active);
+        assert!(f.deps.users.get(2).can_login());
+    }
+
+    #[tokio::test]
+    async fn test_admin_cannot_deactivate_or_delete_themselves() {
+        let f = setup();
+
+        let deactivate = f
+            .deactivate
+            .execute(ADMIN_ID, ADMIN_ID, ClientInfo::default())
+            .await;
+        let delete = f
+            .delete
+            .execute(ADMIN_ID, ADMIN_ID, ClientInfo::default())
+            .await;
+
+        assert!(matches!(deactivate, Err(AppError::BadRequest(_))));
+        assert!(matches!(delete, Err(AppError::BadRequest(_))));
+        assert!(f.deps.users.get(ADMIN_ID).can_login());
+    }
+
+    #[tokio::test]
+    async fn test_soft_deleted_user_is_hidden_from_every_lookup() {
+        let f = setup();
+
+        f.delete
+            .execute(ADMIN_ID, 3, ClientInfo::default())
+            .await
+            .unwrap();
+
+        assert!(f.deps.users.get(3).is_deleted());
+        let events = f.deps.events.all();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, AuthEventType::Delete);
+        assert_eq!(events[0].user_id, Some(3));
+        assert_eq!(events[0].actor_id, Some(ADMIN_ID));
+        assert!(f.deps.users.find_by_id(3).await.unwrap().is_none());
+        assert!(f
+            .deps
+            .users
+            .find_by_email("bob@example.com")
+            .await
+            .unwrap()
+            .is_none());
+        assert!(f
+            .deps
+            .users
+            .find_by_username("bob")
+            .await
+            .unwrap()
+            .is_none());
+
+        let result = f
+            .list
+            .execute(ListUsersQuery::default(), page(1, 10))
+            .await
+            .unwrap();
+        assert_eq!(result.pagination.total_items, 3);
+
+        let again = f.delete.execute(ADMIN_ID, 3, ClientInfo::default()).await;
+        assert!(matches!(again, Err(AppError::NotFound(_))));
+        assert!(matches!(
+            f.reactivate.execute(3).await,
+            Err(AppError::NotFound(_))
+        ));
+    }
+}
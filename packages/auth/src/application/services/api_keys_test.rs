@@ -0,0 +1,185 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::auth_context::AuthContext;
+    use crate::application::dto::{CreateApiKeyRequest, UpdateApiKeyRequest};
+    use crate::application::services::test_fakes::{InMemoryApiKeyRepository, TestDeps};
+    use crate::application::use_case::authenticate_api_key::AuthenticateApiKeyUseCase;
+    use crate::application::use_case::create_api_key::CreateApiKeyUseCase;
+    use crate::application::use_case::list_api_keys::ListApiKeysUseCase;
+    use crate::application::use_case::revoke_api_key::RevokeApiKeyUseCase;
+    use crate::application::use_case::update_api_key::UpdateApiKeyUseCase;
+    use crate::domain::entity::Role;
+    use crate::domain::repository::RoleRepository;
+    use crate::presentation::request_auth_context::RequestAuthContext;
+    use rust_reborn_contracts::common::SecureTokenGenerator;
+    use rust_reborn_contracts::AppError;
+    use std::sync::Arc;
+
+    struct Fixture {
+        deps: TestDeps,
+        keys: Arc<InMemoryApiKeyRepository>,
+        create: CreateApiKeyUseCase,
+        list: ListApiKeysUseCase,
+        update: UpdateApiKeyUseCase,
+        revoke: RevokeApiKeyUseCase,
+        authenticate: AuthenticateApiKeyUseCase,
+    }
+
+    async fn setup() -> Fixture {
+        let deps = TestDeps::default().with_roles(vec![
+            Role::new(
+                "admin",
+                vec!["products:write".to_string(), "users:manage".to_string()],
+            ),
+            Role::new("catalog_admin", vec!["products:write".to_string()]),
+        ]);
+        deps.roles.assign_to_user(1, "catalog_admin").await.unwrap();
+        let keys = Arc::new(InMemoryApiKeyRepository::default());
+
+        Fixture {
+            keys: keys.clone(),
+            create: CreateApiKeyUseCase::new(
+                keys.clone(),
+                deps.roles.clone(),
+                Arc::new(SecureTokenGenerator),
+            ),
+            list: ListApiKeysUseCase::new(keys.clone()),
+            update: UpdateApiKeyUseCase::new(keys.clone(), deps.roles.clone()),
+            revoke: RevokeApiKeyUseCase::new(keys.clone()),
+            authenticate: AuthenticateApiKeyUseCase::new(
+                keys,
+                deps.users.clone(),
+                deps.roles.clone(),
+            ),
+            deps,
+        }
+    }
+
+    fn request(scopes: &[&str], expires_in_days: Option<i64>) -> CreateApiKeyRequest {
+        CreateApiKeyRequest {
+            name: "ci deploy".to_string(),
+            scopes: scopes.iter().map(|s| s.to_string()).collect(),
+            expires_in_days,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_created_key_authenticates_with_its_scopes_only() {
+        let f = setup().await;
+
+        let created = f
+            .create
+            .execute(1, request(&["products:write"], Some(30)))
+            .await
+            .unwrap();
+        assert!(created.key.starts_with(&created.api_key.prefix));
+
+        let stored = f.keys.get(created.api_key.id);
+        assert_ne!(stored.key_hash.value(), created.key);
+
+        let principal = f.authenticate.execute(&created.key).await.unwrap();
+        assert_eq!(principal.user_id, 1);
+        assert_eq!(principal.permissions, vec!["products:write"]);
+        assert!(f.keys.get(created.api_key.id).last_used_at.is_some());
+
+        let ctx = RequestAuthContext::from_api_key(principal);
+        assert!(ctx.has_permission("products:write"));
+        assert!(ctx.roles().is_empty());
+        assert_eq!(ctx.api_key_id(), Some(created.api_key.id));
+    }
+
+    #[tokio::test]
+    async fn test_scopes_beyond_own_permissions_are_refused() {
+        let f = setup().await;
+
+        let result = f
+            .create
+            .execute(1, request(&["products:write", "users:manage"], None))
+            .await;
+
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+        assert!(f.keys.keys.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_wrong_revoked_or_expired_keys_are_rejected() {
+        let f = setup().await;
+        let created = f.create.execute(1, request(&[], None)).await.unwrap();
+
+        let forged = format!("{}{}", created.api_key.prefix, "0".repeat(48));
+        assert!(matches!(
+            f.authenticate.execute(&forged).await,
+            Err(AppError::Unauthorized(_))
+        ));
+
+        let expiring = f.create.execute(1, request(&[], Some(1))).await.unwrap();
+        f.keys.keys.lock().unwrap()[1].expires_at = Some(chrono::Utc::now());
+        assert!(matches!(
+            f.authenticate.execute(&expiring.key).await,
+            Err(AppError::Unauthorized(_))
+        ));
+
+        f.revoke.execute(1, created.api_key.id).await.unwrap();
+        assert!(matches!(
+            f.authenticate.execute(&created.key).await,
+            Err(AppError::Unauthorized(_))
+        ));
+        assert_eq!(f.list.execute(1).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_key_loses_scopes_the_owner_loses() {
+        let f = setup().await;
+        let created = f
+            .create
+            .execute(1, request(&["products:write"], None))
+            .await
+            .unwrap();
+
+        f.deps
+            .roles
+            .remove_from_user(1, "catalog_admin")
+            .await
+            .unwrap();
+
+        let principal = f.authenticate.execute(&created.key).await.unwrap();
+        assert!(principal.permissions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_key_of_deactivated_owner_is_refused() {
+        let f = setup().await;
+        let created = f.create.execute(1, request(&[], None)).await.unwrap();
+
+        f.deps.users.users.lock().unwrap()[0].is_active = false;
+
+        assert!(matches!(
+            f.authenticate.execute(&created.key).await,
+            Err(AppError::Forbidden(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_only_the_owner_can_update_or_revoke_a_key() {
+        let f = setup().await;
+        let created = f.create.execute(1, request(&[], None)).await.unwrap();
+        let id = created.api_key.id;
+
+        let rename = || UpdateApiKeyRequest {
+            name: Some("renamed".to_string()),
+            scopes: Some(vec!["products:write".to_string()]),
+        };
+        assert!(matches!(
+            f.update.execute(2, id, rename()).await,
+            Err(AppError::NotFound(_))
+        ));
+        assert!(matches!(
+            f.revoke.execute(2, id).await,
+            Err(AppError::NotFound(_))
+        ));
+
+        let updated = f.update.execute(1, id, rename()).await.unwrap();
+        assert_eq!(updated.name, "renamed");
+        assert_eq!(f.keys.get(id).scopes, vec!["products:write"]);
+    }
+}
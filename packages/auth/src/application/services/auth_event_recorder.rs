@@ -0,0 +1,27 @@
+use crate::domain::entity::AuthEvent;
+use crate::domain::AuthEventRepository;
+use std::sync::Arc;
+
+/// Writes the authentication audit log. A failed write is logged and
+/// otherwise ignored so the audit log never fails the action it records.
+pub struct AuthEventRecorder {
+    event_repo: Arc<dyn AuthEventRepository>,
+}
+
+impl AuthEventRecorder {
+    pub fn new(event_repo: Arc<dyn AuthEventRepository>) -> Self {
+        Self { event_repo }
+    }
+
+    pub async fn record(&self, mut event: AuthEvent) {
+        if let Err(e) = self.event_repo.save(&mut event).await {
+            tracing::error!(
+                event_type = event.event_type.as_str(),
+                outcome = event.outcome.as_str(),
+                user_id = ?event.user_id,
+                "failed to record auth event: {:?}",
+                e
+            );
+        }
+    }
+}
@@ -0,0 +1,240 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::dto::{ListAuthEventsQuery, LoginRequest, RegisterRequest};
+    use crate::application::services::email_verification::EmailVerificationService;
+    use crate::application::services::login_throttle::LoginThrottleService;
+    use crate::application::services::test_fakes::{
+        test_password_policy, test_user, FakePasswordHasher, TestDeps,
+    };
+    use crate::application::use_case::list_auth_events::ListAuthEventsUseCase;
+    use crate::application::use_case::login_user::LoginUserUseCase;
+    use crate::application::use_case::register_user::RegisterUserUseCase;
+    use crate::domain::entity::{AuthEvent, AuthEventOutcome, AuthEventType};
+    use crate::domain::value_objects::{ClientInfo, HashedPassword};
+    use crate::domain::AuthEventRepository;
+    use crate::infrastructure::config::LockoutConfig;
+    use crate::infrastructure::repository::InMemoryLoginThrottleRepository;
+    use chrono::{Duration, Utc};
+    use rust_reborn_contracts::common::{
+        PaginationParams, SecureTokenGenerator, UuidV7CodeGenerator,
+    };
+    use rust_reborn_contracts::AppError;
+    use std::sync::Arc;
+
+    const PASSWORD: &str = "S3cure-Passw0rd!";
+
+    struct Fixture {
+        deps: TestDeps,
+        login: LoginUserUseCase,
+        register: RegisterUserUseCase,
+        list: ListAuthEventsUseCase,
+    }
+
+    fn setup() -> Fixture {
+        let mut user = test_user();
+        user.is_verified = true;
+        user.password = HashedPassword::from(format!("hashed:{}", PASSWORD));
+        let deps = TestDeps::default().with_users(vec![user]);
+
+        Fixture {
+            login: LoginUserUseCase::new(
+                deps.users.clone(),
+                deps.token_issuer(),
+                Arc::new(FakePasswordHasher),
+                test_password_policy(),
+                deps.mfa_challenges(),
+                Arc::new(LoginThrottleService::new(
+                    Arc::new(InMemoryLoginThrottleRepository::new()),
+                    LockoutConfig::default(),
+                )),
+                deps.recorder(),
+                false,
+            ),
+            register: RegisterUserUseCase::new(
+                deps.users.clone(),
+                Arc::new(FakePasswordHasher),
+                test_password_policy(),
+                Arc::new(UuidV7CodeGenerator),
+                Arc::new(EmailVerificationService::new(
+                    Arc::new(SecureTokenGenerator),
+                    deps.notifier.clone(),
+                    Duration::hours(24),
+                )),
+                deps.recorder(),
+            ),
+            list: ListAuthEventsUseCase::new(deps.events.clone()),
+            deps,
+        }
+    }
+
+    fn laptop() -> ClientInfo {
+        ClientInfo {
+            ip: Some("203.0.113.7".parse().unwrap()),
+            user_agent: Some("Firefox on Linux".to_string()),
+        }
+    }
+
+    fn login_request(username: &str, password: &str) -> LoginRequest {
+        LoginRequest {
+            username: username.to_string(),
+            password: password.to_string(),
+        }
+    }
+
+    fn page(page: u32, per_page: u32) -> PaginationParams {
+        PaginationParams { page, per_page }
+    }
+
+    #[tokio::test]
+    async fn test_login_attempts_are_recorded_with_client_and_reason() {
+        let f = setup();
+
+        let _ = f
+            .login
+            .execute(login_request("nobody", PASSWORD), laptop())
+            .await;
+        let _ = f
+            .login
+            .execute(login_request("testuser", "wrong"), laptop())
+            .await;
+        f.login
+            .execute(login_request("testuser", PASSWORD), laptop())
+            .await
+            .unwrap();
+
+        let events = f.deps.events.all();
+        assert_eq!(events.len(), 3);
+        assert!(events.iter().all(|e| e.event_type == AuthEventType::Login));
+        assert!(events
+            .iter()
+            .all(|e| e.ip_address.as_deref() == Some("203.0.113.7")));
+        assert!(events
+            .iter()
+            .all(|e| e.user_agent.as_deref() == Some("Firefox on Linux")));
+
+        assert_eq!(events[0].outcome, AuthEventOutcome::Failure);
+        assert_eq!(events[0].user_id, None);
+        assert_eq!(events[0].reason.as_deref(), Some("unknown account"));
+
+        assert_eq!(events[1].outcome, AuthEventOutcome::Failure);
+        assert_eq!(events[1].user_id, Some(1));
+        assert_eq!(events[1].reason.as_deref(), Some("invalid password"));
+
+        assert_eq!(events[2].outcome, AuthEventOutcome::Success);
+        assert_eq!(events[2].user_id, Some(1));
+        assert_eq!(events[2].reason, None);
+    }
+
+    #[tokio::test]
+    async fn test_attempt_that_locks_the_account_is_recorded() {
+        let f = setup();
+
+        for _ in 0..4 {
+            let result = f
+                .login
+                .execute(login_request("testuser", "wrong"), laptop())
+                .await;
+            assert!(matches!(result, Err(AppError::Unauthorized(_))));
+        }
+        let result = f
+            .login
+            .execute(login_request("testuser", "wrong"), laptop())
+            .await;
+        assert!(matches!(result, Err(AppError::AccountLocked(_))));
+
+        let events = f.deps.events.all();
+        assert_eq!(events.len(), 5);
+        assert!(events
+            .iter()
+            .all(|e| e.reason.as_deref() == Some("invalid password")));
+    }
+
+    #[tokio::test]
+    async fn test_registration_success_and_conflict_are_recorded() {
+        let f = setup();
+        let request = |email: &str, username: &str| RegisterRequest {
+            email: email.to_string(),
+            username: username.to_string(),
+            password: PASSWORD.to_string(),
+            full_name: None,
+        };
+
+        let created = f
+            .register
+            .execute(request("new@example.com", "newuser"), laptop())
+            .await
+            .unwrap();
+        let duplicate = f
+            .register
+            .execute(request("test@example.com", "other"), laptop())
+            .await;
+        assert!(matches!(duplicate, Err(AppError::Conflict(_))));
+
+        let events = f.deps.events.all();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].event_type, AuthEventType::Register);
+        assert_eq!(events[0].outcome, AuthEventOutcome::Success);
+        assert_eq!(events[0].user_id, Some(created.id));
+        assert_eq!(events[1].outcome, AuthEventOutcome::Failure);
+        assert_eq!(
+            events[1].reason.as_deref(),
+            Some("email already registered")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_listing_filters_by_date_range_and_paginates() {
+        let f = setup();
+        let now = Utc::now();
+        for days_ago in 0..5 {
+            let mut event = AuthEvent::success(AuthEventType::Login, 1, &laptop());
+            event.created_at = now - Duration::days(days_ago);
+            f.deps.events.save(&mut event).await.unwrap();
+        }
+        let mut failure = AuthEvent::failure(AuthEventType::Login, Some(2), &laptop(), "x");
+        failure.created_at = now - Duration::days(1);
+        f.deps.events.save(&mut failure).await.unwrap();
+
+        let query = ListAuthEventsQuery {
+            user_id: Some(1),
+            from: Some(now - Duration::days(3) - Duration::hours(1)),
+            to: Some(now - Duration::hours(1)),
+            ..Default::default()
+        };
+        let first = f.list.execute(query, page(1, 2)).await.unwrap();
+        assert_eq!(first.pagination.total_items, 3);
+        assert_eq!(first.data.len(), 2);
+        assert!(first.data[0].created_at > first.data[1].created_at);
+        assert_eq!(first.data[0].created_at, now - Duration::days(1));
+
+        let failures = ListAuthEventsQuery {
+            outcome: Some("failure".to_string()),
+            ..Default::default()
+        };
+        let failures = f.list.execute(failures, page(1, 20)).await.unwrap();
+        assert_eq!(failures.pagination.total_items, 1);
+        assert_eq!(failures.data[0].user_id, Some(2));
+        assert_eq!(failures.data[0].event_type, "login");
+    }
+
+    #[tokio::test]
+    async fn test_listing_rejects_invalid_filters() {
+        let f = setup();
+        let now = Utc::now();
+
+        let inverted = ListAuthEventsQuery {
+            from: Some(now),
+            to: Some(now - Duration::days(1)),
+            ..Default::default()
+        };
+        let result = f.list.execute(inverted, page(1, 20)).await;
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+
+        let unknown = ListAuthEventsQuery {
+            event_type: Some("teleport".to_string()),
+            ..Default::default()
+        };
+        let result = f.list.execute(unknown, page(1, 20)).await;
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    }
+}
@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::breached_password_checker::BreachedPasswordChecker;
+    use crate::application::services::password_policy::PasswordPolicyService;
+    use crate::application::services::test_fakes::{
+        FakePasswordHasher, InMemoryPasswordHistoryRepository,
+    };
+    use crate::infrastructure::breached_passwords::{
+        open_breached_password_checker, password_digest, BloomFilter, HashListChecker,
+    };
+    use crate::infrastructure::config::{
+        BreachListFormat, BreachedPasswordsConfig, PasswordPolicyConfig,
+    };
+    use rust_reborn_contracts::AppError;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    const BREACHED: [&str; 5] = [
+        "123456",
+        "password",
+        "P@ssw0rd!",
+        "Summer2024!",
+        "Welcome-1",
+    ];
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("reborn-{}-{}", name, uuid::Uuid::new_v4()))
+    }
+
+    /// Same layout as the Have I Been Pwned download: uppercase hashes in
+    /// order, a count after each and CRLF line endings.
+    fn write_hash_list() -> PathBuf {
+        let mut hashes: Vec<_> = BREACHED
+            .iter()
+            .map(|p| hex::encode_upper(password_digest(p)))
+            .collect();
+        hashes.sort();
+
+        let content: String = hashes
+            .iter()
+            .enumerate()
+            .map(|(i, hash)| format!("{}:{}\r\n", hash, i + 1))
+            .collect();
+        let path = temp_path("pwned.txt");
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn test_hash_list_finds_every_entry_and_nothing_else() {
+        let path = write_hash_list();
+        let checker = HashListChecker::open(&path).unwrap();
+
+        for password in BREACHED {
+            assert!(checker.is_breached(password).await.unwrap(), "{}", password);
+        }
+        for password in ["", "Correct-Horse-9", "p@ssw0rd!", "1234567"] {
+            assert!(
+                !checker.is_breached(password).await.unwrap(),
+                "{}",
+                password
+            );
+        }
+
+        std::fs::remove_file(path).unwrap();
+        assert!(HashListChecker::open(temp_path("missing.txt")).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_bloom_filter_survives_a_round_trip() {
+        let mut filter = BloomFilter::new(BREACHED.len() as u64, 0.0001);
+        for password in BREACHED {
+            filter.insert(&password_digest(password));
+        }
+        let path = temp_path("pwned.bloom");
+        filter
+            .write_to(std::fs::File::create(&path).unwrap())
+            .unwrap();
+
+        let checker = open_breached_password_checker(&BreachedPasswordsConfig {
+            enabled: true,
+            format: BreachListFormat::BloomFilter,
+            path: path.display().to_string(),
+        })
+        .unwrap();
+
+        for password in BREACHED {
+            assert!(checker.is_breached(password).await.unwrap(), "{}", password);
+        }
+        assert!(!checker.is_breached("Correct-Horse-9").await.unwrap());
+
+        std::fs::write(&path, b"not a filter").unwrap();
+        assert!(BloomFilter::load(&path).is_err());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_policy_refuses_breached_passwords() {
+        let mut filter = BloomFilter::new(BREACHED.len() as u64, 0.0001);
+        for password in BREACHED {
+            filter.insert(&password_digest(password));
+        }
+        let policy = PasswordPolicyService::new(
+            PasswordPolicyConfig::default(),
+            Arc::new(InMemoryPasswordHistoryRepository::default()),
+            Arc::new(FakePasswordHasher),
+            Some(Arc::new(filter)),
+        );
+
+        let Err(AppError::ValidationError(errors)) = policy
+            .check("password", "Summer2024!", "alice", "alice@example.com")
+            .await
+        else {
+            panic!("expected a validation error");
+        };
+        let codes: Vec<_> = errors.field_errors()["password"]
+            .iter()
+            .map(|e| e.code.to_string())
+            .collect();
+        assert_eq!(codes, vec!["password_breached"]);
+
+        let result = policy
+            .check("password", "Correct-Horse-9", "alice", "alice@example.com")
+            .await;
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,164 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::dto::ChangePasswordRequest;
+    use crate::application::services::test_fakes::{
+        test_password_policy, test_token_issued_at, test_user, FakePasswordHasher, TestDeps,
+    };
+    use crate::application::use_case::change_password::ChangePasswordUseCase;
+    use crate::domain::value_objects::{ClientInfo, HashedPassword};
+    use rust_reborn_contracts::AppError;
+    use std::sync::Arc;
+
+    const CURRENT_PASSWORD: &str = "Curr3nt-Passw0rd!";
+    const NEW_PASSWORD: &str = "N3w-Passw0rd!";
+
+    struct Fixture {
+        deps: TestDeps,
+        use_case: ChangePasswordUseCase,
+    }
+
+    fn setup() -> Fixture {
+        let mut user = test_user();
+        user.password = HashedPassword::new(format!("hashed:{}", CURRENT_PASSWORD));
+        let deps = TestDeps::default().with_users(vec![user]);
+
+        Fixture {
+            use_case: ChangePasswordUseCase::new(
+                deps.users.clone(),
+                Arc::new(FakePasswordHasher),
+                test_password_policy(),
+                deps.logout_all(),
+                deps.token_issuer(),
+                deps.recorder(),
+            ),
+            deps,
+        }
+    }
+
+    fn request(current: &str, new: &str) -> ChangePasswordRequest {
+        ChangePasswordRequest {
+            current_password: current.to_string(),
+            new_password: new.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_change_password_success() {
+        let f = setup();
+
+        let response = f
+            .use_case
+            .execute(
+                1,
+                request(CURRENT_PASSWORD, NEW_PASSWORD),
+                ClientInfo::default(),
+            )
+            .await
+            .unwrap();
+
+        assert!(response.refresh_token.is_some());
+        assert_eq!(
+            f.deps.users.get(1).password.value(),
+            format!("hashed:{}", NEW_PASSWORD)
+        );
+        assert_eq!(
+            f.deps
+                .verify_token()
+                .execute(&response.access_token)
+                .await
+                .unwrap(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_change_password_revokes_other_sessions() {
+        let f = setup();
+        let old_access_token = test_token_issued_at(
+            &test_user(),
+            chrono::Utc::now() - chrono::Duration::minutes(1),
+        );
+
+        f.use_case
+            .execute(
+                1,
+                request(CURRENT_PASSWORD, NEW_PASSWORD),
+                ClientInfo::default(),
+            )
+            .await
+            .unwrap();
+
+        assert!(f
+            .deps
+            .verify_token()
+            .execute(&old_access_token)
+            .await
+            .is_err());
+        assert_eq!(
+            *f.deps.refresh_tokens.revoked_users.lock().unwrap(),
+            vec![1]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_change_password_wrong_current_password() {
+        let f = setup();
+
+        let result = f
+            .use_case
+            .execute(
+                1,
+                request("Wr0ng-Password!", NEW_PASSWORD),
+                ClientInfo::default(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(AppError::Unauthorized(_))));
+        assert!(f
+            .deps
+            .refresh_tokens
+            .revoked_users
+            .lock()
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_change_password_rejects_weak_password() {
+        let f = setup();
+
+        let result = f
+            .use_case
+            .execute(
+                1,
+                request(CURRENT_PASSWORD, "weakpassword"),
+                ClientInfo::default(),
+            )
+            .await;
+
+        let Err(AppError::ValidationError(errors)) = result else {
+            panic!("expected a validation error");
+        };
+        assert!(errors.field_errors().contains_key("new_password"));
+        assert_eq!(
+            f.deps.users.get(1).password.value(),
+            format!("hashed:{}", CURRENT_PASSWORD)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_change_password_rejects_same_password() {
+        let f = setup();
+
+        let result = f
+            .use_case
+            .execute(
+                1,
+                request(CURRENT_PASSWORD, CURRENT_PASSWORD),
+                ClientInfo::default(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    }
+}
@@ -0,0 +1,48 @@
+use crate::application::port::account_notifier::AccountNotifier;
+use crate::domain::entity::User;
+use crate::domain::value_objects::TokenHash;
+use rust_reborn_contracts::common::CodeGenerator;
+use std::sync::Arc;
+
+pub struct EmailVerificationService {
+    token_generator: Arc<dyn CodeGenerator>,
+    notifier: Arc<dyn AccountNotifier>,
+    token_ttl: chrono::Duration,
+}
+
+impl EmailVerificationService {
+    pub fn new(
+        token_generator: Arc<dyn CodeGenerator>,
+        notifier: Arc<dyn AccountNotifier>,
+        token_ttl: chrono::Duration,
+    ) -> Self {
+        Self {
+            token_generator,
+            notifier,
+            token_ttl,
+        }
+    }
+
+    /// Stores a fresh activation key hash on the user and returns the raw token.
+    /// The caller is responsible for persisting the user before calling [`Self::send`].
+    pub fn prepare(&self, user: &mut User) -> String {
+        let token = self.token_generator.generate("ev");
+        user.set_activation_key(
+            TokenHash::of(&token).value().to_string(),
+            chrono::Utc::now() + self.token_ttl,
+        );
+        token
+    }
+
+    /// Delivery failures are logged rather than returned: the account exists
+    /// either way and the user can ask for another link.
+    pub async fn send(&self, user: &User, token: &str) {
+        if let Err(e) = self.notifier.send_email_verification(user, token).await {
+            tracing::error!(
+                user_id = user.id,
+                error = ?e,
+                "failed to send email verification"
+            );
+        }
+    }
+}
@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::dto::{ResendVerificationRequest, VerifyEmailRequest};
+    use crate::application::services::email_verification::EmailVerificationService;
+    use crate::application::services::test_fakes::TestDeps;
+    use crate::application::use_case::resend_verification::ResendVerificationUseCase;
+    use crate::application::use_case::verify_email::VerifyEmailUseCase;
+    use rust_reborn_contracts::common::SecureTokenGenerator;
+    use rust_reborn_contracts::AppError;
+    use std::sync::Arc;
+
+    struct Fixture {
+        deps: TestDeps,
+        verify: VerifyEmailUseCase,
+        resend: ResendVerificationUseCase,
+    }
+
+    fn setup(ttl: chrono::Duration) -> Fixture {
+        let deps = TestDeps::default();
+        let email_verification = Arc::new(EmailVerificationService::new(
+            Arc::new(SecureTokenGenerator),
+            deps.notifier.clone(),
+            ttl,
+        ));
+
+        Fixture {
+            verify: VerifyEmailUseCase::new(deps.users.clone()),
+            resend: ResendVerificationUseCase::new(deps.users.clone(), email_verification),
+            deps,
+        }
+    }
+
+    fn resend_request(email: &str) -> ResendVerificationRequest {
+        ResendVerificationRequest {
+            email: email.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resend_then_verify_email() {
+        let f = setup(chrono::Duration::hours(24));
+
+        f.resend
+            .execute(resend_request("test@example.com"))
+            .await
+            .unwrap();
+        let token = f.deps.notifier.last_verification_token().unwrap();
+        assert_ne!(
+            f.deps.users.get(1).activation_key.as_deref(),
+            Some(token.as_str())
+        );
+
+        let response = f
+            .verify
+            .execute(VerifyEmailRequest { token })
+            .await
+            .unwrap();
+
+        assert!(response.is_verified);
+        let user = f.deps.users.get(1);
+        assert!(user.is_verified);
+        assert!(user.activation_key.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_verify_email_token_is_single_use() {
+        let f = setup(chrono::Duration::hours(24));
+        f.resend
+            .execute(resend_request("test@example.com"))
+            .await
+            .unwrap();
+        let token = f.deps.notifier.last_verification_token().unwrap();
+
+        f.verify
+            .execute(VerifyEmailRequest {
+                token: token.clone(),
+            })
+            .await
+            .unwrap();
+        let result = f.verify.execute(VerifyEmailRequest { token }).await;
+
+        match result.unwrap_err() {
+            AppError::BadRequest(msg) => assert_eq!(msg, "invalid verification token"),
+            _ => panic!("expected BadRequest error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verify_email_expired_token() {
+        let f = setup(chrono::Duration::seconds(-1));
+        f.resend
+            .execute(resend_request("test@example.com"))
+            .await
+            .unwrap();
+        let token = f.deps.notifier.last_verification_token().unwrap();
+
+        let result = f.verify.execute(VerifyEmailRequest { token }).await;
+
+        match result.unwrap_err() {
+            AppError::BadRequest(msg) => assert_eq!(msg, "verification token expired"),
+            _ => panic!("expected BadRequest error"),
+        }
+        assert!(!f.deps.users.get(1).is_verified);
+    }
+
+    #[tokio::test]
+    async fn test_resend_verification_unknown_email_is_silent() {
+        let f = setup(chrono::Duration::hours(24));
+
+        let result = f.resend.execute(resend_request("nobody@example.com")).await;
+
+        assert!(result.is_ok());
+        assert!(f.deps.notifier.verifications.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_resend_verification_skips_verified_account() {
+        let f = setup(chrono::Duration::hours(24));
+        let mut user = f.deps.users.get(1);
+        user.verify();
+        f.deps.users.users.lock().unwrap()[0] = user;
+
+        f.resend
+            .execute(resend_request("test@example.com"))
+            .await
+            .unwrap();
+
+        assert!(f.deps.notifier.verifications.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,169 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::auth_context::AuthContext;
+    use crate::application::end_impersonation::EndImpersonationUseCase;
+    use crate::application::services::test_fakes::{
+        test_jwt_service, test_user, InMemoryOrganizationRepository, TestDeps,
+    };
+    use crate::application::start_impersonation::StartImpersonationUseCase;
+    use crate::domain::entity::{AuthEventType, Role};
+    use crate::domain::repository::{OrganizationRepository, RoleRepository, UserRepository};
+    use crate::domain::value_objects::ClientInfo;
+    use crate::presentation::request_auth_context::RequestAuthContext;
+    use rust_reborn_contracts::AppError;
+    use std::sync::Arc;
+
+    const ADMIN_ID: i64 = 99;
+
+    struct Fixture {
+        deps: TestDeps,
+        organizations: Arc<InMemoryOrganizationRepository>,
+        start: StartImpersonationUseCase,
+        end: EndImpersonationUseCase,
+    }
+
+    fn setup() -> Fixture {
+        let deps = TestDeps::default().with_roles(vec![
+            Role::new("admin", vec!["users:manage".to_string()]),
+            Role::new("customer", vec!["orders:read".to_string()]),
+        ]);
+        let organizations = Arc::new(InMemoryOrganizationRepository::with_roles(vec![
+            Role::new("owner", vec!["users:manage".to_string()]),
+            Role::new("editor", vec!["products:write".to_string()]),
+        ]));
+
+        Fixture {
+            organizations: organizations.clone(),
+            start: StartImpersonationUseCase::new(
+                deps.users.clone(),
+                deps.roles.clone(),
+                organizations,
+                deps.jwt_service.clone(),
+                deps.recorder(),
+                chrono::Duration::minutes(15),
+            ),
+            end: EndImpersonationUseCase::new(
+                deps.verify_token(),
+                deps.revocations.clone(),
+                deps.recorder(),
+            ),
+            deps,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_impersonation_token_carries_actor() {
+        let f = setup();
+        f.deps.roles.assign_to_user(1, "customer").await.unwrap();
+
+        let response = f
+            .start
+            .execute(ADMIN_ID, 1, ClientInfo::default())
+            .await
+            .unwrap();
+        assert_eq!(response.actor_id, ADMIN_ID);
+        assert_eq!(response.expires_in, 15 * 60);
+        assert_eq!(response.user.id, 1);
+
+        let claims = f
+            .deps
+            .verify_token()
+            .verify(&response.access_token)
+            .await
+            .unwrap();
+        assert_eq!(claims.sid, None);
+        let ctx = RequestAuthContext::from_claims(claims).unwrap();
+        assert_eq!(ctx.user_id(), Some(1));
+        assert_eq!(ctx.actor_id(), Some(ADMIN_ID));
+        assert!(ctx.is_impersonating());
+        assert!(ctx.has_permission("orders:read"));
+
+        let events = f.deps.events.all();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, AuthEventType::ImpersonationStart);
+        assert_eq!(events[0].user_id, Some(1));
+        assert_eq!(events[0].actor_id, Some(ADMIN_ID));
+    }
+
+    #[tokio::test]
+    async fn test_ending_revokes_the_token() {
+        let f = setup();
+        let response = f
+            .start
+            .execute(ADMIN_ID, 1, ClientInfo::default())
+            .await
+            .unwrap();
+
+        f.end
+            .execute(&response.access_token, ClientInfo::default())
+            .await
+            .unwrap();
+
+        let result = f.deps.verify_token().verify(&response.access_token).await;
+        assert!(matches!(result, Err(AppError::Unauthorized(_))));
+
+        let events = f.deps.events.all();
+        assert_eq!(events[1].event_type, AuthEventType::ImpersonationEnd);
+        assert_eq!(events[1].user_id, Some(1));
+        assert_eq!(events[1].actor_id, Some(ADMIN_ID));
+    }
+
+    #[tokio::test]
+    async fn test_ending_requires_an_impersonation_token() {
+        let f = setup();
+        let token = test_jwt_service()
+            .generate_token(&test_user(), &[], None)
+            .unwrap();
+
+        let result = f.end.execute(&token, ClientInfo::default()).await;
+
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+        assert!(f.deps.events.all().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_refuses_self_administrators_and_inactive_accounts() {
+        let f = setup();
+
+        let result = f.start.execute(1, 1, ClientInfo::default()).await;
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+
+        f.deps.roles.assign_to_user(1, "admin").await.unwrap();
+        let result = f.start.execute(ADMIN_ID, 1, ClientInfo::default()).await;
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+        f.deps.roles.remove_from_user(1, "admin").await.unwrap();
+
+        let mut user = f.deps.users.get(1);
+        user.deactivate();
+        f.deps.users.update(&user).await.unwrap();
+        let result = f.start.execute(ADMIN_ID, 1, ClientInfo::default()).await;
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+
+        let result = f.start.execute(ADMIN_ID, 42, ClientInfo::default()).await;
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+        assert!(f.deps.events.all().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_refuses_organization_administrators() {
+        let f = setup();
+        f.organizations
+            .set_member_roles(7, 1, &["editor".to_string()])
+            .await
+            .unwrap();
+        f.start
+            .execute(ADMIN_ID, 1, ClientInfo::default())
+            .await
+            .unwrap();
+
+        f.organizations
+            .set_member_roles(7, 1, &["owner".to_string()])
+            .await
+            .unwrap();
+        let result = f.start.execute(ADMIN_ID, 1, ClientInfo::default()).await;
+        match result.unwrap_err() {
+            AppError::Forbidden(msg) => assert_eq!(msg, "administrators cannot be impersonated"),
+            _ => panic!("expected Forbidden error"),
+        }
+    }
+}
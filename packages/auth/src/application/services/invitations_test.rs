@@ -0,0 +1,225 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::accept_invitation::AcceptInvitationUseCase;
+    use crate::application::create_invitation::CreateInvitationUseCase;
+    use crate::application::dto::{AcceptInvitationRequest, CreateInvitationRequest};
+    use crate::application::list_invitations::ListInvitationsUseCase;
+    use crate::application::revoke_invitation::RevokeInvitationUseCase;
+    use crate::application::services::test_fakes::{
+        test_password_policy, FakePasswordHasher, InMemoryInvitationRepository, TestDeps,
+    };
+    use crate::domain::entity::{AuthEventType, Invitation, Role};
+    use crate::domain::repository::RoleRepository;
+    use crate::domain::value_objects::ClientInfo;
+    use rust_reborn_contracts::common::{SecureTokenGenerator, UuidV7CodeGenerator};
+    use rust_reborn_contracts::AppError;
+    use std::sync::Arc;
+
+    const INVITEE: &str = "new@example.com";
+
+    struct Fixture {
+        deps: TestDeps,
+        invitations: Arc<InMemoryInvitationRepository>,
+        create: CreateInvitationUseCase,
+        list: ListInvitationsUseCase,
+        revoke: RevokeInvitationUseCase,
+        accept: AcceptInvitationUseCase,
+    }
+
+    fn setup() -> Fixture {
+        let deps = TestDeps::default().with_roles(vec![Role::new(
+            "editor",
+            vec!["products:write".to_string()],
+        )]);
+        let invitations = Arc::new(InMemoryInvitationRepository::new(
+            deps.users.clone(),
+            deps.roles.clone(),
+        ));
+
+        Fixture {
+            invitations: invitations.clone(),
+            create: CreateInvitationUseCase::new(
+                invitations.clone(),
+                deps.users.clone(),
+                deps.roles.clone(),
+                Arc::new(SecureTokenGenerator),
+                deps.notifier.clone(),
+                chrono::Duration::hours(72),
+            ),
+            list: ListInvitationsUseCase::new(invitations.clone()),
+            revoke: RevokeInvitationUseCase::new(invitations.clone()),
+            accept: AcceptInvitationUseCase::new(
+                invitations,
+                deps.users.clone(),
+                Arc::new(FakePasswordHasher),
+                test_password_policy(),
+                Arc::new(UuidV7CodeGenerator),
+                deps.recorder(),
+            ),
+            deps,
+        }
+    }
+
+    async fn invite(f: &Fixture, email: &str) -> (i64, String) {
+        let invitation = f
+            .create
+            .execute(
+                1,
+                CreateInvitationRequest {
+                    email: email.to_string(),
+                    role: "editor".to_string(),
+                },
+            )
+            .await
+            .unwrap();
+
+        (
+            invitation.id,
+            f.deps.notifier.last_invitation_token().unwrap(),
+        )
+    }
+
+    fn accept_request(token: &str, username: &str) -> AcceptInvitationRequest {
+        AcceptInvitationRequest {
+            token: token.to_string(),
+            username: username.to_string(),
+            password: "Str0ngPassw0rd!".to_string(),
+            full_name: Some("New Colleague".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_accepting_creates_a_verified_user_with_the_role() {
+        let f = setup();
+        let (id, token) = invite(&f, INVITEE).await;
+        assert_eq!(f.deps.notifier.invitations.lock().unwrap()[0].0, INVITEE);
+
+        let user = f
+            .accept
+            .execute(accept_request(&token, "newbie"), ClientInfo::default())
+            .await
+            .unwrap();
+
+        assert_eq!(user.email, INVITEE);
+        assert!(user.is_verified);
+        let stored = f.deps.users.get(user.id);
+        assert_eq!(stored.password.value(), "hashed:Str0ngPassw0rd!");
+        let roles = f.deps.roles.find_by_user(user.id).await.unwrap();
+        assert_eq!(roles[0].name, "editor");
+        assert_eq!(f.invitations.get(id).status(), "accepted");
+
+        let events = f.deps.events.all();
+        assert_eq!(events[0].event_type, AuthEventType::Register);
+        assert_eq!(events[0].user_id, Some(user.id));
+        assert_eq!(events[0].actor_id, Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_invitation_works_once() {
+        let f = setup();
+        let (_, token) = invite(&f, INVITEE).await;
+        f.accept
+            .execute(accept_request(&token, "newbie"), ClientInfo::default())
+            .await
+            .unwrap();
+
+        let result = f
+            .accept
+            .execute(accept_request(&token, "other"), ClientInfo::default())
+            .await;
+
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_revoked_replaced_and_expired_invitations_are_rejected() {
+        let f = setup();
+        let (first_id, first) = invite(&f, INVITEE).await;
+        let (second_id, second) = invite(&f, INVITEE).await;
+        assert_eq!(f.invitations.get(first_id).status(), "revoked");
+
+        f.revoke.execute(second_id).await.unwrap();
+        let result = f.revoke.execute(second_id).await;
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+
+        let (third_id, third) = invite(&f, INVITEE).await;
+        f.invitations.invitations.lock().unwrap()[2].expires_at =
+            chrono::Utc::now() - chrono::Duration::minutes(1);
+        assert_eq!(f.invitations.get(third_id).status(), "expired");
+
+        for token in [first, second, third] {
+            let result = f
+                .accept
+                .execute(accept_request(&token, "newbie"), ClientInfo::default())
+                .await;
+            assert!(matches!(result, Err(AppError::BadRequest(_))));
+        }
+        assert!(f.deps.events.all().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_conflicts_leave_the_invitation_open() {
+        let f = setup();
+
+        let result = f
+            .create
+            .execute(
+                1,
+                CreateInvitationRequest {
+                    email: "test@example.com".to_string(),
+                    role: "editor".to_string(),
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+
+        let result = f
+            .create
+            .execute(
+                1,
+                CreateInvitationRequest {
+                    email: INVITEE.to_string(),
+                    role: "ghost".to_string(),
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+
+        let (id, token) = invite(&f, INVITEE).await;
+        let result = f
+            .accept
+            .execute(accept_request(&token, "testuser"), ClientInfo::default())
+            .await;
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+        assert_eq!(f.invitations.get(id).status(), "pending");
+
+        let listed = f.list.execute().await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].status, "pending");
+    }
+
+    #[tokio::test]
+    async fn test_failed_accept_creates_no_user_and_keeps_the_invitation() {
+        let f = setup();
+        // The role disappeared after the invitation was sent.
+        let mut invitation = Invitation::new(
+            INVITEE.to_string(),
+            "ghost".to_string(),
+            1,
+            "inv-ghost",
+            chrono::Utc::now() + chrono::Duration::hours(1),
+        );
+        invitation.id = Some(1);
+        f.invitations.invitations.lock().unwrap().push(invitation);
+
+        let result = f
+            .accept
+            .execute(accept_request("inv-ghost", "newbie"), ClientInfo::default())
+            .await;
+
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+        assert_eq!(f.invitations.get(1).status(), "pending");
+        assert_eq!(f.deps.users.users.lock().unwrap().len(), 1);
+        assert!(f.deps.events.all().is_empty());
+    }
+}
@@ -0,0 +1,109 @@
+use crate::domain::entity::LoginThrottle;
+use crate::domain::LoginThrottleRepository;
+use crate::infrastructure::config::LockoutConfig;
+use chrono::{Duration, Utc};
+use rust_reborn_contracts::{AppError, Result};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+/// Applies the lockout policy around password checks: accounts are locked
+/// for progressively longer after repeated wrong passwords, and client
+/// addresses are throttled across all accounts they try.
+pub struct LoginThrottleService {
+    repo: Arc<dyn LoginThrottleRepository>,
+    config: LockoutConfig,
+}
+
+impl LoginThrottleService {
+    pub fn new(repo: Arc<dyn LoginThrottleRepository>, config: LockoutConfig) -> Self {
+        Self { repo, config }
+    }
+
+    pub async fn ensure_ip_allowed(&self, ip: Option<IpAddr>) -> Result<()> {
+        let Some(ip) = ip.filter(|_| self.config.enabled) else {
+            return Ok(());
+        };
+
+        match self.repo.find(&LoginThrottle::ip_key(&ip)).await? {
+            Some(entry) if entry.is_locked() => Err(AppError::too_many_requests(format!(
+                "too many failed login attempts, try again in {} seconds",
+                entry.retry_after_seconds()
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    pub async fn ensure_account_unlocked(&self, user_id: i64) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        match self.repo.find(&LoginThrottle::account_key(user_id)).await? {
+            Some(entry) if entry.is_locked() => Err(locked(&entry)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Counts a failed login. Returns the locked error when this failure
+    /// triggered the account lockout.
+    pub async fn record_failure(&self, user_id: Option<i64>, ip: Option<IpAddr>) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+        let now = Utc::now();
+
+        if let Some(ip) = ip {
+            let key = LoginThrottle::ip_key(&ip);
+            let window = Duration::seconds(self.config.ip_window_seconds);
+            let entry = self.repo.record_failure(&key, now, now - window).await?;
+            if entry.failed_attempts >= self.config.ip_max_attempts {
+                tracing::warn!(%ip, "client address throttled after failed logins");
+                self.repo.lock(&key, now + window).await?;
+            }
+        }
+
+        if let Some(user_id) = user_id {
+            let key = LoginThrottle::account_key(user_id);
+            let window = Duration::seconds(self.config.failure_window_seconds);
+            let entry = self.repo.record_failure(&key, now, now - window).await?;
+            if entry.failed_attempts >= self.config.max_failed_attempts {
+                let until = now + self.lockout_duration(entry.lockout_count);
+                tracing::warn!(user_id, %until, "account locked after failed logins");
+                self.repo.lock(&key, until).await?;
+
+                return Err(locked(&LoginThrottle {
+                    locked_until: Some(until),
+                    ..entry
+                }));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// A correct password clears the account's failures and lockout history.
+    pub async fn record_success(&self, user_id: i64) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        self.repo.reset(&LoginThrottle::account_key(user_id)).await
+    }
+
+    fn lockout_duration(&self, previous_lockouts: i32) -> Duration {
+        let factor = 1i64.checked_shl(previous_lockouts.clamp(0, 32) as u32);
+        let seconds = factor
+            .and_then(|f| self.config.base_lockout_seconds.checked_mul(f))
+            .unwrap_or(i64::MAX)
+            .min(self.config.max_lockout_seconds);
+
+        Duration::seconds(seconds)
+    }
+}
+
+fn locked(entry: &LoginThrottle) -> AppError {
+    AppError::account_locked(format!(
+        "account temporarily locked after too many failed login attempts, try again in {} seconds",
+        entry.retry_after_seconds()
+    ))
+}
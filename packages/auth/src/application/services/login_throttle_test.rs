@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::services::login_throttle::LoginThrottleService;
+    use crate::domain::entity::LoginThrottle;
+    use crate::domain::LoginThrottleRepository;
+    use crate::infrastructure::config::LockoutConfig;
+    use crate::infrastructure::repository::InMemoryLoginThrottleRepository;
+    use rust_reborn_contracts::AppError;
+    use std::net::IpAddr;
+    use std::sync::Arc;
+
+    fn setup(
+        config: LockoutConfig,
+    ) -> (Arc<InMemoryLoginThrottleRepository>, LoginThrottleService) {
+        let repo = Arc::new(InMemoryLoginThrottleRepository::new());
+        (repo.clone(), LoginThrottleService::new(repo, config))
+    }
+
+    fn ip(value: &str) -> Option<IpAddr> {
+        Some(value.parse().unwrap())
+    }
+
+    async fn locked_for_seconds(repo: &InMemoryLoginThrottleRepository, user_id: i64) -> i64 {
+        let entry = repo
+            .find(&LoginThrottle::account_key(user_id))
+            .await
+            .unwrap()
+            .unwrap();
+        (entry.locked_until.unwrap() - chrono::Utc::now()).num_seconds()
+    }
+
+    #[tokio::test]
+    async fn test_account_locks_after_max_failed_attempts() {
+        let (_, throttle) = setup(LockoutConfig {
+            max_failed_attempts: 3,
+            ..LockoutConfig::default()
+        });
+
+        for _ in 0..2 {
+            throttle.record_failure(Some(1), None).await.unwrap();
+        }
+        throttle.ensure_account_unlocked(1).await.unwrap();
+
+        let result = throttle.record_failure(Some(1), None).await;
+        assert!(matches!(result.unwrap_err(), AppError::AccountLocked(_)));
+
+        match throttle.ensure_account_unlocked(1).await.unwrap_err() {
+            AppError::AccountLocked(msg) => {
+                assert!(msg
+                    .starts_with("account temporarily locked after too many failed login attempts"))
+            }
+            _ => panic!("expected AccountLocked error"),
+        }
+        throttle.ensure_account_unlocked(2).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_lockout_duration_doubles_up_to_the_maximum() {
+        let (repo, throttle) = setup(LockoutConfig {
+            max_failed_attempts: 1,
+            base_lockout_seconds: 60,
+            max_lockout_seconds: 200,
+            ..LockoutConfig::default()
+        });
+
+        let mut durations = Vec::new();
+        for _ in 0..3 {
+            let _ = throttle.record_failure(Some(1), None).await;
+            durations.push(locked_for_seconds(&repo, 1).await);
+        }
+
+        assert!((59..=60).contains(&durations[0]));
+        assert!((119..=120).contains(&durations[1]));
+        assert!((199..=200).contains(&durations[2]));
+    }
+
+    #[tokio::test]
+    async fn test_successful_login_clears_failures() {
+        let (_, throttle) = setup(LockoutConfig {
+            max_failed_attempts: 2,
+            ..LockoutConfig::default()
+        });
+
+        throttle.record_failure(Some(1), None).await.unwrap();
+        throttle.record_success(1).await.unwrap();
+        throttle.record_failure(Some(1), None).await.unwrap();
+
+        throttle.ensure_account_unlocked(1).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_client_address_is_throttled_across_accounts() {
+        let (_, throttle) = setup(LockoutConfig {
+            ip_max_attempts: 3,
+            ..LockoutConfig::default()
+        });
+
+        throttle.record_failure(None, ip("10.0.0.1")).await.unwrap();
+        throttle
+            .record_failure(Some(1), ip("10.0.0.1"))
+            .await
+            .unwrap();
+        throttle.ensure_ip_allowed(ip("10.0.0.1")).await.unwrap();
+        throttle
+            .record_failure(Some(2), ip("10.0.0.1"))
+            .await
+            .unwrap();
+
+        let result = throttle.ensure_ip_allowed(ip("10.0.0.1")).await;
+        assert!(matches!(result.unwrap_err(), AppError::TooManyRequests(_)));
+        throttle.ensure_ip_allowed(ip("10.0.0.2")).await.unwrap();
+        throttle.ensure_ip_allowed(None).await.unwrap();
+        throttle.ensure_account_unlocked(1).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_disabled_policy_never_locks() {
+        let (repo, throttle) = setup(LockoutConfig {
+            enabled: false,
+            max_failed_attempts: 1,
+            ip_max_attempts: 1,
+            ..LockoutConfig::default()
+        });
+
+        throttle
+            .record_failure(Some(1), ip("10.0.0.1"))
+            .await
+            .unwrap();
+
+        throttle.ensure_account_unlocked(1).await.unwrap();
+        throttle.ensure_ip_allowed(ip("10.0.0.1")).await.unwrap();
+        assert!(repo
+            .find(&LoginThrottle::account_key(1))
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
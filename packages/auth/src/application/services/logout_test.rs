@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::services::test_fakes::{test_token_issued_at, test_user, TestDeps};
+    use crate::application::use_case::logout::LogoutUseCase;
+    use crate::application::use_case::logout_all_devices::LogoutAllDevicesUseCase;
+    use crate::domain::entity::{AuthEventType, Session};
+    use crate::domain::value_objects::ClientInfo;
+    use crate::domain::SessionRepository;
+    use rust_reborn_contracts::AppError;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    struct Fixture {
+        deps: TestDeps,
+        logout: LogoutUseCase,
+        logout_all: Arc<LogoutAllDevicesUseCase>,
+    }
+
+    fn setup() -> Fixture {
+        let deps = TestDeps::default();
+
+        Fixture {
+            logout: LogoutUseCase::new(
+                deps.verify_token(),
+                deps.revocations.clone(),
+                deps.refresh_tokens.clone(),
+                deps.sessions.clone(),
+                deps.recorder(),
+            ),
+            logout_all: deps.logout_all(),
+            deps,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_logout_revokes_current_token_only() {
+        let f = setup();
+        let user = test_user();
+        let current = f.deps.jwt_service.generate_token(&user, &[], None).unwrap();
+        let other = f.deps.jwt_service.generate_token(&user, &[], None).unwrap();
+
+        f.logout
+            .execute(&current, None, ClientInfo::default())
+            .await
+            .unwrap();
+
+        match f.deps.verify_token().execute(&current).await.unwrap_err() {
+            AppError::Unauthorized(msg) => assert_eq!(msg, "token has been revoked"),
+            _ => panic!("expected unauthorized error"),
+        }
+        assert_eq!(f.deps.verify_token().execute(&other).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_logout_twice_is_rejected() {
+        let f = setup();
+        let token = f
+            .deps
+            .jwt_service
+            .generate_token(&test_user(), &[], None)
+            .unwrap();
+
+        f.logout
+            .execute(&token, None, ClientInfo::default())
+            .await
+            .unwrap();
+        let result = f.logout.execute(&token, None, ClientInfo::default()).await;
+
+        assert!(matches!(result, Err(AppError::Unauthorized(_))));
+    }
+
+    #[tokio::test]
+    async fn test_logout_all_devices_revokes_every_token() {
+        let f = setup();
+        let user = test_user();
+        let earlier = chrono::Utc::now() - chrono::Duration::minutes(1);
+        let first = test_token_issued_at(&user, earlier);
+        let second = test_token_issued_at(&user, earlier);
+        // Has no session to revoke, like an impersonation token.
+        let same_second = f.deps.jwt_service.generate_token(&user, &[], None).unwrap();
+
+        f.logout_all
+            .execute(1, ClientInfo::default())
+            .await
+            .unwrap();
+
+        assert!(f.deps.verify_token().execute(&first).await.is_err());
+        assert!(f.deps.verify_token().execute(&second).await.is_err());
+        assert!(f.deps.verify_token().execute(&same_second).await.is_err());
+        // A login right afterwards, even within the same second, still works.
+        let session = Session::new(Uuid::new_v4(), 1, &ClientInfo::default());
+        f.deps.sessions.save(&session).await.unwrap();
+        let fresh = f
+            .deps
+            .jwt_service
+            .generate_token(&user, &[], Some(&session))
+            .unwrap();
+        assert_eq!(f.deps.verify_token().execute(&fresh).await.unwrap(), 1);
+        assert_eq!(
+            *f.deps.refresh_tokens.revoked_users.lock().unwrap(),
+            vec![1]
+        );
+
+        let events = f.deps.events.all();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, AuthEventType::LogoutAll);
+        assert_eq!(events[0].user_id, Some(1));
+    }
+}
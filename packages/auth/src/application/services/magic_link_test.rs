@@ -0,0 +1,174 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::dto::{LoginResponse, MagicLinkRequest, VerifyMagicLinkRequest};
+    use crate::application::services::test_fakes::{InMemoryMagicLinkRepository, TestDeps};
+    use crate::application::use_case::request_magic_link::RequestMagicLinkUseCase;
+    use crate::application::use_case::verify_magic_link::VerifyMagicLinkUseCase;
+    use crate::domain::entity::TotpFactor;
+    use crate::domain::value_objects::ClientInfo;
+    use crate::domain::UserRepository;
+    use rust_reborn_contracts::common::SecureTokenGenerator;
+    use rust_reborn_contracts::AppError;
+    use std::sync::Arc;
+
+    struct Fixture {
+        deps: TestDeps,
+        links: Arc<InMemoryMagicLinkRepository>,
+        request: RequestMagicLinkUseCase,
+        verify: VerifyMagicLinkUseCase,
+    }
+
+    fn setup_with(enabled: bool) -> Fixture {
+        let deps = TestDeps::default();
+        let links = Arc::new(InMemoryMagicLinkRepository::default());
+
+        Fixture {
+            links: links.clone(),
+            request: RequestMagicLinkUseCase::new(
+                deps.users.clone(),
+                links.clone(),
+                Arc::new(SecureTokenGenerator),
+                deps.notifier.clone(),
+                chrono::Duration::minutes(15),
+                enabled,
+            ),
+            verify: VerifyMagicLinkUseCase::new(
+                deps.users.clone(),
+                links,
+                deps.token_issuer(),
+                deps.mfa_challenges(),
+                deps.recorder(),
+                enabled,
+            ),
+            deps,
+        }
+    }
+
+    fn setup() -> Fixture {
+        setup_with(true)
+    }
+
+    fn request(email: &str) -> MagicLinkRequest {
+        MagicLinkRequest {
+            email: email.to_string(),
+        }
+    }
+
+    fn verify_request(token: &str) -> VerifyMagicLinkRequest {
+        VerifyMagicLinkRequest {
+            token: token.to_string(),
+        }
+    }
+
+    async fn send_link(f: &Fixture) -> String {
+        f.request
+            .execute(request("test@example.com"))
+            .await
+            .unwrap();
+        f.deps
+            .notifier
+            .last_magic_link_token()
+            .expect("magic link sent")
+    }
+
+    #[tokio::test]
+    async fn test_link_logs_in_once() {
+        let f = setup();
+        let token = send_link(&f).await;
+
+        let stored = f.links.tokens.lock().unwrap()[0].clone();
+        assert_ne!(stored.token_hash.value(), token);
+
+        let response = f
+            .verify
+            .execute(verify_request(&token), ClientInfo::default())
+            .await
+            .unwrap();
+        let LoginResponse::Authenticated(auth) = response else {
+            panic!("expected tokens");
+        };
+        assert_eq!(auth.user.email, "test@example.com");
+        assert!(!auth.token.access_token.is_empty());
+
+        let reused = f
+            .verify
+            .execute(verify_request(&token), ClientInfo::default())
+            .await;
+        assert!(matches!(reused, Err(AppError::Unauthorized(_))));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_email_sends_nothing() {
+        let f = setup();
+
+        f.request
+            .execute(request("nobody@example.com"))
+            .await
+            .unwrap();
+
+        assert!(f.deps.notifier.last_magic_link_token().is_none());
+        assert!(f.links.tokens.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_expired_link_is_rejected() {
+        let f = setup();
+        let token = send_link(&f).await;
+        f.links.tokens.lock().unwrap()[0].expires_at =
+            chrono::Utc::now() - chrono::Duration::seconds(1);
+
+        let result = f
+            .verify
+            .execute(verify_request(&token), ClientInfo::default())
+            .await;
+
+        assert!(matches!(result, Err(AppError::Unauthorized(_))));
+    }
+
+    #[tokio::test]
+    async fn test_new_link_replaces_the_previous_one() {
+        let f = setup();
+        let first = send_link(&f).await;
+        let second = send_link(&f).await;
+
+        let result = f
+            .verify
+            .execute(verify_request(&first), ClientInfo::default())
+            .await;
+        assert!(matches!(result, Err(AppError::Unauthorized(_))));
+
+        f.verify
+            .execute(verify_request(&second), ClientInfo::default())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_link_verifies_email_and_still_requires_mfa() {
+        let f = setup();
+        let mut factor = TotpFactor::new(1, "JBSWY3DPEHPK3PXP".to_string());
+        factor.confirmed_at = Some(chrono::Utc::now());
+        f.deps.mfa.factors.lock().unwrap().insert(1, factor);
+        let token = send_link(&f).await;
+
+        let response = f
+            .verify
+            .execute(verify_request(&token), ClientInfo::default())
+            .await
+            .unwrap();
+
+        assert!(matches!(response, LoginResponse::MfaRequired(_)));
+        let user = f.deps.users.find_by_id(1).await.unwrap().unwrap();
+        assert!(user.is_verified);
+    }
+
+    #[tokio::test]
+    async fn test_disabled_feature_is_refused() {
+        let f = setup_with(false);
+
+        let result = f.request.execute(request("test@example.com")).await;
+
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+        assert!(f.deps.notifier.last_magic_link_token().is_none());
+    }
+}
@@ -0,0 +1,220 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::port::account_notifier::AccountNotifier;
+    use crate::application::services::test_fakes::test_user;
+    use crate::domain::entity::Invitation;
+    use crate::domain::value_objects::Email;
+    use crate::infrastructure::mail_notifier::MailAccountNotifier;
+    use async_trait::async_trait;
+    use rust_reborn_contracts::mail::{
+        EmailMessage, FileMailer, MailTemplates, Mailer, TemplatedMailer,
+    };
+    use rust_reborn_contracts::{AppError, Result};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Default)]
+    struct RecordingMailer {
+        sent: Mutex<Vec<EmailMessage>>,
+    }
+
+    #[async_trait]
+    impl Mailer for RecordingMailer {
+        async fn send(&self, message: &EmailMessage) -> Result<()> {
+            self.sent.lock().unwrap().push(message.clone());
+            Ok(())
+        }
+    }
+
+    fn notifier(locale: &str) -> (Arc<RecordingMailer>, MailAccountNotifier) {
+        let mailer = Arc::new(RecordingMailer::default());
+        let templated = Arc::new(TemplatedMailer::new(
+            mailer.clone(),
+            MailTemplates::with_defaults(locale),
+        ));
+
+        (
+            mailer,
+            MailAccountNotifier::new(templated, "https://app.example.com/"),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_verification_email_contains_link() {
+        let (mailer, notifier) = notifier("en");
+        let mut user = test_user();
+        user.set_activation_key(
+            "hash".to_string(),
+            chrono::Utc::now() + chrono::Duration::hours(1),
+        );
+
+        notifier
+            .send_email_verification(&user, "ev-token")
+            .await
+            .unwrap();
+
+        let sent = mailer.sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].to, "test@example.com");
+        assert_eq!(sent[0].subject, "Verify your email address");
+        assert!(sent[0]
+            .text_body
+            .contains("https://app.example.com/verify-email?token=ev-token"));
+        assert!(sent[0]
+            .html_body
+            .contains("href=\"https://app.example.com/verify-email?token=ev-token\""));
+        assert!(!sent[0].text_body.contains("{{"));
+    }
+
+    #[tokio::test]
+    async fn test_password_reset_email_uses_default_locale() {
+        let (mailer, notifier) = notifier("id");
+
+        notifier
+            .send_password_reset(&test_user(), "pr-token")
+            .await
+            .unwrap();
+
+        let sent = mailer.sent.lock().unwrap();
+        assert_eq!(sent[0].subject, "Atur ulang password Anda");
+        assert!(sent[0].text_body.contains("/reset-password?token=pr-token"));
+    }
+
+    #[tokio::test]
+    async fn test_email_change_goes_to_pending_address() {
+        let (mailer, notifier) = notifier("en");
+        let mut user = test_user();
+        user.request_email_change(
+            Email::new("new@example.com".to_string()).unwrap(),
+            "hash".to_string(),
+            chrono::Utc::now() + chrono::Duration::hours(1),
+        );
+
+        notifier.send_email_change(&user, "ec-token").await.unwrap();
+
+        let sent = mailer.sent.lock().unwrap();
+        assert_eq!(sent[0].to, "new@example.com");
+        assert_eq!(sent[0].subject, "Confirm your new email address");
+        assert!(sent[0]
+            .text_body
+            .contains("/confirm-email-change?token=ec-token"));
+    }
+
+    #[tokio::test]
+    async fn test_magic_link_email_contains_link_and_expiry() {
+        let (mailer, notifier) = notifier("en");
+        let expires_at = chrono::DateTime::parse_from_rfc3339("2026-03-01T10:15:00Z")
+            .unwrap()
+            .to_utc();
+
+        notifier
+            .send_magic_link(&test_user(), "ml-token", expires_at)
+            .await
+            .unwrap();
+
+        let sent = mailer.sent.lock().unwrap();
+        assert_eq!(sent[0].to, "test@example.com");
+        assert_eq!(sent[0].subject, "Your sign-in link");
+        assert!(sent[0]
+            .text_body
+            .contains("https://app.example.com/magic-link?token=ml-token"));
+        assert!(sent[0].text_body.contains("2026-03-01 10:15 UTC"));
+    }
+
+    #[tokio::test]
+    async fn test_invitation_email_names_the_inviter() {
+        let (mailer, notifier) = notifier("en");
+        let invitation = Invitation::new(
+            "new@example.com".to_string(),
+            "editor".to_string(),
+            1,
+            "inv-token",
+            chrono::Utc::now() + chrono::Duration::hours(72),
+        );
+
+        notifier
+            .send_invitation(&invitation, &test_user(), "inv-token")
+            .await
+            .unwrap();
+
+        let sent = mailer.sent.lock().unwrap();
+        assert_eq!(sent[0].to, "new@example.com");
+        assert_eq!(sent[0].subject, "You have been invited");
+        assert!(sent[0]
+            .text_body
+            .contains("https://app.example.com/accept-invitation?token=inv-token"));
+        assert!(sent[0].text_body.contains("Test User"));
+    }
+
+    #[test]
+    fn test_render_escapes_html() {
+        let templates = MailTemplates::with_defaults("en");
+
+        let rendered = templates
+            .render(
+                "email_verification",
+                &[
+                    ("username", "<b>Eve</b>"),
+                    ("link", "x"),
+                    ("expires_at", ""),
+                ],
+            )
+            .unwrap();
+
+        assert_eq!(rendered.subject, "Verify your email address");
+        assert!(rendered.text.contains("Hi <b>Eve</b>,"));
+        assert!(rendered.html.contains("Hi &lt;b&gt;Eve&lt;/b&gt;,"));
+    }
+
+    #[test]
+    fn test_render_does_not_expand_placeholders_in_values() {
+        let templates = MailTemplates::with_defaults("en");
+
+        let rendered = templates
+            .render(
+                "email_verification",
+                &[
+                    ("username", "{{link}}"),
+                    ("link", "https://app.example.com/verify-email?token=t"),
+                    ("expires_at", ""),
+                ],
+            )
+            .unwrap();
+
+        assert!(rendered.text.starts_with("Hi {{link}},"));
+        assert!(rendered.html.starts_with("<p>Hi {{link}},</p>"));
+    }
+
+    #[test]
+    fn test_render_unknown_template() {
+        let templates = MailTemplates::with_defaults("en");
+
+        let result = templates.render("missing", &[]);
+
+        assert!(matches!(result, Err(AppError::Internal(_))));
+    }
+
+    #[tokio::test]
+    async fn test_file_mailer_writes_message() {
+        let dir = std::env::temp_dir().join(format!("reborn-mail-{}", uuid::Uuid::new_v4()));
+        let mailer = FileMailer::new(&dir).unwrap();
+
+        mailer
+            .send(&EmailMessage {
+                to: "test@example.com".to_string(),
+                subject: "Hello".to_string(),
+                text_body: "text".to_string(),
+                html_body: "<p>html</p>".to_string(),
+            })
+            .await
+            .unwrap();
+
+        let files: Vec<_> = std::fs::read_dir(&dir).unwrap().collect();
+        assert_eq!(files.len(), 1);
+        let content = std::fs::read_to_string(files[0].as_ref().unwrap().path()).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(json["to"], "test@example.com");
+        assert_eq!(json["subject"], "Hello");
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,52 @@
+use crate::application::dto::MfaChallengeResponse;
+use crate::domain::entity::MfaChallenge;
+use crate::domain::value_objects::TokenHash;
+use crate::domain::MfaRepository;
+use rust_reborn_contracts::common::CodeGenerator;
+use rust_reborn_contracts::Result;
+use std::sync::Arc;
+
+pub struct MfaChallengeService {
+    mfa_repo: Arc<dyn MfaRepository>,
+    token_generator: Arc<dyn CodeGenerator>,
+    ttl: chrono::Duration,
+}
+
+impl MfaChallengeService {
+    pub fn new(
+        mfa_repo: Arc<dyn MfaRepository>,
+        token_generator: Arc<dyn CodeGenerator>,
+        ttl: chrono::Duration,
+    ) -> Self {
+        Self {
+            mfa_repo,
+            token_generator,
+            ttl,
+        }
+    }
+
+    pub async fn is_required(&self, user_id: i64) -> Result<bool> {
+        Ok(self
+            .mfa_repo
+            .find_totp(user_id)
+            .await?
+            .is_some_and(|factor| factor.is_confirmed()))
+    }
+
+    /// Creates a pending challenge and returns its raw token.
+    pub async fn start(&self, user_id: i64) -> Result<MfaChallengeResponse> {
+        let token = self.token_generator.generate("mfa");
+        let mut challenge = MfaChallenge::new(
+            user_id,
+            TokenHash::of(&token),
+            chrono::Utc::now() + self.ttl,
+        );
+        self.mfa_repo.save_challenge(&mut challenge).await?;
+
+        Ok(MfaChallengeResponse {
+            mfa_required: true,
+            mfa_token: token,
+            expires_in: self.ttl.num_seconds(),
+        })
+    }
+}
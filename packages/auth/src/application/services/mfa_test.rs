@@ -0,0 +1,314 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::dto::{ConfirmTotpRequest, MfaLoginRequest};
+    use crate::application::services::login_throttle::LoginThrottleService;
+    use crate::application::services::mfa_challenge::MfaChallengeService;
+    use crate::application::services::test_fakes::{test_password_policy, TestDeps};
+    use crate::application::services::totp::TotpService;
+    use crate::application::use_case::confirm_totp::ConfirmTotpUseCase;
+    use crate::application::use_case::enroll_totp::EnrollTotpUseCase;
+    use crate::application::use_case::verify_mfa_login::VerifyMfaLoginUseCase;
+    use crate::domain::value_objects::ClientInfo;
+    use crate::infrastructure::config::LockoutConfig;
+    use crate::infrastructure::repository::InMemoryLoginThrottleRepository;
+    use rust_reborn_contracts::common::SecureTokenGenerator;
+    use rust_reborn_contracts::AppError;
+    use std::sync::Arc;
+
+    struct Fixture {
+        deps: TestDeps,
+        totp: Arc<TotpService>,
+        challenges: Arc<MfaChallengeService>,
+        enroll: EnrollTotpUseCase,
+        confirm: ConfirmTotpUseCase,
+        verify: VerifyMfaLoginUseCase,
+    }
+
+    fn setup() -> Fixture {
+        let deps = TestDeps::default();
+        let totp = Arc::new(TotpService::new("Rust Reborn"));
+
+        Fixture {
+            totp: totp.clone(),
+            challenges: deps.mfa_challenges(),
+            enroll: EnrollTotpUseCase::new(deps.users.clone(), deps.mfa.clone(), totp.clone()),
+            confirm: ConfirmTotpUseCase::new(deps.mfa.clone(), totp.clone(), 3),
+            verify: VerifyMfaLoginUseCase::new(
+                deps.users.clone(),
+                deps.mfa.clone(),
+                totp,
+                deps.token_issuer(),
+                test_password_policy(),
+                Arc::new(LoginThrottleService::new(
+                    Arc::new(InMemoryLoginThrottleRepository::new()),
+                    LockoutConfig {
+                        max_failed_attempts: 3,
+                        ..LockoutConfig::default()
+                    },
+                )),
+                deps.recorder(),
+                2,
+            ),
+            deps,
+        }
+    }
+
+    /// Enrolls and confirms TOTP for the test user using the previous time
+    /// step, so the current step is still unused afterwards.
+    async fn enable_totp(f: &Fixture) -> (String, Vec<String>) {
+        let secret = f.enroll.execute(1).await.unwrap().secret;
+        let code = f.totp.code_at(&secret, f.totp.current_step() - 1).unwrap();
+        let codes = f
+            .confirm
+            .execute(1, ConfirmTotpRequest { code })
+            .await
+            .unwrap()
+            .recovery_codes;
+        (secret, codes)
+    }
+
+    fn login_request(mfa_token: &str, code: &str) -> MfaLoginRequest {
+        MfaLoginRequest {
+            mfa_token: mfa_token.to_string(),
+            code: code.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_totp_matches_rfc6238_vector() {
+        // RFC 6238 appendix B, SHA-1 secret "12345678901234567890" at T = 59s.
+        let totp = TotpService::new("test");
+        let secret = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+        assert_eq!(totp.code_at(secret, 1).unwrap(), "287082");
+        assert_eq!(totp.verify_at(secret, "287082", 2).unwrap(), Some(1));
+        assert_eq!(totp.verify_at(secret, "287082", 3).unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_enroll_and_confirm_enables_mfa() {
+        let f = setup();
+
+        assert!(!f.challenges.is_required(1).await.unwrap());
+        let enrollment = f.enroll.execute(1).await.unwrap();
+        assert!(enrollment
+            .otpauth_uri
+            .starts_with("otpauth://totp/Rust%20Reborn:test@example.com?secret="));
+        assert!(enrollment.qr_svg.contains("<svg"));
+        assert!(!f.challenges.is_required(1).await.unwrap());
+
+        let (_, codes) = enable_totp(&f).await;
+
+        assert_eq!(codes.len(), 3);
+        assert!(f.challenges.is_required(1).await.unwrap());
+        assert!(!f.deps.mfa.recovery_codes.lock().unwrap()[0].1.contains('-'));
+        let result = f.enroll.execute(1).await;
+        assert!(matches!(result.unwrap_err(), AppError::Conflict(_)));
+    }
+
+    #[tokio::test]
+    async fn test_confirm_with_wrong_code_is_rejected() {
+        let f = setup();
+        f.enroll.execute(1).await.unwrap();
+
+        let result = f
+            .confirm
+            .execute(
+                1,
+                ConfirmTotpRequest {
+                    code: "abcdef".to_string(),
+                },
+            )
+            .await;
+
+        match result.unwrap_err() {
+            AppError::BadRequest(msg) => assert_eq!(msg, "invalid verification code"),
+            _ => panic!("expected BadRequest error"),
+        }
+        assert!(!f.deps.mfa.totp(1).unwrap().is_confirmed());
+    }
+
+    #[tokio::test]
+    async fn test_mfa_login_with_totp_code_is_single_use() {
+        let f = setup();
+        let (secret, _) = enable_totp(&f).await;
+        let code = f.totp.code_at(&secret, f.totp.current_step()).unwrap();
+
+        let challenge = f.challenges.start(1).await.unwrap();
+        let response = f
+            .verify
+            .execute(
+                login_request(&challenge.mfa_token, &code),
+                ClientInfo::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.user.id, 1);
+
+        // Same code with a new challenge: the time step was already used.
+        let challenge = f.challenges.start(1).await.unwrap();
+        let result = f
+            .verify
+            .execute(
+                login_request(&challenge.mfa_token, &code),
+                ClientInfo::default(),
+            )
+            .await;
+        match result.unwrap_err() {
+            AppError::Unauthorized(msg) => assert_eq!(msg, "invalid verification code"),
+            _ => panic!("expected Unauthorized error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mfa_token_cannot_be_reused() {
+        let f = setup();
+        let (_, codes) = enable_totp(&f).await;
+        let challenge = f.challenges.start(1).await.unwrap();
+
+        f.verify
+            .execute(
+                login_request(&challenge.mfa_token, &codes[0]),
+                ClientInfo::default(),
+            )
+            .await
+            .unwrap();
+        let result = f
+            .verify
+            .execute(
+                login_request(&challenge.mfa_token, &codes[1]),
+                ClientInfo::default(),
+            )
+            .await;
+
+        match result.unwrap_err() {
+            AppError::Unauthorized(msg) => assert_eq!(msg, "invalid or expired mfa token"),
+            _ => panic!("expected Unauthorized error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_recovery_code_is_single_use() {
+        let f = setup();
+        let (_, codes) = enable_totp(&f).await;
+
+        let challenge = f.challenges.start(1).await.unwrap();
+        f.verify
+            .execute(
+                login_request(
+                    &challenge.mfa_token,
+                    &codes[0].to_uppercase().replace('-', " "),
+                ),
+                ClientInfo::default(),
+            )
+            .await
+            .unwrap();
+
+        let challenge = f.challenges.start(1).await.unwrap();
+        let result = f
+            .verify
+            .execute(
+                login_request(&challenge.mfa_token, &codes[0]),
+                ClientInfo::default(),
+            )
+            .await;
+        assert!(matches!(result.unwrap_err(), AppError::Unauthorized(_)));
+    }
+
+    #[tokio::test]
+    async fn test_mfa_challenge_locks_after_max_attempts() {
+        let f = setup();
+        let (_, codes) = enable_totp(&f).await;
+        let challenge = f.challenges.start(1).await.unwrap();
+
+        for _ in 0..2 {
+            let result = f
+                .verify
+                .execute(
+                    login_request(&challenge.mfa_token, "000000-wrong"),
+                    ClientInfo::default(),
+                )
+                .await;
+            assert!(matches!(result.unwrap_err(), AppError::Unauthorized(_)));
+        }
+        let result = f
+            .verify
+            .execute(
+                login_request(&challenge.mfa_token, &codes[0]),
+                ClientInfo::default(),
+            )
+            .await;
+
+        match result.unwrap_err() {
+            AppError::Unauthorized(msg) => {
+                assert_eq!(msg, "too many invalid codes, please log in again")
+            }
+            _ => panic!("expected Unauthorized error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failed_codes_lock_the_account_across_challenges() {
+        let f = setup();
+        let (_, codes) = enable_totp(&f).await;
+
+        // Each challenge allows two codes; the account allows three in total.
+        for _ in 0..2 {
+            let challenge = f.challenges.start(1).await.unwrap();
+            let result = f
+                .verify
+                .execute(
+                    login_request(&challenge.mfa_token, "000000-wrong"),
+                    ClientInfo::default(),
+                )
+                .await;
+            assert!(matches!(result.unwrap_err(), AppError::Unauthorized(_)));
+        }
+
+        let challenge = f.challenges.start(1).await.unwrap();
+        let result = f
+            .verify
+            .execute(
+                login_request(&challenge.mfa_token, "000000-wrong"),
+                ClientInfo::default(),
+            )
+            .await;
+        assert!(matches!(result.unwrap_err(), AppError::AccountLocked(_)));
+
+        // A valid code no longer helps once the account is locked.
+        let challenge = f.challenges.start(1).await.unwrap();
+        let result = f
+            .verify
+            .execute(
+                login_request(&challenge.mfa_token, &codes[0]),
+                ClientInfo::default(),
+            )
+            .await;
+        assert!(matches!(result.unwrap_err(), AppError::AccountLocked(_)));
+    }
+
+    #[tokio::test]
+    async fn test_expired_mfa_token_is_rejected() {
+        let f = setup();
+        let (_, codes) = enable_totp(&f).await;
+        let expired = MfaChallengeService::new(
+            f.deps.mfa.clone(),
+            Arc::new(SecureTokenGenerator),
+            chrono::Duration::seconds(-1),
+        );
+        let challenge = expired.start(1).await.unwrap();
+
+        let result = f
+            .verify
+            .execute(
+                login_request(&challenge.mfa_token, &codes[0]),
+                ClientInfo::default(),
+            )
+            .await;
+
+        match result.unwrap_err() {
+            AppError::Unauthorized(msg) => assert_eq!(msg, "invalid or expired mfa token"),
+            _ => panic!("expected Unauthorized error"),
+        }
+    }
+}
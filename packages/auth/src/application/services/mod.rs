@@ -1 +1,35 @@
+pub mod admin_users_test;
+pub mod api_keys_test;
+pub mod auth_event_recorder;
+pub mod auth_events_test;
+pub mod breached_passwords_test;
+pub mod change_password_test;
+pub mod email_verification;
+pub mod email_verification_test;
+pub mod impersonation_test;
+pub mod invitations_test;
+pub mod jwt_test;
+pub mod login_throttle;
+pub mod login_throttle_test;
+pub mod logout_test;
+pub mod magic_link_test;
+pub mod mail_notifier_test;
+pub mod mfa_challenge;
+pub mod mfa_test;
+pub mod oauth_clients_test;
+pub mod oidc_test;
+pub mod organizations_test;
+pub mod password_hashing_test;
+pub mod password_policy;
+pub mod password_policy_test;
+pub mod password_reset_test;
+pub mod profile_test;
+pub mod rbac_test;
+pub mod refresh_token_test;
 pub mod register_test;
+pub mod sessions_test;
+pub mod tenant_resolver;
+#[cfg(test)]
+pub mod test_fakes;
+pub mod token_issuer;
+pub mod totp;
@@ -0,0 +1,166 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::auth_context::{AuthContext, Principal};
+    use crate::application::dto::RegisterOAuthClientRequest;
+    use crate::application::services::test_fakes::TestDeps;
+    use crate::application::use_case::disable_oauth_client::DisableOAuthClientUseCase;
+    use crate::application::use_case::issue_client_token::IssueClientTokenUseCase;
+    use crate::application::use_case::list_oauth_clients::ListOAuthClientsUseCase;
+    use crate::application::use_case::register_oauth_client::RegisterOAuthClientUseCase;
+    use crate::presentation::request_auth_context::RequestAuthContext;
+    use rust_reborn_contracts::common::{SecureTokenGenerator, UuidV7CodeGenerator};
+    use rust_reborn_contracts::AppError;
+    use std::sync::Arc;
+
+    const ADMIN_ID: i64 = 1;
+
+    struct Fixture {
+        deps: TestDeps,
+        register: RegisterOAuthClientUseCase,
+        list: ListOAuthClientsUseCase,
+        disable: DisableOAuthClientUseCase,
+        issue: IssueClientTokenUseCase,
+    }
+
+    fn setup() -> Fixture {
+        let deps = TestDeps::default();
+
+        Fixture {
+            register: RegisterOAuthClientUseCase::new(
+                deps.clients.clone(),
+                Arc::new(UuidV7CodeGenerator),
+                Arc::new(SecureTokenGenerator),
+            ),
+            list: ListOAuthClientsUseCase::new(deps.clients.clone()),
+            disable: DisableOAuthClientUseCase::new(deps.clients.clone()),
+            issue: IssueClientTokenUseCase::new(
+                deps.clients.clone(),
+                deps.jwt_service.clone(),
+                chrono::Duration::minutes(15),
+            ),
+            deps,
+        }
+    }
+
+    async fn register(f: &Fixture) -> (String, String, i64) {
+        let created = f
+            .register
+            .execute(
+                ADMIN_ID,
+                RegisterOAuthClientRequest {
+                    name: "billing worker".to_string(),
+                    scopes: vec!["products:write".to_string(), "products:read".to_string()],
+                },
+            )
+            .await
+            .unwrap();
+
+        (
+            created.client.client_id,
+            created.client_secret,
+            created.client.id,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_client_token_yields_service_principal_with_client_scopes() {
+        let f = setup();
+        let (client_id, secret, id) = register(&f).await;
+
+        let stored = f.deps.clients.get(id);
+        assert_ne!(stored.secret_hash.value(), secret);
+        assert_eq!(stored.created_by, Some(ADMIN_ID));
+
+        let token = f.issue.execute(&client_id, &secret, None).await.unwrap();
+        assert_eq!(token.token_type, "Bearer");
+        assert_eq!(token.expires_in, 900);
+        assert_eq!(token.scope, "products:read products:write");
+
+        let claims = f
+            .deps
+            .verify_token()
+            .verify(&token.access_token)
+            .await
+            .unwrap();
+        assert!(claims.is_service());
+        assert!(claims.user_id().is_err());
+
+        let ctx = RequestAuthContext::from_claims(claims).unwrap();
+        assert_eq!(ctx.principal(), Principal::Service(client_id.as_str()));
+        assert_eq!(ctx.user_id(), None);
+        assert!(ctx.has_permission("products:write"));
+        assert!(!ctx.has_permission("users:manage"));
+
+        assert!(f.deps.clients.get(id).last_used_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_requested_scope_narrows_the_token() {
+        let f = setup();
+        let (client_id, secret, _) = register(&f).await;
+
+        let token = f
+            .issue
+            .execute(&client_id, &secret, Some("products:read"))
+            .await
+            .unwrap();
+        assert_eq!(token.scope, "products:read");
+
+        let claims = f
+            .deps
+            .verify_token()
+            .verify(&token.access_token)
+            .await
+            .unwrap();
+        let ctx = RequestAuthContext::from_claims(claims).unwrap();
+        assert!(ctx.has_permission("products:read"));
+        assert!(!ctx.has_permission("products:write"));
+    }
+
+    #[tokio::test]
+    async fn test_scope_outside_the_client_allowance_is_rejected() {
+        let f = setup();
+        let (client_id, secret, _) = register(&f).await;
+
+        let result = f
+            .issue
+            .execute(&client_id, &secret, Some("products:read users:manage"))
+            .await;
+
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_wrong_secret_or_unknown_client_is_rejected() {
+        let f = setup();
+        let (client_id, _, _) = register(&f).await;
+
+        let wrong_secret = f.issue.execute(&client_id, "cs-wrong", None).await;
+        assert!(matches!(wrong_secret, Err(AppError::Unauthorized(_))));
+
+        let unknown = f.issue.execute("svc-unknown", "cs-wrong", None).await;
+        assert!(matches!(unknown, Err(AppError::Unauthorized(_))));
+    }
+
+    #[tokio::test]
+    async fn test_disabled_client_gets_no_tokens() {
+        let f = setup();
+        let (client_id, secret, id) = register(&f).await;
+        let issued = f.issue.execute(&client_id, &secret, None).await.unwrap();
+
+        f.disable.execute(ADMIN_ID, id).await.unwrap();
+
+        let verified = f.deps.verify_token().verify(&issued.access_token).await;
+        assert!(matches!(verified, Err(AppError::Unauthorized(_))));
+
+        let result = f.issue.execute(&client_id, &secret, None).await;
+        assert!(matches!(result, Err(AppError::Unauthorized(_))));
+
+        let listed = f.list.execute().await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert!(!listed[0].is_active);
+
+        let missing = f.disable.execute(ADMIN_ID, 99).await;
+        assert!(matches!(missing, Err(AppError::NotFound(_))));
+    }
+}
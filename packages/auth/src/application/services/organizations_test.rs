@@ -0,0 +1,290 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::auth_context::AuthContext;
+    use crate::application::authenticate_api_key::ApiKeyPrincipal;
+    use crate::application::create_organization::CreateOrganizationUseCase;
+    use crate::application::dto::{
+        CreateOrganizationRequest, SetMemberRolesRequest, SwitchOrganizationRequest,
+    };
+    use crate::application::list_my_organizations::ListMyOrganizationsUseCase;
+    use crate::application::services::tenant_resolver::TenantResolver;
+    use crate::application::services::test_fakes::{InMemoryOrganizationRepository, TestDeps};
+    use crate::application::set_organization_member::SetOrganizationMemberUseCase;
+    use crate::application::switch_organization::SwitchOrganizationUseCase;
+    use crate::domain::entity::{Role, Session};
+    use crate::domain::repository::{OrganizationRepository, SessionRepository};
+    use crate::domain::value_objects::ClientInfo;
+    use crate::presentation::request_auth_context::RequestAuthContext;
+    use rust_reborn_contracts::AppError;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    struct Fixture {
+        deps: TestDeps,
+        organizations: Arc<InMemoryOrganizationRepository>,
+        resolver: TenantResolver,
+        create: CreateOrganizationUseCase,
+        set_member: SetOrganizationMemberUseCase,
+        list_mine: ListMyOrganizationsUseCase,
+        switch: SwitchOrganizationUseCase,
+    }
+
+    fn setup() -> Fixture {
+        let deps = TestDeps::default();
+        let organizations = Arc::new(InMemoryOrganizationRepository::with_roles(vec![
+            Role::new("editor", vec!["products:write".to_string()]),
+            Role::new("viewer", vec!["products:read".to_string()]),
+        ]));
+
+        Fixture {
+            organizations: organizations.clone(),
+            resolver: TenantResolver::new(organizations.clone(), Some("App.Example.com.".into())),
+            create: CreateOrganizationUseCase::new(organizations.clone()),
+            set_member: SetOrganizationMemberUseCase::new(
+                organizations.clone(),
+                deps.users.clone(),
+            ),
+            list_mine: ListMyOrganizationsUseCase::new(organizations.clone()),
+            switch: SwitchOrganizationUseCase::new(
+                organizations,
+                deps.sessions.clone(),
+                deps.users.clone(),
+                deps.token_issuer(),
+            ),
+            deps,
+        }
+    }
+
+    async fn create(f: &Fixture, slug: &str) -> i64 {
+        f.create
+            .execute(CreateOrganizationRequest {
+                slug: slug.to_string(),
+                name: slug.to_uppercase(),
+            })
+            .await
+            .unwrap()
+            .id
+    }
+
+    async fn join(f: &Fixture, organization_id: i64, role: &str) {
+        f.set_member
+            .execute(
+                organization_id,
+                1,
+                SetMemberRolesRequest {
+                    roles: vec![role.to_string()],
+                },
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rejects_invalid_and_duplicate_slugs() {
+        let f = setup();
+        create(&f, "acme").await;
+
+        for slug in ["Acme", "-acme", "ac.me", ""] {
+            let result = f
+                .create
+                .execute(CreateOrganizationRequest {
+                    slug: slug.to_string(),
+                    name: "Acme".to_string(),
+                })
+                .await;
+            assert!(matches!(result, Err(AppError::BadRequest(_))), "{}", slug);
+        }
+
+        let result = f
+            .create
+            .execute(CreateOrganizationRequest {
+                slug: "acme".to_string(),
+                name: "Acme again".to_string(),
+            })
+            .await;
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_requested_tenant_must_be_a_membership() {
+        let f = setup();
+        let acme = create(&f, "acme").await;
+        create(&f, "globex").await;
+        join(&f, acme, "editor").await;
+
+        let membership = f
+            .resolver
+            .resolve(1, Some("acme"), None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(membership.organization_id, acme);
+        assert_eq!(membership.role_names(), vec!["editor"]);
+
+        for slug in ["globex", "initech"] {
+            let result = f.resolver.resolve(1, Some(slug), Some(acme)).await;
+            assert!(matches!(result, Err(AppError::Forbidden(_))), "{}", slug);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_claimed_tenant_is_dropped_once_membership_ends() {
+        let f = setup();
+        let acme = create(&f, "acme").await;
+        join(&f, acme, "viewer").await;
+
+        let membership = f.resolver.resolve(1, None, Some(acme)).await.unwrap();
+        assert!(membership.is_some());
+
+        assert!(f.organizations.remove_member(acme, 1).await.unwrap());
+        let membership = f.resolver.resolve(1, None, Some(acme)).await.unwrap();
+        assert!(membership.is_none());
+    }
+
+    #[test]
+    fn test_slug_from_host() {
+        let f = setup();
+
+        assert_eq!(
+            f.resolver.slug_from_host("acme.app.example.com:8080"),
+            Some("acme".to_string())
+        );
+        assert_eq!(
+            f.resolver.slug_from_host("ACME.App.Example.com"),
+            Some("acme".to_string())
+        );
+        assert_eq!(f.resolver.slug_from_host("app.example.com"), None);
+        assert_eq!(f.resolver.slug_from_host("a.b.app.example.com"), None);
+        assert_eq!(f.resolver.slug_from_host("acme.evilapp.example.com"), None);
+        assert_eq!(f.resolver.slug_from_host("acme.other.com"), None);
+
+        let no_subdomains = TenantResolver::new(f.organizations.clone(), None);
+        assert_eq!(no_subdomains.slug_from_host("acme.app.example.com"), None);
+    }
+
+    #[tokio::test]
+    async fn test_entering_a_tenant_grants_its_roles_only_within_it() {
+        let f = setup();
+        let acme = create(&f, "acme").await;
+        join(&f, acme, "editor").await;
+        let membership = f
+            .organizations
+            .find_membership(acme, 1)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let mut ctx = RequestAuthContext::authenticated(
+            1,
+            vec!["customer".to_string()],
+            vec!["orders:read".to_string()],
+        );
+        ctx.enter_tenant(&membership);
+        assert_eq!(ctx.tenant_id(), Some(acme));
+        assert!(ctx.has_role("customer"));
+        assert_eq!(ctx.tenant_roles(), ["editor".to_string()]);
+        // Tenant grants never satisfy a global check such as `/api/admin`.
+        assert!(!ctx.has_role("editor"));
+        assert!(!ctx.has_permission("products:write"));
+        assert!(ctx.has_tenant_permission("products:write"));
+        assert!(ctx.has_tenant_permission("orders:read"));
+
+        let mut key_ctx = RequestAuthContext::from_api_key(ApiKeyPrincipal {
+            key_id: 7,
+            user_id: 1,
+            permissions: vec!["orders:read".to_string()],
+        });
+        key_ctx.enter_tenant(&membership);
+        assert_eq!(key_ctx.tenant_id(), Some(acme));
+        assert!(!key_ctx.has_tenant_permission("products:write"));
+    }
+
+    #[tokio::test]
+    async fn test_switch_stores_the_tenant_on_the_session_and_token() {
+        let f = setup();
+        let acme = create(&f, "acme").await;
+        let globex = create(&f, "globex").await;
+        join(&f, acme, "viewer").await;
+        let session = Session::new(Uuid::new_v4(), 1, &ClientInfo::default());
+        f.deps.sessions.save(&session).await.unwrap();
+
+        let token = f
+            .switch
+            .execute(
+                1,
+                session.id,
+                SwitchOrganizationRequest {
+                    organization_id: Some(acme),
+                },
+            )
+            .await
+            .unwrap();
+        assert!(token.refresh_token.is_none());
+        assert_eq!(
+            f.deps.sessions.get(session.id).unwrap().organization_id,
+            Some(acme)
+        );
+        let claims = f
+            .deps
+            .jwt_service
+            .decode_token(&token.access_token)
+            .unwrap();
+        assert_eq!(claims.sid, Some(session.id));
+        assert_eq!(claims.tid, Some(acme));
+
+        let result = f
+            .switch
+            .execute(
+                1,
+                session.id,
+                SwitchOrganizationRequest {
+                    organization_id: Some(globex),
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+        assert_eq!(
+            f.deps.sessions.get(session.id).unwrap().organization_id,
+            Some(acme)
+        );
+
+        let token = f
+            .switch
+            .execute(
+                1,
+                session.id,
+                SwitchOrganizationRequest {
+                    organization_id: None,
+                },
+            )
+            .await
+            .unwrap();
+        let claims = f
+            .deps
+            .jwt_service
+            .decode_token(&token.access_token)
+            .unwrap();
+        assert_eq!(claims.tid, None);
+        assert_eq!(
+            f.deps.sessions.get(session.id).unwrap().organization_id,
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_lists_only_active_organizations_with_roles() {
+        let f = setup();
+        let acme = create(&f, "acme").await;
+        let globex = create(&f, "globex").await;
+        create(&f, "initech").await;
+        join(&f, acme, "editor").await;
+        join(&f, globex, "viewer").await;
+        f.organizations.organizations.lock().unwrap()[1].is_active = false;
+
+        let mine = f.list_mine.execute(1).await.unwrap();
+
+        assert_eq!(mine.len(), 1);
+        assert_eq!(mine[0].organization.slug, "acme");
+        assert_eq!(mine[0].roles, vec!["editor"]);
+    }
+}
@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::dto::{LoginRequest, LoginResponse};
+    use crate::application::port::password_hasher::PasswordHasher;
+    use crate::application::services::login_throttle::LoginThrottleService;
+    use crate::application::services::test_fakes::{test_password_policy, test_user, TestDeps};
+    use crate::application::use_case::login_user::LoginUserUseCase;
+    use crate::domain::value_objects::{ClientInfo, HashedPassword};
+    use crate::infrastructure::config::{
+        Argon2Config, LockoutConfig, PasswordAlgorithm, PasswordHashingConfig,
+    };
+    use crate::infrastructure::password::PasswordService;
+    use crate::infrastructure::repository::InMemoryLoginThrottleRepository;
+    use rust_reborn_contracts::Result;
+    use std::sync::Arc;
+
+    const PASSWORD: &str = "S3cure-Passw0rd!";
+
+    /// Cheap parameters; hashing cost is irrelevant to what is tested here.
+    fn config(algorithm: PasswordAlgorithm, memory_kib: u32) -> PasswordHashingConfig {
+        PasswordHashingConfig {
+            algorithm,
+            argon2: Argon2Config {
+                memory_kib,
+                iterations: 1,
+                parallelism: 1,
+            },
+            bcrypt_cost: 4,
+        }
+    }
+
+    fn service(algorithm: PasswordAlgorithm, memory_kib: u32) -> PasswordService {
+        PasswordService::new(&config(algorithm, memory_kib)).unwrap()
+    }
+
+    #[test]
+    fn test_argon2id_hash_verifies() {
+        let hasher = service(PasswordAlgorithm::Argon2id, 1024);
+
+        let hash = hasher.hash(PASSWORD).unwrap();
+
+        assert!(hash.starts_with("$argon2id$v=19$m=1024,t=1,p=1$"));
+        assert!(hasher.verify(PASSWORD, &hash).unwrap());
+        assert!(!hasher.verify("wrong-password", &hash).unwrap());
+        assert!(!hasher.needs_rehash(&hash));
+    }
+
+    #[test]
+    fn test_bcrypt_hash_still_verifies_and_needs_rehash() {
+        let bcrypt_hash = service(PasswordAlgorithm::Bcrypt, 1024)
+            .hash(PASSWORD)
+            .unwrap();
+        let hasher = service(PasswordAlgorithm::Argon2id, 1024);
+
+        assert!(hasher.verify(PASSWORD, &bcrypt_hash).unwrap());
+        assert!(!hasher.verify("wrong-password", &bcrypt_hash).unwrap());
+        assert!(hasher.needs_rehash(&bcrypt_hash));
+    }
+
+    #[test]
+    fn test_changed_parameters_need_rehash() {
+        let old_hash = service(PasswordAlgorithm::Argon2id, 1024)
+            .hash(PASSWORD)
+            .unwrap();
+        let hasher = service(PasswordAlgorithm::Argon2id, 2048);
+
+        assert!(hasher.verify(PASSWORD, &old_hash).unwrap());
+        assert!(hasher.needs_rehash(&old_hash));
+
+        let bcrypt = PasswordService::new(&PasswordHashingConfig {
+            bcrypt_cost: 5,
+            ..config(PasswordAlgorithm::Bcrypt, 1024)
+        })
+        .unwrap();
+        let cost_4 = service(PasswordAlgorithm::Bcrypt, 1024)
+            .hash(PASSWORD)
+            .unwrap();
+        assert!(bcrypt.needs_rehash(&cost_4));
+        assert!(bcrypt.needs_rehash(&old_hash));
+    }
+
+    #[test]
+    fn test_invalid_argon2_parameters_are_rejected() {
+        let result = PasswordService::new(&config(PasswordAlgorithm::Argon2id, 1));
+
+        assert!(result.is_err());
+    }
+
+    /// Accepts `legacy:<raw>` as an outdated hash and `hashed:<raw>` as current.
+    struct UpgradingHasher;
+
+    impl PasswordHasher for UpgradingHasher {
+        fn hash(&self, raw: &str) -> Result<String> {
+            Ok(format!("hashed:{}", raw))
+        }
+
+        fn verify(&self, raw: &str, hashed: &str) -> Result<bool> {
+            Ok(hashed == format!("hashed:{}", raw) || hashed == format!("legacy:{}", raw))
+        }
+
+        fn needs_rehash(&self, hashed: &str) -> bool {
+            hashed.starts_with("legacy:")
+        }
+    }
+
+    fn login_use_case(deps: &TestDeps) -> LoginUserUseCase {
+        LoginUserUseCase::new(
+            deps.users.clone(),
+            deps.token_issuer(),
+            Arc::new(UpgradingHasher),
+            test_password_policy(),
+            deps.mfa_challenges(),
+            Arc::new(LoginThrottleService::new(
+                Arc::new(InMemoryLoginThrottleRepository::new()),
+                LockoutConfig::default(),
+            )),
+            deps.recorder(),
+            false,
+        )
+    }
+
+    fn login_request(password: &str) -> LoginRequest {
+        LoginRequest {
+            username: "testuser".to_string(),
+            password: password.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_login_upgrades_outdated_hash() {
+        let mut user = test_user();
+        user.password = HashedPassword::new(format!("legacy:{}", PASSWORD));
+        let deps = TestDeps::default().with_users(vec![user]);
+        let login = login_use_case(&deps);
+
+        let response = login
+            .execute(login_request(PASSWORD), ClientInfo::default())
+            .await
+            .unwrap();
+
+        assert!(matches!(response, LoginResponse::Authenticated(_)));
+        assert_eq!(
+            deps.users.get(1).password.value(),
+            format!("hashed:{}", PASSWORD)
+        );
+
+        // The upgraded hash keeps working.
+        login
+            .execute(login_request(PASSWORD), ClientInfo::default())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_failed_login_keeps_outdated_hash() {
+        let mut user = test_user();
+        user.password = HashedPassword::new(format!("legacy:{}", PASSWORD));
+        let deps = TestDeps::default().with_users(vec![user]);
+
+        let result = login_use_case(&deps)
+            .execute(login_request("wrong-password"), ClientInfo::default())
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            deps.users.get(1).password.value(),
+            format!("legacy:{}", PASSWORD)
+        );
+    }
+}
@@ -0,0 +1,219 @@
+use crate::application::breached_password_checker::BreachedPasswordChecker;
+use crate::application::password_hasher::PasswordHasher;
+use crate::domain::{PasswordHistoryRepository, User};
+use crate::infrastructure::config::PasswordPolicyConfig;
+use chrono::{Duration, Utc};
+use rust_reborn_contracts::{AppError, Result};
+use std::borrow::Cow;
+use std::sync::Arc;
+use validator::{ValidationError, ValidationErrors};
+
+/// Checks new passwords against the configured rules and the user's recent
+/// passwords. Every broken rule is reported under the request field with
+/// its own code (`password_too_short`, `password_reused`, ...) so a form
+/// can show them all at once.
+pub struct PasswordPolicyService {
+    config: PasswordPolicyConfig,
+    history_repo: Arc<dyn PasswordHistoryRepository>,
+    password_hasher: Arc<dyn PasswordHasher>,
+    /// Set when `breached_passwords` is enabled.
+    breached_passwords: Option<Arc<dyn BreachedPasswordChecker>>,
+}
+
+impl PasswordPolicyService {
+    pub fn new(
+        config: PasswordPolicyConfig,
+        history_repo: Arc<dyn PasswordHistoryRepository>,
+        password_hasher: Arc<dyn PasswordHasher>,
+        breached_passwords: Option<Arc<dyn BreachedPasswordChecker>>,
+    ) -> Self {
+        Self {
+            config,
+            history_repo,
+            password_hasher,
+            breached_passwords,
+        }
+    }
+
+    /// Checks a password for an account that does not exist yet.
+    pub async fn check(
+        &self,
+        field: &'static str,
+        password: &str,
+        username: &str,
+        email: &str,
+    ) -> Result<()> {
+        into_result(field, self.violations(password, username, email).await?)
+    }
+
+    /// Checks a replacement for `user`'s password, including the history.
+    pub async fn check_for_user(
+        &self,
+        field: &'static str,
+        password: &str,
+        user: &User,
+    ) -> Result<()> {
+        let mut violations = self
+            .violations(password, &user.username, user.email.value())
+            .await?;
+
+        if self.config.history_size > 0 && self.is_reused(password, user).await? {
+            let mut error = rule(
+                "password_reused",
+                format!(
+                    "must not match any of your last {} passwords",
+                    self.config.history_size
+                ),
+            );
+            error.add_param(Cow::from("history"), &self.config.history_size);
+            violations.push(error);
+        }
+
+        into_result(field, violations)
+    }
+
+    /// Keeps `user`'s current hash for the history check. Call it before
+    /// the password is replaced.
+    pub async fn remember(&self, user: &User) -> Result<()> {
+        // The current password is checked from the user itself.
+        if self.config.history_size < 2 {
+            return Ok(());
+        }
+        let user_id = user
+            .id
+            .ok_or_else(|| AppError::internal("user must be persisted"))?;
+
+        self.history_repo
+            .add(user_id, &user.password, self.config.history_size - 1)
+            .await
+    }
+
+    /// Whether the password is older than the configured maximum age.
+    pub fn is_expired(&self, user: &User) -> bool {
+        self.config.max_age_days > 0
+            && user.password_changed_at + Duration::days(self.config.max_age_days) <= Utc::now()
+    }
+
+    async fn violations(
+        &self,
+        password: &str,
+        username: &str,
+        email: &str,
+    ) -> Result<Vec<ValidationError>> {
+        let config = &self.config;
+        let mut violations = Vec::new();
+        let length = password.chars().count();
+
+        if length < config.min_length {
+            let mut error = rule(
+                "password_too_short",
+                format!("must be at least {} characters", config.min_length),
+            );
+            error.add_param(Cow::from("min"), &config.min_length);
+            violations.push(error);
+        }
+        if length > config.max_length {
+            let mut error = rule(
+                "password_too_long",
+                format!("must be at most {} characters", config.max_length),
+            );
+            error.add_param(Cow::from("max"), &config.max_length);
+            violations.push(error);
+        }
+
+        let classes = [
+            (
+                config.require_uppercase,
+                char::is_uppercase as fn(char) -> bool,
+                "password_missing_uppercase",
+                "must contain an uppercase letter",
+            ),
+            (
+                config.require_lowercase,
+                char::is_lowercase,
+                "password_missing_lowercase",
+                "must contain a lowercase letter",
+            ),
+            (
+                config.require_digit,
+                char::is_numeric,
+                "password_missing_digit",
+                "must contain a digit",
+            ),
+            (
+                config.require_symbol,
+                |c: char| !c.is_alphanumeric(),
+                "password_missing_symbol",
+                "must contain a symbol",
+            ),
+        ];
+        for (required, matches, code, message) in classes {
+            if required && !password.chars().any(matches) {
+                violations.push(rule(code, message));
+            }
+        }
+
+        if config.disallow_user_info && contains_user_info(password, username, email) {
+            violations.push(rule(
+                "password_contains_user_info",
+                "must not contain your username or email",
+            ));
+        }
+
+        if let Some(checker) = &self.breached_passwords {
+            if checker.is_breached(password).await? {
+                violations.push(rule(
+                    "password_breached",
+                    "appears in a list of passwords exposed in data breaches",
+                ));
+            }
+        }
+
+        Ok(violations)
+    }
+
+    async fn is_reused(&self, password: &str, user: &User) -> Result<bool> {
+        let mut previous = vec![user.password.clone()];
+        if let Some(user_id) = user.id {
+            previous.extend(
+                self.history_repo
+                    .find_recent(user_id, self.config.history_size - 1)
+                    .await?,
+            );
+        }
+
+        for hash in previous {
+            if self.password_hasher.verify(password, hash.value())? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+fn rule(code: &'static str, message: impl Into<Cow<'static, str>>) -> ValidationError {
+    ValidationError::new(code).with_message(message.into())
+}
+
+/// Short usernames and local parts would match too much to be useful.
+fn contains_user_info(password: &str, username: &str, email: &str) -> bool {
+    let password = password.to_lowercase();
+    let local_part = email.split('@').next().unwrap_or_default();
+
+    [username, local_part]
+        .iter()
+        .filter(|part| part.chars().count() >= 3)
+        .any(|part| password.contains(&part.to_lowercase()))
+}
+
+fn into_result(field: &'static str, violations: Vec<ValidationError>) -> Result<()> {
+    if violations.is_empty() {
+        return Ok(());
+    }
+
+    let mut errors = ValidationErrors::new();
+    for error in violations {
+        errors.add(field, error);
+    }
+    Err(AppError::ValidationError(errors))
+}
@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::services::password_policy::PasswordPolicyService;
+    use crate::application::services::test_fakes::{
+        test_user, FakePasswordHasher, InMemoryPasswordHistoryRepository,
+    };
+    use crate::domain::value_objects::HashedPassword;
+    use crate::infrastructure::config::PasswordPolicyConfig;
+    use rust_reborn_contracts::{AppError, Result};
+    use std::sync::Arc;
+
+    fn policy(config: PasswordPolicyConfig) -> PasswordPolicyService {
+        PasswordPolicyService::new(
+            config,
+            Arc::new(InMemoryPasswordHistoryRepository::default()),
+            Arc::new(FakePasswordHasher),
+            None,
+        )
+    }
+
+    fn codes(result: Result<()>) -> Vec<String> {
+        match result {
+            Ok(()) => Vec::new(),
+            Err(AppError::ValidationError(errors)) => errors
+                .field_errors()
+                .values()
+                .flat_map(|errors| errors.iter().map(|e| e.code.to_string()))
+                .collect(),
+            Err(e) => panic!("expected a validation error, got {:?}", e),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reports_every_broken_rule_under_the_field() {
+        let policy = policy(PasswordPolicyConfig {
+            min_length: 12,
+            max_length: 16,
+            ..PasswordPolicyConfig::default()
+        });
+
+        let Err(AppError::ValidationError(errors)) = policy
+            .check("new_password", "short", "alice", "alice@example.com")
+            .await
+        else {
+            panic!("expected a validation error");
+        };
+        let errors = &errors.field_errors()["new_password"];
+        let broken: Vec<_> = errors.iter().map(|e| e.code.to_string()).collect();
+        assert_eq!(
+            broken,
+            vec![
+                "password_too_short",
+                "password_missing_uppercase",
+                "password_missing_digit",
+                "password_missing_symbol",
+            ]
+        );
+        assert_eq!(errors[0].params["min"], 12);
+
+        let result = policy
+            .check("password", "Très-L0ngue-Phrase!", "alice", "a@example.com")
+            .await;
+        assert_eq!(codes(result), vec!["password_too_long"]);
+        // Length is counted in characters, so 16 accented characters fit.
+        let result = policy
+            .check("password", "Éééééééééééé-1aÉ", "alice", "a@example.com")
+            .await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_character_classes_and_user_info_can_be_relaxed() {
+        let strict = policy(PasswordPolicyConfig::default());
+        let relaxed = policy(PasswordPolicyConfig {
+            require_uppercase: false,
+            require_lowercase: false,
+            require_digit: false,
+            require_symbol: false,
+            disallow_user_info: false,
+            ..PasswordPolicyConfig::default()
+        });
+
+        for password in ["MyAlice-2026!", "x-JSMITH-99x"] {
+            let result = strict
+                .check("password", password, "alice", "jsmith@example.com")
+                .await;
+            assert!(result.is_err(), "{}", password);
+        }
+        let result = strict
+            .check("password", "Jsm!th-2026x", "alice", "jsmith@example.com")
+            .await;
+        assert_eq!(codes(result), Vec::<String>::new());
+        let result = strict
+            .check("password", "x-JSMITH-99x", "bob", "jsmith@example.com")
+            .await;
+        assert_eq!(codes(result), vec!["password_contains_user_info"]);
+
+        let result = relaxed
+            .check("password", "alice alice", "alice", "alice@example.com")
+            .await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_history_refuses_recent_passwords() {
+        let policy = policy(PasswordPolicyConfig {
+            history_size: 3,
+            ..PasswordPolicyConfig::default()
+        });
+        let mut user = test_user();
+        user.password = HashedPassword::new("hashed:First-Passw0rd".to_string());
+
+        for next in ["Second-Passw0rd", "Third-Passw0rd", "Fourth-Passw0rd"] {
+            policy
+                .check_for_user("new_password", next, &user)
+                .await
+                .unwrap();
+            policy.remember(&user).await.unwrap();
+            user.change_password(HashedPassword::new(format!("hashed:{}", next)));
+        }
+
+        for reused in ["Fourth-Passw0rd", "Third-Passw0rd", "Second-Passw0rd"] {
+            let result = policy.check_for_user("new_password", reused, &user).await;
+            assert_eq!(codes(result), vec!["password_reused"], "{}", reused);
+        }
+        let result = policy
+            .check_for_user("new_password", "First-Passw0rd", &user)
+            .await;
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_password_expires_after_max_age() {
+        let mut user = test_user();
+        user.password_changed_at = chrono::Utc::now() - chrono::Duration::days(91);
+
+        assert!(!policy(PasswordPolicyConfig::default()).is_expired(&user));
+
+        let policy = policy(PasswordPolicyConfig {
+            max_age_days: 90,
+            ..PasswordPolicyConfig::default()
+        });
+        assert!(policy.is_expired(&user));
+
+        user.change_password(HashedPassword::new("hashed:New-Passw0rd".to_string()));
+        assert!(!policy.is_expired(&user));
+    }
+}
@@ -0,0 +1,176 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::dto::{ForgotPasswordRequest, ResetPasswordRequest};
+    use crate::application::services::test_fakes::{
+        test_password_policy, test_token_issued_at, test_user, FakePasswordHasher, TestDeps,
+    };
+    use crate::application::use_case::request_password_reset::RequestPasswordResetUseCase;
+    use crate::application::use_case::reset_password::ResetPasswordUseCase;
+    use crate::domain::entity::{AuthEventOutcome, AuthEventType};
+    use crate::domain::value_objects::ClientInfo;
+    use rust_reborn_contracts::common::SecureTokenGenerator;
+    use rust_reborn_contracts::AppError;
+    use std::sync::Arc;
+
+    const NEW_PASSWORD: &str = "N3w-Passw0rd!";
+
+    struct Fixture {
+        deps: TestDeps,
+        request: RequestPasswordResetUseCase,
+        reset: ResetPasswordUseCase,
+    }
+
+    fn setup(ttl: chrono::Duration) -> Fixture {
+        let deps = TestDeps::default();
+
+        Fixture {
+            request: RequestPasswordResetUseCase::new(
+                deps.users.clone(),
+                Arc::new(SecureTokenGenerator),
+                deps.notifier.clone(),
+                ttl,
+            ),
+            reset: ResetPasswordUseCase::new(
+                deps.users.clone(),
+                Arc::new(FakePasswordHasher),
+                test_password_policy(),
+                deps.logout_all(),
+                deps.recorder(),
+            ),
+            deps,
+        }
+    }
+
+    async fn request_token(f: &Fixture) -> String {
+        f.request
+            .execute(ForgotPasswordRequest {
+                email: "test@example.com".to_string(),
+            })
+            .await
+            .unwrap();
+        f.deps.notifier.last_password_reset_token().unwrap()
+    }
+
+    fn reset_request(token: &str, password: &str) -> ResetPasswordRequest {
+        ResetPasswordRequest {
+            token: token.to_string(),
+            new_password: password.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reset_password_success() {
+        let f = setup(chrono::Duration::minutes(60));
+        let token = request_token(&f).await;
+        assert_ne!(
+            f.deps.users.get(1).reset_key.as_deref(),
+            Some(token.as_str())
+        );
+
+        f.reset
+            .execute(reset_request(&token, NEW_PASSWORD), ClientInfo::default())
+            .await
+            .unwrap();
+
+        let user = f.deps.users.get(1);
+        assert_eq!(user.password.value(), format!("hashed:{}", NEW_PASSWORD));
+        assert!(user.reset_key.is_none());
+        assert!(user.reset_date.is_some());
+
+        let events = f.deps.events.all();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, AuthEventType::ResetPassword);
+        assert_eq!(events[0].outcome, AuthEventOutcome::Success);
+        assert_eq!(events[0].user_id, Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_reset_password_revokes_existing_sessions() {
+        let f = setup(chrono::Duration::minutes(60));
+        let access_token = test_token_issued_at(
+            &test_user(),
+            chrono::Utc::now() - chrono::Duration::minutes(1),
+        );
+        let token = request_token(&f).await;
+
+        f.reset
+            .execute(reset_request(&token, NEW_PASSWORD), ClientInfo::default())
+            .await
+            .unwrap();
+
+        assert!(f.deps.verify_token().execute(&access_token).await.is_err());
+        assert_eq!(
+            *f.deps.refresh_tokens.revoked_users.lock().unwrap(),
+            vec![1]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reset_token_is_single_use() {
+        let f = setup(chrono::Duration::minutes(60));
+        let token = request_token(&f).await;
+
+        f.reset
+            .execute(reset_request(&token, NEW_PASSWORD), ClientInfo::default())
+            .await
+            .unwrap();
+        let result = f
+            .reset
+            .execute(reset_request(&token, NEW_PASSWORD), ClientInfo::default())
+            .await;
+
+        match result.unwrap_err() {
+            AppError::BadRequest(msg) => assert_eq!(msg, "invalid reset token"),
+            _ => panic!("expected BadRequest error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reset_password_expired_token() {
+        let f = setup(chrono::Duration::seconds(-1));
+        let token = request_token(&f).await;
+
+        let result = f
+            .reset
+            .execute(reset_request(&token, NEW_PASSWORD), ClientInfo::default())
+            .await;
+
+        match result.unwrap_err() {
+            AppError::BadRequest(msg) => assert_eq!(msg, "reset token expired"),
+            _ => panic!("expected BadRequest error"),
+        }
+        assert_eq!(f.deps.users.get(1).password.value(), "hashed_password");
+        let events = f.deps.events.all();
+        assert_eq!(events[0].outcome, AuthEventOutcome::Failure);
+        assert_eq!(events[0].reason.as_deref(), Some("reset token expired"));
+    }
+
+    #[tokio::test]
+    async fn test_reset_password_rejects_weak_password() {
+        let f = setup(chrono::Duration::minutes(60));
+        let token = request_token(&f).await;
+
+        let result = f
+            .reset
+            .execute(reset_request(&token, "weakpassword"), ClientInfo::default())
+            .await;
+
+        assert!(matches!(result, Err(AppError::ValidationError(_))));
+        assert!(f.deps.users.get(1).reset_key.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_forgot_password_unknown_email_is_silent() {
+        let f = setup(chrono::Duration::minutes(60));
+
+        let result = f
+            .request
+            .execute(ForgotPasswordRequest {
+                email: "nobody@example.com".to_string(),
+            })
+            .await;
+
+        assert!(result.is_ok());
+        assert!(f.deps.notifier.password_resets.lock().unwrap().is_empty());
+    }
+}
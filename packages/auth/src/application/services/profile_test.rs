@@ -0,0 +1,244 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::dto::{
+        ChangeEmailRequest, ConfirmEmailChangeRequest, UpdateProfileRequest,
+    };
+    use crate::application::services::test_fakes::{test_user, FakePasswordHasher, TestDeps};
+    use crate::application::use_case::confirm_email_change::ConfirmEmailChangeUseCase;
+    use crate::application::use_case::request_email_change::RequestEmailChangeUseCase;
+    use crate::application::use_case::update_profile::UpdateProfileUseCase;
+    use crate::domain::entity::User;
+    use crate::domain::value_objects::{Email, HashedPassword};
+    use crate::domain::UserRepository;
+    use rust_reborn_contracts::common::SecureTokenGenerator;
+    use rust_reborn_contracts::AppError;
+    use std::sync::Arc;
+
+    const PASSWORD: &str = "Curr3nt-Passw0rd!";
+
+    struct Fixture {
+        deps: TestDeps,
+        update: UpdateProfileUseCase,
+        request: RequestEmailChangeUseCase,
+        confirm: ConfirmEmailChangeUseCase,
+    }
+
+    fn other_user() -> User {
+        let mut user = User::new(
+            "USR-TEST-002".to_string(),
+            Email::new("taken@example.com".to_string()).unwrap(),
+            "taken".to_string(),
+            HashedPassword::from("hashed_password".to_string()),
+            None,
+        );
+        user.id = Some(2);
+        user
+    }
+
+    fn setup(ttl: chrono::Duration) -> Fixture {
+        let mut user = test_user();
+        user.password = HashedPassword::new(format!("hashed:{}", PASSWORD));
+        let deps = TestDeps::default().with_users(vec![user, other_user()]);
+
+        Fixture {
+            update: UpdateProfileUseCase::new(deps.users.clone()),
+            request: RequestEmailChangeUseCase::new(
+                deps.users.clone(),
+                Arc::new(FakePasswordHasher),
+                Arc::new(SecureTokenGenerator),
+                deps.notifier.clone(),
+                ttl,
+            ),
+            confirm: ConfirmEmailChangeUseCase::new(deps.users.clone()),
+            deps,
+        }
+    }
+
+    fn change_email(new_email: &str, password: &str) -> ChangeEmailRequest {
+        ChangeEmailRequest {
+            new_email: new_email.to_string(),
+            current_password: password.to_string(),
+        }
+    }
+
+    fn last_token(f: &Fixture) -> String {
+        f.deps
+            .notifier
+            .email_changes
+            .lock()
+            .unwrap()
+            .last()
+            .map(|(_, token)| token.clone())
+            .expect("confirmation should be sent")
+    }
+
+    #[tokio::test]
+    async fn test_update_profile_changes_name_and_username() {
+        let f = setup(chrono::Duration::hours(1));
+
+        let response = f
+            .update
+            .execute(
+                1,
+                UpdateProfileRequest {
+                    full_name: Some("New Name".to_string()),
+                    username: Some("newname".to_string()),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.username, "newname");
+        assert_eq!(response.full_name.as_deref(), Some("New Name"));
+        let stored = f.deps.users.get(1);
+        assert_eq!(stored.updated_by.as_deref(), Some("USR-TEST-001"));
+        assert!(f
+            .deps
+            .users
+            .find_by_username("newname")
+            .await
+            .unwrap()
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn test_update_profile_rejects_taken_username() {
+        let f = setup(chrono::Duration::hours(1));
+
+        let result = f
+            .update
+            .execute(
+                1,
+                UpdateProfileRequest {
+                    full_name: None,
+                    username: Some("taken".to_string()),
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+        assert_eq!(f.deps.users.get(1).username, "testuser");
+    }
+
+    #[tokio::test]
+    async fn test_update_profile_keeps_own_username() {
+        let f = setup(chrono::Duration::hours(1));
+
+        let response = f
+            .update
+            .execute(
+                1,
+                UpdateProfileRequest {
+                    full_name: None,
+                    username: Some("testuser".to_string()),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.username, "testuser");
+        assert_eq!(response.full_name.as_deref(), Some("Test User"));
+    }
+
+    #[tokio::test]
+    async fn test_email_only_changes_after_confirmation() {
+        let f = setup(chrono::Duration::hours(1));
+
+        let pending = f
+            .request
+            .execute(1, change_email("new@example.com", PASSWORD))
+            .await
+            .unwrap();
+
+        assert_eq!(pending.email, "test@example.com");
+        assert_eq!(pending.pending_email.as_deref(), Some("new@example.com"));
+        let (sent_to, _) = f.deps.notifier.email_changes.lock().unwrap()[0].clone();
+        assert_eq!(sent_to, "new@example.com");
+
+        let confirmed = f
+            .confirm
+            .execute(ConfirmEmailChangeRequest {
+                token: last_token(&f),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(confirmed.email, "new@example.com");
+        assert!(confirmed.pending_email.is_none());
+        assert!(confirmed.is_verified);
+        assert_eq!(
+            f.deps.users.get(1).updated_by.as_deref(),
+            Some("USR-TEST-001")
+        );
+        assert!(f
+            .deps
+            .users
+            .find_by_email("test@example.com")
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_email_change_requires_current_password() {
+        let f = setup(chrono::Duration::hours(1));
+
+        let result = f
+            .request
+            .execute(1, change_email("new@example.com", "Wr0ng-Password!"))
+            .await;
+
+        assert!(matches!(result, Err(AppError::Unauthorized(_))));
+        assert!(f.deps.users.get(1).pending_email.is_none());
+        assert!(f.deps.notifier.email_changes.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_email_change_rejects_registered_address() {
+        let f = setup(chrono::Duration::hours(1));
+
+        let result = f
+            .request
+            .execute(1, change_email("taken@example.com", PASSWORD))
+            .await;
+
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_confirmation_token_is_single_use_and_expires() {
+        let f = setup(chrono::Duration::hours(1));
+        f.request
+            .execute(1, change_email("new@example.com", PASSWORD))
+            .await
+            .unwrap();
+        let token = last_token(&f);
+
+        f.confirm
+            .execute(ConfirmEmailChangeRequest {
+                token: token.clone(),
+            })
+            .await
+            .unwrap();
+        let reused = f.confirm.execute(ConfirmEmailChangeRequest { token }).await;
+        assert!(matches!(reused, Err(AppError::BadRequest(_))));
+
+        let expired = setup(chrono::Duration::seconds(-1));
+        expired
+            .request
+            .execute(1, change_email("new@example.com", PASSWORD))
+            .await
+            .unwrap();
+        let result = expired
+            .confirm
+            .execute(ConfirmEmailChangeRequest {
+                token: last_token(&expired),
+            })
+            .await;
+        match result.unwrap_err() {
+            AppError::BadRequest(msg) => assert_eq!(msg, "email change token expired"),
+            _ => panic!("expected BadRequest error"),
+        }
+        assert_eq!(expired.deps.users.get(1).email.value(), "test@example.com");
+    }
+}
@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::auth_context::AuthContext;
+    use crate::application::services::test_fakes::{test_user, InMemoryRoleRepository, TestDeps};
+    use crate::application::services::token_issuer::TokenIssuer;
+    use crate::domain::entity::Role;
+    use crate::domain::repository::RoleRepository;
+    use crate::domain::value_objects::ClientInfo;
+    use crate::infrastructure::jwt::JwtService;
+    use crate::presentation::request_auth_context::RequestAuthContext;
+    use rust_reborn_contracts::AppError;
+    use std::sync::Arc;
+
+    fn roles() -> Vec<Role> {
+        vec![
+            Role::new(
+                "admin",
+                vec!["products:write".to_string(), "users:manage".to_string()],
+            ),
+            Role::new("catalog_admin", vec!["products:write".to_string()]),
+        ]
+    }
+
+    fn setup() -> (
+        Arc<InMemoryRoleRepository>,
+        Arc<JwtService>,
+        Arc<TokenIssuer>,
+    ) {
+        let deps = TestDeps::default().with_roles(roles());
+        let issuer = deps.token_issuer();
+
+        (deps.roles, deps.jwt_service, issuer)
+    }
+
+    #[tokio::test]
+    async fn test_issued_token_embeds_roles_and_permissions() {
+        let (role_repo, jwt_service, issuer) = setup();
+        role_repo.assign_to_user(1, "admin").await.unwrap();
+        role_repo.assign_to_user(1, "catalog_admin").await.unwrap();
+
+        let token = issuer
+            .issue(&test_user(), &ClientInfo::default())
+            .await
+            .unwrap();
+        let claims = jwt_service.decode_token(&token.access_token).unwrap();
+
+        assert_eq!(claims.roles, vec!["admin", "catalog_admin"]);
+        assert_eq!(claims.permissions, vec!["products:write", "users:manage"]);
+    }
+
+    #[tokio::test]
+    async fn test_context_from_claims_checks_permissions() {
+        let (role_repo, jwt_service, issuer) = setup();
+        role_repo.assign_to_user(1, "catalog_admin").await.unwrap();
+
+        let token = issuer
+            .issue(&test_user(), &ClientInfo::default())
+            .await
+            .unwrap();
+        let claims = jwt_service.decode_token(&token.access_token).unwrap();
+        let ctx = RequestAuthContext::from_claims(claims).unwrap();
+
+        assert_eq!(ctx.user_id(), Some(1));
+        assert!(ctx.has_role("catalog_admin"));
+        assert!(!ctx.has_role("admin"));
+        assert!(ctx.has_permission("products:write"));
+        assert!(!ctx.has_permission("users:manage"));
+    }
+
+    #[tokio::test]
+    async fn test_user_without_roles_has_no_permissions() {
+        let (_, jwt_service, issuer) = setup();
+
+        let token = issuer
+            .issue(&test_user(), &ClientInfo::default())
+            .await
+            .unwrap();
+        let claims = jwt_service.decode_token(&token.access_token).unwrap();
+
+        assert!(claims.roles.is_empty());
+        assert!(claims.permissions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_assign_unknown_role() {
+        let (role_repo, _, _) = setup();
+
+        let result = role_repo.assign_to_user(1, "superuser").await;
+
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+}
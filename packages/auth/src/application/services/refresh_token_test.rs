@@ -0,0 +1,190 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::dto::RefreshTokenRequest;
+    use crate::application::services::test_fakes::{
+        test_jwt_service, test_user, InMemoryRoleRepository, InMemorySessionRepository,
+        InMemoryUserRepository,
+    };
+    use crate::application::services::token_issuer::TokenIssuer;
+    use crate::application::use_case::refresh_token::RefreshTokenUseCase;
+    use crate::domain::entity::RefreshToken;
+    use crate::domain::repository::RefreshTokenRepository;
+    use crate::domain::value_objects::ClientInfo;
+    use async_trait::async_trait;
+    use rust_reborn_contracts::common::SecureTokenGenerator;
+    use rust_reborn_contracts::{AppError, Result};
+    use std::sync::{Arc, Mutex};
+    use uuid::Uuid;
+
+    #[derive(Default)]
+    struct InMemoryRefreshTokenRepository {
+        tokens: Mutex<Vec<RefreshToken>>,
+    }
+
+    impl InMemoryRefreshTokenRepository {
+        fn family(&self, family_id: Uuid) -> Vec<RefreshToken> {
+            self.tokens
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|t| t.family_id == family_id)
+                .cloned()
+                .collect()
+        }
+    }
+
+    #[async_trait]
+    impl RefreshTokenRepository for InMemoryRefreshTokenRepository {
+        async fn save(&self, token: &mut RefreshToken) -> Result<()> {
+            let mut tokens = self.tokens.lock().unwrap();
+            token.id = Some(tokens.len() as i64 + 1);
+            tokens.push(token.clone());
+            Ok(())
+        }
+
+        async fn find_by_hash(&self, token_hash: &str) -> Result<Option<RefreshToken>> {
+            let tokens = self.tokens.lock().unwrap();
+            Ok(tokens
+                .iter()
+                .find(|t| t.token_hash.value() == token_hash)
+                .cloned())
+        }
+
+        async fn rotate(&self, current: &RefreshToken, next: &mut RefreshToken) -> Result<bool> {
+            let mut tokens = self.tokens.lock().unwrap();
+            let next_id = tokens.len() as i64 + 1;
+            let stored = tokens.iter_mut().find(|t| t.id == current.id).unwrap();
+            if stored.is_revoked() {
+                return Ok(false);
+            }
+            stored.revoked_at = Some(chrono::Utc::now());
+            stored.replaced_by = Some(next_id);
+            next.id = Some(next_id);
+            tokens.push(next.clone());
+            Ok(true)
+        }
+
+        async fn revoke_family(&self, family_id: Uuid) -> Result<()> {
+            let mut tokens = self.tokens.lock().unwrap();
+            for token in tokens.iter_mut().filter(|t| t.family_id == family_id) {
+                token.revoked_at.get_or_insert_with(chrono::Utc::now);
+            }
+            Ok(())
+        }
+
+        async fn revoke_all_for_user(&self, user_id: i64) -> Result<()> {
+            let mut tokens = self.tokens.lock().unwrap();
+            for token in tokens.iter_mut().filter(|t| t.user_id == user_id) {
+                token.revoked_at.get_or_insert_with(chrono::Utc::now);
+            }
+            Ok(())
+        }
+    }
+
+    fn setup() -> (
+        Arc<InMemoryRefreshTokenRepository>,
+        Arc<TokenIssuer>,
+        RefreshTokenUseCase,
+    ) {
+        let repo = Arc::new(InMemoryRefreshTokenRepository::default());
+        let jwt_service = Arc::new(test_jwt_service());
+        let issuer = Arc::new(TokenIssuer::new(
+            jwt_service,
+            repo.clone(),
+            Arc::new(InMemoryRoleRepository::default()),
+            Arc::new(InMemorySessionRepository::default()),
+            Arc::new(SecureTokenGenerator),
+        ));
+        let use_case = RefreshTokenUseCase::new(
+            Arc::new(InMemoryUserRepository::with_user(test_user())),
+            repo.clone(),
+            issuer.clone(),
+        );
+
+        (repo, issuer, use_case)
+    }
+
+    fn request(token: &str) -> RefreshTokenRequest {
+        RefreshTokenRequest {
+            refresh_token: token.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_refresh_rotates_token() {
+        let (repo, issuer, use_case) = setup();
+        let issued = issuer
+            .issue(&test_user(), &ClientInfo::default())
+            .await
+            .unwrap();
+        let first = issued.refresh_token.unwrap();
+
+        let response = use_case.execute(request(&first)).await.unwrap();
+        let second = response.refresh_token.unwrap();
+
+        assert_ne!(first, second);
+        assert_eq!(response.token_type, "Bearer");
+        assert_eq!(response.expires_in, 3600);
+
+        let family_id = repo.tokens.lock().unwrap()[0].family_id;
+        let family = repo.family(family_id);
+        assert_eq!(family.len(), 2);
+        assert!(family[0].is_revoked());
+        assert_eq!(family[0].replaced_by, family[1].id);
+        assert!(!family[1].is_revoked());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_reuse_revokes_family() {
+        let (repo, issuer, use_case) = setup();
+        let issued = issuer
+            .issue(&test_user(), &ClientInfo::default())
+            .await
+            .unwrap();
+        let first = issued.refresh_token.unwrap();
+
+        let second = use_case
+            .execute(request(&first))
+            .await
+            .unwrap()
+            .refresh_token
+            .unwrap();
+
+        let replay = use_case.execute(request(&first)).await;
+        assert!(matches!(replay, Err(AppError::Unauthorized(_))));
+
+        let family_id = repo.tokens.lock().unwrap()[0].family_id;
+        assert!(repo.family(family_id).iter().all(|t| t.is_revoked()));
+
+        let after_reuse = use_case.execute(request(&second)).await;
+        assert!(matches!(after_reuse, Err(AppError::Unauthorized(_))));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_expired_token() {
+        let (repo, _, use_case) = setup();
+        let mut expired = RefreshToken::new(
+            1,
+            Uuid::new_v4(),
+            crate::domain::value_objects::TokenHash::of("rt-expired"),
+            chrono::Utc::now() - chrono::Duration::minutes(1),
+        );
+        repo.save(&mut expired).await.unwrap();
+
+        let result = use_case.execute(request("rt-expired")).await;
+
+        match result.unwrap_err() {
+            AppError::Unauthorized(msg) => assert_eq!(msg, "refresh token expired"),
+            _ => panic!("expected unauthorized error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_refresh_unknown_token() {
+        let (_, _, use_case) = setup();
+
+        let result = use_case.execute(request("rt-unknown")).await;
+
+        assert!(matches!(result, Err(AppError::Unauthorized(_))));
+    }
+}
@@ -2,10 +2,14 @@
 mod tests {
     use crate::application::dto::RegisterRequest;
     use crate::application::password_hasher::PasswordHasher;
+    use crate::application::services::email_verification::EmailVerificationService;
+    use crate::application::services::test_fakes::{
+        test_event_recorder, test_password_policy, RecordingNotifier,
+    };
     use crate::application::use_case::register_user::RegisterUserUseCase;
     use crate::domain::entity::User;
-    use crate::domain::repository::user_repository::UserRepository;
-    use crate::domain::value_objects::{Email, HashedPassword};
+    use crate::domain::repository::user_repository::{UserFilter, UserRepository};
+    use crate::domain::value_objects::{ClientInfo, Email, HashedPassword};
     use async_trait::async_trait;
     use rust_reborn_contracts::common::CodeGenerator;
     use rust_reborn_contracts::{AppError, Result};
@@ -85,6 +89,36 @@ mod tests {
             })
         }
 
+        async fn find_by_activation_key(&self, _: &str) -> Result<Option<User>> {
+            if self.should_fail {
+                return Err(AppError::internal("database error"));
+            }
+            Ok(None)
+        }
+
+        async fn find_by_reset_key(&self, _: &str) -> Result<Option<User>> {
+            if self.should_fail {
+                return Err(AppError::internal("database error"));
+            }
+            Ok(None)
+        }
+
+        async fn consume_reset_key(&self, _: &str) -> Result<bool> {
+            Ok(false)
+        }
+
+        async fn find_by_email_change_key(&self, _: &str) -> Result<Option<User>> {
+            Ok(None)
+        }
+
+        async fn find_all(&self, _: &UserFilter, _: u32, _: u32) -> Result<Vec<User>> {
+            Ok(Vec::new())
+        }
+
+        async fn count(&self, _: &UserFilter) -> Result<u64> {
+            Ok(0)
+        }
+
         async fn update(&self, user: &User) -> Result<User> {
             if self.should_fail {
                 return Err(AppError::internal("database error"));
@@ -130,13 +164,18 @@ mod tests {
             email: Email::new("test@example.com".to_string()).unwrap(),
             username: "testuser".to_string(),
             password: HashedPassword::from("hashed_password".to_string()),
+            password_changed_at: chrono::Utc::now(),
             full_name: Some("Test User".to_string()),
             is_active: true,
             is_verified: false,
             activation_key: None,
+            activation_key_expires_at: None,
             reset_key: None,
             reset_key_expires_at: None,
             reset_date: None,
+            pending_email: None,
+            email_change_key: None,
+            email_change_key_expires_at: None,
             status: 1,
             created_by: None,
             updated_by: None,
@@ -147,6 +186,14 @@ mod tests {
         }
     }
 
+    fn email_verification(notifier: Arc<RecordingNotifier>) -> Arc<EmailVerificationService> {
+        Arc::new(EmailVerificationService::new(
+            Arc::new(FakeCodeGenerator),
+            notifier,
+            chrono::Duration::hours(24),
+        ))
+    }
+
     fn create_register_request() -> RegisterRequest {
         RegisterRequest {
             email: "test@example.com".to_string(),
@@ -161,11 +208,19 @@ mod tests {
         let repo = Arc::new(FakeUserRepository::new());
         let password_hasher = Arc::new(FakePasswordHasher);
         let code_generator = Arc::new(FakeCodeGenerator);
-
-        let use_case = RegisterUserUseCase::new(repo.clone(), password_hasher, code_generator);
+        let notifier = Arc::new(RecordingNotifier::default());
+
+        let use_case = RegisterUserUseCase::new(
+            repo.clone(),
+            password_hasher,
+            test_password_policy(),
+            code_generator,
+            email_verification(notifier.clone()),
+            test_event_recorder(),
+        );
         let request = create_register_request();
 
-        let result = use_case.execute(request).await;
+        let result = use_case.execute(request, ClientInfo::default()).await;
         println!("{:?} ", result);
         assert!(result.is_ok());
         let response = result.unwrap();
@@ -177,6 +232,14 @@ mod tests {
 
         let saved_user = repo.saved_user.lock().unwrap();
         assert!(saved_user.is_some());
+
+        let saved_user = saved_user.as_ref().unwrap();
+        assert!(saved_user.activation_key.is_some());
+        assert!(!saved_user.activation_key_expired());
+        assert_eq!(
+            notifier.verifications.lock().unwrap()[0].0,
+            "test@example.com"
+        );
     }
 
     #[tokio::test]
@@ -185,10 +248,17 @@ mod tests {
         let password_hasher = Arc::new(FakePasswordHasher);
         let code_generator = Arc::new(FakeCodeGenerator);
 
-        let use_case = RegisterUserUseCase::new(repo, password_hasher, code_generator);
+        let use_case = RegisterUserUseCase::new(
+            repo,
+            password_hasher,
+            test_password_policy(),
+            code_generator,
+            email_verification(Arc::new(RecordingNotifier::default())),
+            test_event_recorder(),
+        );
         let request = create_register_request();
 
-        let result = use_case.execute(request).await;
+        let result = use_case.execute(request, ClientInfo::default()).await;
 
         assert!(result.is_err());
         match result.unwrap_err() {
@@ -203,10 +273,17 @@ mod tests {
         let password_hasher = Arc::new(FakePasswordHasher);
         let code_generator = Arc::new(FakeCodeGenerator);
 
-        let use_case = RegisterUserUseCase::new(repo, password_hasher, code_generator);
+        let use_case = RegisterUserUseCase::new(
+            repo,
+            password_hasher,
+            test_password_policy(),
+            code_generator,
+            email_verification(Arc::new(RecordingNotifier::default())),
+            test_event_recorder(),
+        );
         let request = create_register_request();
 
-        let result = use_case.execute(request).await;
+        let result = use_case.execute(request, ClientInfo::default()).await;
 
         assert!(result.is_err());
         match result.unwrap_err() {
@@ -221,11 +298,18 @@ mod tests {
         let password_hasher = Arc::new(FakePasswordHasher);
         let code_generator = Arc::new(FakeCodeGenerator);
 
-        let use_case = RegisterUserUseCase::new(repo, password_hasher, code_generator);
+        let use_case = RegisterUserUseCase::new(
+            repo,
+            password_hasher,
+            test_password_policy(),
+            code_generator,
+            email_verification(Arc::new(RecordingNotifier::default())),
+            test_event_recorder(),
+        );
         let mut request = create_register_request();
         request.email = "invalid-email".to_string();
 
-        let result = use_case.execute(request).await;
+        let result = use_case.execute(request, ClientInfo::default()).await;
 
         assert!(result.is_err());
         match result.unwrap_err() {
@@ -240,17 +324,35 @@ mod tests {
         let password_hasher = Arc::new(FakePasswordHasher);
         let code_generator = Arc::new(FakeCodeGenerator);
 
-        let use_case = RegisterUserUseCase::new(repo, password_hasher, code_generator);
+        let use_case = RegisterUserUseCase::new(
+            repo,
+            password_hasher,
+            test_password_policy(),
+            code_generator,
+            email_verification(Arc::new(RecordingNotifier::default())),
+            test_event_recorder(),
+        );
         let mut request = create_register_request();
         request.password = "weak".to_string();
 
-        let result = use_case.execute(request).await;
-
-        assert!(result.is_err());
-        match result.unwrap_err() {
-            AppError::BadRequest(_) => {}
-            _ => panic!("expected BadRequest error"),
-        }
+        let result = use_case.execute(request, ClientInfo::default()).await;
+
+        let Err(AppError::ValidationError(errors)) = result else {
+            panic!("expected a validation error");
+        };
+        let codes: Vec<_> = errors.field_errors()["password"]
+            .iter()
+            .map(|e| e.code.to_string())
+            .collect();
+        assert_eq!(
+            codes,
+            vec![
+                "password_too_short",
+                "password_missing_uppercase",
+                "password_missing_digit",
+                "password_missing_symbol",
+            ]
+        );
     }
 
     #[tokio::test]
@@ -259,10 +361,17 @@ mod tests {
         let password_hasher = Arc::new(FakePasswordHasher);
         let code_generator = Arc::new(FakeCodeGenerator);
 
-        let use_case = RegisterUserUseCase::new(repo, password_hasher, code_generator);
+        let use_case = RegisterUserUseCase::new(
+            repo,
+            password_hasher,
+            test_password_policy(),
+            code_generator,
+            email_verification(Arc::new(RecordingNotifier::default())),
+            test_event_recorder(),
+        );
         let request = create_register_request();
 
-        let result = use_case.execute(request).await;
+        let result = use_case.execute(request, ClientInfo::default()).await;
 
         assert!(result.is_err());
         match result.unwrap_err() {
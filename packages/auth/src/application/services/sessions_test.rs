@@ -0,0 +1,125 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::services::test_fakes::{test_user, TestDeps};
+    use crate::application::services::token_issuer::TokenIssuer;
+    use crate::application::use_case::list_sessions::ListSessionsUseCase;
+    use crate::application::use_case::logout::LogoutUseCase;
+    use crate::application::use_case::revoke_session::RevokeSessionUseCase;
+    use crate::application::use_case::verify_token::VerifyTokenUseCase;
+    use crate::domain::value_objects::ClientInfo;
+    use rust_reborn_contracts::AppError;
+    use std::sync::Arc;
+
+    struct Fixture {
+        deps: TestDeps,
+        issuer: Arc<TokenIssuer>,
+        verify: Arc<VerifyTokenUseCase>,
+        list: ListSessionsUseCase,
+        revoke: RevokeSessionUseCase,
+        logout: LogoutUseCase,
+    }
+
+    fn setup() -> Fixture {
+        let deps = TestDeps::default();
+        let verify = deps.verify_token();
+
+        Fixture {
+            issuer: deps.token_issuer(),
+            verify: verify.clone(),
+            list: ListSessionsUseCase::new(deps.sessions.clone(), chrono::Duration::days(7)),
+            revoke: RevokeSessionUseCase::new(deps.sessions.clone(), deps.refresh_tokens.clone()),
+            logout: LogoutUseCase::new(
+                verify,
+                deps.revocations.clone(),
+                deps.refresh_tokens.clone(),
+                deps.sessions.clone(),
+                deps.recorder(),
+            ),
+            deps,
+        }
+    }
+
+    fn laptop() -> ClientInfo {
+        ClientInfo {
+            ip: Some("203.0.113.7".parse().unwrap()),
+            user_agent: Some("Mozilla/5.0 (X11; Linux x86_64)".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_login_records_session_with_client_details() {
+        let f = setup();
+        let token = f.issuer.issue(&test_user(), &laptop()).await.unwrap();
+        let claims = f.verify.verify(&token.access_token).await.unwrap();
+        let session_id = claims.sid.expect("access token should carry a session id");
+
+        let session = f.deps.sessions.get(session_id).unwrap();
+        assert_eq!(session.user_id, 1);
+        assert_eq!(session.ip_address.as_deref(), Some("203.0.113.7"));
+        assert_eq!(
+            session.user_agent.as_deref(),
+            Some("Mozilla/5.0 (X11; Linux x86_64)")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_marks_the_current_session() {
+        let f = setup();
+        let current = f.issuer.issue(&test_user(), &laptop()).await.unwrap();
+        f.issuer
+            .issue(&test_user(), &ClientInfo::default())
+            .await
+            .unwrap();
+        let sid = f.verify.verify(&current.access_token).await.unwrap().sid;
+
+        let sessions = f.list.execute(1, sid).await.unwrap();
+
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions.iter().filter(|s| s.current).count(), 1);
+        assert!(sessions.iter().any(|s| s.current && Some(s.id) == sid));
+    }
+
+    #[tokio::test]
+    async fn test_revoked_session_rejects_its_access_token() {
+        let f = setup();
+        let token = f.issuer.issue(&test_user(), &laptop()).await.unwrap();
+        let other = f.issuer.issue(&test_user(), &laptop()).await.unwrap();
+        let sid = f.verify.verify(&token.access_token).await.unwrap().sid;
+
+        f.revoke.execute(1, sid.unwrap()).await.unwrap();
+
+        match f.verify.verify(&token.access_token).await.unwrap_err() {
+            AppError::Unauthorized(msg) => assert_eq!(msg, "session has been revoked"),
+            _ => panic!("expected unauthorized error"),
+        }
+        assert!(f.verify.verify(&other.access_token).await.is_ok());
+        assert_eq!(f.list.execute(1, None).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_cannot_revoke_another_users_session() {
+        let f = setup();
+        let token = f.issuer.issue(&test_user(), &laptop()).await.unwrap();
+        let sid = f.verify.verify(&token.access_token).await.unwrap().sid;
+
+        let result = f.revoke.execute(2, sid.unwrap()).await;
+
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+        assert!(f.verify.verify(&token.access_token).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_logout_ends_the_session() {
+        let f = setup();
+        let token = f.issuer.issue(&test_user(), &laptop()).await.unwrap();
+        let sid = f.verify.verify(&token.access_token).await.unwrap().sid;
+
+        f.logout
+            .execute(&token.access_token, None, ClientInfo::default())
+            .await
+            .unwrap();
+
+        assert!(f.deps.sessions.get(sid.unwrap()).unwrap().is_revoked());
+        assert!(f.list.execute(1, None).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,81 @@
+use crate::domain::entity::Membership;
+use crate::domain::OrganizationRepository;
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+/// Works out which organization a request acts in and what the user may
+/// do there. A tenant named by the request (`X-Tenant` header or
+/// subdomain) must be one the user belongs to; otherwise the session's
+/// active tenant from the token is used while the membership lasts.
+pub struct TenantResolver {
+    organization_repo: Arc<dyn OrganizationRepository>,
+    /// Requests to `<slug>.<base_domain>` name the tenant by subdomain.
+    base_domain: Option<String>,
+}
+
+impl TenantResolver {
+    pub fn new(
+        organization_repo: Arc<dyn OrganizationRepository>,
+        base_domain: Option<String>,
+    ) -> Self {
+        Self {
+            organization_repo,
+            base_domain: base_domain.map(|d| d.trim_matches('.').to_ascii_lowercase()),
+        }
+    }
+
+    /// Tenant slug named by a `Host` header, if subdomains are configured.
+    pub fn slug_from_host(&self, host: &str) -> Option<String> {
+        let base_domain = self.base_domain.as_deref()?;
+        let host = host.split(':').next()?.to_ascii_lowercase();
+        let slug = host.strip_suffix(base_domain)?.strip_suffix('.')?;
+
+        (!slug.is_empty() && !slug.contains('.')).then(|| slug.to_string())
+    }
+
+    pub async fn resolve(
+        &self,
+        user_id: i64,
+        requested_slug: Option<&str>,
+        active_tenant: Option<i64>,
+    ) -> Result<Option<Membership>> {
+        if let Some(slug) = requested_slug {
+            // Unknown and foreign organizations look the same to the caller.
+            let not_member = || AppError::forbidden("not a member of this organization");
+
+            let organization = self
+                .organization_repo
+                .find_by_slug(&slug.to_ascii_lowercase())
+                .await?
+                .filter(|o| o.is_active)
+                .ok_or_else(not_member)?;
+            let organization_id = organization
+                .id
+                .ok_or_else(|| AppError::internal("organization without id"))?;
+
+            return self
+                .organization_repo
+                .find_membership(organization_id, user_id)
+                .await?
+                .map(Some)
+                .ok_or_else(not_member);
+        }
+
+        let Some(organization_id) = active_tenant else {
+            return Ok(None);
+        };
+
+        let active = self
+            .organization_repo
+            .find_by_id(organization_id)
+            .await?
+            .is_some_and(|o| o.is_active);
+        if !active {
+            return Ok(None);
+        }
+
+        self.organization_repo
+            .find_membership(organization_id, user_id)
+            .await
+    }
+}
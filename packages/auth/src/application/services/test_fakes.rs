@@ -0,0 +1,1260 @@
+use crate::application::port::account_notifier::AccountNotifier;
+use crate::application::port::password_hasher::PasswordHasher;
+use crate::application::services::auth_event_recorder::AuthEventRecorder;
+use crate::application::services::mfa_challenge::MfaChallengeService;
+use crate::application::services::password_policy::PasswordPolicyService;
+use crate::application::services::token_issuer::TokenIssuer;
+use crate::application::use_case::logout_all_devices::LogoutAllDevicesUseCase;
+use crate::application::use_case::verify_token::VerifyTokenUseCase;
+use crate::domain::entity::{
+    ApiKey, AuthEvent, ExternalIdentity, Invitation, MagicLinkToken, Membership, MfaChallenge,
+    OAuthClient, OidcLoginState, Organization, RefreshToken, Role, Session, TotpFactor, User,
+};
+use crate::domain::repository::{
+    ApiKeyRepository, AuthEventFilter, AuthEventRepository, ExternalIdentityRepository,
+    InvitationRepository, MagicLinkRepository, MfaRepository, OAuthClientRepository,
+    OrganizationRepository, PasswordHistoryRepository, RefreshTokenRepository, RoleRepository,
+    SessionRepository, UserFilter, UserRepository,
+};
+use crate::domain::value_objects::{Email, HashedPassword};
+use crate::infrastructure::config::PasswordPolicyConfig;
+use crate::infrastructure::jwt::{Claims, JwtConfig, JwtService};
+use crate::infrastructure::repository::InMemoryTokenRevocationRepository;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use jsonwebtoken::{encode, EncodingKey, Header};
+use rust_reborn_contracts::common::SecureTokenGenerator;
+use rust_reborn_contracts::{AppError, Result};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+pub fn test_user() -> User {
+    let mut user = User::new(
+        "USR-TEST-001".to_string(),
+        Email::new("test@example.com".to_string()).unwrap(),
+        "testuser".to_string(),
+        HashedPassword::from("hashed_password".to_string()),
+        Some("Test User".to_string()),
+    );
+    user.id = Some(1);
+    user
+}
+
+pub fn test_jwt_service() -> JwtService {
+    JwtService::new(JwtConfig::hs256("test-secret", 1, 7)).unwrap()
+}
+
+/// Access token for `user` as if [`test_jwt_service`] had issued it at
+/// `issued_at`. Revocation cutoffs have second precision, so an earlier
+/// session needs a token whose `iat` lies in a previous second.
+pub fn test_token_issued_at(user: &User, issued_at: DateTime<Utc>) -> String {
+    let claims = Claims {
+        sub: user.id.unwrap().to_string(),
+        email: user.email.value().to_string(),
+        username: user.username.clone(),
+        exp: (issued_at + chrono::Duration::hours(1)).timestamp(),
+        iat: issued_at.timestamp(),
+        jti: Uuid::new_v4().to_string(),
+        roles: vec![],
+        permissions: vec![],
+        sid: None,
+        tid: None,
+        client_id: None,
+        act: None,
+    };
+    encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(b"test-secret"),
+    )
+    .unwrap()
+}
+
+/// The repositories a use case under test usually needs, shared the way
+/// `AuthState` shares the real ones. Tests build the use cases they exercise
+/// from these and inspect the repositories afterwards. The default has
+/// [`test_user`] as its only user and no roles.
+pub struct TestDeps {
+    pub users: Arc<InMemoryUserRepository>,
+    pub roles: Arc<InMemoryRoleRepository>,
+    pub sessions: Arc<InMemorySessionRepository>,
+    pub refresh_tokens: Arc<RecordingRefreshTokenRepository>,
+    pub revocations: Arc<InMemoryTokenRevocationRepository>,
+    pub clients: Arc<InMemoryOAuthClientRepository>,
+    pub mfa: Arc<InMemoryMfaRepository>,
+    pub events: Arc<InMemoryAuthEventRepository>,
+    pub notifier: Arc<RecordingNotifier>,
+    pub jwt_service: Arc<JwtService>,
+}
+
+impl Default for TestDeps {
+    fn default() -> Self {
+        Self {
+            users: Arc::new(InMemoryUserRepository::with_user(test_user())),
+            roles: Arc::default(),
+            sessions: Arc::default(),
+            refresh_tokens: Arc::default(),
+            revocations: Arc::new(InMemoryTokenRevocationRepository::new()),
+            clients: Arc::default(),
+            mfa: Arc::default(),
+            events: Arc::default(),
+            notifier: Arc::default(),
+            jwt_service: Arc::new(test_jwt_service()),
+        }
+    }
+}
+
+impl TestDeps {
+    pub fn with_users(mut self, users: Vec<User>) -> Self {
+        self.users = Arc::new(InMemoryUserRepository {
+            users: Mutex::new(users),
+        });
+        self
+    }
+
+    pub fn with_roles(mut self, roles: Vec<Role>) -> Self {
+        self.roles = Arc::new(InMemoryRoleRepository::with_roles(roles));
+        self
+    }
+
+    /// Records into [`TestDeps::events`].
+    pub fn recorder(&self) -> Arc<AuthEventRecorder> {
+        Arc::new(AuthEventRecorder::new(self.events.clone()))
+    }
+
+    pub fn token_issuer(&self) -> Arc<TokenIssuer> {
+        Arc::new(TokenIssuer::new(
+            self.jwt_service.clone(),
+            self.refresh_tokens.clone(),
+            self.roles.clone(),
+            self.sessions.clone(),
+            Arc::new(SecureTokenGenerator),
+        ))
+    }
+
+    pub fn verify_token(&self) -> Arc<VerifyTokenUseCase> {
+        Arc::new(VerifyTokenUseCase::new(
+            self.jwt_service.clone(),
+            self.revocations.clone(),
+            self.sessions.clone(),
+            self.clients.clone(),
+        ))
+    }
+
+    pub fn logout_all(&self) -> Arc<LogoutAllDevicesUseCase> {
+        Arc::new(LogoutAllDevicesUseCase::new(
+            self.revocations.clone(),
+            self.refresh_tokens.clone(),
+            self.sessions.clone(),
+            self.recorder(),
+        ))
+    }
+
+    pub fn mfa_challenges(&self) -> Arc<MfaChallengeService> {
+        Arc::new(MfaChallengeService::new(
+            self.mfa.clone(),
+            Arc::new(SecureTokenGenerator),
+            chrono::Duration::minutes(5),
+        ))
+    }
+}
+
+/// Reversible "hash" so tests can assert which password was stored.
+pub struct FakePasswordHasher;
+
+impl PasswordHasher for FakePasswordHasher {
+    fn hash(&self, raw: &str) -> Result<String> {
+        Ok(format!("hashed:{}", raw))
+    }
+
+    fn verify(&self, raw: &str, hashed: &str) -> Result<bool> {
+        Ok(hashed == format!("hashed:{}", raw))
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryUserRepository {
+    pub users: Mutex<Vec<User>>,
+}
+
+impl InMemoryUserRepository {
+    pub fn with_user(user: User) -> Self {
+        Self {
+            users: Mutex::new(vec![user]),
+        }
+    }
+
+    pub fn get(&self, id: i64) -> User {
+        self.users
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|u| u.id == Some(id))
+            .cloned()
+            .expect("user not found")
+    }
+
+    fn find(&self, predicate: impl Fn(&User) -> bool) -> Option<User> {
+        self.users
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|u| u.deleted_at.is_none() && predicate(u))
+            .cloned()
+    }
+
+    fn matching(&self, filter: &UserFilter) -> Vec<User> {
+        let search = filter.search.as_deref().map(str::to_lowercase);
+        let mut users: Vec<User> = self
+            .users
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|u| u.deleted_at.is_none())
+            .filter(|u| filter.status.is_none_or(|s| u.status == s))
+            .filter(|u| filter.is_verified.is_none_or(|v| u.is_verified == v))
+            .filter(|u| filter.is_active.is_none_or(|a| u.is_active == a))
+            .filter(|u| {
+                search.as_deref().is_none_or(|term| {
+                    u.email.value().to_lowercase().contains(term)
+                        || u.username.to_lowercase().contains(term)
+                })
+            })
+            .cloned()
+            .collect();
+        users.sort_by_key(|u| std::cmp::Reverse((u.created_at, u.id)));
+        users
+    }
+}
+
+#[async_trait]
+impl UserRepository for InMemoryUserRepository {
+    async fn find_by_email(&self, email: &str) -> Result<Option<User>> {
+        Ok(self.find(|u| u.email.value() == email))
+    }
+
+    async fn find_by_id(&self, id: i64) -> Result<Option<User>> {
+        Ok(self.find(|u| u.id == Some(id)))
+    }
+
+    async fn find_by_username(&self, username: &str) -> Result<Option<User>> {
+        Ok(self.find(|u| u.username == username))
+    }
+
+    async fn find_by_activation_key(&self, activation_key: &str) -> Result<Option<User>> {
+        Ok(self.find(|u| u.activation_key.as_deref() == Some(activation_key)))
+    }
+
+    async fn find_by_reset_key(&self, reset_key: &str) -> Result<Option<User>> {
+        Ok(self.find(|u| u.reset_key.as_deref() == Some(reset_key)))
+    }
+
+    async fn consume_reset_key(&self, reset_key: &str) -> Result<bool> {
+        let mut users = self.users.lock().unwrap();
+        match users
+            .iter_mut()
+            .find(|u| u.deleted_at.is_none() && u.reset_key.as_deref() == Some(reset_key))
+        {
+            Some(user) => {
+                user.reset_key = None;
+                user.reset_key_expires_at = None;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn find_by_email_change_key(&self, email_change_key: &str) -> Result<Option<User>> {
+        Ok(self.find(|u| u.email_change_key.as_deref() == Some(email_change_key)))
+    }
+
+    async fn find_all(&self, filter: &UserFilter, limit: u32, offset: u32) -> Result<Vec<User>> {
+        Ok(self
+            .matching(filter)
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect())
+    }
+
+    async fn count(&self, filter: &UserFilter) -> Result<u64> {
+        Ok(self.matching(filter).len() as u64)
+    }
+
+    async fn update(&self, user: &User) -> Result<User> {
+        let mut users = self.users.lock().unwrap();
+        if let Some(stored) = users.iter_mut().find(|u| u.id == user.id) {
+            *stored = user.clone();
+        }
+        Ok(user.clone())
+    }
+
+    async fn save(&self, user: &mut User) -> Result<()> {
+        let mut users = self.users.lock().unwrap();
+        user.id = Some(users.len() as i64 + 1);
+        users.push(user.clone());
+        Ok(())
+    }
+}
+
+/// Only records which users had all their refresh tokens revoked.
+#[derive(Default)]
+pub struct RecordingRefreshTokenRepository {
+    pub revoked_users: Mutex<Vec<i64>>,
+}
+
+#[async_trait]
+impl RefreshTokenRepository for RecordingRefreshTokenRepository {
+    async fn save(&self, _: &mut RefreshToken) -> Result<()> {
+        Ok(())
+    }
+
+    async fn find_by_hash(&self, _: &str) -> Result<Option<RefreshToken>> {
+        Ok(None)
+    }
+
+    async fn rotate(&self, _: &RefreshToken, _: &mut RefreshToken) -> Result<bool> {
+        Ok(true)
+    }
+
+    async fn revoke_family(&self, _: Uuid) -> Result<()> {
+        Ok(())
+    }
+
+    async fn revoke_all_for_user(&self, user_id: i64) -> Result<()> {
+        self.revoked_users.lock().unwrap().push(user_id);
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryRoleRepository {
+    pub roles: Vec<Role>,
+    pub assignments: Mutex<HashMap<i64, Vec<String>>>,
+}
+
+impl InMemoryRoleRepository {
+    pub fn with_roles(roles: Vec<Role>) -> Self {
+        Self {
+            roles,
+            assignments: Mutex::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl RoleRepository for InMemoryRoleRepository {
+    async fn find_by_user(&self, user_id: i64) -> Result<Vec<Role>> {
+        let assignments = self.assignments.lock().unwrap();
+        let names = assignments.get(&user_id).cloned().unwrap_or_default();
+        Ok(self
+            .roles
+            .iter()
+            .filter(|r| names.contains(&r.name))
+            .cloned()
+            .collect())
+    }
+
+    async fn exists(&self, role_name: &str) -> Result<bool> {
+        Ok(self.roles.iter().any(|r| r.name == role_name))
+    }
+
+    async fn assign_to_user(&self, user_id: i64, role_name: &str) -> Result<()> {
+        if !self.roles.iter().any(|r| r.name == role_name) {
+            return Err(AppError::not_found(format!(
+                "role not found: {}",
+                role_name
+            )));
+        }
+        let mut assignments = self.assignments.lock().unwrap();
+        let names = assignments.entry(user_id).or_default();
+        if !names.iter().any(|n| n == role_name) {
+            names.push(role_name.to_string());
+        }
+        Ok(())
+    }
+
+    async fn remove_from_user(&self, user_id: i64, role_name: &str) -> Result<()> {
+        if let Some(names) = self.assignments.lock().unwrap().get_mut(&user_id) {
+            names.retain(|n| n != role_name);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryMfaRepository {
+    pub factors: Mutex<HashMap<i64, TotpFactor>>,
+    /// (user_id, code_hash, used)
+    pub recovery_codes: Mutex<Vec<(i64, String, bool)>>,
+    pub challenges: Mutex<Vec<MfaChallenge>>,
+}
+
+impl InMemoryMfaRepository {
+    pub fn totp(&self, user_id: i64) -> Option<TotpFactor> {
+        self.factors.lock().unwrap().get(&user_id).cloned()
+    }
+}
+
+#[async_trait]
+impl MfaRepository for InMemoryMfaRepository {
+    async fn find_totp(&self, user_id: i64) -> Result<Option<TotpFactor>> {
+        Ok(self.totp(user_id))
+    }
+
+    async fn save_totp(&self, factor: &TotpFactor) -> Result<()> {
+        self.factors
+            .lock()
+            .unwrap()
+            .insert(factor.user_id, factor.clone());
+        Ok(())
+    }
+
+    async fn confirm_totp(&self, user_id: i64, step: i64) -> Result<()> {
+        if let Some(factor) = self.factors.lock().unwrap().get_mut(&user_id) {
+            factor.confirmed_at = Some(chrono::Utc::now());
+            factor.last_used_step = Some(step);
+        }
+        Ok(())
+    }
+
+    async fn use_totp_step(&self, user_id: i64, step: i64) -> Result<bool> {
+        let mut factors = self.factors.lock().unwrap();
+        match factors.get_mut(&user_id) {
+            Some(factor) if factor.last_used_step.is_none_or(|last| step > last) => {
+                factor.last_used_step = Some(step);
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    async fn replace_recovery_codes(&self, user_id: i64, code_hashes: &[String]) -> Result<()> {
+        let mut codes = self.recovery_codes.lock().unwrap();
+        codes.retain(|(owner, _, _)| *owner != user_id);
+        codes.extend(code_hashes.iter().map(|h| (user_id, h.clone(), false)));
+        Ok(())
+    }
+
+    async fn use_recovery_code(&self, user_id: i64, code_hash: &str) -> Result<bool> {
+        let mut codes = self.recovery_codes.lock().unwrap();
+        match codes
+            .iter_mut()
+            .find(|(owner, hash, used)| *owner == user_id && hash == code_hash && !used)
+        {
+            Some(code) => {
+                code.2 = true;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn save_challenge(&self, challenge: &mut MfaChallenge) -> Result<()> {
+        let mut challenges = self.challenges.lock().unwrap();
+        challenge.id = Some(challenges.len() as i64 + 1);
+        challenges.push(challenge.clone());
+        Ok(())
+    }
+
+    async fn find_challenge(&self, token_hash: &str) -> Result<Option<MfaChallenge>> {
+        Ok(self
+            .challenges
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|c| c.token_hash.value() == token_hash)
+            .cloned())
+    }
+
+    async fn record_failed_attempt(&self, challenge_id: i64) -> Result<()> {
+        if let Some(c) = self
+            .challenges
+            .lock()
+            .unwrap()
+            .iter_mut()
+            .find(|c| c.id == Some(challenge_id))
+        {
+            c.failed_attempts += 1;
+        }
+        Ok(())
+    }
+
+    async fn consume_challenge(&self, challenge_id: i64) -> Result<bool> {
+        let mut challenges = self.challenges.lock().unwrap();
+        match challenges
+            .iter_mut()
+            .find(|c| c.id == Some(challenge_id) && c.consumed_at.is_none())
+        {
+            Some(c) => {
+                c.consumed_at = Some(chrono::Utc::now());
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct InMemorySessionRepository {
+    pub sessions: Mutex<HashMap<Uuid, Session>>,
+}
+
+impl InMemorySessionRepository {
+    pub fn get(&self, id: Uuid) -> Option<Session> {
+        self.sessions.lock().unwrap().get(&id).cloned()
+    }
+}
+
+#[async_trait]
+impl SessionRepository for InMemorySessionRepository {
+    async fn save(&self, session: &Session) -> Result<()> {
+        self.sessions
+            .lock()
+            .unwrap()
+            .insert(session.id, session.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Session>> {
+        Ok(self.get(id))
+    }
+
+    async fn find_active_by_user(
+        &self,
+        user_id: i64,
+        seen_since: DateTime<Utc>,
+    ) -> Result<Vec<Session>> {
+        let mut sessions: Vec<Session> = self
+            .sessions
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|s| s.user_id == user_id && !s.is_revoked() && s.last_seen_at >= seen_since)
+            .cloned()
+            .collect();
+        sessions.sort_by_key(|s| std::cmp::Reverse(s.last_seen_at));
+        Ok(sessions)
+    }
+
+    async fn touch(&self, id: Uuid, seen_at: DateTime<Utc>) -> Result<()> {
+        if let Some(s) = self.sessions.lock().unwrap().get_mut(&id) {
+            s.last_seen_at = seen_at;
+        }
+        Ok(())
+    }
+
+    async fn set_organization(&self, id: Uuid, organization_id: Option<i64>) -> Result<()> {
+        if let Some(s) = self.sessions.lock().unwrap().get_mut(&id) {
+            s.organization_id = organization_id;
+        }
+        Ok(())
+    }
+
+    async fn revoke(&self, id: Uuid) -> Result<()> {
+        if let Some(s) = self.sessions.lock().unwrap().get_mut(&id) {
+            s.revoked_at.get_or_insert_with(Utc::now);
+        }
+        Ok(())
+    }
+
+    async fn revoke_all_for_user(&self, user_id: i64) -> Result<()> {
+        for s in self.sessions.lock().unwrap().values_mut() {
+            if s.user_id == user_id {
+                s.revoked_at.get_or_insert_with(Utc::now);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryApiKeyRepository {
+    pub keys: Mutex<Vec<ApiKey>>,
+}
+
+impl InMemoryApiKeyRepository {
+    pub fn get(&self, id: i64) -> ApiKey {
+        self.keys
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|k| k.id == Some(id))
+            .cloned()
+            .expect("api key not found")
+    }
+
+    fn modify(&self, id: Option<i64>, f: impl FnOnce(&mut ApiKey)) {
+        if let Some(k) = self.keys.lock().unwrap().iter_mut().find(|k| k.id == id) {
+            f(k);
+        }
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for InMemoryApiKeyRepository {
+    async fn save(&self, key: &mut ApiKey) -> Result<()> {
+        let mut keys = self.keys.lock().unwrap();
+        key.id = Some(keys.len() as i64 + 1);
+        keys.push(key.clone());
+        Ok(())
+    }
+
+    async fn find_by_prefix(&self, prefix: &str) -> Result<Option<ApiKey>> {
+        Ok(self
+            .keys
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|k| k.prefix == prefix)
+            .cloned())
+    }
+
+    async fn find_by_user(&self, user_id: i64) -> Result<Vec<ApiKey>> {
+        let mut keys: Vec<ApiKey> = self
+            .keys
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|k| k.user_id == user_id && k.revoked_at.is_none())
+            .cloned()
+            .collect();
+        keys.reverse();
+        Ok(keys)
+    }
+
+    async fn update(&self, key: &ApiKey) -> Result<()> {
+        self.modify(key.id, |k| {
+            k.name = key.name.clone();
+            k.scopes = key.scopes.clone();
+        });
+        Ok(())
+    }
+
+    async fn revoke(&self, id: i64) -> Result<()> {
+        self.modify(Some(id), |k| {
+            k.revoked_at.get_or_insert_with(Utc::now);
+        });
+        Ok(())
+    }
+
+    async fn touch(&self, id: i64, used_at: DateTime<Utc>) -> Result<()> {
+        self.modify(Some(id), |k| k.last_used_at = Some(used_at));
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryAuthEventRepository {
+    pub events: Mutex<Vec<AuthEvent>>,
+}
+
+impl InMemoryAuthEventRepository {
+    pub fn all(&self) -> Vec<AuthEvent> {
+        self.events.lock().unwrap().clone()
+    }
+
+    fn matching(&self, filter: &AuthEventFilter) -> Vec<AuthEvent> {
+        let mut events: Vec<AuthEvent> = self
+            .events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|e| filter.user_id.is_none_or(|id| e.user_id == Some(id)))
+            .filter(|e| filter.event_type.is_none_or(|t| e.event_type == t))
+            .filter(|e| filter.outcome.is_none_or(|o| e.outcome == o))
+            .filter(|e| filter.from.is_none_or(|from| e.created_at >= from))
+            .filter(|e| filter.to.is_none_or(|to| e.created_at < to))
+            .cloned()
+            .collect();
+        events.sort_by(|a, b| b.created_at.cmp(&a.created_at).then(b.id.cmp(&a.id)));
+        events
+    }
+}
+
+#[async_trait]
+impl AuthEventRepository for InMemoryAuthEventRepository {
+    async fn save(&self, event: &mut AuthEvent) -> Result<()> {
+        let mut events = self.events.lock().unwrap();
+        event.id = Some(events.len() as i64 + 1);
+        events.push(event.clone());
+        Ok(())
+    }
+
+    async fn find_all(
+        &self,
+        filter: &AuthEventFilter,
+        limit: u32,
+        offset: u32,
+    ) -> Result<Vec<AuthEvent>> {
+        Ok(self
+            .matching(filter)
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect())
+    }
+
+    async fn count(&self, filter: &AuthEventFilter) -> Result<u64> {
+        Ok(self.matching(filter).len() as u64)
+    }
+}
+
+/// A recorder whose events nobody looks at.
+pub fn test_event_recorder() -> Arc<AuthEventRecorder> {
+    Arc::new(AuthEventRecorder::new(Arc::new(
+        InMemoryAuthEventRepository::default(),
+    )))
+}
+
+#[derive(Default)]
+pub struct InMemoryPasswordHistoryRepository {
+    /// Oldest first.
+    pub entries: Mutex<Vec<(i64, HashedPassword)>>,
+}
+
+#[async_trait]
+impl PasswordHistoryRepository for InMemoryPasswordHistoryRepository {
+    async fn add(&self, user_id: i64, password: &HashedPassword, keep: usize) -> Result<()> {
+        let mut entries = self.entries.lock().unwrap();
+        entries.push((user_id, password.clone()));
+        let excess = entries.iter().filter(|(id, _)| *id == user_id).count();
+        let mut excess = excess.saturating_sub(keep);
+        entries.retain(|(id, _)| {
+            let drop = *id == user_id && excess > 0;
+            if drop {
+                excess -= 1;
+            }
+            !drop
+        });
+        Ok(())
+    }
+
+    async fn find_recent(&self, user_id: i64, limit: usize) -> Result<Vec<HashedPassword>> {
+        Ok(self
+            .entries
+            .lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .filter(|(id, _)| *id == user_id)
+            .take(limit)
+            .map(|(_, password)| password.clone())
+            .collect())
+    }
+}
+
+/// The default policy without password history.
+pub fn test_password_policy() -> Arc<PasswordPolicyService> {
+    Arc::new(PasswordPolicyService::new(
+        PasswordPolicyConfig::default(),
+        Arc::new(InMemoryPasswordHistoryRepository::default()),
+        Arc::new(FakePasswordHasher),
+        None,
+    ))
+}
+
+#[derive(Default)]
+pub struct InMemoryMagicLinkRepository {
+    pub tokens: Mutex<Vec<MagicLinkToken>>,
+}
+
+#[async_trait]
+impl MagicLinkRepository for InMemoryMagicLinkRepository {
+    async fn save(&self, token: &mut MagicLinkToken) -> Result<()> {
+        let mut tokens = self.tokens.lock().unwrap();
+        token.id = Some(tokens.len() as i64 + 1);
+        tokens.push(token.clone());
+        Ok(())
+    }
+
+    async fn consume(&self, token_hash: &str) -> Result<Option<MagicLinkToken>> {
+        let mut tokens = self.tokens.lock().unwrap();
+        let Some(token) = tokens
+            .iter_mut()
+            .find(|t| t.token_hash.value() == token_hash && t.is_usable())
+        else {
+            return Ok(None);
+        };
+        token.used_at = Some(Utc::now());
+        Ok(Some(token.clone()))
+    }
+
+    async fn invalidate_for_user(&self, user_id: i64) -> Result<()> {
+        for token in self.tokens.lock().unwrap().iter_mut() {
+            if token.user_id == user_id && token.used_at.is_none() {
+                token.used_at = Some(Utc::now());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryOAuthClientRepository {
+    pub clients: Mutex<Vec<OAuthClient>>,
+}
+
+impl InMemoryOAuthClientRepository {
+    pub fn get(&self, id: i64) -> OAuthClient {
+        self.clients
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|c| c.id == Some(id))
+            .cloned()
+            .expect("oauth client not found")
+    }
+
+    fn modify(&self, id: i64, f: impl FnOnce(&mut OAuthClient)) {
+        if let Some(c) = self
+            .clients
+            .lock()
+            .unwrap()
+            .iter_mut()
+            .find(|c| c.id == Some(id))
+        {
+            f(c);
+        }
+    }
+}
+
+#[async_trait]
+impl OAuthClientRepository for InMemoryOAuthClientRepository {
+    async fn save(&self, client: &mut OAuthClient) -> Result<()> {
+        let mut clients = self.clients.lock().unwrap();
+        client.id = Some(clients.len() as i64 + 1);
+        clients.push(client.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: i64) -> Result<Option<OAuthClient>> {
+        Ok(self
+            .clients
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|c| c.id == Some(id))
+            .cloned())
+    }
+
+    async fn find_by_client_id(&self, client_id: &str) -> Result<Option<OAuthClient>> {
+        Ok(self
+            .clients
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|c| c.client_id == client_id)
+            .cloned())
+    }
+
+    async fn find_all(&self) -> Result<Vec<OAuthClient>> {
+        Ok(self.clients.lock().unwrap().clone())
+    }
+
+    async fn set_active(&self, id: i64, is_active: bool) -> Result<()> {
+        self.modify(id, |c| c.is_active = is_active);
+        Ok(())
+    }
+
+    async fn touch(&self, id: i64, used_at: DateTime<Utc>) -> Result<()> {
+        self.modify(id, |c| c.last_used_at = Some(used_at));
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryOrganizationRepository {
+    pub roles: Vec<Role>,
+    pub organizations: Mutex<Vec<Organization>>,
+    /// (organization_id, user_id, role names)
+    pub members: Mutex<Vec<(i64, i64, Vec<String>)>>,
+}
+
+impl InMemoryOrganizationRepository {
+    pub fn with_roles(roles: Vec<Role>) -> Self {
+        Self {
+            roles,
+            ..Self::default()
+        }
+    }
+
+    fn membership(&self, member: &(i64, i64, Vec<String>)) -> Membership {
+        Membership {
+            organization_id: member.0,
+            user_id: member.1,
+            roles: self
+                .roles
+                .iter()
+                .filter(|r| member.2.contains(&r.name))
+                .cloned()
+                .collect(),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn memberships(&self, f: impl Fn(&(i64, i64, Vec<String>)) -> bool) -> Vec<Membership> {
+        self.members
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|m| f(m))
+            .map(|m| self.membership(m))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl OrganizationRepository for InMemoryOrganizationRepository {
+    async fn save(&self, organization: &mut Organization) -> Result<()> {
+        let mut organizations = self.organizations.lock().unwrap();
+        if organizations.iter().any(|o| o.slug == organization.slug) {
+            return Err(AppError::conflict("organization slug already taken"));
+        }
+        organization.id = Some(organizations.len() as i64 + 1);
+        organizations.push(organization.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: i64) -> Result<Option<Organization>> {
+        Ok(self
+            .organizations
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|o| o.id == Some(id))
+            .cloned())
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Option<Organization>> {
+        Ok(self
+            .organizations
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|o| o.slug == slug)
+            .cloned())
+    }
+
+    async fn find_all(&self) -> Result<Vec<Organization>> {
+        Ok(self.organizations.lock().unwrap().clone())
+    }
+
+    async fn set_member_roles(
+        &self,
+        organization_id: i64,
+        user_id: i64,
+        role_names: &[String],
+    ) -> Result<()> {
+        if let Some(missing) = role_names
+            .iter()
+            .find(|name| !self.roles.iter().any(|r| &r.name == *name))
+        {
+            return Err(AppError::not_found(format!("role not found: {}", missing)));
+        }
+
+        let mut members = self.members.lock().unwrap();
+        members.retain(|m| !(m.0 == organization_id && m.1 == user_id));
+        members.push((organization_id, user_id, role_names.to_vec()));
+        Ok(())
+    }
+
+    async fn remove_member(&self, organization_id: i64, user_id: i64) -> Result<bool> {
+        let mut members = self.members.lock().unwrap();
+        let before = members.len();
+        members.retain(|m| !(m.0 == organization_id && m.1 == user_id));
+        Ok(members.len() < before)
+    }
+
+    async fn find_membership(
+        &self,
+        organization_id: i64,
+        user_id: i64,
+    ) -> Result<Option<Membership>> {
+        Ok(self
+            .memberships(|m| m.0 == organization_id && m.1 == user_id)
+            .pop())
+    }
+
+    async fn find_members(&self, organization_id: i64) -> Result<Vec<Membership>> {
+        Ok(self.memberships(|m| m.0 == organization_id))
+    }
+
+    async fn find_memberships_of_user(&self, user_id: i64) -> Result<Vec<Membership>> {
+        Ok(self.memberships(|m| m.1 == user_id))
+    }
+}
+
+/// Accepting saves the user and its role into the given repositories.
+pub struct InMemoryInvitationRepository {
+    pub users: Arc<InMemoryUserRepository>,
+    pub roles: Arc<InMemoryRoleRepository>,
+    pub invitations: Mutex<Vec<Invitation>>,
+}
+
+impl InMemoryInvitationRepository {
+    pub fn new(users: Arc<InMemoryUserRepository>, roles: Arc<InMemoryRoleRepository>) -> Self {
+        Self {
+            users,
+            roles,
+            invitations: Mutex::default(),
+        }
+    }
+
+    pub fn get(&self, id: i64) -> Invitation {
+        self.invitations
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|i| i.id == Some(id))
+            .cloned()
+            .expect("invitation not found")
+    }
+
+    fn modify(&self, id: i64, f: impl FnOnce(&mut Invitation) -> bool) -> bool {
+        self.invitations
+            .lock()
+            .unwrap()
+            .iter_mut()
+            .find(|i| i.id == Some(id))
+            .is_some_and(f)
+    }
+}
+
+#[async_trait]
+impl InvitationRepository for InMemoryInvitationRepository {
+    async fn save(&self, invitation: &mut Invitation) -> Result<()> {
+        if !self.roles.exists(&invitation.role).await? {
+            return Err(AppError::not_found(format!(
+                "role not found: {}",
+                invitation.role
+            )));
+        }
+        let mut invitations = self.invitations.lock().unwrap();
+        for i in invitations.iter_mut() {
+            if i.email == invitation.email && i.accepted_at.is_none() && i.revoked_at.is_none() {
+                i.revoked_at = Some(Utc::now());
+            }
+        }
+        invitation.id = Some(invitations.len() as i64 + 1);
+        invitations.push(invitation.clone());
+        Ok(())
+    }
+
+    async fn find_by_token_hash(&self, token_hash: &str) -> Result<Option<Invitation>> {
+        Ok(self
+            .invitations
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|i| i.token_hash.value() == token_hash)
+            .cloned())
+    }
+
+    async fn find_all(&self) -> Result<Vec<Invitation>> {
+        let mut invitations = self.invitations.lock().unwrap().clone();
+        invitations.reverse();
+        Ok(invitations)
+    }
+
+    async fn revoke(&self, id: i64) -> Result<bool> {
+        Ok(self.modify(id, |i| {
+            let open = i.accepted_at.is_none() && i.revoked_at.is_none();
+            if open {
+                i.revoked_at = Some(Utc::now());
+            }
+            open
+        }))
+    }
+
+    async fn accept(&self, id: i64, user: &mut User) -> Result<bool> {
+        let Some(invitation) = self
+            .invitations
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|i| i.id == Some(id) && i.is_pending())
+            .cloned()
+        else {
+            return Ok(false);
+        };
+        if !self.roles.exists(&invitation.role).await? {
+            return Err(AppError::not_found(format!(
+                "role not found: {}",
+                invitation.role
+            )));
+        }
+
+        self.users.save(user).await?;
+        let user_id = user
+            .id
+            .ok_or_else(|| AppError::internal("user.id is None"))?;
+        self.roles.assign_to_user(user_id, &invitation.role).await?;
+        Ok(self.modify(id, |i| {
+            i.accepted_at = Some(Utc::now());
+            true
+        }))
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryExternalIdentityRepository {
+    pub identities: Mutex<Vec<ExternalIdentity>>,
+    pub login_states: Mutex<HashMap<String, OidcLoginState>>,
+}
+
+#[async_trait]
+impl ExternalIdentityRepository for InMemoryExternalIdentityRepository {
+    async fn find(&self, provider: &str, subject: &str) -> Result<Option<ExternalIdentity>> {
+        Ok(self
+            .identities
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|i| i.provider == provider && i.subject == subject)
+            .cloned())
+    }
+
+    async fn find_by_user(&self, user_id: i64) -> Result<Vec<ExternalIdentity>> {
+        Ok(self
+            .identities
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|i| i.user_id == user_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn save(&self, identity: &mut ExternalIdentity) -> Result<()> {
+        let mut identities = self.identities.lock().unwrap();
+        if identities
+            .iter()
+            .any(|i| i.provider == identity.provider && i.subject == identity.subject)
+        {
+            return Err(AppError::conflict("identity already linked"));
+        }
+        identity.id = Some(identities.len() as i64 + 1);
+        identities.push(identity.clone());
+        Ok(())
+    }
+
+    async fn record_login(&self, id: i64, at: DateTime<Utc>) -> Result<()> {
+        if let Some(i) = self
+            .identities
+            .lock()
+            .unwrap()
+            .iter_mut()
+            .find(|i| i.id == Some(id))
+        {
+            i.last_login_at = Some(at);
+        }
+        Ok(())
+    }
+
+    async fn save_login_state(&self, state: &OidcLoginState) -> Result<()> {
+        self.login_states
+            .lock()
+            .unwrap()
+            .insert(state.state_hash.value().to_string(), state.clone());
+        Ok(())
+    }
+
+    async fn take_login_state(&self, state_hash: &str) -> Result<Option<OidcLoginState>> {
+        Ok(self.login_states.lock().unwrap().remove(state_hash))
+    }
+}
+
+#[derive(Default)]
+pub struct RecordingNotifier {
+    pub verifications: Mutex<Vec<(String, String)>>,
+    pub password_resets: Mutex<Vec<(String, String)>>,
+    pub email_changes: Mutex<Vec<(String, String)>>,
+    pub magic_links: Mutex<Vec<(String, String)>>,
+    pub invitations: Mutex<Vec<(String, String)>>,
+}
+
+impl RecordingNotifier {
+    pub fn last_verification_token(&self) -> Option<String> {
+        self.verifications
+            .lock()
+            .unwrap()
+            .last()
+            .map(|(_, token)| token.clone())
+    }
+
+    pub fn last_password_reset_token(&self) -> Option<String> {
+        self.password_resets
+            .lock()
+            .unwrap()
+            .last()
+            .map(|(_, token)| token.clone())
+    }
+
+    pub fn last_magic_link_token(&self) -> Option<String> {
+        self.magic_links
+            .lock()
+            .unwrap()
+            .last()
+            .map(|(_, token)| token.clone())
+    }
+
+    pub fn last_invitation_token(&self) -> Option<String> {
+        self.invitations
+            .lock()
+            .unwrap()
+            .last()
+            .map(|(_, token)| token.clone())
+    }
+}
+
+#[async_trait]
+impl AccountNotifier for RecordingNotifier {
+    async fn send_email_verification(&self, user: &User, token: &str) -> Result<()> {
+        self.verifications
+            .lock()
+            .unwrap()
+            .push((user.email.value().to_string(), token.to_string()));
+        Ok(())
+    }
+    async fn send_password_reset(&self, user: &User, token: &str) -> Result<()> {
+        self.password_resets
+            .lock()
+            .unwrap()
+            .push((user.email.value().to_string(), token.to_string()));
+        Ok(())
+    }
+    async fn send_email_change(&self, user: &User, token: &str) -> Result<()> {
+        let to = user.pending_email.as_ref().expect("pending email");
+        self.email_changes
+            .lock()
+            .unwrap()
+            .push((to.value().to_string(), token.to_string()));
+        Ok(())
+    }
+    async fn send_magic_link(
+        &self,
+        user: &User,
+        token: &str,
+        _expires_at: DateTime<Utc>,
+    ) -> Result<()> {
+        self.magic_links
+            .lock()
+            .unwrap()
+            .push((user.email.value().to_string(), token.to_string()));
+        Ok(())
+    }
+    async fn send_invitation(
+        &self,
+        invitation: &Invitation,
+        _inviter: &User,
+        token: &str,
+    ) -> Result<()> {
+        self.invitations
+            .lock()
+            .unwrap()
+            .push((invitation.email.clone(), token.to_string()));
+        Ok(())
+    }
+}
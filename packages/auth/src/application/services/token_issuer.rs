@@ -0,0 +1,105 @@
+use crate::domain::entity::{RefreshToken, Session, User};
+use crate::domain::value_objects::{ClientInfo, Token, TokenHash};
+use crate::domain::{RefreshTokenRepository, RoleRepository, SessionRepository};
+use crate::infrastructure::jwt::JwtService;
+use rust_reborn_contracts::common::CodeGenerator;
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Issues the access/refresh token pair handed to clients after any successful authentication.
+pub struct TokenIssuer {
+    jwt_service: Arc<JwtService>,
+    refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+    role_repo: Arc<dyn RoleRepository>,
+    session_repo: Arc<dyn SessionRepository>,
+    token_generator: Arc<dyn CodeGenerator>,
+}
+
+impl TokenIssuer {
+    pub fn new(
+        jwt_service: Arc<JwtService>,
+        refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+        role_repo: Arc<dyn RoleRepository>,
+        session_repo: Arc<dyn SessionRepository>,
+        token_generator: Arc<dyn CodeGenerator>,
+    ) -> Self {
+        Self {
+            jwt_service,
+            refresh_token_repo,
+            role_repo,
+            session_repo,
+            token_generator,
+        }
+    }
+
+    /// Starts a new session, and with it a new refresh-token family, for the user.
+    pub async fn issue(&self, user: &User, client: &ClientInfo) -> Result<Token> {
+        let user_id = user
+            .id
+            .ok_or_else(|| AppError::internal("cannot issue tokens: user.id is None"))?;
+
+        let session = Session::new(Uuid::new_v4(), user_id, client);
+        self.session_repo.save(&session).await?;
+
+        let (raw, mut refresh_token) = self.new_refresh_token(user_id, session.id);
+        self.refresh_token_repo.save(&mut refresh_token).await?;
+
+        self.access_token(user_id, user, Some(&session), raw).await
+    }
+
+    /// Replaces `current` with a fresh token in the same family.
+    /// Returns `None` if `current` was rotated concurrently by another request.
+    pub async fn rotate(&self, user: &User, current: &RefreshToken) -> Result<Option<Token>> {
+        let (raw, mut next) = self.new_refresh_token(current.user_id, current.family_id);
+
+        if !self.refresh_token_repo.rotate(current, &mut next).await? {
+            return Ok(None);
+        }
+
+        // Families created before sessions existed have no session row.
+        let session = self.session_repo.find_by_id(current.family_id).await?;
+        if let Some(session) = &session {
+            self.session_repo
+                .touch(session.id, chrono::Utc::now())
+                .await?;
+        }
+
+        self.access_token(current.user_id, user, session.as_ref(), raw)
+            .await
+            .map(Some)
+    }
+
+    /// A new access token for an existing session, e.g. after its active
+    /// tenant changed. The session's refresh token stays valid.
+    pub async fn reissue(&self, user: &User, session: &Session) -> Result<Token> {
+        let roles = self.role_repo.find_by_user(session.user_id).await?;
+        let access_token = self
+            .jwt_service
+            .generate_token(user, &roles, Some(session))?;
+
+        Ok(Token::new(access_token, self.jwt_service.expires_in()))
+    }
+
+    fn new_refresh_token(&self, user_id: i64, family_id: Uuid) -> (String, RefreshToken) {
+        let raw = self.token_generator.generate("rt");
+        let expires_at = chrono::Utc::now() + self.jwt_service.refresh_expires_in();
+        let token = RefreshToken::new(user_id, family_id, TokenHash::of(&raw), expires_at);
+
+        (raw, token)
+    }
+
+    async fn access_token(
+        &self,
+        user_id: i64,
+        user: &User,
+        session: Option<&Session>,
+        refresh_token: String,
+    ) -> Result<Token> {
+        // Roles are looked up on every issue so a refresh picks up role changes.
+        let roles = self.role_repo.find_by_user(user_id).await?;
+        let access_token = self.jwt_service.generate_token(user, &roles, session)?;
+
+        Ok(Token::new(access_token, self.jwt_service.expires_in()).with_refresh(refresh_token))
+    }
+}
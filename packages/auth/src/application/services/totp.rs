@@ -0,0 +1,158 @@
+use data_encoding::BASE32_NOPAD;
+use hmac::{Hmac, Mac};
+use qrcode::render::svg;
+use qrcode::QrCode;
+use rand::RngCore;
+use rust_reborn_contracts::{AppError, Result};
+use sha1::Sha1;
+
+const DIGITS: u32 = 6;
+const PERIOD_SECONDS: i64 = 30;
+/// Accept codes one step before/after the current one to tolerate clock drift.
+const ALLOWED_SKEW: i64 = 1;
+const SECRET_BYTES: usize = 20;
+
+/// RFC 6238 TOTP with the parameters every authenticator app supports
+/// (SHA-1, 6 digits, 30 second period).
+pub struct TotpService {
+    issuer: String,
+}
+
+impl TotpService {
+    pub fn new(issuer: impl Into<String>) -> Self {
+        Self {
+            issuer: issuer.into(),
+        }
+    }
+
+    /// A new random base32 secret.
+    pub fn generate_secret(&self) -> String {
+        let mut bytes = [0u8; SECRET_BYTES];
+        rand::rng().fill_bytes(&mut bytes);
+        BASE32_NOPAD.encode(&bytes)
+    }
+
+    pub fn otpauth_uri(&self, secret: &str, account: &str) -> String {
+        let issuer = encode_uri_component(&self.issuer);
+        format!(
+            "otpauth://totp/{}:{}?secret={}&issuer={}&algorithm=SHA1&digits={}&period={}",
+            issuer,
+            encode_uri_component(account),
+            secret,
+            issuer,
+            DIGITS,
+            PERIOD_SECONDS
+        )
+    }
+
+    /// SVG rendering of the otpauth URI for authenticator apps to scan.
+    pub fn qr_svg(&self, otpauth_uri: &str) -> Result<String> {
+        let code = QrCode::new(otpauth_uri.as_bytes())
+            .map_err(|e| AppError::internal(format!("failed to build QR code: {}", e)))?;
+
+        Ok(code.render::<svg::Color>().min_dimensions(200, 200).build())
+    }
+
+    pub fn current_step(&self) -> i64 {
+        chrono::Utc::now().timestamp() / PERIOD_SECONDS
+    }
+
+    pub fn code_at(&self, secret: &str, step: i64) -> Result<String> {
+        let key = BASE32_NOPAD
+            .decode(secret.as_bytes())
+            .map_err(|e| AppError::internal(format!("invalid TOTP secret: {}", e)))?;
+
+        let mut mac = Hmac::<Sha1>::new_from_slice(&key)
+            .map_err(|e| AppError::internal(format!("invalid TOTP secret: {}", e)))?;
+        mac.update(&step.to_be_bytes());
+        let digest = mac.finalize().into_bytes();
+
+        let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+        let binary = u32::from_be_bytes([
+            digest[offset] & 0x7f,
+            digest[offset + 1],
+            digest[offset + 2],
+            digest[offset + 3],
+        ]);
+
+        Ok(format!(
+            "{:0width$}",
+            binary % 10u32.pow(DIGITS),
+            width = DIGITS as usize
+        ))
+    }
+
+    /// Returns the matching time step if `code` is valid around `step`.
+    pub fn verify_at(&self, secret: &str, code: &str, step: i64) -> Result<Option<i64>> {
+        let code = code.trim();
+        if code.len() != DIGITS as usize || !code.chars().all(|c| c.is_ascii_digit()) {
+            return Ok(None);
+        }
+
+        for candidate in (step - ALLOWED_SKEW)..=(step + ALLOWED_SKEW) {
+            if constant_time_eq(self.code_at(secret, candidate)?.as_bytes(), code.as_bytes()) {
+                return Ok(Some(candidate));
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub fn verify(&self, secret: &str, code: &str) -> Result<Option<i64>> {
+        self.verify_at(secret, code, self.current_step())
+    }
+
+    /// Recovery codes look like `k3f9-x2mq-8dtw`; they are only shown once.
+    pub fn generate_recovery_codes(&self, count: usize) -> Vec<String> {
+        const ALPHABET: &[u8] = b"abcdefghjkmnpqrstuvwxyz23456789";
+        let mut rng = rand::rng();
+
+        (0..count)
+            .map(|_| {
+                let mut bytes = [0u8; 12];
+                rng.fill_bytes(&mut bytes);
+                let chars: Vec<char> = bytes
+                    .iter()
+                    .map(|b| ALPHABET[*b as usize % ALPHABET.len()] as char)
+                    .collect();
+                chars
+                    .chunks(4)
+                    .map(|c| c.iter().collect::<String>())
+                    .collect::<Vec<_>>()
+                    .join("-")
+            })
+            .collect()
+    }
+}
+
+/// Normalises user input so `K3F9 X2MQ 8DTW` matches `k3f9-x2mq-8dtw`.
+pub fn normalize_recovery_code(code: &str) -> String {
+    let compact: String = code
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+
+    compact
+        .as_bytes()
+        .chunks(4)
+        .map(|c| String::from_utf8_lossy(c).into_owned())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+fn encode_uri_component(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'@' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
@@ -0,0 +1,111 @@
+use crate::application::dto::{AcceptInvitationRequest, UserResponse};
+use crate::application::password_hasher::PasswordHasher;
+use crate::application::services::auth_event_recorder::AuthEventRecorder;
+use crate::application::services::password_policy::PasswordPolicyService;
+use crate::domain::entity::{AuthEvent, AuthEventType, User};
+use crate::domain::value_objects::{ClientInfo, Email, HashedPassword, TokenHash};
+use crate::domain::{InvitationRepository, UserRepository};
+use rust_reborn_contracts::common::CodeGenerator;
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+pub struct AcceptInvitationUseCase {
+    invitation_repo: Arc<dyn InvitationRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    password_hasher: Arc<dyn PasswordHasher>,
+    password_policy: Arc<PasswordPolicyService>,
+    code_generator: Arc<dyn CodeGenerator>,
+    events: Arc<AuthEventRecorder>,
+}
+
+impl AcceptInvitationUseCase {
+    pub fn new(
+        invitation_repo: Arc<dyn InvitationRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        password_hasher: Arc<dyn PasswordHasher>,
+        password_policy: Arc<PasswordPolicyService>,
+        code_generator: Arc<dyn CodeGenerator>,
+        events: Arc<AuthEventRecorder>,
+    ) -> Self {
+        Self {
+            invitation_repo,
+            user_repo,
+            password_hasher,
+            password_policy,
+            code_generator,
+            events,
+        }
+    }
+
+    /// Creates the invited account with the invited role. The email address
+    /// counts as verified: the token could only be read from that inbox.
+    pub async fn execute(
+        &self,
+        req: AcceptInvitationRequest,
+        client: ClientInfo,
+    ) -> Result<UserResponse> {
+        let invalid = || AppError::bad_request("invalid or expired invitation");
+
+        let invitation = self
+            .invitation_repo
+            .find_by_token_hash(TokenHash::of(&req.token).value())
+            .await?
+            .filter(|i| i.is_pending())
+            .ok_or_else(invalid)?;
+        let invitation_id = invitation.id.ok_or_else(invalid)?;
+
+        if self
+            .user_repo
+            .find_by_email(&invitation.email)
+            .await?
+            .is_some()
+        {
+            return Err(AppError::conflict("email already registered"));
+        }
+        if self
+            .user_repo
+            .find_by_username(&req.username)
+            .await?
+            .is_some()
+        {
+            return Err(AppError::conflict("username already taken"));
+        }
+
+        self.password_policy
+            .check("password", &req.password, &req.username, &invitation.email)
+            .await?;
+        let email = Email::new(invitation.email.clone())
+            .map_err(|e| AppError::bad_request(format!("invalid email: {}", e)))?;
+
+        let hashed = self.password_hasher.hash(&req.password)?;
+        let mut user = User::new(
+            self.code_generator.generate("usr"),
+            email,
+            req.username,
+            HashedPassword::new(hashed),
+            req.full_name,
+        );
+        user.verify();
+        user.created_by = invitation.invited_by.map(|id| id.to_string());
+
+        if !self
+            .invitation_repo
+            .accept(invitation_id, &mut user)
+            .await?
+        {
+            return Err(invalid());
+        }
+        let user_id = user
+            .id
+            .ok_or_else(|| AppError::internal("user.id is None"))?;
+
+        let event = AuthEvent::success(AuthEventType::Register, user_id, &client);
+        let event = match invitation.invited_by {
+            Some(inviter_id) => event.by_actor(inviter_id),
+            None => event,
+        };
+        self.events.record(event).await;
+
+        Ok(user.into())
+    }
+}
@@ -0,0 +1,84 @@
+use crate::domain::entity::ApiKey;
+use crate::domain::value_objects::TokenHash;
+use crate::domain::{ApiKeyRepository, RoleRepository, UserRepository};
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+/// Who is calling with an API key and what the key lets them do.
+#[derive(Debug, Clone)]
+pub struct ApiKeyPrincipal {
+    pub key_id: i64,
+    pub user_id: i64,
+    /// The key's scopes the owner still holds; revoking a role from the
+    /// owner takes effect on their keys immediately.
+    pub permissions: Vec<String>,
+}
+
+pub struct AuthenticateApiKeyUseCase {
+    api_key_repo: Arc<dyn ApiKeyRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    role_repo: Arc<dyn RoleRepository>,
+}
+
+/// `last_used_at` is only written when older than this, not on every request.
+const LAST_USED_RESOLUTION_SECONDS: i64 = 60;
+
+impl AuthenticateApiKeyUseCase {
+    pub fn new(
+        api_key_repo: Arc<dyn ApiKeyRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        role_repo: Arc<dyn RoleRepository>,
+    ) -> Self {
+        Self {
+            api_key_repo,
+            user_repo,
+            role_repo,
+        }
+    }
+
+    pub async fn execute(&self, raw_key: &str) -> Result<ApiKeyPrincipal> {
+        let key = self
+            .api_key_repo
+            .find_by_prefix(ApiKey::prefix_of(raw_key))
+            .await?
+            .filter(|k| k.key_hash == TokenHash::of(raw_key))
+            .ok_or_else(|| AppError::unauthorized("invalid API key"))?;
+
+        if !key.is_usable() {
+            return Err(AppError::unauthorized("API key is expired or revoked"));
+        }
+        let key_id = key
+            .id
+            .ok_or_else(|| AppError::internal("api_key.id is None"))?;
+
+        let user = self
+            .user_repo
+            .find_by_id(key.user_id)
+            .await?
+            .ok_or_else(|| AppError::unauthorized("invalid API key"))?;
+        if !user.can_login() {
+            return Err(AppError::forbidden("account is not active or verified"));
+        }
+
+        let roles = self.role_repo.find_by_user(key.user_id).await?;
+        let permissions = key
+            .scopes
+            .into_iter()
+            .filter(|scope| roles.iter().any(|r| r.permissions.contains(scope)))
+            .collect();
+
+        let now = chrono::Utc::now();
+        if key
+            .last_used_at
+            .is_none_or(|at| (now - at).num_seconds() >= LAST_USED_RESOLUTION_SECONDS)
+        {
+            self.api_key_repo.touch(key_id, now).await?;
+        }
+
+        Ok(ApiKeyPrincipal {
+            key_id,
+            user_id: key.user_id,
+            permissions,
+        })
+    }
+}
@@ -1,33 +1,98 @@
+use crate::application::logout_all_devices::LogoutAllDevicesUseCase;
 use crate::application::password_hasher::PasswordHasher;
-use crate::domain::value_objects::HashedPassword;
-use crate::domain::{value_objects::Password, UserRepository};
+use crate::application::services::auth_event_recorder::AuthEventRecorder;
+use crate::application::services::password_policy::PasswordPolicyService;
+use crate::application::services::token_issuer::TokenIssuer;
+use crate::domain::entity::{AuthEvent, AuthEventType};
+use crate::domain::value_objects::{ClientInfo, HashedPassword};
+use crate::domain::UserRepository;
+use crate::{ChangePasswordRequest, TokenResponse};
 use rust_reborn_contracts::{AppError, Result};
 use std::sync::Arc;
 
 pub struct ChangePasswordUseCase {
     user_repo: Arc<dyn UserRepository>,
     password_hasher: Arc<dyn PasswordHasher>,
+    password_policy: Arc<PasswordPolicyService>,
+    logout_all_devices: Arc<LogoutAllDevicesUseCase>,
+    token_issuer: Arc<TokenIssuer>,
+    events: Arc<AuthEventRecorder>,
 }
 
 impl ChangePasswordUseCase {
-    pub async fn execute(&self, user_id: i64, old: String, new: String) -> Result<()> {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        password_hasher: Arc<dyn PasswordHasher>,
+        password_policy: Arc<PasswordPolicyService>,
+        logout_all_devices: Arc<LogoutAllDevicesUseCase>,
+        token_issuer: Arc<TokenIssuer>,
+        events: Arc<AuthEventRecorder>,
+    ) -> Self {
+        Self {
+            user_repo,
+            password_hasher,
+            password_policy,
+            logout_all_devices,
+            token_issuer,
+            events,
+        }
+    }
+
+    /// Changes the password, signs out every existing session and returns a
+    /// fresh token pair so the caller's own session carries on.
+    pub async fn execute(
+        &self,
+        user_id: i64,
+        req: ChangePasswordRequest,
+        client: ClientInfo,
+    ) -> Result<TokenResponse> {
         let mut user = self
             .user_repo
             .find_by_id(user_id)
             .await?
             .ok_or_else(|| AppError::not_found("user not found"))?;
 
-        if !self.password_hasher.verify(&old, user.password.value())? {
+        if !self
+            .password_hasher
+            .verify(&req.current_password, user.password.value())?
+        {
+            self.events
+                .record(AuthEvent::failure(
+                    AuthEventType::ChangePassword,
+                    Some(user_id),
+                    &client,
+                    "invalid current password",
+                ))
+                .await;
             return Err(AppError::unauthorized("invalid password"));
         }
 
-        Password::new(new.clone())
-            .map_err(|e| AppError::bad_request(format!("invalid password: {}", e)))?;
+        if req.current_password == req.new_password {
+            return Err(AppError::bad_request(
+                "new password must differ from the current password",
+            ));
+        }
+
+        self.password_policy
+            .check_for_user("new_password", &req.new_password, &user)
+            .await?;
+
+        let hashed = self.password_hasher.hash(&req.new_password)?;
+        self.password_policy.remember(&user).await?;
+        user.change_password(HashedPassword::new(hashed));
+
+        let user = self.user_repo.update(&user).await?;
 
-        user.password = HashedPassword::new(self.password_hasher.hash(&new)?);
-        user.updated_at = chrono::Utc::now();
+        self.logout_all_devices.revoke_all(user_id).await?;
+        let token = self.token_issuer.issue(&user, &client).await?;
+        self.events
+            .record(AuthEvent::success(
+                AuthEventType::ChangePassword,
+                user_id,
+                &client,
+            ))
+            .await;
 
-        self.user_repo.update(&user).await?;
-        Ok(())
+        Ok(token.into())
     }
 }
@@ -0,0 +1,278 @@
+use crate::application::dto::{AuthResponse, LoginResponse, OidcCallbackRequest};
+use crate::application::password_hasher::PasswordHasher;
+use crate::application::services::auth_event_recorder::AuthEventRecorder;
+use crate::application::services::mfa_challenge::MfaChallengeService;
+use crate::application::services::token_issuer::TokenIssuer;
+use crate::domain::entity::{AuthEvent, AuthEventType, ExternalIdentity, User};
+use crate::domain::value_objects::{ClientInfo, Email, HashedPassword, TokenHash};
+use crate::domain::{ExternalIdentityRepository, UserRepository};
+use crate::infrastructure::config::OidcProviderConfig;
+use crate::infrastructure::oidc::{OidcIdentity, OidcProviders};
+use rust_reborn_contracts::common::CodeGenerator;
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+pub struct CompleteOidcLoginUseCase {
+    providers: Arc<OidcProviders>,
+    identity_repo: Arc<dyn ExternalIdentityRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    password_hasher: Arc<dyn PasswordHasher>,
+    code_generator: Arc<dyn CodeGenerator>,
+    token_generator: Arc<dyn CodeGenerator>,
+    token_issuer: Arc<TokenIssuer>,
+    mfa_challenges: Arc<MfaChallengeService>,
+    events: Arc<AuthEventRecorder>,
+    require_verified_email: bool,
+}
+
+impl CompleteOidcLoginUseCase {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        providers: Arc<OidcProviders>,
+        identity_repo: Arc<dyn ExternalIdentityRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        password_hasher: Arc<dyn PasswordHasher>,
+        code_generator: Arc<dyn CodeGenerator>,
+        token_generator: Arc<dyn CodeGenerator>,
+        token_issuer: Arc<TokenIssuer>,
+        mfa_challenges: Arc<MfaChallengeService>,
+        events: Arc<AuthEventRecorder>,
+        require_verified_email: bool,
+    ) -> Self {
+        Self {
+            providers,
+            identity_repo,
+            user_repo,
+            password_hasher,
+            code_generator,
+            token_generator,
+            token_issuer,
+            mfa_challenges,
+            events,
+            require_verified_email,
+        }
+    }
+
+    /// Redeems the provider's authorization code and signs in the linked
+    /// account, linking or creating one first when the provider allows it.
+    pub async fn execute(
+        &self,
+        provider: &str,
+        req: OidcCallbackRequest,
+        client: ClientInfo,
+    ) -> Result<LoginResponse> {
+        let oidc = self.providers.get(provider)?;
+
+        let state = self
+            .identity_repo
+            .take_login_state(TokenHash::of(&req.state).value())
+            .await?
+            .filter(|s| s.provider == provider && !s.is_expired())
+            .ok_or_else(|| AppError::unauthorized("invalid or expired login state"))?;
+
+        let identity = oidc
+            .exchange_code(&req.code, &state.code_verifier, &state.nonce)
+            .await?;
+
+        let mut user = match self.resolve_user(oidc.config(), &identity, &client).await {
+            Ok(user) => user,
+            Err(e) => return Err(self.failed(None, &client, "identity not linked", e).await),
+        };
+        let user_id = user
+            .id
+            .ok_or_else(|| AppError::internal("user.id is None"))?;
+
+        if !user.can_login() {
+            let err = AppError::forbidden("account is not active or verified");
+            return Err(self
+                .failed(Some(user_id), &client, "account inactive", err)
+                .await);
+        }
+
+        if self.require_verified_email && !user.is_verified {
+            let err = AppError::forbidden("email address is not verified");
+            return Err(self
+                .failed(Some(user_id), &client, "email not verified", err)
+                .await);
+        }
+
+        if self.mfa_challenges.is_required(user_id).await? {
+            let challenge = self.mfa_challenges.start(user_id).await?;
+            return Ok(LoginResponse::MfaRequired(challenge));
+        }
+
+        user.update_last_login();
+        let user = self.user_repo.update(&user).await?;
+
+        let token = self.token_issuer.issue(&user, &client).await?;
+        self.events
+            .record(AuthEvent::success(AuthEventType::Login, user_id, &client))
+            .await;
+
+        Ok(LoginResponse::Authenticated(AuthResponse {
+            user: user.into(),
+            token: token.into(),
+            password_expired: false,
+        }))
+    }
+
+    async fn failed(
+        &self,
+        user_id: Option<i64>,
+        client: &ClientInfo,
+        reason: &str,
+        err: AppError,
+    ) -> AppError {
+        self.events
+            .record(AuthEvent::failure(
+                AuthEventType::Login,
+                user_id,
+                client,
+                reason,
+            ))
+            .await;
+        err
+    }
+
+    /// An identity seen before signs in its linked user. Otherwise a
+    /// provider-verified email links to the account with that address, and
+    /// failing that a new account is created for it when the provider
+    /// allows it.
+    async fn resolve_user(
+        &self,
+        config: &OidcProviderConfig,
+        identity: &OidcIdentity,
+        client: &ClientInfo,
+    ) -> Result<User> {
+        if let Some(linked) = self
+            .identity_repo
+            .find(&config.name, &identity.subject)
+            .await?
+        {
+            let user = self
+                .user_repo
+                .find_by_id(linked.user_id)
+                .await?
+                .ok_or_else(|| AppError::unauthorized("linked account no longer exists"))?;
+            if let Some(id) = linked.id {
+                self.identity_repo
+                    .record_login(id, chrono::Utc::now())
+                    .await?;
+            }
+            return Ok(user);
+        }
+
+        let email = identity.email.as_deref().ok_or_else(|| {
+            AppError::unauthorized("identity provider did not share an email address")
+        })?;
+
+        if let Some(existing) = self.user_repo.find_by_email(email).await? {
+            // Linking to an unverified account would hand it to whoever
+            // registered the address without owning it.
+            if !(config.link_verified_email && identity.email_verified && existing.is_verified) {
+                return Err(AppError::conflict(
+                    "an account with this email already exists; sign in with your password",
+                ));
+            }
+            self.link(config, identity, &existing).await?;
+            return Ok(existing);
+        }
+
+        if !config.auto_provision {
+            return Err(AppError::forbidden("no account is linked to this identity"));
+        }
+        // An unverified address would stay reserved for whoever used it,
+        // blocking its owner from registering.
+        if !identity.email_verified {
+            return Err(AppError::forbidden(
+                "identity provider has not verified this email address",
+            ));
+        }
+
+        let user = self.provision(identity, email).await?;
+        self.link(config, identity, &user).await?;
+        if let Some(user_id) = user.id {
+            self.events
+                .record(AuthEvent::success(AuthEventType::Register, user_id, client))
+                .await;
+        }
+        Ok(user)
+    }
+
+    async fn link(
+        &self,
+        config: &OidcProviderConfig,
+        identity: &OidcIdentity,
+        user: &User,
+    ) -> Result<()> {
+        let user_id = user
+            .id
+            .ok_or_else(|| AppError::internal("user.id is None"))?;
+        let mut external = ExternalIdentity::new(
+            user_id,
+            &config.name,
+            &identity.subject,
+            identity.email.clone(),
+        );
+        self.identity_repo.save(&mut external).await
+    }
+
+    /// Creates an account for a first-time provider login. It gets an
+    /// unusable random password; a password reset sets a real one.
+    async fn provision(&self, identity: &OidcIdentity, email: &str) -> Result<User> {
+        let email = Email::new(email.to_string())
+            .map_err(|e| AppError::bad_request(format!("invalid email: {}", e)))?;
+        let username = self.available_username(identity, email.value()).await?;
+        let password = self
+            .password_hasher
+            .hash(&self.token_generator.generate("pwd"))?;
+
+        let mut user = User::new(
+            self.code_generator.generate("usr"),
+            email,
+            username,
+            HashedPassword::new(password),
+            identity.name.clone(),
+        );
+        user.verify();
+
+        self.user_repo.save(&mut user).await?;
+        Ok(user)
+    }
+
+    async fn available_username(&self, identity: &OidcIdentity, email: &str) -> Result<String> {
+        let base = identity
+            .preferred_username
+            .as_deref()
+            .map(sanitize_username)
+            .filter(|u| u.len() >= 3)
+            .unwrap_or_else(|| sanitize_username(email.split('@').next().unwrap_or_default()));
+        let base = if base.len() < 3 {
+            format!("user{}", base)
+        } else {
+            base
+        };
+
+        if self.user_repo.find_by_username(&base).await?.is_none() {
+            return Ok(base);
+        }
+        for n in 2..100 {
+            let candidate = format!("{}{}", base, n);
+            if self.user_repo.find_by_username(&candidate).await?.is_none() {
+                return Ok(candidate);
+            }
+        }
+
+        Err(AppError::conflict("could not find a free username"))
+    }
+}
+
+/// Keeps letters, digits, `.`, `_` and `-` and limits the length so a
+/// numeric suffix still fits the 50 character column.
+fn sanitize_username(raw: &str) -> String {
+    raw.chars()
+        .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'))
+        .take(40)
+        .collect::<String>()
+        .to_lowercase()
+}
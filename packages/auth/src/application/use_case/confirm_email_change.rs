@@ -0,0 +1,49 @@
+use crate::domain::value_objects::TokenHash;
+use crate::domain::UserRepository;
+use crate::{ConfirmEmailChangeRequest, UserResponse};
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+pub struct ConfirmEmailChangeUseCase {
+    user_repo: Arc<dyn UserRepository>,
+}
+
+impl ConfirmEmailChangeUseCase {
+    pub fn new(user_repo: Arc<dyn UserRepository>) -> Self {
+        Self { user_repo }
+    }
+
+    pub async fn execute(&self, req: ConfirmEmailChangeRequest) -> Result<UserResponse> {
+        let hash = TokenHash::of(&req.token);
+
+        let mut user = self
+            .user_repo
+            .find_by_email_change_key(hash.value())
+            .await?
+            .ok_or_else(|| AppError::bad_request("invalid email change token"))?;
+
+        if user.email_change_key_expired() {
+            return Err(AppError::bad_request("email change token expired"));
+        }
+
+        // Someone may have registered the address since the change was requested.
+        if let Some(pending) = &user.pending_email {
+            if self
+                .user_repo
+                .find_by_email(pending.value())
+                .await?
+                .is_some()
+            {
+                return Err(AppError::conflict("email already registered"));
+            }
+        }
+
+        let updated_by = user.code.clone();
+        if !user.confirm_email_change(&updated_by) {
+            return Err(AppError::bad_request("invalid email change token"));
+        }
+        let user = self.user_repo.update(&user).await?;
+
+        Ok(user.into())
+    }
+}
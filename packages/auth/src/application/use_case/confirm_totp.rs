@@ -0,0 +1,67 @@
+use crate::application::services::totp::{normalize_recovery_code, TotpService};
+use crate::domain::value_objects::TokenHash;
+use crate::domain::MfaRepository;
+use crate::{ConfirmTotpRequest, RecoveryCodesResponse};
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+pub struct ConfirmTotpUseCase {
+    mfa_repo: Arc<dyn MfaRepository>,
+    totp: Arc<TotpService>,
+    recovery_code_count: usize,
+}
+
+impl ConfirmTotpUseCase {
+    pub fn new(
+        mfa_repo: Arc<dyn MfaRepository>,
+        totp: Arc<TotpService>,
+        recovery_code_count: usize,
+    ) -> Self {
+        Self {
+            mfa_repo,
+            totp,
+            recovery_code_count,
+        }
+    }
+
+    /// Enables the pending factor and returns one-time recovery codes. The
+    /// codes are stored hashed and cannot be shown again.
+    pub async fn execute(
+        &self,
+        user_id: i64,
+        req: ConfirmTotpRequest,
+    ) -> Result<RecoveryCodesResponse> {
+        let factor =
+            self.mfa_repo.find_totp(user_id).await?.ok_or_else(|| {
+                AppError::bad_request("two-factor enrollment has not been started")
+            })?;
+
+        if factor.is_confirmed() {
+            return Err(AppError::conflict(
+                "two-factor authentication is already enabled",
+            ));
+        }
+
+        let step = self
+            .totp
+            .verify(&factor.secret, &req.code)?
+            .ok_or_else(|| AppError::bad_request("invalid verification code"))?;
+
+        let recovery_codes = self.totp.generate_recovery_codes(self.recovery_code_count);
+        let hashes: Vec<String> = recovery_codes
+            .iter()
+            .map(|code| {
+                TokenHash::of(&normalize_recovery_code(code))
+                    .value()
+                    .to_string()
+            })
+            .collect();
+
+        self.mfa_repo
+            .replace_recovery_codes(user_id, &hashes)
+            .await?;
+        self.mfa_repo.confirm_totp(user_id, step).await?;
+
+        Ok(RecoveryCodesResponse { recovery_codes })
+    }
+}
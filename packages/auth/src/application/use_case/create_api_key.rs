@@ -0,0 +1,73 @@
+use crate::application::dto::{ApiKeyResponse, CreateApiKeyRequest, CreatedApiKeyResponse};
+use crate::domain::entity::ApiKey;
+use crate::domain::{ApiKeyRepository, RoleRepository};
+use rust_reborn_contracts::common::CodeGenerator;
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+pub struct CreateApiKeyUseCase {
+    api_key_repo: Arc<dyn ApiKeyRepository>,
+    role_repo: Arc<dyn RoleRepository>,
+    token_generator: Arc<dyn CodeGenerator>,
+}
+
+impl CreateApiKeyUseCase {
+    pub fn new(
+        api_key_repo: Arc<dyn ApiKeyRepository>,
+        role_repo: Arc<dyn RoleRepository>,
+        token_generator: Arc<dyn CodeGenerator>,
+    ) -> Self {
+        Self {
+            api_key_repo,
+            role_repo,
+            token_generator,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: i64,
+        req: CreateApiKeyRequest,
+    ) -> Result<CreatedApiKeyResponse> {
+        let scopes = grantable_scopes(self.role_repo.as_ref(), user_id, req.scopes).await?;
+        let expires_at = req
+            .expires_in_days
+            .map(|days| chrono::Utc::now() + chrono::Duration::days(days));
+
+        let raw_key = self.token_generator.generate("rbk");
+        let mut key = ApiKey::new(user_id, req.name, &raw_key, scopes, expires_at);
+        self.api_key_repo.save(&mut key).await?;
+
+        Ok(CreatedApiKeyResponse {
+            key: raw_key,
+            api_key: ApiKeyResponse::from(key),
+        })
+    }
+}
+
+/// Normalizes requested scopes and refuses any the user does not hold,
+/// so a key can never do more than its owner.
+pub(crate) async fn grantable_scopes(
+    role_repo: &dyn RoleRepository,
+    user_id: i64,
+    mut scopes: Vec<String>,
+) -> Result<Vec<String>> {
+    scopes.sort();
+    scopes.dedup();
+
+    let roles = role_repo.find_by_user(user_id).await?;
+    let missing: Vec<&str> = scopes
+        .iter()
+        .filter(|scope| !roles.iter().any(|r| r.permissions.contains(scope)))
+        .map(String::as_str)
+        .collect();
+
+    if !missing.is_empty() {
+        return Err(AppError::bad_request(format!(
+            "scopes not granted to you: {}",
+            missing.join(", ")
+        )));
+    }
+
+    Ok(scopes)
+}
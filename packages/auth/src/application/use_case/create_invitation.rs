@@ -0,0 +1,76 @@
+use crate::application::dto::{CreateInvitationRequest, InvitationResponse};
+use crate::application::port::account_notifier::AccountNotifier;
+use crate::domain::entity::Invitation;
+use crate::domain::{InvitationRepository, RoleRepository, UserRepository};
+use rust_reborn_contracts::common::CodeGenerator;
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+pub struct CreateInvitationUseCase {
+    invitation_repo: Arc<dyn InvitationRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    role_repo: Arc<dyn RoleRepository>,
+    token_generator: Arc<dyn CodeGenerator>,
+    notifier: Arc<dyn AccountNotifier>,
+    token_ttl: chrono::Duration,
+}
+
+impl CreateInvitationUseCase {
+    pub fn new(
+        invitation_repo: Arc<dyn InvitationRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        role_repo: Arc<dyn RoleRepository>,
+        token_generator: Arc<dyn CodeGenerator>,
+        notifier: Arc<dyn AccountNotifier>,
+        token_ttl: chrono::Duration,
+    ) -> Self {
+        Self {
+            invitation_repo,
+            user_repo,
+            role_repo,
+            token_generator,
+            notifier,
+            token_ttl,
+        }
+    }
+
+    /// Emails an invitation link. Inviting the same address again replaces
+    /// the earlier invitation.
+    pub async fn execute(
+        &self,
+        inviter_id: i64,
+        req: CreateInvitationRequest,
+    ) -> Result<InvitationResponse> {
+        if self.user_repo.find_by_email(&req.email).await?.is_some() {
+            return Err(AppError::conflict("email already registered"));
+        }
+        if !self.role_repo.exists(&req.role).await? {
+            return Err(AppError::not_found(format!("role not found: {}", req.role)));
+        }
+        let inviter = self
+            .user_repo
+            .find_by_id(inviter_id)
+            .await?
+            .ok_or_else(|| AppError::unauthorized("user not found"))?;
+
+        let token = self.token_generator.generate("inv");
+        let mut invitation = Invitation::new(
+            req.email,
+            req.role,
+            inviter_id,
+            &token,
+            chrono::Utc::now() + self.token_ttl,
+        );
+        self.invitation_repo.save(&mut invitation).await?;
+
+        if let Err(e) = self
+            .notifier
+            .send_invitation(&invitation, &inviter, &token)
+            .await
+        {
+            tracing::error!(invitation_id = ?invitation.id, error = ?e, "failed to send invitation");
+        }
+
+        Ok(invitation.into())
+    }
+}
@@ -0,0 +1,22 @@
+use crate::application::dto::{CreateOrganizationRequest, OrganizationResponse};
+use crate::domain::entity::Organization;
+use crate::domain::OrganizationRepository;
+use rust_reborn_contracts::Result;
+use std::sync::Arc;
+
+pub struct CreateOrganizationUseCase {
+    organization_repo: Arc<dyn OrganizationRepository>,
+}
+
+impl CreateOrganizationUseCase {
+    pub fn new(organization_repo: Arc<dyn OrganizationRepository>) -> Self {
+        Self { organization_repo }
+    }
+
+    pub async fn execute(&self, req: CreateOrganizationRequest) -> Result<OrganizationResponse> {
+        let mut organization = Organization::new(req.slug, req.name.trim().to_string())?;
+        self.organization_repo.save(&mut organization).await?;
+
+        Ok(organization.into())
+    }
+}
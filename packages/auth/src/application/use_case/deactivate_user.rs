@@ -0,0 +1,60 @@
+use crate::application::dto::AdminUserResponse;
+use crate::application::logout_all_devices::LogoutAllDevicesUseCase;
+use crate::application::services::auth_event_recorder::AuthEventRecorder;
+use crate::domain::entity::{AuthEvent, AuthEventType};
+use crate::domain::value_objects::ClientInfo;
+use crate::domain::UserRepository;
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+pub struct DeactivateUserUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    logout_all_devices: Arc<LogoutAllDevicesUseCase>,
+    events: Arc<AuthEventRecorder>,
+}
+
+impl DeactivateUserUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        logout_all_devices: Arc<LogoutAllDevicesUseCase>,
+        events: Arc<AuthEventRecorder>,
+    ) -> Self {
+        Self {
+            user_repo,
+            logout_all_devices,
+            events,
+        }
+    }
+
+    /// Blocks the account from logging in and signs it out everywhere.
+    pub async fn execute(
+        &self,
+        actor_id: i64,
+        user_id: i64,
+        client: ClientInfo,
+    ) -> Result<AdminUserResponse> {
+        if actor_id == user_id {
+            return Err(AppError::bad_request(
+                "you cannot deactivate your own account",
+            ));
+        }
+
+        let mut user = self
+            .user_repo
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("user not found"))?;
+
+        user.deactivate();
+        let user = self.user_repo.update(&user).await?;
+
+        self.logout_all_devices.revoke_all(user_id).await?;
+        self.events
+            .record(
+                AuthEvent::success(AuthEventType::Deactivate, user_id, &client).by_actor(actor_id),
+            )
+            .await;
+
+        Ok(user.into())
+    }
+}
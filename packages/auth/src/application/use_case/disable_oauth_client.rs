@@ -0,0 +1,27 @@
+use crate::domain::OAuthClientRepository;
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+pub struct DisableOAuthClientUseCase {
+    client_repo: Arc<dyn OAuthClientRepository>,
+}
+
+impl DisableOAuthClientUseCase {
+    pub fn new(client_repo: Arc<dyn OAuthClientRepository>) -> Self {
+        Self { client_repo }
+    }
+
+    /// The client gets no new tokens and the ones already issued stop being accepted.
+    pub async fn execute(&self, actor_id: i64, id: i64) -> Result<()> {
+        let client = self
+            .client_repo
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::not_found("OAuth client not found"))?;
+
+        self.client_repo.set_active(id, false).await?;
+        tracing::info!(actor_id, client_id = %client.client_id, "OAuth client disabled");
+
+        Ok(())
+    }
+}
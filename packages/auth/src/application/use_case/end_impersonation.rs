@@ -0,0 +1,50 @@
+use crate::application::services::auth_event_recorder::AuthEventRecorder;
+use crate::application::verify_token::VerifyTokenUseCase;
+use crate::domain::entity::{AuthEvent, AuthEventType};
+use crate::domain::value_objects::ClientInfo;
+use crate::domain::TokenRevocationRepository;
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+pub struct EndImpersonationUseCase {
+    verify_token: Arc<VerifyTokenUseCase>,
+    revocation_repo: Arc<dyn TokenRevocationRepository>,
+    events: Arc<AuthEventRecorder>,
+}
+
+impl EndImpersonationUseCase {
+    pub fn new(
+        verify_token: Arc<VerifyTokenUseCase>,
+        revocation_repo: Arc<dyn TokenRevocationRepository>,
+        events: Arc<AuthEventRecorder>,
+    ) -> Self {
+        Self {
+            verify_token,
+            revocation_repo,
+            events,
+        }
+    }
+
+    /// Revokes the impersonation token presented with the request.
+    pub async fn execute(&self, access_token: &str, client: ClientInfo) -> Result<()> {
+        let claims = self.verify_token.verify(access_token).await?;
+        let actor_id = claims
+            .actor_id()?
+            .ok_or_else(|| AppError::bad_request("not an impersonation token"))?;
+        let user_id = claims.user_id()?;
+
+        self.revocation_repo
+            .revoke(&claims.jti, user_id, claims.expires_at())
+            .await?;
+
+        tracing::info!(actor_id, user_id, "impersonation ended");
+        self.events
+            .record(
+                AuthEvent::success(AuthEventType::ImpersonationEnd, user_id, &client)
+                    .by_actor(actor_id),
+            )
+            .await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use crate::application::services::totp::TotpService;
+use crate::domain::entity::TotpFactor;
+use crate::domain::{MfaRepository, UserRepository};
+use crate::TotpEnrollmentResponse;
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+pub struct EnrollTotpUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    mfa_repo: Arc<dyn MfaRepository>,
+    totp: Arc<TotpService>,
+}
+
+impl EnrollTotpUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        mfa_repo: Arc<dyn MfaRepository>,
+        totp: Arc<TotpService>,
+    ) -> Self {
+        Self {
+            user_repo,
+            mfa_repo,
+            totp,
+        }
+    }
+
+    /// Starts (or restarts) enrollment with a new secret. The factor does not
+    /// protect logins until it is confirmed with a code.
+    pub async fn execute(&self, user_id: i64) -> Result<TotpEnrollmentResponse> {
+        let user = self
+            .user_repo
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("user not found"))?;
+
+        if let Some(existing) = self.mfa_repo.find_totp(user_id).await? {
+            if existing.is_confirmed() {
+                return Err(AppError::conflict(
+                    "two-factor authentication is already enabled",
+                ));
+            }
+        }
+
+        let secret = self.totp.generate_secret();
+        self.mfa_repo
+            .save_totp(&TotpFactor::new(user_id, secret.clone()))
+            .await?;
+
+        let otpauth_uri = self.totp.otpauth_uri(&secret, user.email.value());
+        let qr_svg = self.totp.qr_svg(&otpauth_uri)?;
+
+        Ok(TotpEnrollmentResponse {
+            secret,
+            otpauth_uri,
+            qr_svg,
+        })
+    }
+}
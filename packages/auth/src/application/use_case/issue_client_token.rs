@@ -0,0 +1,73 @@
+use crate::application::dto::ClientTokenResponse;
+use crate::domain::OAuthClientRepository;
+use crate::infrastructure::jwt::JwtService;
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+/// OAuth 2.0 client-credentials grant: a registered service exchanges its
+/// id and secret for an access token with its own scopes.
+pub struct IssueClientTokenUseCase {
+    client_repo: Arc<dyn OAuthClientRepository>,
+    jwt_service: Arc<JwtService>,
+    ttl: chrono::Duration,
+}
+
+impl IssueClientTokenUseCase {
+    pub fn new(
+        client_repo: Arc<dyn OAuthClientRepository>,
+        jwt_service: Arc<JwtService>,
+        ttl: chrono::Duration,
+    ) -> Self {
+        Self {
+            client_repo,
+            jwt_service,
+            ttl,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+        scope: Option<&str>,
+    ) -> Result<ClientTokenResponse> {
+        let client = self
+            .client_repo
+            .find_by_client_id(client_id)
+            .await?
+            .filter(|c| c.is_active && c.verify_secret(client_secret))
+            .ok_or_else(|| AppError::unauthorized("invalid client credentials"))?;
+
+        let scopes: Vec<String> = match scope {
+            Some(requested) => {
+                let mut requested: Vec<String> =
+                    requested.split_whitespace().map(str::to_string).collect();
+                requested.sort();
+                requested.dedup();
+                if let Some(denied) = requested.iter().find(|s| !client.scopes.contains(s)) {
+                    return Err(AppError::bad_request(format!(
+                        "scope not allowed for this client: {}",
+                        denied
+                    )));
+                }
+                requested
+            }
+            None => client.scopes.clone(),
+        };
+
+        let access_token =
+            self.jwt_service
+                .generate_service_token(&client.client_id, &scopes, self.ttl)?;
+
+        if let Some(id) = client.id {
+            self.client_repo.touch(id, chrono::Utc::now()).await?;
+        }
+
+        Ok(ClientTokenResponse {
+            access_token,
+            token_type: "Bearer",
+            expires_in: self.ttl.num_seconds(),
+            scope: scopes.join(" "),
+        })
+    }
+}
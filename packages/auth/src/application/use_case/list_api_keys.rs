@@ -0,0 +1,20 @@
+use crate::application::dto::ApiKeyResponse;
+use crate::domain::ApiKeyRepository;
+use rust_reborn_contracts::Result;
+use std::sync::Arc;
+
+pub struct ListApiKeysUseCase {
+    api_key_repo: Arc<dyn ApiKeyRepository>,
+}
+
+impl ListApiKeysUseCase {
+    pub fn new(api_key_repo: Arc<dyn ApiKeyRepository>) -> Self {
+        Self { api_key_repo }
+    }
+
+    pub async fn execute(&self, user_id: i64) -> Result<Vec<ApiKeyResponse>> {
+        let keys = self.api_key_repo.find_by_user(user_id).await?;
+
+        Ok(keys.into_iter().map(ApiKeyResponse::from).collect())
+    }
+}
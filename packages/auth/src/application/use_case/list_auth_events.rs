@@ -0,0 +1,35 @@
+use crate::application::dto::{AuthEventResponse, ListAuthEventsQuery};
+use crate::domain::{AuthEventFilter, AuthEventRepository};
+use rust_reborn_contracts::common::{PaginatedResponse, PaginationParams};
+use rust_reborn_contracts::Result;
+use std::sync::Arc;
+
+pub struct ListAuthEventsUseCase {
+    event_repo: Arc<dyn AuthEventRepository>,
+}
+
+impl ListAuthEventsUseCase {
+    pub fn new(event_repo: Arc<dyn AuthEventRepository>) -> Self {
+        Self { event_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        query: ListAuthEventsQuery,
+        pagination: PaginationParams,
+    ) -> Result<PaginatedResponse<AuthEventResponse>> {
+        let filter = AuthEventFilter::try_from(query)?;
+
+        let total = self.event_repo.count(&filter).await?;
+        let events = self
+            .event_repo
+            .find_all(&filter, pagination.limit(), pagination.offset())
+            .await?;
+
+        Ok(PaginatedResponse::new(
+            events.into_iter().map(AuthEventResponse::from).collect(),
+            &pagination,
+            total,
+        ))
+    }
+}
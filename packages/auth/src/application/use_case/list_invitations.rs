@@ -0,0 +1,23 @@
+use crate::application::dto::InvitationResponse;
+use crate::domain::InvitationRepository;
+use rust_reborn_contracts::Result;
+use std::sync::Arc;
+
+pub struct ListInvitationsUseCase {
+    invitation_repo: Arc<dyn InvitationRepository>,
+}
+
+impl ListInvitationsUseCase {
+    pub fn new(invitation_repo: Arc<dyn InvitationRepository>) -> Self {
+        Self { invitation_repo }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<InvitationResponse>> {
+        let invitations = self.invitation_repo.find_all().await?;
+
+        Ok(invitations
+            .into_iter()
+            .map(InvitationResponse::from)
+            .collect())
+    }
+}
@@ -0,0 +1,41 @@
+use crate::application::dto::{OrganizationResponse, UserOrganizationResponse};
+use crate::domain::OrganizationRepository;
+use rust_reborn_contracts::Result;
+use std::sync::Arc;
+
+pub struct ListMyOrganizationsUseCase {
+    organization_repo: Arc<dyn OrganizationRepository>,
+}
+
+impl ListMyOrganizationsUseCase {
+    pub fn new(organization_repo: Arc<dyn OrganizationRepository>) -> Self {
+        Self { organization_repo }
+    }
+
+    /// Active organizations the user belongs to, with their roles there.
+    pub async fn execute(&self, user_id: i64) -> Result<Vec<UserOrganizationResponse>> {
+        let memberships = self
+            .organization_repo
+            .find_memberships_of_user(user_id)
+            .await?;
+
+        let mut organizations = Vec::with_capacity(memberships.len());
+        for membership in memberships {
+            let Some(organization) = self
+                .organization_repo
+                .find_by_id(membership.organization_id)
+                .await?
+                .filter(|o| o.is_active)
+            else {
+                continue;
+            };
+
+            organizations.push(UserOrganizationResponse {
+                organization: OrganizationResponse::from(organization),
+                roles: membership.role_names(),
+            });
+        }
+
+        Ok(organizations)
+    }
+}
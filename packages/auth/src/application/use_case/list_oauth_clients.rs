@@ -0,0 +1,20 @@
+use crate::application::dto::OAuthClientResponse;
+use crate::domain::OAuthClientRepository;
+use rust_reborn_contracts::Result;
+use std::sync::Arc;
+
+pub struct ListOAuthClientsUseCase {
+    client_repo: Arc<dyn OAuthClientRepository>,
+}
+
+impl ListOAuthClientsUseCase {
+    pub fn new(client_repo: Arc<dyn OAuthClientRepository>) -> Self {
+        Self { client_repo }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<OAuthClientResponse>> {
+        let clients = self.client_repo.find_all().await?;
+
+        Ok(clients.into_iter().map(OAuthClientResponse::from).collect())
+    }
+}
@@ -0,0 +1,25 @@
+use crate::application::dto::MembershipResponse;
+use crate::domain::OrganizationRepository;
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+pub struct ListOrganizationMembersUseCase {
+    organization_repo: Arc<dyn OrganizationRepository>,
+}
+
+impl ListOrganizationMembersUseCase {
+    pub fn new(organization_repo: Arc<dyn OrganizationRepository>) -> Self {
+        Self { organization_repo }
+    }
+
+    pub async fn execute(&self, organization_id: i64) -> Result<Vec<MembershipResponse>> {
+        self.organization_repo
+            .find_by_id(organization_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("organization not found"))?;
+
+        let members = self.organization_repo.find_members(organization_id).await?;
+
+        Ok(members.into_iter().map(MembershipResponse::from).collect())
+    }
+}
@@ -0,0 +1,23 @@
+use crate::application::dto::OrganizationResponse;
+use crate::domain::OrganizationRepository;
+use rust_reborn_contracts::Result;
+use std::sync::Arc;
+
+pub struct ListOrganizationsUseCase {
+    organization_repo: Arc<dyn OrganizationRepository>,
+}
+
+impl ListOrganizationsUseCase {
+    pub fn new(organization_repo: Arc<dyn OrganizationRepository>) -> Self {
+        Self { organization_repo }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<OrganizationResponse>> {
+        let organizations = self.organization_repo.find_all().await?;
+
+        Ok(organizations
+            .into_iter()
+            .map(OrganizationResponse::from)
+            .collect())
+    }
+}
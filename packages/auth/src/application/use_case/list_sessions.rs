@@ -0,0 +1,38 @@
+use crate::application::dto::SessionResponse;
+use crate::domain::SessionRepository;
+use chrono::Duration;
+use rust_reborn_contracts::Result;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct ListSessionsUseCase {
+    session_repo: Arc<dyn SessionRepository>,
+    idle_timeout: Duration,
+}
+
+impl ListSessionsUseCase {
+    /// Sessions not seen within `idle_timeout` can no longer refresh and are left out.
+    pub fn new(session_repo: Arc<dyn SessionRepository>, idle_timeout: Duration) -> Self {
+        Self {
+            session_repo,
+            idle_timeout,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: i64,
+        current_session: Option<Uuid>,
+    ) -> Result<Vec<SessionResponse>> {
+        let seen_since = chrono::Utc::now() - self.idle_timeout;
+        let sessions = self
+            .session_repo
+            .find_active_by_user(user_id, seen_since)
+            .await?;
+
+        Ok(sessions
+            .into_iter()
+            .map(|s| SessionResponse::from_session(s, current_session))
+            .collect())
+    }
+}
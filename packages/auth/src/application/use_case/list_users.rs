@@ -0,0 +1,35 @@
+use crate::application::dto::{AdminUserResponse, ListUsersQuery};
+use crate::domain::{UserFilter, UserRepository};
+use rust_reborn_contracts::common::{PaginatedResponse, PaginationParams};
+use rust_reborn_contracts::Result;
+use std::sync::Arc;
+
+pub struct ListUsersUseCase {
+    user_repo: Arc<dyn UserRepository>,
+}
+
+impl ListUsersUseCase {
+    pub fn new(user_repo: Arc<dyn UserRepository>) -> Self {
+        Self { user_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        query: ListUsersQuery,
+        pagination: PaginationParams,
+    ) -> Result<PaginatedResponse<AdminUserResponse>> {
+        let filter = UserFilter::from(query);
+
+        let total = self.user_repo.count(&filter).await?;
+        let users = self
+            .user_repo
+            .find_all(&filter, pagination.limit(), pagination.offset())
+            .await?;
+
+        Ok(PaginatedResponse::new(
+            users.into_iter().map(AdminUserResponse::from).collect(),
+            &pagination,
+            total,
+        ))
+    }
+}
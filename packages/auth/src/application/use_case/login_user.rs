@@ -1,62 +1,175 @@
 use crate::application::password_hasher::PasswordHasher;
+use crate::application::services::auth_event_recorder::AuthEventRecorder;
+use crate::application::services::login_throttle::LoginThrottleService;
+use crate::application::services::mfa_challenge::MfaChallengeService;
+use crate::application::services::password_policy::PasswordPolicyService;
+use crate::application::services::token_issuer::TokenIssuer;
+use crate::domain::entity::{AuthEvent, AuthEventType};
+use crate::domain::value_objects::{ClientInfo, HashedPassword};
 use crate::domain::UserRepository;
-use crate::infrastructure::jwt::JwtService;
-use crate::infrastructure::password::PasswordService;
-use crate::{AuthResponse, LoginRequest, TokenResponse, User};
+use crate::{AuthResponse, LoginRequest, LoginResponse, User};
 use rust_reborn_contracts::{AppError, Result};
 use std::sync::Arc;
 
 pub struct LoginUserUseCase {
     user_repo: Arc<dyn UserRepository>,
-    token_provider: Arc<JwtService>,
-    password_service: Arc<PasswordService>,
+    token_issuer: Arc<TokenIssuer>,
+    password_hasher: Arc<dyn PasswordHasher>,
+    password_policy: Arc<PasswordPolicyService>,
+    mfa_challenges: Arc<MfaChallengeService>,
+    throttle: Arc<LoginThrottleService>,
+    events: Arc<AuthEventRecorder>,
+    require_verified_email: bool,
 }
 
 impl LoginUserUseCase {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         user_repo: Arc<dyn UserRepository>,
-        token_provider: Arc<JwtService>,
-        password_service: Arc<PasswordService>,
+        token_issuer: Arc<TokenIssuer>,
+        password_hasher: Arc<dyn PasswordHasher>,
+        password_policy: Arc<PasswordPolicyService>,
+        mfa_challenges: Arc<MfaChallengeService>,
+        throttle: Arc<LoginThrottleService>,
+        events: Arc<AuthEventRecorder>,
+        require_verified_email: bool,
     ) -> Self {
         Self {
             user_repo,
-            token_provider,
-            password_service,
+            token_issuer,
+            password_hasher,
+            password_policy,
+            mfa_challenges,
+            throttle,
+            events,
+            require_verified_email,
         }
     }
 
-    pub async fn execute(&self, req: LoginRequest) -> Result<AuthResponse> {
-        let user = self
-            .find_user_by_username_or_email(&req.username)
-            .await?
-            .ok_or_else(|| AppError::unauthorized("invalid credentials"))?;
+    /// Returns the token pair, or an MFA challenge when the account has a
+    /// confirmed second factor.
+    pub async fn execute(&self, req: LoginRequest, client: ClientInfo) -> Result<LoginResponse> {
+        if let Err(e) = self.throttle.ensure_ip_allowed(client.ip).await {
+            return Err(self.failed(None, &client, "address throttled", e).await);
+        }
+
+        let Some(user) = self.find_user_by_username_or_email(&req.username).await? else {
+            // The attempt that triggers a lockout is still recorded.
+            let err = self
+                .throttle
+                .record_failure(None, client.ip)
+                .await
+                .err()
+                .unwrap_or_else(|| AppError::unauthorized("invalid credentials"));
+            return Err(self.failed(None, &client, "unknown account", err).await);
+        };
+        let user_id = user
+            .id
+            .ok_or_else(|| AppError::internal("user.id is None"))?;
+
+        // Checked before the password so a locked account gives no signal to guesses.
+        if let Err(e) = self.throttle.ensure_account_unlocked(user_id).await {
+            return Err(self
+                .failed(Some(user_id), &client, "account locked", e)
+                .await);
+        }
 
         if !user.can_login() {
-            return Err(AppError::forbidden("account is not active or verified"));
+            let err = AppError::forbidden("account is not active or verified");
+            return Err(self
+                .failed(Some(user_id), &client, "account inactive", err)
+                .await);
         }
 
         if !self
-            .password_service
+            .password_hasher
             .verify(&req.password, user.password.value())?
         {
-            return Err(AppError::unauthorized("invalid credentials"));
+            let err = self
+                .throttle
+                .record_failure(Some(user_id), client.ip)
+                .await
+                .err()
+                .unwrap_or_else(|| AppError::unauthorized("invalid credentials"));
+            return Err(self
+                .failed(Some(user_id), &client, "invalid password", err)
+                .await);
         }
 
         let mut user = user;
+        let rehashed = self.upgrade_password_hash(&mut user, &req.password);
+
+        // Checked after the password so the verification state isn't disclosed to strangers.
+        if self.require_verified_email && !user.is_verified {
+            let err = AppError::forbidden("email address is not verified");
+            return Err(self
+                .failed(Some(user_id), &client, "email not verified", err)
+                .await);
+        }
+
+        if self.mfa_challenges.is_required(user_id).await? {
+            if rehashed {
+                self.user_repo.update(&user).await?;
+            }
+            // The lockout is only reset once the second factor succeeds.
+            let challenge = self.mfa_challenges.start(user_id).await?;
+            return Ok(LoginResponse::MfaRequired(challenge));
+        }
+
+        self.throttle.record_success(user_id).await?;
+
         user.update_last_login();
         let user = self.user_repo.update(&user).await?;
 
-        let token = self.token_provider.generate_token(&user)?;
+        let token = self.token_issuer.issue(&user, &client).await?;
+        self.events
+            .record(AuthEvent::success(AuthEventType::Login, user_id, &client))
+            .await;
 
-        Ok(AuthResponse {
+        Ok(LoginResponse::Authenticated(AuthResponse {
+            password_expired: self.password_policy.is_expired(&user),
             user: user.into(),
-            token: TokenResponse {
-                access_token: token,
-                token_type: "Bearer".to_string(),
-                expires_in: 86400,
-                refresh_token: None,
-            },
-        })
+            token: token.into(),
+        }))
+    }
+
+    /// Records the failed attempt and hands back the error to return.
+    async fn failed(
+        &self,
+        user_id: Option<i64>,
+        client: &ClientInfo,
+        reason: &str,
+        err: AppError,
+    ) -> AppError {
+        self.events
+            .record(AuthEvent::failure(
+                AuthEventType::Login,
+                user_id,
+                client,
+                reason,
+            ))
+            .await;
+        err
+    }
+
+    /// Re-hashes with the current algorithm and cost while the raw password
+    /// is at hand. The caller persists the user. A failure only delays the
+    /// upgrade to the next login.
+    fn upgrade_password_hash(&self, user: &mut User, raw: &str) -> bool {
+        if !self.password_hasher.needs_rehash(user.password.value()) {
+            return false;
+        }
+
+        match self.password_hasher.hash(raw) {
+            Ok(hash) => {
+                user.password = HashedPassword::new(hash);
+                true
+            }
+            Err(e) => {
+                tracing::warn!(user_id = ?user.id, "failed to upgrade password hash: {:?}", e);
+                false
+            }
+        }
     }
 
     async fn find_user_by_username_or_email(
@@ -0,0 +1,75 @@
+use crate::application::services::auth_event_recorder::AuthEventRecorder;
+use crate::application::verify_token::VerifyTokenUseCase;
+use crate::domain::entity::{AuthEvent, AuthEventType};
+use crate::domain::value_objects::{ClientInfo, TokenHash};
+use crate::domain::{RefreshTokenRepository, SessionRepository, TokenRevocationRepository};
+use rust_reborn_contracts::Result;
+use std::sync::Arc;
+
+pub struct LogoutUseCase {
+    verify_token: Arc<VerifyTokenUseCase>,
+    revocation_repo: Arc<dyn TokenRevocationRepository>,
+    refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+    session_repo: Arc<dyn SessionRepository>,
+    events: Arc<AuthEventRecorder>,
+}
+
+impl LogoutUseCase {
+    pub fn new(
+        verify_token: Arc<VerifyTokenUseCase>,
+        revocation_repo: Arc<dyn TokenRevocationRepository>,
+        refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+        session_repo: Arc<dyn SessionRepository>,
+        events: Arc<AuthEventRecorder>,
+    ) -> Self {
+        Self {
+            verify_token,
+            revocation_repo,
+            refresh_token_repo,
+            session_repo,
+            events,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        access_token: &str,
+        refresh_token: Option<String>,
+        client: ClientInfo,
+    ) -> Result<()> {
+        let claims = self.verify_token.verify(access_token).await?;
+        let user_id = claims.user_id()?;
+
+        self.revocation_repo
+            .revoke(&claims.jti, user_id, claims.expires_at())
+            .await?;
+
+        // The session id is the refresh token family, so this also ends refreshing.
+        if let Some(session_id) = claims.sid {
+            self.session_repo.revoke(session_id).await?;
+            self.refresh_token_repo.revoke_family(session_id).await?;
+        }
+
+        if let Some(raw) = refresh_token {
+            let hash = TokenHash::of(&raw);
+            if let Some(token) = self.refresh_token_repo.find_by_hash(hash.value()).await? {
+                // Never let one user revoke someone else's session with a guessed token.
+                if token.user_id == user_id {
+                    self.refresh_token_repo
+                        .revoke_family(token.family_id)
+                        .await?;
+                }
+            }
+        }
+
+        // Logging out of an impersonation token ends the impersonation.
+        let event = match claims.actor_id()? {
+            Some(actor_id) => AuthEvent::success(AuthEventType::ImpersonationEnd, user_id, &client)
+                .by_actor(actor_id),
+            None => AuthEvent::success(AuthEventType::Logout, user_id, &client),
+        };
+        self.events.record(event).await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use crate::application::services::auth_event_recorder::AuthEventRecorder;
+use crate::domain::entity::{AuthEvent, AuthEventType};
+use crate::domain::value_objects::ClientInfo;
+use crate::domain::{RefreshTokenRepository, SessionRepository, TokenRevocationRepository};
+use chrono::Utc;
+use rust_reborn_contracts::Result;
+use std::sync::Arc;
+
+pub struct LogoutAllDevicesUseCase {
+    revocation_repo: Arc<dyn TokenRevocationRepository>,
+    refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+    session_repo: Arc<dyn SessionRepository>,
+    events: Arc<AuthEventRecorder>,
+}
+
+impl LogoutAllDevicesUseCase {
+    pub fn new(
+        revocation_repo: Arc<dyn TokenRevocationRepository>,
+        refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+        session_repo: Arc<dyn SessionRepository>,
+        events: Arc<AuthEventRecorder>,
+    ) -> Self {
+        Self {
+            revocation_repo,
+            refresh_token_repo,
+            session_repo,
+            events,
+        }
+    }
+
+    /// Signs the user out everywhere at their own request.
+    pub async fn execute(&self, user_id: i64, client: ClientInfo) -> Result<()> {
+        self.revoke_all(user_id).await?;
+        self.events
+            .record(AuthEvent::success(
+                AuthEventType::LogoutAll,
+                user_id,
+                &client,
+            ))
+            .await;
+
+        Ok(())
+    }
+
+    /// Revokes every token and session of the user. Callers that do this as
+    /// part of another action record that action instead.
+    pub async fn revoke_all(&self, user_id: i64) -> Result<()> {
+        // Session tokens die with their session below; the cutoff catches
+        // the ones without a session, such as impersonation tokens.
+        self.revocation_repo
+            .revoke_all_for_user(user_id, Utc::now())
+            .await?;
+        self.refresh_token_repo.revoke_all_for_user(user_id).await?;
+        self.session_repo.revoke_all_for_user(user_id).await?;
+
+        Ok(())
+    }
+}
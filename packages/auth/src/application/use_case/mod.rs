@@ -1,5 +1,51 @@
+pub mod accept_invitation;
+pub mod authenticate_api_key;
 pub mod change_password;
+pub mod complete_oidc_login;
+pub mod confirm_email_change;
+pub mod confirm_totp;
+pub mod create_api_key;
+pub mod create_invitation;
+pub mod create_organization;
+pub mod deactivate_user;
+pub mod delete_user;
+pub mod disable_oauth_client;
+pub mod end_impersonation;
+pub mod enroll_totp;
 pub mod get_user_detail;
+pub mod issue_client_token;
+pub mod list_api_keys;
+pub mod list_auth_events;
+pub mod list_invitations;
+pub mod list_my_organizations;
+pub mod list_oauth_clients;
+pub mod list_organization_members;
+pub mod list_organizations;
+pub mod list_sessions;
+pub mod list_users;
 pub mod login_user;
+pub mod logout;
+pub mod logout_all_devices;
+pub mod reactivate_user;
+pub mod refresh_token;
+pub mod register_oauth_client;
 pub mod register_user;
+pub mod remove_organization_member;
+pub mod request_email_change;
+pub mod request_magic_link;
+pub mod request_password_reset;
+pub mod resend_verification;
+pub mod reset_password;
+pub mod revoke_api_key;
+pub mod revoke_invitation;
+pub mod revoke_session;
+pub mod set_organization_member;
+pub mod start_impersonation;
+pub mod start_oidc_login;
+pub mod switch_organization;
+pub mod update_api_key;
+pub mod update_profile;
+pub mod verify_email;
+pub mod verify_magic_link;
+pub mod verify_mfa_login;
 pub mod verify_token;
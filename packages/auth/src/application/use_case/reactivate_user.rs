@@ -0,0 +1,27 @@
+use crate::application::dto::AdminUserResponse;
+use crate::domain::UserRepository;
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+pub struct ReactivateUserUseCase {
+    user_repo: Arc<dyn UserRepository>,
+}
+
+impl ReactivateUserUseCase {
+    pub fn new(user_repo: Arc<dyn UserRepository>) -> Self {
+        Self { user_repo }
+    }
+
+    pub async fn execute(&self, user_id: i64) -> Result<AdminUserResponse> {
+        let mut user = self
+            .user_repo
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("user not found"))?;
+
+        user.reactivate();
+        let user = self.user_repo.update(&user).await?;
+
+        Ok(user.into())
+    }
+}
@@ -0,0 +1,76 @@
+use crate::application::services::token_issuer::TokenIssuer;
+use crate::domain::value_objects::TokenHash;
+use crate::domain::{RefreshTokenRepository, UserRepository};
+use crate::{RefreshTokenRequest, TokenResponse};
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+pub struct RefreshTokenUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+    token_issuer: Arc<TokenIssuer>,
+}
+
+impl RefreshTokenUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+        token_issuer: Arc<TokenIssuer>,
+    ) -> Self {
+        Self {
+            user_repo,
+            refresh_token_repo,
+            token_issuer,
+        }
+    }
+
+    pub async fn execute(&self, req: RefreshTokenRequest) -> Result<TokenResponse> {
+        let hash = TokenHash::of(&req.refresh_token);
+
+        let current = self
+            .refresh_token_repo
+            .find_by_hash(hash.value())
+            .await?
+            .ok_or_else(|| AppError::unauthorized("invalid refresh token"))?;
+
+        if current.is_revoked() {
+            // A rotated token is being replayed: assume it leaked and kill the whole chain.
+            tracing::warn!(
+                user_id = current.user_id,
+                family_id = %current.family_id,
+                "refresh token reuse detected, revoking family"
+            );
+            self.refresh_token_repo
+                .revoke_family(current.family_id)
+                .await?;
+            return Err(AppError::unauthorized("invalid refresh token"));
+        }
+
+        if current.is_expired() {
+            return Err(AppError::unauthorized("refresh token expired"));
+        }
+
+        let user = self
+            .user_repo
+            .find_by_id(current.user_id)
+            .await?
+            .ok_or_else(|| AppError::unauthorized("invalid refresh token"))?;
+
+        if !user.can_login() {
+            self.refresh_token_repo
+                .revoke_family(current.family_id)
+                .await?;
+            return Err(AppError::forbidden("account is not active or verified"));
+        }
+
+        match self.token_issuer.rotate(&user, &current).await? {
+            Some(token) => Ok(token.into()),
+            None => {
+                self.refresh_token_repo
+                    .revoke_family(current.family_id)
+                    .await?;
+                Err(AppError::unauthorized("invalid refresh token"))
+            }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use crate::application::dto::{
+    OAuthClientResponse, RegisterOAuthClientRequest, RegisteredOAuthClientResponse,
+};
+use crate::domain::entity::OAuthClient;
+use crate::domain::OAuthClientRepository;
+use rust_reborn_contracts::common::CodeGenerator;
+use rust_reborn_contracts::Result;
+use std::sync::Arc;
+
+pub struct RegisterOAuthClientUseCase {
+    client_repo: Arc<dyn OAuthClientRepository>,
+    code_generator: Arc<dyn CodeGenerator>,
+    token_generator: Arc<dyn CodeGenerator>,
+}
+
+impl RegisterOAuthClientUseCase {
+    pub fn new(
+        client_repo: Arc<dyn OAuthClientRepository>,
+        code_generator: Arc<dyn CodeGenerator>,
+        token_generator: Arc<dyn CodeGenerator>,
+    ) -> Self {
+        Self {
+            client_repo,
+            code_generator,
+            token_generator,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        actor_id: i64,
+        req: RegisterOAuthClientRequest,
+    ) -> Result<RegisteredOAuthClientResponse> {
+        let mut scopes = req.scopes;
+        scopes.sort();
+        scopes.dedup();
+
+        let secret = self.token_generator.generate("cs");
+        let mut client = OAuthClient::new(
+            self.code_generator.generate("svc"),
+            req.name,
+            &secret,
+            scopes,
+            Some(actor_id),
+        );
+        self.client_repo.save(&mut client).await?;
+
+        tracing::info!(actor_id, client_id = %client.client_id, "OAuth client registered");
+
+        Ok(RegisteredOAuthClientResponse {
+            client_secret: secret,
+            client: OAuthClientResponse::from(client),
+        })
+    }
+}
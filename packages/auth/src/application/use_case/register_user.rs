@@ -1,8 +1,11 @@
 use crate::application::dto::{RegisterRequest, RegisterResponse};
 use crate::application::password_hasher::PasswordHasher;
-use crate::domain::entity::User;
+use crate::application::services::auth_event_recorder::AuthEventRecorder;
+use crate::application::services::email_verification::EmailVerificationService;
+use crate::application::services::password_policy::PasswordPolicyService;
+use crate::domain::entity::{AuthEvent, AuthEventType, User};
 use crate::domain::repository::user_repository::UserRepository;
-use crate::domain::value_objects::{Email, HashedPassword, Password};
+use crate::domain::value_objects::{ClientInfo, Email, HashedPassword};
 use rust_reborn_contracts::common::CodeGenerator;
 use rust_reborn_contracts::{AppError, Result};
 use std::sync::Arc;
@@ -10,23 +13,67 @@ use std::sync::Arc;
 pub struct RegisterUserUseCase {
     user_repo: Arc<dyn UserRepository>,
     password_hasher: Arc<dyn PasswordHasher>,
+    password_policy: Arc<PasswordPolicyService>,
     code_generator: Arc<dyn CodeGenerator>,
+    email_verification: Arc<EmailVerificationService>,
+    events: Arc<AuthEventRecorder>,
 }
 
 impl RegisterUserUseCase {
     pub fn new(
         user_repo: Arc<dyn UserRepository>,
         password_hasher: Arc<dyn PasswordHasher>,
+        password_policy: Arc<PasswordPolicyService>,
         code_generator: Arc<dyn CodeGenerator>,
+        email_verification: Arc<EmailVerificationService>,
+        events: Arc<AuthEventRecorder>,
     ) -> Self {
         Self {
             user_repo,
             password_hasher,
+            password_policy,
             code_generator,
+            email_verification,
+            events,
         }
     }
 
-    pub async fn execute(&self, req: RegisterRequest) -> Result<RegisterResponse> {
+    pub async fn execute(
+        &self,
+        req: RegisterRequest,
+        client: ClientInfo,
+    ) -> Result<RegisterResponse> {
+        match self.register(req).await {
+            Ok(response) => {
+                self.events
+                    .record(AuthEvent::success(
+                        AuthEventType::Register,
+                        response.id,
+                        &client,
+                    ))
+                    .await;
+                Ok(response)
+            }
+            Err(e) => {
+                let reason = match &e {
+                    AppError::Conflict(msg) | AppError::BadRequest(msg) => msg.clone(),
+                    AppError::ValidationError(e) => e.to_string(),
+                    _ => "internal error".to_string(),
+                };
+                self.events
+                    .record(AuthEvent::failure(
+                        AuthEventType::Register,
+                        None,
+                        &client,
+                        reason,
+                    ))
+                    .await;
+                Err(e)
+            }
+        }
+    }
+
+    async fn register(&self, req: RegisterRequest) -> Result<RegisterResponse> {
         if self.user_repo.find_by_email(&req.email).await?.is_some() {
             return Err(AppError::conflict("email already registered"));
         }
@@ -40,7 +87,9 @@ impl RegisterUserUseCase {
             return Err(AppError::conflict("username already taken"));
         }
 
-        Password::new(req.password.clone()).map_err(|e| AppError::bad_request(e.to_string()))?;
+        self.password_policy
+            .check("password", &req.password, &req.username, &req.email)
+            .await?;
 
         let email_obj = Email::new(req.email)
             .map_err(|e| AppError::bad_request(format!("invalid email: {}", e)))?;
@@ -57,8 +106,14 @@ impl RegisterUserUseCase {
             req.full_name,
         );
 
+        let verification_token = self.email_verification.prepare(&mut user);
+
         self.user_repo.save(&mut user).await?;
 
+        self.email_verification
+            .send(&user, &verification_token)
+            .await;
+
         Ok(RegisterResponse {
             id: user.id.expect("user must be persisted"),
             email: user.email.value().to_string(),
@@ -0,0 +1,27 @@
+use crate::domain::OrganizationRepository;
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+pub struct RemoveOrganizationMemberUseCase {
+    organization_repo: Arc<dyn OrganizationRepository>,
+}
+
+impl RemoveOrganizationMemberUseCase {
+    pub fn new(organization_repo: Arc<dyn OrganizationRepository>) -> Self {
+        Self { organization_repo }
+    }
+
+    /// Takes effect on the next request: tenants are checked against the
+    /// membership every time, not trusted from the token.
+    pub async fn execute(&self, organization_id: i64, user_id: i64) -> Result<()> {
+        if !self
+            .organization_repo
+            .remove_member(organization_id, user_id)
+            .await?
+        {
+            return Err(AppError::not_found("membership not found"));
+        }
+
+        Ok(())
+    }
+}
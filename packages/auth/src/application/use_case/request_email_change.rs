@@ -0,0 +1,82 @@
+use crate::application::password_hasher::PasswordHasher;
+use crate::application::port::account_notifier::AccountNotifier;
+use crate::domain::value_objects::{Email, TokenHash};
+use crate::domain::UserRepository;
+use crate::{ChangeEmailRequest, UserResponse};
+use rust_reborn_contracts::common::CodeGenerator;
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+pub struct RequestEmailChangeUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    password_hasher: Arc<dyn PasswordHasher>,
+    token_generator: Arc<dyn CodeGenerator>,
+    notifier: Arc<dyn AccountNotifier>,
+    token_ttl: chrono::Duration,
+}
+
+impl RequestEmailChangeUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        password_hasher: Arc<dyn PasswordHasher>,
+        token_generator: Arc<dyn CodeGenerator>,
+        notifier: Arc<dyn AccountNotifier>,
+        token_ttl: chrono::Duration,
+    ) -> Self {
+        Self {
+            user_repo,
+            password_hasher,
+            token_generator,
+            notifier,
+            token_ttl,
+        }
+    }
+
+    /// Stores the new address as pending and mails a confirmation link to
+    /// it. The current address keeps working until the link is used.
+    pub async fn execute(&self, user_id: i64, req: ChangeEmailRequest) -> Result<UserResponse> {
+        let mut user = self
+            .user_repo
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("user not found"))?;
+
+        if !self
+            .password_hasher
+            .verify(&req.current_password, user.password.value())?
+        {
+            return Err(AppError::unauthorized("invalid password"));
+        }
+
+        let email = Email::new(req.new_email)
+            .map_err(|e| AppError::bad_request(format!("invalid email: {}", e)))?;
+
+        if email.value() == user.email.value() {
+            return Err(AppError::bad_request(
+                "new email must differ from the current one",
+            ));
+        }
+
+        if self.user_repo.find_by_email(email.value()).await?.is_some() {
+            return Err(AppError::conflict("email already registered"));
+        }
+
+        let token = self.token_generator.generate("ec");
+        user.request_email_change(
+            email,
+            TokenHash::of(&token).value().to_string(),
+            chrono::Utc::now() + self.token_ttl,
+        );
+        let user = self.user_repo.update(&user).await?;
+
+        if let Err(e) = self.notifier.send_email_change(&user, &token).await {
+            tracing::error!(
+                user_id = user.id,
+                error = ?e,
+                "failed to send email change confirmation"
+            );
+        }
+
+        Ok(user.into())
+    }
+}
@@ -0,0 +1,66 @@
+use crate::application::port::account_notifier::AccountNotifier;
+use crate::domain::entity::MagicLinkToken;
+use crate::domain::{MagicLinkRepository, UserRepository};
+use crate::MagicLinkRequest;
+use rust_reborn_contracts::common::CodeGenerator;
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+pub struct RequestMagicLinkUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    magic_link_repo: Arc<dyn MagicLinkRepository>,
+    token_generator: Arc<dyn CodeGenerator>,
+    notifier: Arc<dyn AccountNotifier>,
+    token_ttl: chrono::Duration,
+    enabled: bool,
+}
+
+impl RequestMagicLinkUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        magic_link_repo: Arc<dyn MagicLinkRepository>,
+        token_generator: Arc<dyn CodeGenerator>,
+        notifier: Arc<dyn AccountNotifier>,
+        token_ttl: chrono::Duration,
+        enabled: bool,
+    ) -> Self {
+        Self {
+            user_repo,
+            magic_link_repo,
+            token_generator,
+            notifier,
+            token_ttl,
+            enabled,
+        }
+    }
+
+    /// Emails a single-use login link. Like the password reset it succeeds
+    /// for unknown addresses too, and a new link replaces any earlier one.
+    pub async fn execute(&self, req: MagicLinkRequest) -> Result<()> {
+        if !self.enabled {
+            return Err(AppError::forbidden("magic link login is not enabled"));
+        }
+
+        let Some(user) = self.user_repo.find_by_email(&req.email).await? else {
+            return Ok(());
+        };
+        let Some(user_id) = user.id.filter(|_| user.can_login()) else {
+            return Ok(());
+        };
+
+        let token = self.token_generator.generate("ml");
+        let mut link = MagicLinkToken::new(user_id, &token, chrono::Utc::now() + self.token_ttl);
+        self.magic_link_repo.invalidate_for_user(user_id).await?;
+        self.magic_link_repo.save(&mut link).await?;
+
+        if let Err(e) = self
+            .notifier
+            .send_magic_link(&user, &token, link.expires_at)
+            .await
+        {
+            tracing::error!(user_id, error = ?e, "failed to send magic link");
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use crate::application::port::account_notifier::AccountNotifier;
+use crate::domain::value_objects::TokenHash;
+use crate::domain::UserRepository;
+use crate::ForgotPasswordRequest;
+use rust_reborn_contracts::common::CodeGenerator;
+use rust_reborn_contracts::Result;
+use std::sync::Arc;
+
+pub struct RequestPasswordResetUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    token_generator: Arc<dyn CodeGenerator>,
+    notifier: Arc<dyn AccountNotifier>,
+    token_ttl: chrono::Duration,
+}
+
+impl RequestPasswordResetUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        token_generator: Arc<dyn CodeGenerator>,
+        notifier: Arc<dyn AccountNotifier>,
+        token_ttl: chrono::Duration,
+    ) -> Self {
+        Self {
+            user_repo,
+            token_generator,
+            notifier,
+            token_ttl,
+        }
+    }
+
+    /// Always succeeds for well-formed input so callers cannot tell whether
+    /// the email belongs to an account. Only the key's hash is stored.
+    pub async fn execute(&self, req: ForgotPasswordRequest) -> Result<()> {
+        let Some(mut user) = self.user_repo.find_by_email(&req.email).await? else {
+            return Ok(());
+        };
+
+        if !user.can_login() {
+            return Ok(());
+        }
+
+        let token = self.token_generator.generate("pr");
+        user.set_reset_key(
+            TokenHash::of(&token).value().to_string(),
+            chrono::Utc::now() + self.token_ttl,
+        );
+        let user = self.user_repo.update(&user).await?;
+
+        if let Err(e) = self.notifier.send_password_reset(&user, &token).await {
+            tracing::error!(
+                user_id = user.id,
+                error = ?e,
+                "failed to send password reset"
+            );
+        }
+
+        Ok(())
+    }
+}
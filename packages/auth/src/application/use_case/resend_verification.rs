@@ -0,0 +1,40 @@
+use crate::application::services::email_verification::EmailVerificationService;
+use crate::domain::UserRepository;
+use crate::ResendVerificationRequest;
+use rust_reborn_contracts::Result;
+use std::sync::Arc;
+
+pub struct ResendVerificationUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    email_verification: Arc<EmailVerificationService>,
+}
+
+impl ResendVerificationUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        email_verification: Arc<EmailVerificationService>,
+    ) -> Self {
+        Self {
+            user_repo,
+            email_verification,
+        }
+    }
+
+    /// Succeeds whether or not the address belongs to an unverified account,
+    /// so the endpoint cannot be used to probe for registered emails.
+    pub async fn execute(&self, req: ResendVerificationRequest) -> Result<()> {
+        let Some(mut user) = self.user_repo.find_by_email(&req.email).await? else {
+            return Ok(());
+        };
+
+        if user.is_verified || !user.is_active {
+            return Ok(());
+        }
+
+        let token = self.email_verification.prepare(&mut user);
+        let user = self.user_repo.update(&user).await?;
+        self.email_verification.send(&user, &token).await;
+
+        Ok(())
+    }
+}
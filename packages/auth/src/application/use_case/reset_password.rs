@@ -0,0 +1,106 @@
+use crate::application::logout_all_devices::LogoutAllDevicesUseCase;
+use crate::application::password_hasher::PasswordHasher;
+use crate::application::services::auth_event_recorder::AuthEventRecorder;
+use crate::application::services::password_policy::PasswordPolicyService;
+use crate::domain::entity::{AuthEvent, AuthEventType, User};
+use crate::domain::value_objects::{ClientInfo, HashedPassword, TokenHash};
+use crate::domain::UserRepository;
+use crate::ResetPasswordRequest;
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+pub struct ResetPasswordUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    password_hasher: Arc<dyn PasswordHasher>,
+    password_policy: Arc<PasswordPolicyService>,
+    logout_all_devices: Arc<LogoutAllDevicesUseCase>,
+    events: Arc<AuthEventRecorder>,
+}
+
+impl ResetPasswordUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        password_hasher: Arc<dyn PasswordHasher>,
+        password_policy: Arc<PasswordPolicyService>,
+        logout_all_devices: Arc<LogoutAllDevicesUseCase>,
+        events: Arc<AuthEventRecorder>,
+    ) -> Self {
+        Self {
+            user_repo,
+            password_hasher,
+            password_policy,
+            logout_all_devices,
+            events,
+        }
+    }
+
+    pub async fn execute(&self, req: ResetPasswordRequest, client: ClientInfo) -> Result<()> {
+        let hash = TokenHash::of(&req.token);
+
+        let mut user = self
+            .user_repo
+            .find_by_reset_key(hash.value())
+            .await?
+            .ok_or_else(|| AppError::bad_request("invalid reset token"))?;
+
+        if user.reset_key_expired() {
+            let err = AppError::bad_request("reset token expired");
+            return Err(self
+                .failed(&user, &client, "reset token expired", err)
+                .await);
+        }
+
+        if !user.can_login() {
+            let err = AppError::forbidden("account is not active");
+            return Err(self.failed(&user, &client, "account inactive", err).await);
+        }
+
+        self.password_policy
+            .check_for_user("new_password", &req.new_password, &user)
+            .await?;
+
+        let hashed = self.password_hasher.hash(&req.new_password)?;
+
+        // Two requests with the same token can both get this far; only the
+        // one that clears the key may set the password.
+        if !self.user_repo.consume_reset_key(hash.value()).await? {
+            return Err(AppError::bad_request("invalid reset token"));
+        }
+        self.password_policy.remember(&user).await?;
+        user.reset_password(HashedPassword::new(hashed));
+        let user = self.user_repo.update(&user).await?;
+
+        // Whoever knew the old password must not keep a live session.
+        let user_id = user
+            .id
+            .ok_or_else(|| AppError::internal("user must be persisted"))?;
+        self.logout_all_devices.revoke_all(user_id).await?;
+        self.events
+            .record(AuthEvent::success(
+                AuthEventType::ResetPassword,
+                user_id,
+                &client,
+            ))
+            .await;
+
+        Ok(())
+    }
+
+    async fn failed(
+        &self,
+        user: &User,
+        client: &ClientInfo,
+        reason: &str,
+        err: AppError,
+    ) -> AppError {
+        self.events
+            .record(AuthEvent::failure(
+                AuthEventType::ResetPassword,
+                user.id,
+                client,
+                reason,
+            ))
+            .await;
+        err
+    }
+}
@@ -0,0 +1,29 @@
+use crate::domain::ApiKeyRepository;
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+pub struct RevokeApiKeyUseCase {
+    api_key_repo: Arc<dyn ApiKeyRepository>,
+}
+
+impl RevokeApiKeyUseCase {
+    pub fn new(api_key_repo: Arc<dyn ApiKeyRepository>) -> Self {
+        Self { api_key_repo }
+    }
+
+    pub async fn execute(&self, user_id: i64, key_id: i64) -> Result<()> {
+        // Someone else's key is reported exactly like a missing one.
+        let key = self
+            .api_key_repo
+            .find_by_user(user_id)
+            .await?
+            .into_iter()
+            .find(|k| k.id == Some(key_id))
+            .ok_or_else(|| AppError::not_found("API key not found"))?;
+
+        self.api_key_repo.revoke(key_id).await?;
+        tracing::info!(user_id, key_id, prefix = %key.prefix, "API key revoked");
+
+        Ok(())
+    }
+}
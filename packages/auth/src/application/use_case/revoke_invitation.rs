@@ -0,0 +1,23 @@
+use crate::domain::InvitationRepository;
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+pub struct RevokeInvitationUseCase {
+    invitation_repo: Arc<dyn InvitationRepository>,
+}
+
+impl RevokeInvitationUseCase {
+    pub fn new(invitation_repo: Arc<dyn InvitationRepository>) -> Self {
+        Self { invitation_repo }
+    }
+
+    pub async fn execute(&self, id: i64) -> Result<()> {
+        if !self.invitation_repo.revoke(id).await? {
+            return Err(AppError::not_found(
+                "invitation not found or already accepted",
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use crate::domain::{RefreshTokenRepository, SessionRepository};
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct RevokeSessionUseCase {
+    session_repo: Arc<dyn SessionRepository>,
+    refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+}
+
+impl RevokeSessionUseCase {
+    pub fn new(
+        session_repo: Arc<dyn SessionRepository>,
+        refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+    ) -> Self {
+        Self {
+            session_repo,
+            refresh_token_repo,
+        }
+    }
+
+    pub async fn execute(&self, user_id: i64, session_id: Uuid) -> Result<()> {
+        // Someone else's session is reported exactly like a missing one.
+        let session = self
+            .session_repo
+            .find_by_id(session_id)
+            .await?
+            .filter(|s| s.user_id == user_id && !s.is_revoked())
+            .ok_or_else(|| AppError::not_found("session not found"))?;
+
+        self.session_repo.revoke(session.id).await?;
+        self.refresh_token_repo.revoke_family(session.id).await?;
+
+        Ok(())
+    }
+}
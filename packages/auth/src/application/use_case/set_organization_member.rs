@@ -0,0 +1,54 @@
+use crate::application::dto::{MembershipResponse, SetMemberRolesRequest};
+use crate::domain::{OrganizationRepository, UserRepository};
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+pub struct SetOrganizationMemberUseCase {
+    organization_repo: Arc<dyn OrganizationRepository>,
+    user_repo: Arc<dyn UserRepository>,
+}
+
+impl SetOrganizationMemberUseCase {
+    pub fn new(
+        organization_repo: Arc<dyn OrganizationRepository>,
+        user_repo: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self {
+            organization_repo,
+            user_repo,
+        }
+    }
+
+    /// Adds the user to the organization, or replaces their roles there.
+    pub async fn execute(
+        &self,
+        organization_id: i64,
+        user_id: i64,
+        req: SetMemberRolesRequest,
+    ) -> Result<MembershipResponse> {
+        self.organization_repo
+            .find_by_id(organization_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("organization not found"))?;
+        self.user_repo
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("user not found"))?;
+
+        let mut roles = req.roles;
+        roles.sort();
+        roles.dedup();
+
+        self.organization_repo
+            .set_member_roles(organization_id, user_id, &roles)
+            .await?;
+
+        let membership = self
+            .organization_repo
+            .find_membership(organization_id, user_id)
+            .await?
+            .ok_or_else(|| AppError::internal("membership missing after update"))?;
+
+        Ok(membership.into())
+    }
+}
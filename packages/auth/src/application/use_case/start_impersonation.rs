@@ -0,0 +1,98 @@
+use crate::application::dto::ImpersonationResponse;
+use crate::application::services::auth_event_recorder::AuthEventRecorder;
+use crate::domain::entity::{AuthEvent, AuthEventType};
+use crate::domain::value_objects::ClientInfo;
+use crate::domain::{OrganizationRepository, RoleRepository, UserRepository};
+use crate::infrastructure::jwt::JwtService;
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+/// Holders of this permission, globally or in any organization, cannot be
+/// impersonated, so impersonation never hands out administrative rights
+/// under someone else's name.
+const ADMIN_PERMISSION: &str = "users:manage";
+
+pub struct StartImpersonationUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    role_repo: Arc<dyn RoleRepository>,
+    organization_repo: Arc<dyn OrganizationRepository>,
+    jwt_service: Arc<JwtService>,
+    events: Arc<AuthEventRecorder>,
+    ttl: chrono::Duration,
+}
+
+impl StartImpersonationUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        role_repo: Arc<dyn RoleRepository>,
+        organization_repo: Arc<dyn OrganizationRepository>,
+        jwt_service: Arc<JwtService>,
+        events: Arc<AuthEventRecorder>,
+        ttl: chrono::Duration,
+    ) -> Self {
+        Self {
+            user_repo,
+            role_repo,
+            organization_repo,
+            jwt_service,
+            events,
+            ttl,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        actor_id: i64,
+        user_id: i64,
+        client: ClientInfo,
+    ) -> Result<ImpersonationResponse> {
+        if actor_id == user_id {
+            return Err(AppError::bad_request("you cannot impersonate yourself"));
+        }
+
+        let user = self
+            .user_repo
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("user not found"))?;
+
+        if !user.can_login() {
+            return Err(AppError::bad_request(
+                "inactive accounts cannot be impersonated",
+            ));
+        }
+
+        let roles = self.role_repo.find_by_user(user_id).await?;
+        let memberships = self
+            .organization_repo
+            .find_memberships_of_user(user_id)
+            .await?;
+        if roles
+            .iter()
+            .chain(memberships.iter().flat_map(|m| &m.roles))
+            .any(|r| r.permissions.iter().any(|p| p == ADMIN_PERMISSION))
+        {
+            return Err(AppError::forbidden("administrators cannot be impersonated"));
+        }
+
+        let access_token = self
+            .jwt_service
+            .generate_impersonation_token(&user, &roles, actor_id, self.ttl)?;
+
+        tracing::info!(actor_id, user_id, "impersonation started");
+        self.events
+            .record(
+                AuthEvent::success(AuthEventType::ImpersonationStart, user_id, &client)
+                    .by_actor(actor_id),
+            )
+            .await;
+
+        Ok(ImpersonationResponse {
+            access_token,
+            token_type: "Bearer".to_string(),
+            expires_in: self.ttl.num_seconds(),
+            actor_id,
+            user: user.into(),
+        })
+    }
+}
@@ -0,0 +1,61 @@
+use crate::application::dto::OidcAuthorizationResponse;
+use crate::domain::entity::OidcLoginState;
+use crate::domain::value_objects::TokenHash;
+use crate::domain::ExternalIdentityRepository;
+use crate::infrastructure::oidc::{pkce_challenge, OidcProviders};
+use rust_reborn_contracts::common::CodeGenerator;
+use rust_reborn_contracts::Result;
+use std::sync::Arc;
+
+pub struct StartOidcLoginUseCase {
+    providers: Arc<OidcProviders>,
+    identity_repo: Arc<dyn ExternalIdentityRepository>,
+    token_generator: Arc<dyn CodeGenerator>,
+    ttl: chrono::Duration,
+}
+
+impl StartOidcLoginUseCase {
+    pub fn new(
+        providers: Arc<OidcProviders>,
+        identity_repo: Arc<dyn ExternalIdentityRepository>,
+        token_generator: Arc<dyn CodeGenerator>,
+        ttl: chrono::Duration,
+    ) -> Self {
+        Self {
+            providers,
+            identity_repo,
+            token_generator,
+            ttl,
+        }
+    }
+
+    /// Builds the provider login URL. The PKCE verifier and nonce stay on
+    /// the server, keyed by the state the provider sends back.
+    pub async fn execute(&self, provider: &str) -> Result<OidcAuthorizationResponse> {
+        let client = self.providers.get(provider)?;
+
+        let state = self.token_generator.generate("oidc");
+        let code_verifier = self.token_generator.generate("pkce");
+        let nonce = self.token_generator.generate("nonce");
+
+        let authorization_url = client
+            .authorization_url(&state, &nonce, &pkce_challenge(&code_verifier))
+            .await?;
+
+        self.identity_repo
+            .save_login_state(&OidcLoginState {
+                state_hash: TokenHash::of(&state),
+                provider: provider.to_string(),
+                code_verifier,
+                nonce,
+                expires_at: chrono::Utc::now() + self.ttl,
+            })
+            .await?;
+
+        Ok(OidcAuthorizationResponse {
+            authorization_url,
+            state,
+            expires_in: self.ttl.num_seconds(),
+        })
+    }
+}
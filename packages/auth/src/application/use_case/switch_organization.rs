@@ -0,0 +1,68 @@
+use crate::application::dto::{SwitchOrganizationRequest, TokenResponse};
+use crate::application::services::tenant_resolver::TenantResolver;
+use crate::application::services::token_issuer::TokenIssuer;
+use crate::domain::{OrganizationRepository, SessionRepository, UserRepository};
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct SwitchOrganizationUseCase {
+    organization_repo: Arc<dyn OrganizationRepository>,
+    session_repo: Arc<dyn SessionRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    token_issuer: Arc<TokenIssuer>,
+}
+
+impl SwitchOrganizationUseCase {
+    pub fn new(
+        organization_repo: Arc<dyn OrganizationRepository>,
+        session_repo: Arc<dyn SessionRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        token_issuer: Arc<TokenIssuer>,
+    ) -> Self {
+        Self {
+            organization_repo,
+            session_repo,
+            user_repo,
+            token_issuer,
+        }
+    }
+
+    /// Makes `organization_id` the session's active tenant and returns an
+    /// access token carrying it. Refreshing keeps the tenant.
+    pub async fn execute(
+        &self,
+        user_id: i64,
+        session_id: Uuid,
+        req: SwitchOrganizationRequest,
+    ) -> Result<TokenResponse> {
+        if let Some(organization_id) = req.organization_id {
+            let resolver = TenantResolver::new(self.organization_repo.clone(), None);
+            resolver
+                .resolve(user_id, None, Some(organization_id))
+                .await?
+                .ok_or_else(|| AppError::forbidden("not a member of this organization"))?;
+        }
+
+        let mut session = self
+            .session_repo
+            .find_by_id(session_id)
+            .await?
+            .filter(|s| s.user_id == user_id && !s.is_revoked())
+            .ok_or_else(|| AppError::unauthorized("session has been revoked"))?;
+        let user = self
+            .user_repo
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| AppError::unauthorized("user not found"))?;
+
+        self.session_repo
+            .set_organization(session.id, req.organization_id)
+            .await?;
+        session.organization_id = req.organization_id;
+
+        let token = self.token_issuer.reissue(&user, &session).await?;
+
+        Ok(token.into())
+    }
+}
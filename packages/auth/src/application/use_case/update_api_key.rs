@@ -0,0 +1,48 @@
+use crate::application::create_api_key::grantable_scopes;
+use crate::application::dto::{ApiKeyResponse, UpdateApiKeyRequest};
+use crate::domain::{ApiKeyRepository, RoleRepository};
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+pub struct UpdateApiKeyUseCase {
+    api_key_repo: Arc<dyn ApiKeyRepository>,
+    role_repo: Arc<dyn RoleRepository>,
+}
+
+impl UpdateApiKeyUseCase {
+    pub fn new(
+        api_key_repo: Arc<dyn ApiKeyRepository>,
+        role_repo: Arc<dyn RoleRepository>,
+    ) -> Self {
+        Self {
+            api_key_repo,
+            role_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: i64,
+        key_id: i64,
+        req: UpdateApiKeyRequest,
+    ) -> Result<ApiKeyResponse> {
+        let mut key = self
+            .api_key_repo
+            .find_by_user(user_id)
+            .await?
+            .into_iter()
+            .find(|k| k.id == Some(key_id))
+            .ok_or_else(|| AppError::not_found("API key not found"))?;
+
+        if let Some(name) = req.name {
+            key.name = name;
+        }
+        if let Some(scopes) = req.scopes {
+            key.scopes = grantable_scopes(self.role_repo.as_ref(), user_id, scopes).await?;
+        }
+
+        self.api_key_repo.update(&key).await?;
+
+        Ok(ApiKeyResponse::from(key))
+    }
+}
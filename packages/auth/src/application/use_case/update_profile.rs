@@ -0,0 +1,36 @@
+use crate::domain::UserRepository;
+use crate::{UpdateProfileRequest, UserResponse};
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+pub struct UpdateProfileUseCase {
+    user_repo: Arc<dyn UserRepository>,
+}
+
+impl UpdateProfileUseCase {
+    pub fn new(user_repo: Arc<dyn UserRepository>) -> Self {
+        Self { user_repo }
+    }
+
+    pub async fn execute(&self, user_id: i64, req: UpdateProfileRequest) -> Result<UserResponse> {
+        let mut user = self
+            .user_repo
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("user not found"))?;
+
+        // Re-sending the current username is not a change and must not conflict with itself.
+        let username = req.username.filter(|u| *u != user.username);
+        if let Some(username) = &username {
+            if self.user_repo.find_by_username(username).await?.is_some() {
+                return Err(AppError::conflict("username already taken"));
+            }
+        }
+
+        let updated_by = user.code.clone();
+        user.update_profile(req.full_name, username, &updated_by);
+        let user = self.user_repo.update(&user).await?;
+
+        Ok(user.into())
+    }
+}
@@ -0,0 +1,34 @@
+use crate::domain::value_objects::TokenHash;
+use crate::domain::UserRepository;
+use crate::{UserResponse, VerifyEmailRequest};
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+pub struct VerifyEmailUseCase {
+    user_repo: Arc<dyn UserRepository>,
+}
+
+impl VerifyEmailUseCase {
+    pub fn new(user_repo: Arc<dyn UserRepository>) -> Self {
+        Self { user_repo }
+    }
+
+    pub async fn execute(&self, req: VerifyEmailRequest) -> Result<UserResponse> {
+        let hash = TokenHash::of(&req.token);
+
+        let mut user = self
+            .user_repo
+            .find_by_activation_key(hash.value())
+            .await?
+            .ok_or_else(|| AppError::bad_request("invalid verification token"))?;
+
+        if user.activation_key_expired() {
+            return Err(AppError::bad_request("verification token expired"));
+        }
+
+        user.verify();
+        let user = self.user_repo.update(&user).await?;
+
+        Ok(user.into())
+    }
+}
@@ -0,0 +1,106 @@
+use crate::application::services::auth_event_recorder::AuthEventRecorder;
+use crate::application::services::mfa_challenge::MfaChallengeService;
+use crate::application::services::token_issuer::TokenIssuer;
+use crate::domain::entity::{AuthEvent, AuthEventType};
+use crate::domain::value_objects::{ClientInfo, TokenHash};
+use crate::domain::{MagicLinkRepository, UserRepository};
+use crate::{AuthResponse, LoginResponse, VerifyMagicLinkRequest};
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+pub struct VerifyMagicLinkUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    magic_link_repo: Arc<dyn MagicLinkRepository>,
+    token_issuer: Arc<TokenIssuer>,
+    mfa_challenges: Arc<MfaChallengeService>,
+    events: Arc<AuthEventRecorder>,
+    enabled: bool,
+}
+
+impl VerifyMagicLinkUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        magic_link_repo: Arc<dyn MagicLinkRepository>,
+        token_issuer: Arc<TokenIssuer>,
+        mfa_challenges: Arc<MfaChallengeService>,
+        events: Arc<AuthEventRecorder>,
+        enabled: bool,
+    ) -> Self {
+        Self {
+            user_repo,
+            magic_link_repo,
+            token_issuer,
+            mfa_challenges,
+            events,
+            enabled,
+        }
+    }
+
+    /// Exchanges a login link for the same response as a password login,
+    /// including the MFA challenge when the account has a second factor.
+    pub async fn execute(
+        &self,
+        req: VerifyMagicLinkRequest,
+        client: ClientInfo,
+    ) -> Result<LoginResponse> {
+        if !self.enabled {
+            return Err(AppError::forbidden("magic link login is not enabled"));
+        }
+
+        let link = self
+            .magic_link_repo
+            .consume(TokenHash::of(&req.token).value())
+            .await?
+            .ok_or_else(|| AppError::unauthorized("invalid or expired login link"))?;
+
+        let mut user = self
+            .user_repo
+            .find_by_id(link.user_id)
+            .await?
+            .ok_or_else(|| AppError::unauthorized("invalid or expired login link"))?;
+
+        if !user.can_login() {
+            self.events
+                .record(AuthEvent::failure(
+                    AuthEventType::Login,
+                    Some(link.user_id),
+                    &client,
+                    "account inactive",
+                ))
+                .await;
+            return Err(AppError::forbidden("account is not active or verified"));
+        }
+
+        // Opening the link proves control of the address.
+        let newly_verified = !user.is_verified;
+        if newly_verified {
+            user.verify();
+        }
+
+        if self.mfa_challenges.is_required(link.user_id).await? {
+            if newly_verified {
+                self.user_repo.update(&user).await?;
+            }
+            let challenge = self.mfa_challenges.start(link.user_id).await?;
+            return Ok(LoginResponse::MfaRequired(challenge));
+        }
+
+        user.update_last_login();
+        let user = self.user_repo.update(&user).await?;
+
+        let token = self.token_issuer.issue(&user, &client).await?;
+        self.events
+            .record(AuthEvent::success(
+                AuthEventType::Login,
+                link.user_id,
+                &client,
+            ))
+            .await;
+
+        Ok(LoginResponse::Authenticated(AuthResponse {
+            user: user.into(),
+            token: token.into(),
+            password_expired: false,
+        }))
+    }
+}
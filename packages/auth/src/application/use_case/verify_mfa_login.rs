@@ -0,0 +1,157 @@
+use crate::application::services::auth_event_recorder::AuthEventRecorder;
+use crate::application::services::login_throttle::LoginThrottleService;
+use crate::application::services::password_policy::PasswordPolicyService;
+use crate::application::services::token_issuer::TokenIssuer;
+use crate::application::services::totp::{normalize_recovery_code, TotpService};
+use crate::domain::entity::{AuthEvent, AuthEventType, TotpFactor};
+use crate::domain::value_objects::{ClientInfo, TokenHash};
+use crate::domain::{MfaRepository, UserRepository};
+use crate::{AuthResponse, MfaLoginRequest};
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+pub struct VerifyMfaLoginUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    mfa_repo: Arc<dyn MfaRepository>,
+    totp: Arc<TotpService>,
+    token_issuer: Arc<TokenIssuer>,
+    password_policy: Arc<PasswordPolicyService>,
+    throttle: Arc<LoginThrottleService>,
+    events: Arc<AuthEventRecorder>,
+    max_attempts: i32,
+}
+
+impl VerifyMfaLoginUseCase {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        mfa_repo: Arc<dyn MfaRepository>,
+        totp: Arc<TotpService>,
+        token_issuer: Arc<TokenIssuer>,
+        password_policy: Arc<PasswordPolicyService>,
+        throttle: Arc<LoginThrottleService>,
+        events: Arc<AuthEventRecorder>,
+        max_attempts: i32,
+    ) -> Self {
+        Self {
+            user_repo,
+            mfa_repo,
+            totp,
+            token_issuer,
+            password_policy,
+            throttle,
+            events,
+            max_attempts,
+        }
+    }
+
+    /// Exchanges the pending MFA token and a TOTP or recovery code for the
+    /// real token pair.
+    pub async fn execute(&self, req: MfaLoginRequest, client: ClientInfo) -> Result<AuthResponse> {
+        let challenge = self
+            .mfa_repo
+            .find_challenge(TokenHash::of(&req.mfa_token).value())
+            .await?
+            .filter(|c| !c.is_consumed() && !c.is_expired())
+            .ok_or_else(|| AppError::unauthorized("invalid or expired mfa token"))?;
+        let challenge_id = challenge
+            .id
+            .ok_or_else(|| AppError::internal("mfa challenge id is None"))?;
+
+        if challenge.failed_attempts >= self.max_attempts {
+            return Err(AppError::unauthorized(
+                "too many invalid codes, please log in again",
+            ));
+        }
+
+        // A fresh challenge resets the per-challenge count, so codes are
+        // also throttled per account like passwords.
+        self.throttle.ensure_ip_allowed(client.ip).await?;
+        if let Err(e) = self
+            .throttle
+            .ensure_account_unlocked(challenge.user_id)
+            .await
+        {
+            self.events
+                .record(AuthEvent::failure(
+                    AuthEventType::Login,
+                    Some(challenge.user_id),
+                    &client,
+                    "account locked",
+                ))
+                .await;
+            return Err(e);
+        }
+
+        let factor = self
+            .mfa_repo
+            .find_totp(challenge.user_id)
+            .await?
+            .filter(TotpFactor::is_confirmed)
+            .ok_or_else(|| AppError::unauthorized("invalid or expired mfa token"))?;
+
+        if !self.check_code(&factor, &req.code).await? {
+            self.mfa_repo.record_failed_attempt(challenge_id).await?;
+            let throttled = self
+                .throttle
+                .record_failure(Some(challenge.user_id), client.ip)
+                .await;
+            self.events
+                .record(AuthEvent::failure(
+                    AuthEventType::Login,
+                    Some(challenge.user_id),
+                    &client,
+                    "invalid second factor",
+                ))
+                .await;
+            throttled?;
+            return Err(AppError::unauthorized("invalid verification code"));
+        }
+
+        if !self.mfa_repo.consume_challenge(challenge_id).await? {
+            return Err(AppError::unauthorized("invalid or expired mfa token"));
+        }
+        self.throttle.record_success(challenge.user_id).await?;
+
+        let mut user = self
+            .user_repo
+            .find_by_id(challenge.user_id)
+            .await?
+            .ok_or_else(|| AppError::unauthorized("invalid or expired mfa token"))?;
+
+        if !user.can_login() {
+            return Err(AppError::forbidden("account is not active or verified"));
+        }
+
+        user.update_last_login();
+        let user = self.user_repo.update(&user).await?;
+
+        let token = self.token_issuer.issue(&user, &client).await?;
+        self.events
+            .record(AuthEvent::success(
+                AuthEventType::Login,
+                challenge.user_id,
+                &client,
+            ))
+            .await;
+
+        Ok(AuthResponse {
+            password_expired: self.password_policy.is_expired(&user),
+            user: user.into(),
+            token: token.into(),
+        })
+    }
+
+    /// TOTP codes are single use per time step; anything else is tried as a
+    /// recovery code.
+    async fn check_code(&self, factor: &TotpFactor, code: &str) -> Result<bool> {
+        if let Some(step) = self.totp.verify(&factor.secret, code)? {
+            return self.mfa_repo.use_totp_step(factor.user_id, step).await;
+        }
+
+        let hash = TokenHash::of(&normalize_recovery_code(code));
+        self.mfa_repo
+            .use_recovery_code(factor.user_id, hash.value())
+            .await
+    }
+}
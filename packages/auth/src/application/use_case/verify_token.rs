@@ -1,17 +1,77 @@
-use crate::infrastructure::jwt::JwtService;
-use rust_reborn_contracts::Result;
+use crate::domain::{OAuthClientRepository, SessionRepository, TokenRevocationRepository};
+use crate::infrastructure::jwt::{Claims, JwtService};
+use rust_reborn_contracts::{AppError, Result};
 use std::sync::Arc;
 
 pub struct VerifyTokenUseCase {
     jwt_service: Arc<JwtService>,
+    revocation_repo: Arc<dyn TokenRevocationRepository>,
+    session_repo: Arc<dyn SessionRepository>,
+    client_repo: Arc<dyn OAuthClientRepository>,
 }
 
+/// `last_seen_at` is only written when older than this, not on every request.
+const LAST_SEEN_RESOLUTION_SECONDS: i64 = 60;
+
 impl VerifyTokenUseCase {
-    pub fn new(jwt_service: Arc<JwtService>) -> Self {
-        Self { jwt_service }
+    pub fn new(
+        jwt_service: Arc<JwtService>,
+        revocation_repo: Arc<dyn TokenRevocationRepository>,
+        session_repo: Arc<dyn SessionRepository>,
+        client_repo: Arc<dyn OAuthClientRepository>,
+    ) -> Self {
+        Self {
+            jwt_service,
+            revocation_repo,
+            session_repo,
+            client_repo,
+        }
     }
 
     pub async fn execute(&self, token: &str) -> Result<i64> {
-        self.jwt_service.verify_token(token)
+        self.verify(token).await?.user_id()
+    }
+
+    pub async fn verify(&self, token: &str) -> Result<Claims> {
+        let claims = self.jwt_service.decode_token(token)?;
+
+        // Service tokens have no user or session to revoke, but stop working
+        // as soon as their client is disabled.
+        if let Some(client_id) = &claims.client_id {
+            self.client_repo
+                .find_by_client_id(client_id)
+                .await?
+                .filter(|c| c.is_active)
+                .ok_or_else(|| AppError::unauthorized("client has been disabled"))?;
+            return Ok(claims);
+        }
+
+        if self
+            .revocation_repo
+            .is_revoked(
+                &claims.jti,
+                claims.user_id()?,
+                claims.sid.is_none().then(|| claims.issued_at()),
+            )
+            .await?
+        {
+            return Err(AppError::unauthorized("token has been revoked"));
+        }
+
+        if let Some(session_id) = claims.sid {
+            let session = self
+                .session_repo
+                .find_by_id(session_id)
+                .await?
+                .filter(|s| !s.is_revoked())
+                .ok_or_else(|| AppError::unauthorized("session has been revoked"))?;
+
+            let now = chrono::Utc::now();
+            if (now - session.last_seen_at).num_seconds() >= LAST_SEEN_RESOLUTION_SECONDS {
+                self.session_repo.touch(session_id, now).await?;
+            }
+        }
+
+        Ok(claims)
     }
 }
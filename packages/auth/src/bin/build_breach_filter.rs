@@ -0,0 +1,73 @@
+//! Builds the Bloom filter read by `auth.password_policy.breached_passwords`
+//! (format `bloom_filter`) from the Have I Been Pwned SHA-1 download:
+//!
+//! ```text
+//! build_breach_filter pwned-passwords-sha1-ordered-by-hash-v8.txt data/pwned-passwords.bloom [0.001]
+//! ```
+//!
+//! The optional last argument is the false-positive rate: the share of
+//! passwords that are not on the list but get refused anyway.
+
+use rust_reborn_auth::infrastructure::breached_passwords::{parse_hash_line, BloomFilter};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::process::ExitCode;
+
+const DEFAULT_FALSE_POSITIVE_RATE: f64 = 0.001;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (input, output, rate) = match args.as_slice() {
+        [input, output] => (input, output, DEFAULT_FALSE_POSITIVE_RATE),
+        [input, output, rate] => match rate.parse::<f64>() {
+            Ok(rate) if rate > 0.0 && rate < 1.0 => (input, output, rate),
+            _ => return usage("the false-positive rate must be between 0 and 1"),
+        },
+        _ => return usage("expected an input and an output path"),
+    };
+
+    match build(input, output, rate) {
+        Ok(count) => {
+            eprintln!("wrote {} hashes to {}", count, output);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Reads the list twice: once to size the filter, once to fill it.
+fn build(input: &str, output: &str, rate: f64) -> io::Result<u64> {
+    let mut count = 0;
+    for_each_hash(input, |_| count += 1)?;
+
+    let mut filter = BloomFilter::new(count, rate);
+    for_each_hash(input, |digest| filter.insert(digest))?;
+
+    let mut writer = BufWriter::new(File::create(output)?);
+    filter.write_to(&mut writer)?;
+    writer.flush()?;
+
+    Ok(count)
+}
+
+fn for_each_hash(path: &str, mut f: impl FnMut(&[u8; 20])) -> io::Result<()> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut line = String::new();
+
+    while reader.read_line(&mut line)? > 0 {
+        if let Some(digest) = parse_hash_line(&line) {
+            f(&digest);
+        }
+        line.clear();
+    }
+    Ok(())
+}
+
+fn usage(problem: &str) -> ExitCode {
+    eprintln!("{}", problem);
+    eprintln!("usage: build_breach_filter <sha1-list.txt> <output.bloom> [false-positive-rate]");
+    ExitCode::FAILURE
+}
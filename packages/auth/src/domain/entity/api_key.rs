@@ -0,0 +1,58 @@
+use crate::domain::value_objects::TokenHash;
+use chrono::{DateTime, Utc};
+
+/// Long-lived credential for scripts and integrations. The raw key is shown
+/// once; `prefix` (its first characters) finds the row and the full key
+/// must match `key_hash`.
+#[derive(Debug, Clone)]
+pub struct ApiKey {
+    pub id: Option<i64>,
+    pub user_id: i64,
+    pub name: String,
+    pub prefix: String,
+    pub key_hash: TokenHash,
+    /// Permissions the key may use; the owner's own permissions still apply.
+    pub scopes: Vec<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl ApiKey {
+    /// Characters of the raw key stored in clear for lookup and display.
+    pub const PREFIX_LEN: usize = 16;
+
+    pub fn new(
+        user_id: i64,
+        name: String,
+        raw_key: &str,
+        scopes: Vec<String>,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id: None,
+            user_id,
+            name,
+            prefix: Self::prefix_of(raw_key).to_string(),
+            key_hash: TokenHash::of(raw_key),
+            scopes,
+            expires_at,
+            last_used_at: None,
+            created_at: Utc::now(),
+            revoked_at: None,
+        }
+    }
+
+    pub fn prefix_of(raw_key: &str) -> &str {
+        raw_key.get(..Self::PREFIX_LEN).unwrap_or(raw_key)
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at.is_some_and(|at| at <= Utc::now())
+    }
+
+    pub fn is_usable(&self) -> bool {
+        self.revoked_at.is_none() && !self.is_expired()
+    }
+}
@@ -0,0 +1,153 @@
+use crate::domain::value_objects::ClientInfo;
+use chrono::{DateTime, Utc};
+use rust_reborn_contracts::AppError;
+use std::str::FromStr;
+
+/// What an audit event records. Stored under its `as_str` name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthEventType {
+    Login,
+    Register,
+    ChangePassword,
+    ResetPassword,
+    Logout,
+    LogoutAll,
+    Deactivate,
+    Delete,
+    ImpersonationStart,
+    ImpersonationEnd,
+}
+
+impl AuthEventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Login => "login",
+            Self::Register => "register",
+            Self::ChangePassword => "change_password",
+            Self::ResetPassword => "reset_password",
+            Self::Logout => "logout",
+            Self::LogoutAll => "logout_all",
+            Self::Deactivate => "deactivate",
+            Self::Delete => "delete",
+            Self::ImpersonationStart => "impersonation_start",
+            Self::ImpersonationEnd => "impersonation_end",
+        }
+    }
+}
+
+impl FromStr for AuthEventType {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "login" => Ok(Self::Login),
+            "register" => Ok(Self::Register),
+            "change_password" => Ok(Self::ChangePassword),
+            "reset_password" => Ok(Self::ResetPassword),
+            "logout" => Ok(Self::Logout),
+            "logout_all" => Ok(Self::LogoutAll),
+            "deactivate" => Ok(Self::Deactivate),
+            "delete" => Ok(Self::Delete),
+            "impersonation_start" => Ok(Self::ImpersonationStart),
+            "impersonation_end" => Ok(Self::ImpersonationEnd),
+            other => Err(AppError::bad_request(format!(
+                "unknown event type: {}",
+                other
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthEventOutcome {
+    Success,
+    Failure,
+}
+
+impl AuthEventOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Failure => "failure",
+        }
+    }
+}
+
+impl FromStr for AuthEventOutcome {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "success" => Ok(Self::Success),
+            "failure" => Ok(Self::Failure),
+            other => Err(AppError::bad_request(format!("unknown outcome: {}", other))),
+        }
+    }
+}
+
+/// One entry of the authentication audit log. `user_id` is the account
+/// the event is about, `actor_id` who caused it when that was someone else.
+#[derive(Debug, Clone)]
+pub struct AuthEvent {
+    pub id: Option<i64>,
+    pub event_type: AuthEventType,
+    pub outcome: AuthEventOutcome,
+    pub user_id: Option<i64>,
+    pub actor_id: Option<i64>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub reason: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AuthEvent {
+    pub fn success(event_type: AuthEventType, user_id: i64, client: &ClientInfo) -> Self {
+        Self::new(
+            event_type,
+            AuthEventOutcome::Success,
+            Some(user_id),
+            client,
+            None,
+        )
+    }
+
+    pub fn failure(
+        event_type: AuthEventType,
+        user_id: Option<i64>,
+        client: &ClientInfo,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self::new(
+            event_type,
+            AuthEventOutcome::Failure,
+            user_id,
+            client,
+            Some(reason.into()),
+        )
+    }
+
+    pub fn by_actor(mut self, actor_id: i64) -> Self {
+        self.actor_id = Some(actor_id);
+        self
+    }
+
+    fn new(
+        event_type: AuthEventType,
+        outcome: AuthEventOutcome,
+        user_id: Option<i64>,
+        client: &ClientInfo,
+        reason: Option<String>,
+    ) -> Self {
+        Self {
+            id: None,
+            event_type,
+            outcome,
+            user_id,
+            actor_id: None,
+            ip_address: client.ip.map(|ip| ip.to_string()),
+            user_agent: client.user_agent.clone(),
+            reason,
+            created_at: Utc::now(),
+        }
+    }
+}
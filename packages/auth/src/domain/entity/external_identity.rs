@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+
+/// Links an account at an external identity provider (the provider's
+/// `sub` claim) to a local user.
+#[derive(Debug, Clone)]
+pub struct ExternalIdentity {
+    pub id: Option<i64>,
+    pub user_id: i64,
+    pub provider: String,
+    pub subject: String,
+    pub email: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_login_at: Option<DateTime<Utc>>,
+}
+
+impl ExternalIdentity {
+    pub fn new(user_id: i64, provider: &str, subject: &str, email: Option<String>) -> Self {
+        let now = Utc::now();
+        Self {
+            id: None,
+            user_id,
+            provider: provider.to_string(),
+            subject: subject.to_string(),
+            email,
+            created_at: now,
+            last_login_at: Some(now),
+        }
+    }
+}
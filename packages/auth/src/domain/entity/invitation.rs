@@ -0,0 +1,57 @@
+use crate::domain::value_objects::TokenHash;
+use chrono::{DateTime, Utc};
+
+/// An administrator's invitation to create an account with `email`. The
+/// account gets `role` and starts out verified, since the link went to
+/// that address.
+#[derive(Debug, Clone)]
+pub struct Invitation {
+    pub id: Option<i64>,
+    pub email: String,
+    pub role: String,
+    pub invited_by: Option<i64>,
+    pub token_hash: TokenHash,
+    pub expires_at: DateTime<Utc>,
+    pub accepted_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Invitation {
+    pub fn new(
+        email: String,
+        role: String,
+        invited_by: i64,
+        token: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: None,
+            email,
+            role,
+            invited_by: Some(invited_by),
+            token_hash: TokenHash::of(token),
+            expires_at,
+            accepted_at: None,
+            revoked_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Neither accepted, revoked nor expired.
+    pub fn is_pending(&self) -> bool {
+        self.accepted_at.is_none() && self.revoked_at.is_none() && self.expires_at > Utc::now()
+    }
+
+    pub fn status(&self) -> &'static str {
+        if self.accepted_at.is_some() {
+            "accepted"
+        } else if self.revoked_at.is_some() {
+            "revoked"
+        } else if self.expires_at <= Utc::now() {
+            "expired"
+        } else {
+            "pending"
+        }
+    }
+}
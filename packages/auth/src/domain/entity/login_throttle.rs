@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+
+/// Failed login attempts counted for one key, either an account or a client address.
+#[derive(Debug, Clone)]
+pub struct LoginThrottle {
+    pub key: String,
+    /// Failures since `window_started_at`.
+    pub failed_attempts: i32,
+    /// Lockouts so far; each one lasts longer than the previous.
+    pub lockout_count: i32,
+    pub window_started_at: DateTime<Utc>,
+    pub locked_until: Option<DateTime<Utc>>,
+}
+
+impl LoginThrottle {
+    pub fn account_key(user_id: i64) -> String {
+        format!("user:{}", user_id)
+    }
+
+    pub fn ip_key(ip: &std::net::IpAddr) -> String {
+        format!("ip:{}", ip)
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.locked_until.is_some_and(|until| until > Utc::now())
+    }
+
+    pub fn retry_after_seconds(&self) -> i64 {
+        self.locked_until
+            .map(|until| (until - Utc::now()).num_seconds().max(1))
+            .unwrap_or(0)
+    }
+}
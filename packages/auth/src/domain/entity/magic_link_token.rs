@@ -0,0 +1,31 @@
+use crate::domain::value_objects::TokenHash;
+use chrono::{DateTime, Utc};
+
+/// A passwordless login link sent by email. It can be used once, before
+/// `expires_at`.
+#[derive(Debug, Clone)]
+pub struct MagicLinkToken {
+    pub id: Option<i64>,
+    pub user_id: i64,
+    pub token_hash: TokenHash,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl MagicLinkToken {
+    pub fn new(user_id: i64, token: &str, expires_at: DateTime<Utc>) -> Self {
+        Self {
+            id: None,
+            user_id,
+            token_hash: TokenHash::of(token),
+            expires_at,
+            used_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn is_usable(&self) -> bool {
+        self.used_at.is_none() && self.expires_at > Utc::now()
+    }
+}
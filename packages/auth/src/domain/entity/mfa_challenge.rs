@@ -0,0 +1,35 @@
+use crate::domain::value_objects::TokenHash;
+use chrono::{DateTime, Utc};
+
+/// Issued after a correct password when the account has MFA enabled; it is
+/// exchanged together with a code for the real token pair.
+#[derive(Debug, Clone)]
+pub struct MfaChallenge {
+    pub id: Option<i64>,
+    pub user_id: i64,
+    pub token_hash: TokenHash,
+    pub expires_at: DateTime<Utc>,
+    pub failed_attempts: i32,
+    pub consumed_at: Option<DateTime<Utc>>,
+}
+
+impl MfaChallenge {
+    pub fn new(user_id: i64, token_hash: TokenHash, expires_at: DateTime<Utc>) -> Self {
+        Self {
+            id: None,
+            user_id,
+            token_hash,
+            expires_at,
+            failed_attempts: 0,
+            consumed_at: None,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= Utc::now()
+    }
+
+    pub fn is_consumed(&self) -> bool {
+        self.consumed_at.is_some()
+    }
+}
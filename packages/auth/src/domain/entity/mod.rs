@@ -1,2 +1,30 @@
+pub mod api_key;
+pub mod auth_event;
+pub mod external_identity;
+pub mod invitation;
+pub mod login_throttle;
+pub mod magic_link_token;
+pub mod mfa_challenge;
+pub mod oauth_client;
+pub mod oidc_login_state;
+pub mod organization;
+pub mod refresh_token;
+pub mod role;
+pub mod session;
+pub mod totp_factor;
 pub mod user;
+pub use api_key::ApiKey;
+pub use auth_event::{AuthEvent, AuthEventOutcome, AuthEventType};
+pub use external_identity::ExternalIdentity;
+pub use invitation::Invitation;
+pub use login_throttle::LoginThrottle;
+pub use magic_link_token::MagicLinkToken;
+pub use mfa_challenge::MfaChallenge;
+pub use oauth_client::OAuthClient;
+pub use oidc_login_state::OidcLoginState;
+pub use organization::{Membership, Organization};
+pub use refresh_token::RefreshToken;
+pub use role::Role;
+pub use session::Session;
+pub use totp_factor::TotpFactor;
 pub use user::User;
@@ -0,0 +1,44 @@
+use crate::domain::value_objects::TokenHash;
+use chrono::{DateTime, Utc};
+
+/// A service registered to obtain tokens with the client-credentials
+/// grant. It acts as itself, not on behalf of a user.
+#[derive(Debug, Clone)]
+pub struct OAuthClient {
+    pub id: Option<i64>,
+    pub client_id: String,
+    pub name: String,
+    pub secret_hash: TokenHash,
+    /// Permissions the client may request.
+    pub scopes: Vec<String>,
+    pub is_active: bool,
+    pub created_by: Option<i64>,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl OAuthClient {
+    pub fn new(
+        client_id: String,
+        name: String,
+        secret: &str,
+        scopes: Vec<String>,
+        created_by: Option<i64>,
+    ) -> Self {
+        Self {
+            id: None,
+            client_id,
+            name,
+            secret_hash: TokenHash::of(secret),
+            scopes,
+            is_active: true,
+            created_by,
+            created_at: Utc::now(),
+            last_used_at: None,
+        }
+    }
+
+    pub fn verify_secret(&self, secret: &str) -> bool {
+        self.secret_hash == TokenHash::of(secret)
+    }
+}
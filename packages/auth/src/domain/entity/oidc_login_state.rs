@@ -0,0 +1,19 @@
+use crate::domain::value_objects::TokenHash;
+use chrono::{DateTime, Utc};
+
+/// Remembers an authorization request sent to a provider until its callback
+/// arrives. Only the hash of the `state` parameter is stored.
+#[derive(Debug, Clone)]
+pub struct OidcLoginState {
+    pub state_hash: TokenHash,
+    pub provider: String,
+    pub code_verifier: String,
+    pub nonce: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl OidcLoginState {
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= Utc::now()
+    }
+}
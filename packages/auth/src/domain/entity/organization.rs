@@ -0,0 +1,59 @@
+use crate::domain::entity::Role;
+use chrono::{DateTime, Utc};
+use rust_reborn_contracts::{AppError, Result};
+
+/// A tenant. The slug names it in the `X-Tenant` header and as a subdomain.
+#[derive(Debug, Clone)]
+pub struct Organization {
+    pub id: Option<i64>,
+    pub slug: String,
+    pub name: String,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Organization {
+    pub fn new(slug: String, name: String) -> Result<Self> {
+        if !Self::is_valid_slug(&slug) {
+            return Err(AppError::bad_request(
+                "slug must be 1-63 lowercase letters, digits or hyphens, not starting or ending with a hyphen",
+            ));
+        }
+
+        let now = Utc::now();
+        Ok(Self {
+            id: None,
+            slug,
+            name,
+            is_active: true,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Slugs double as DNS labels, so they follow the same rules.
+    pub fn is_valid_slug(slug: &str) -> bool {
+        (1..=63).contains(&slug.len())
+            && slug
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+            && !slug.starts_with('-')
+            && !slug.ends_with('-')
+    }
+}
+
+/// A user's membership of an organization, with the roles they hold there.
+#[derive(Debug, Clone)]
+pub struct Membership {
+    pub organization_id: i64,
+    pub user_id: i64,
+    pub roles: Vec<Role>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Membership {
+    pub fn role_names(&self) -> Vec<String> {
+        self.roles.iter().map(|r| r.name.clone()).collect()
+    }
+}
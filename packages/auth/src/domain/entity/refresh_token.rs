@@ -0,0 +1,45 @@
+use crate::domain::value_objects::TokenHash;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// A persisted refresh token. Every rotation creates a new row in the same
+/// `family_id`, so a replayed (already rotated) token can revoke the whole chain.
+#[derive(Debug, Clone)]
+pub struct RefreshToken {
+    pub id: Option<i64>,
+    pub user_id: i64,
+    pub family_id: Uuid,
+    pub token_hash: TokenHash,
+    pub expires_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub replaced_by: Option<i64>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl RefreshToken {
+    pub fn new(
+        user_id: i64,
+        family_id: Uuid,
+        token_hash: TokenHash,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: None,
+            user_id,
+            family_id,
+            token_hash,
+            expires_at,
+            revoked_at: None,
+            replaced_by: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= Utc::now()
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+}
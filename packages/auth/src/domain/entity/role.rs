@@ -0,0 +1,16 @@
+#[derive(Debug, Clone)]
+pub struct Role {
+    pub id: Option<i64>,
+    pub name: String,
+    pub permissions: Vec<String>,
+}
+
+impl Role {
+    pub fn new(name: impl Into<String>, permissions: Vec<String>) -> Self {
+        Self {
+            id: None,
+            name: name.into(),
+            permissions,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::domain::value_objects::ClientInfo;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// A signed-in device. The id is the refresh token family, and access
+/// tokens carry it as `sid` so revoking the session rejects them too.
+#[derive(Debug, Clone)]
+pub struct Session {
+    pub id: Uuid,
+    pub user_id: i64,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    /// Active tenant; access tokens of the session carry it as `tid`.
+    pub organization_id: Option<i64>,
+}
+
+impl Session {
+    pub fn new(id: Uuid, user_id: i64, client: &ClientInfo) -> Self {
+        let now = Utc::now();
+        Self {
+            id,
+            user_id,
+            user_agent: client.user_agent.clone(),
+            ip_address: client.ip.map(|ip| ip.to_string()),
+            created_at: now,
+            last_seen_at: now,
+            revoked_at: None,
+            organization_id: None,
+        }
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+}
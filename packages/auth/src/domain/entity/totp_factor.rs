@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+
+/// A user's TOTP authenticator. It only protects logins once confirmed.
+/// The base32 secret has to be stored as-is to compute codes.
+#[derive(Debug, Clone)]
+pub struct TotpFactor {
+    pub user_id: i64,
+    pub secret: String,
+    pub confirmed_at: Option<DateTime<Utc>>,
+    /// Last accepted time step, so a code cannot be replayed in its window.
+    pub last_used_step: Option<i64>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TotpFactor {
+    pub fn new(user_id: i64, secret: String) -> Self {
+        Self {
+            user_id,
+            secret,
+            confirmed_at: None,
+            last_used_step: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn is_confirmed(&self) -> bool {
+        self.confirmed_at.is_some()
+    }
+}
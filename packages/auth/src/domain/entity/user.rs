@@ -8,13 +8,20 @@ pub struct User {
     pub email: Email,
     pub username: String,
     pub password: HashedPassword,
+    /// When the password was last set; re-hashing on login does not count.
+    pub password_changed_at: DateTime<Utc>,
     pub full_name: Option<String>,
     pub is_active: bool,
     pub is_verified: bool,
     pub activation_key: Option<String>,
+    pub activation_key_expires_at: Option<DateTime<Utc>>,
     pub reset_key: Option<String>,
     pub reset_key_expires_at: Option<DateTime<Utc>>,
     pub reset_date: Option<DateTime<Utc>>,
+    /// Address waiting to replace `email` once the owner confirms it.
+    pub pending_email: Option<Email>,
+    pub email_change_key: Option<String>,
+    pub email_change_key_expires_at: Option<DateTime<Utc>>,
     pub status: i32,
     pub created_by: Option<String>,
     pub updated_by: Option<String>,
@@ -39,13 +46,18 @@ impl User {
             email,
             username,
             password,
+            password_changed_at: now,
             full_name,
             is_verified: false,
             is_active: true,
             activation_key: None,
+            activation_key_expires_at: None,
             reset_key: None,
             reset_key_expires_at: None,
             reset_date: None,
+            pending_email: None,
+            email_change_key: None,
+            email_change_key_expires_at: None,
             status: 1,
             created_by: None,
             updated_by: None,
@@ -62,14 +74,121 @@ impl User {
 
     pub fn verify(&mut self) {
         self.is_verified = true;
+        self.activation_key = None;
+        self.activation_key_expires_at = None;
         self.updated_at = Utc::now();
     }
 
+    pub fn set_activation_key(&mut self, key_hash: String, expires_at: DateTime<Utc>) {
+        self.activation_key = Some(key_hash);
+        self.activation_key_expires_at = Some(expires_at);
+        self.updated_at = Utc::now();
+    }
+
+    pub fn activation_key_expired(&self) -> bool {
+        self.activation_key_expires_at
+            .is_none_or(|expires_at| expires_at <= Utc::now())
+    }
+
+    pub fn set_reset_key(&mut self, key_hash: String, expires_at: DateTime<Utc>) {
+        self.reset_key = Some(key_hash);
+        self.reset_key_expires_at = Some(expires_at);
+        self.updated_at = Utc::now();
+    }
+
+    pub fn reset_key_expired(&self) -> bool {
+        self.reset_key_expires_at
+            .is_none_or(|expires_at| expires_at <= Utc::now())
+    }
+
+    pub fn change_password(&mut self, password: HashedPassword) {
+        let now = Utc::now();
+        self.password = password;
+        self.password_changed_at = now;
+        self.updated_at = now;
+    }
+
+    /// Replaces the password and consumes the reset key so it cannot be reused.
+    pub fn reset_password(&mut self, password: HashedPassword) {
+        self.change_password(password);
+        self.reset_key = None;
+        self.reset_key_expires_at = None;
+        self.reset_date = Some(self.updated_at);
+    }
+
+    /// Applies a self-service profile edit; `None` leaves a field unchanged.
+    pub fn update_profile(
+        &mut self,
+        full_name: Option<String>,
+        username: Option<String>,
+        updated_by: &str,
+    ) {
+        if let Some(full_name) = full_name {
+            self.full_name = Some(full_name);
+        }
+        if let Some(username) = username {
+            self.username = username;
+        }
+        self.updated_by = Some(updated_by.to_string());
+        self.updated_at = Utc::now();
+    }
+
+    /// Replaces any earlier pending change; only the latest link works.
+    pub fn request_email_change(
+        &mut self,
+        email: Email,
+        key_hash: String,
+        expires_at: DateTime<Utc>,
+    ) {
+        self.pending_email = Some(email);
+        self.email_change_key = Some(key_hash);
+        self.email_change_key_expires_at = Some(expires_at);
+        self.updated_at = Utc::now();
+    }
+
+    pub fn email_change_key_expired(&self) -> bool {
+        self.email_change_key_expires_at
+            .is_none_or(|expires_at| expires_at <= Utc::now())
+    }
+
+    /// Swaps in the pending address. Following the link proves the owner
+    /// controls it, so the account counts as verified afterwards.
+    pub fn confirm_email_change(&mut self, updated_by: &str) -> bool {
+        let Some(email) = self.pending_email.take() else {
+            return false;
+        };
+        self.email = email;
+        self.email_change_key = None;
+        self.email_change_key_expires_at = None;
+        self.is_verified = true;
+        self.updated_by = Some(updated_by.to_string());
+        self.updated_at = Utc::now();
+        true
+    }
+
     pub fn deactivate(&mut self) {
         self.is_active = false;
         self.updated_at = Utc::now();
     }
 
+    pub fn reactivate(&mut self) {
+        self.is_active = true;
+        self.updated_at = Utc::now();
+    }
+
+    /// Hides the account from every lookup while keeping the row, so its
+    /// email and username stay reserved.
+    pub fn soft_delete(&mut self) {
+        let now = Utc::now();
+        self.is_active = false;
+        self.deleted_at = Some(now);
+        self.updated_at = now;
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
     pub fn update_last_login(&mut self) {
         self.last_login_at = Some(Utc::now());
     }
@@ -1,8 +1,11 @@
 pub mod entity;
 pub mod repository;
-pub mod validation;
 pub mod value_objects;
 pub use entity::User;
-pub use repository::UserRepository;
-pub use validation::password_validation::validate_password_strength;
-pub use value_objects::Password;
+pub use repository::{
+    ApiKeyRepository, AuthEventFilter, AuthEventRepository, ExternalIdentityRepository,
+    InvitationRepository, LoginThrottleRepository, MagicLinkRepository, MfaRepository,
+    OAuthClientRepository, OrganizationRepository, PasswordHistoryRepository,
+    RefreshTokenRepository, RoleRepository, SessionRepository, TokenRevocationRepository,
+    UserFilter, UserRepository,
+};
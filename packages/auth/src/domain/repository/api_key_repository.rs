@@ -0,0 +1,16 @@
+use crate::domain::entity::ApiKey;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_reborn_contracts::Result;
+
+#[async_trait]
+pub trait ApiKeyRepository: Send + Sync {
+    async fn save(&self, key: &mut ApiKey) -> Result<()>;
+    async fn find_by_prefix(&self, prefix: &str) -> Result<Option<ApiKey>>;
+    /// Keys of the user that have not been revoked, newest first.
+    async fn find_by_user(&self, user_id: i64) -> Result<Vec<ApiKey>>;
+    /// Writes the name and scopes.
+    async fn update(&self, key: &ApiKey) -> Result<()>;
+    async fn revoke(&self, id: i64) -> Result<()>;
+    async fn touch(&self, id: i64, used_at: DateTime<Utc>) -> Result<()>;
+}
@@ -0,0 +1,29 @@
+use crate::domain::entity::{AuthEvent, AuthEventOutcome, AuthEventType};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_reborn_contracts::Result;
+
+/// Criteria for querying the audit log. `None` fields do not filter.
+#[derive(Debug, Clone, Default)]
+pub struct AuthEventFilter {
+    pub user_id: Option<i64>,
+    pub event_type: Option<AuthEventType>,
+    pub outcome: Option<AuthEventOutcome>,
+    /// Inclusive lower bound of `created_at`.
+    pub from: Option<DateTime<Utc>>,
+    /// Exclusive upper bound of `created_at`.
+    pub to: Option<DateTime<Utc>>,
+}
+
+#[async_trait]
+pub trait AuthEventRepository: Send + Sync {
+    async fn save(&self, event: &mut AuthEvent) -> Result<()>;
+    /// Newest first.
+    async fn find_all(
+        &self,
+        filter: &AuthEventFilter,
+        limit: u32,
+        offset: u32,
+    ) -> Result<Vec<AuthEvent>>;
+    async fn count(&self, filter: &AuthEventFilter) -> Result<u64>;
+}
@@ -0,0 +1,16 @@
+use crate::domain::entity::{ExternalIdentity, OidcLoginState};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_reborn_contracts::Result;
+
+#[async_trait]
+pub trait ExternalIdentityRepository: Send + Sync {
+    async fn find(&self, provider: &str, subject: &str) -> Result<Option<ExternalIdentity>>;
+    async fn find_by_user(&self, user_id: i64) -> Result<Vec<ExternalIdentity>>;
+    async fn save(&self, identity: &mut ExternalIdentity) -> Result<()>;
+    async fn record_login(&self, id: i64, at: DateTime<Utc>) -> Result<()>;
+
+    async fn save_login_state(&self, state: &OidcLoginState) -> Result<()>;
+    /// Removes and returns the state, so each one can be used only once.
+    async fn take_login_state(&self, state_hash: &str) -> Result<Option<OidcLoginState>>;
+}
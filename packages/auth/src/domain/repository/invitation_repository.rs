@@ -0,0 +1,22 @@
+use crate::domain::entity::{Invitation, User};
+use async_trait::async_trait;
+use rust_reborn_contracts::Result;
+
+#[async_trait]
+pub trait InvitationRepository: Send + Sync {
+    /// Revokes any other open invitation for the same address. Fails with
+    /// not found when `invitation.role` does not exist.
+    async fn save(&self, invitation: &mut Invitation) -> Result<()>;
+    async fn find_by_token_hash(&self, token_hash: &str) -> Result<Option<Invitation>>;
+    /// Newest first.
+    async fn find_all(&self) -> Result<Vec<Invitation>>;
+    /// Revokes the invitation unless it was already accepted or revoked.
+    /// Returns false if there was no such invitation.
+    async fn revoke(&self, id: i64) -> Result<bool>;
+    /// Marks the pending invitation as accepted and saves the invited user
+    /// with the invitation's role, all in one transaction. Returns false and
+    /// saves nothing when it was accepted, revoked or expired in the
+    /// meantime, so each works once; if saving the user fails the invitation
+    /// stays pending.
+    async fn accept(&self, id: i64, user: &mut User) -> Result<bool>;
+}
@@ -0,0 +1,20 @@
+use crate::domain::entity::LoginThrottle;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_reborn_contracts::Result;
+
+#[async_trait]
+pub trait LoginThrottleRepository: Send + Sync {
+    async fn find(&self, key: &str) -> Result<Option<LoginThrottle>>;
+    /// Atomically counts a failure. The counter starts over when the current
+    /// window began before `window_start`.
+    async fn record_failure(
+        &self,
+        key: &str,
+        now: DateTime<Utc>,
+        window_start: DateTime<Utc>,
+    ) -> Result<LoginThrottle>;
+    /// Locks the key until `until`, bumps the lockout count and clears the failures.
+    async fn lock(&self, key: &str, until: DateTime<Utc>) -> Result<()>;
+    async fn reset(&self, key: &str) -> Result<()>;
+}
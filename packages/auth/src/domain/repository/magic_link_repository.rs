@@ -0,0 +1,13 @@
+use crate::domain::entity::MagicLinkToken;
+use async_trait::async_trait;
+use rust_reborn_contracts::Result;
+
+#[async_trait]
+pub trait MagicLinkRepository: Send + Sync {
+    async fn save(&self, token: &mut MagicLinkToken) -> Result<()>;
+    /// Marks the unused, unexpired token with this hash as used and returns
+    /// it. Returns `None` when there is no such token, so each link works once.
+    async fn consume(&self, token_hash: &str) -> Result<Option<MagicLinkToken>>;
+    /// Invalidates every unused link of the user.
+    async fn invalidate_for_user(&self, user_id: i64) -> Result<()>;
+}
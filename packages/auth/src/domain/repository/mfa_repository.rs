@@ -0,0 +1,24 @@
+use crate::domain::entity::{MfaChallenge, TotpFactor};
+use async_trait::async_trait;
+use rust_reborn_contracts::Result;
+
+#[async_trait]
+pub trait MfaRepository: Send + Sync {
+    async fn find_totp(&self, user_id: i64) -> Result<Option<TotpFactor>>;
+    /// Inserts or replaces the user's (unconfirmed) factor.
+    async fn save_totp(&self, factor: &TotpFactor) -> Result<()>;
+    async fn confirm_totp(&self, user_id: i64, step: i64) -> Result<()>;
+    /// Records `step` as used; false if it is not newer than the last used step.
+    async fn use_totp_step(&self, user_id: i64, step: i64) -> Result<bool>;
+
+    /// Replaces all recovery codes of the user.
+    async fn replace_recovery_codes(&self, user_id: i64, code_hashes: &[String]) -> Result<()>;
+    /// Marks an unused code as used; false if there is no such unused code.
+    async fn use_recovery_code(&self, user_id: i64, code_hash: &str) -> Result<bool>;
+
+    async fn save_challenge(&self, challenge: &mut MfaChallenge) -> Result<()>;
+    async fn find_challenge(&self, token_hash: &str) -> Result<Option<MfaChallenge>>;
+    async fn record_failed_attempt(&self, challenge_id: i64) -> Result<()>;
+    /// False if the challenge was already consumed by a concurrent request.
+    async fn consume_challenge(&self, challenge_id: i64) -> Result<bool>;
+}
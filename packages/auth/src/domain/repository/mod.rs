@@ -1,2 +1,30 @@
+pub mod api_key_repository;
+pub mod auth_event_repository;
+pub mod external_identity_repository;
+pub mod invitation_repository;
+pub mod login_throttle_repository;
+pub mod magic_link_repository;
+pub mod mfa_repository;
+pub mod oauth_client_repository;
+pub mod organization_repository;
+pub mod password_history_repository;
+pub mod refresh_token_repository;
+pub mod role_repository;
+pub mod session_repository;
+pub mod token_revocation_repository;
 pub mod user_repository;
-pub use user_repository::UserRepository;
+pub use api_key_repository::ApiKeyRepository;
+pub use auth_event_repository::{AuthEventFilter, AuthEventRepository};
+pub use external_identity_repository::ExternalIdentityRepository;
+pub use invitation_repository::InvitationRepository;
+pub use login_throttle_repository::LoginThrottleRepository;
+pub use magic_link_repository::MagicLinkRepository;
+pub use mfa_repository::MfaRepository;
+pub use oauth_client_repository::OAuthClientRepository;
+pub use organization_repository::OrganizationRepository;
+pub use password_history_repository::PasswordHistoryRepository;
+pub use refresh_token_repository::RefreshTokenRepository;
+pub use role_repository::RoleRepository;
+pub use session_repository::SessionRepository;
+pub use token_revocation_repository::TokenRevocationRepository;
+pub use user_repository::{UserFilter, UserRepository};
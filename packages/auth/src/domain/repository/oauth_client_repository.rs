@@ -0,0 +1,14 @@
+use crate::domain::entity::OAuthClient;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_reborn_contracts::Result;
+
+#[async_trait]
+pub trait OAuthClientRepository: Send + Sync {
+    async fn save(&self, client: &mut OAuthClient) -> Result<()>;
+    async fn find_by_id(&self, id: i64) -> Result<Option<OAuthClient>>;
+    async fn find_by_client_id(&self, client_id: &str) -> Result<Option<OAuthClient>>;
+    async fn find_all(&self) -> Result<Vec<OAuthClient>>;
+    async fn set_active(&self, id: i64, is_active: bool) -> Result<()>;
+    async fn touch(&self, id: i64, used_at: DateTime<Utc>) -> Result<()>;
+}
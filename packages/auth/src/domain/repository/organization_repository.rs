@@ -0,0 +1,27 @@
+use crate::domain::entity::{Membership, Organization};
+use async_trait::async_trait;
+use rust_reborn_contracts::Result;
+
+#[async_trait]
+pub trait OrganizationRepository: Send + Sync {
+    async fn save(&self, organization: &mut Organization) -> Result<()>;
+    async fn find_by_id(&self, id: i64) -> Result<Option<Organization>>;
+    async fn find_by_slug(&self, slug: &str) -> Result<Option<Organization>>;
+    async fn find_all(&self) -> Result<Vec<Organization>>;
+    /// Adds the user as a member if needed and replaces their roles there.
+    async fn set_member_roles(
+        &self,
+        organization_id: i64,
+        user_id: i64,
+        role_names: &[String],
+    ) -> Result<()>;
+    /// Returns false if the user was not a member.
+    async fn remove_member(&self, organization_id: i64, user_id: i64) -> Result<bool>;
+    async fn find_membership(
+        &self,
+        organization_id: i64,
+        user_id: i64,
+    ) -> Result<Option<Membership>>;
+    async fn find_members(&self, organization_id: i64) -> Result<Vec<Membership>>;
+    async fn find_memberships_of_user(&self, user_id: i64) -> Result<Vec<Membership>>;
+}
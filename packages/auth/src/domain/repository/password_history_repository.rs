@@ -0,0 +1,11 @@
+use crate::domain::value_objects::HashedPassword;
+use async_trait::async_trait;
+use rust_reborn_contracts::Result;
+
+/// Hashes of passwords a user has replaced, newest first.
+#[async_trait]
+pub trait PasswordHistoryRepository: Send + Sync {
+    /// Stores a replaced password and forgets all but the newest `keep` entries.
+    async fn add(&self, user_id: i64, password: &HashedPassword, keep: usize) -> Result<()>;
+    async fn find_recent(&self, user_id: i64, limit: usize) -> Result<Vec<HashedPassword>>;
+}
@@ -0,0 +1,15 @@
+use crate::domain::entity::RefreshToken;
+use async_trait::async_trait;
+use rust_reborn_contracts::Result;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait RefreshTokenRepository: Send + Sync {
+    async fn save(&self, token: &mut RefreshToken) -> Result<()>;
+    async fn find_by_hash(&self, token_hash: &str) -> Result<Option<RefreshToken>>;
+    /// Atomically revokes `current` and persists `next` in its place.
+    /// Returns `false` when `current` was already revoked (lost a concurrent rotation).
+    async fn rotate(&self, current: &RefreshToken, next: &mut RefreshToken) -> Result<bool>;
+    async fn revoke_family(&self, family_id: Uuid) -> Result<()>;
+    async fn revoke_all_for_user(&self, user_id: i64) -> Result<()>;
+}
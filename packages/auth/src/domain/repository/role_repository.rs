@@ -0,0 +1,12 @@
+use crate::domain::entity::Role;
+use async_trait::async_trait;
+use rust_reborn_contracts::Result;
+
+#[async_trait]
+pub trait RoleRepository: Send + Sync {
+    /// Roles granted to the user, each with its permission names.
+    async fn find_by_user(&self, user_id: i64) -> Result<Vec<Role>>;
+    async fn exists(&self, role_name: &str) -> Result<bool>;
+    async fn assign_to_user(&self, user_id: i64, role_name: &str) -> Result<()>;
+    async fn remove_from_user(&self, user_id: i64, role_name: &str) -> Result<()>;
+}
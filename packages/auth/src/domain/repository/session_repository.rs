@@ -0,0 +1,21 @@
+use crate::domain::entity::Session;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_reborn_contracts::Result;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait SessionRepository: Send + Sync {
+    async fn save(&self, session: &Session) -> Result<()>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Session>>;
+    /// Sessions that are not revoked and were seen after `seen_since`, most recent first.
+    async fn find_active_by_user(
+        &self,
+        user_id: i64,
+        seen_since: DateTime<Utc>,
+    ) -> Result<Vec<Session>>;
+    async fn touch(&self, id: Uuid, seen_at: DateTime<Utc>) -> Result<()>;
+    async fn set_organization(&self, id: Uuid, organization_id: Option<i64>) -> Result<()>;
+    async fn revoke(&self, id: Uuid) -> Result<()>;
+    async fn revoke_all_for_user(&self, user_id: i64) -> Result<()>;
+}
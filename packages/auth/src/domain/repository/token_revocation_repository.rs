@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_reborn_contracts::Result;
+
+#[async_trait]
+pub trait TokenRevocationRepository: Send + Sync {
+    async fn revoke(&self, jti: &str, user_id: i64, expires_at: DateTime<Utc>) -> Result<()>;
+    /// Rejects every token of the user issued up to `before`.
+    async fn revoke_all_for_user(&self, user_id: i64, before: DateTime<Utc>) -> Result<()>;
+    /// `issued_at` has whole seconds only, so a token from the second of the
+    /// cutoff counts as revoked. Tokens bound to a session pass `None`: they
+    /// end with their session instead, so a login right after is not caught.
+    async fn is_revoked(
+        &self,
+        jti: &str,
+        user_id: i64,
+        issued_at: Option<DateTime<Utc>>,
+    ) -> Result<bool>;
+}
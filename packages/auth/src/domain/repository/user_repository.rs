@@ -2,11 +2,31 @@ use crate::domain::entity::User;
 use async_trait::async_trait;
 use rust_reborn_contracts::Result;
 
+/// Criteria for listing users. `None` fields do not filter.
+#[derive(Debug, Clone, Default)]
+pub struct UserFilter {
+    pub status: Option<i32>,
+    pub is_verified: Option<bool>,
+    pub is_active: Option<bool>,
+    /// Case-insensitive substring of the email or username.
+    pub search: Option<String>,
+}
+
+/// Soft-deleted users are invisible to every query.
 #[async_trait]
 pub trait UserRepository: Send + Sync {
     async fn find_by_email(&self, email: &str) -> Result<Option<User>>;
     async fn find_by_id(&self, id: i64) -> Result<Option<User>>;
     async fn find_by_username(&self, username: &str) -> Result<Option<User>>;
+    async fn find_by_activation_key(&self, activation_key: &str) -> Result<Option<User>>;
+    async fn find_by_reset_key(&self, reset_key: &str) -> Result<Option<User>>;
+    /// Clears the reset key in one conditional write; `false` when another
+    /// request already used it.
+    async fn consume_reset_key(&self, reset_key: &str) -> Result<bool>;
+    async fn find_by_email_change_key(&self, email_change_key: &str) -> Result<Option<User>>;
+    /// Newest first.
+    async fn find_all(&self, filter: &UserFilter, limit: u32, offset: u32) -> Result<Vec<User>>;
+    async fn count(&self, filter: &UserFilter) -> Result<u64>;
     async fn update(&self, user: &User) -> Result<User>;
     async fn save(&self, user: &mut User) -> Result<()>;
 }
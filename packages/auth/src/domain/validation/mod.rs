@@ -1 +0,0 @@
-pub mod password_validation;
@@ -0,0 +1,8 @@
+use std::net::IpAddr;
+
+/// Where a request came from, as far as the server can tell.
+#[derive(Debug, Clone, Default)]
+pub struct ClientInfo {
+    pub ip: Option<IpAddr>,
+    pub user_agent: Option<String>,
+}
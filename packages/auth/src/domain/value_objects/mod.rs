@@ -1,6 +1,10 @@
+pub mod client_info;
 pub mod email;
 pub mod password;
 pub mod token;
+pub mod token_hash;
+pub use client_info::ClientInfo;
 pub use email::Email;
-pub use password::{HashedPassword, Password};
+pub use password::HashedPassword;
 pub use token::Token;
+pub use token_hash::TokenHash;
@@ -1,22 +1,5 @@
-use crate::domain::validate_password_strength;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
-pub struct Password {
-    value: String,
-}
-
-impl Password {
-    pub fn new(value: String) -> Result<Self, validator::ValidationError> {
-        validate_password_strength(&value)?;
-        Ok(Self { value })
-    }
-
-    pub fn value(&self) -> &str {
-        &self.value
-    }
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HashedPassword(String);
 
@@ -28,9 +11,11 @@ impl HashedPassword {
     pub fn value(&self) -> &str {
         &self.0
     }
+}
 
-    pub fn to_string(&self) -> String {
-        self.0.clone()
+impl std::fmt::Display for HashedPassword {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
     }
 }
 
@@ -0,0 +1,22 @@
+use sha2::{Digest, Sha256};
+
+/// SHA-256 digest of an opaque token. Only the digest is ever persisted,
+/// so a leaked table cannot be replayed against the API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenHash(String);
+
+impl TokenHash {
+    pub fn of(raw: &str) -> Self {
+        Self(hex::encode(Sha256::digest(raw.as_bytes())))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for TokenHash {
+    fn from(s: String) -> Self {
+        Self(s)
+    }
+}
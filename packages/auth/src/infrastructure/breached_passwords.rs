@@ -0,0 +1,228 @@
+use crate::application::port::breached_password_checker::BreachedPasswordChecker;
+use crate::infrastructure::config::{BreachListFormat, BreachedPasswordsConfig};
+use async_trait::async_trait;
+use rust_reborn_contracts::{AppError, Result};
+use sha1::{Digest, Sha1};
+use std::cmp::Ordering;
+use std::f64::consts::LN_2;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+const BLOOM_MAGIC: &[u8; 8] = b"RRBLOOM1";
+
+/// Opens the configured breach list. A Bloom filter is read into memory
+/// here, so a missing or corrupt file stops startup instead of failing
+/// the first registration.
+pub fn open_breached_password_checker(
+    config: &BreachedPasswordsConfig,
+) -> Result<Arc<dyn BreachedPasswordChecker>> {
+    let checker: Arc<dyn BreachedPasswordChecker> = match config.format {
+        BreachListFormat::HashList => Arc::new(HashListChecker::open(&config.path)?),
+        BreachListFormat::BloomFilter => Arc::new(BloomFilter::load(&config.path)?),
+    };
+    Ok(checker)
+}
+
+/// SHA-1 of the password, the key both list formats are built on.
+pub fn password_digest(raw: &str) -> [u8; 20] {
+    Sha1::digest(raw.as_bytes()).into()
+}
+
+/// Digest from a `HASH:COUNT` line of the Have I Been Pwned download.
+pub fn parse_hash_line(line: &str) -> Option<[u8; 20]> {
+    let hash = line.split(':').next()?.trim();
+    let mut digest = [0u8; 20];
+    hex::decode_to_slice(hash, &mut digest).ok()?;
+    Some(digest)
+}
+
+/// Binary search over the list ordered by hash. A lookup reads a few dozen
+/// lines, so the multi-gigabyte file stays on disk.
+pub struct HashListChecker {
+    path: PathBuf,
+    len: u64,
+}
+
+impl HashListChecker {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let len = std::fs::metadata(&path)
+            .map_err(|e| read_error(&path, e))?
+            .len();
+
+        Ok(Self { path, len })
+    }
+
+    /// Blocking; takes the file rather than `self` so it can run on the
+    /// blocking pool.
+    fn contains(path: &Path, len: u64, hash: &[u8]) -> io::Result<bool> {
+        let mut reader = BufReader::new(File::open(path)?);
+        let mut line = Vec::new();
+        // A matching line would start within lo..hi.
+        let (mut lo, mut hi) = (0, len);
+
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let start = seek_line_start(&mut reader, mid, &mut line)?;
+            if start >= hi {
+                hi = mid;
+                continue;
+            }
+
+            line.clear();
+            let read = reader.read_until(b'\n', &mut line)? as u64;
+            let key = line
+                .split(|b| matches!(b, b':' | b'\r' | b'\n'))
+                .next()
+                .unwrap_or_default()
+                .to_ascii_uppercase();
+
+            match key.as_slice().cmp(hash) {
+                Ordering::Equal => return Ok(true),
+                Ordering::Less => lo = start + read,
+                Ordering::Greater => hi = start,
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+#[async_trait]
+impl BreachedPasswordChecker for HashListChecker {
+    async fn is_breached(&self, raw: &str) -> Result<bool> {
+        let hash = hex::encode_upper(password_digest(raw));
+        let (path, len) = (self.path.clone(), self.len);
+
+        tokio::task::spawn_blocking(move || Self::contains(&path, len, hash.as_bytes()))
+            .await
+            .map_err(|e| AppError::internal(format!("breached password lookup failed: {}", e)))?
+            .map_err(|e| read_error(&self.path, e))
+    }
+}
+
+/// Positions the reader at the first line starting at or after `pos` and
+/// returns that offset.
+fn seek_line_start(
+    reader: &mut BufReader<File>,
+    pos: u64,
+    scratch: &mut Vec<u8>,
+) -> io::Result<u64> {
+    if pos == 0 {
+        return reader.seek(SeekFrom::Start(0));
+    }
+
+    reader.seek(SeekFrom::Start(pos - 1))?;
+    scratch.clear();
+    Ok(pos - 1 + reader.read_until(b'\n', scratch)? as u64)
+}
+
+/// Bloom filter over the SHA-1 digests of a breach list, built with the
+/// `build_breach_filter` tool. No false negatives; false positives at
+/// about the rate it was built for.
+pub struct BloomFilter {
+    words: Vec<u64>,
+    hashes: u32,
+}
+
+impl BloomFilter {
+    /// Sized for `expected` entries at `false_positive_rate`.
+    pub fn new(expected: u64, false_positive_rate: f64) -> Self {
+        let n = expected.max(1) as f64;
+        let bits = (-n * false_positive_rate.ln() / (LN_2 * LN_2)).ceil() as u64;
+        let words = bits.max(64).div_ceil(64);
+        let hashes = ((words * 64) as f64 / n * LN_2).round().clamp(1.0, 32.0) as u32;
+
+        Self {
+            words: vec![0; words as usize],
+            hashes,
+        }
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        File::open(path)
+            .and_then(|file| Self::read_from(BufReader::new(file)))
+            .map_err(|e| read_error(path, e))
+    }
+
+    pub fn insert(&mut self, digest: &[u8; 20]) {
+        for bit in self.positions(digest) {
+            self.words[(bit / 64) as usize] |= 1 << (bit % 64);
+        }
+    }
+
+    pub fn contains(&self, digest: &[u8; 20]) -> bool {
+        self.positions(digest)
+            .all(|bit| self.words[(bit / 64) as usize] & (1 << (bit % 64)) != 0)
+    }
+
+    pub fn write_to(&self, mut writer: impl Write) -> io::Result<()> {
+        writer.write_all(BLOOM_MAGIC)?;
+        writer.write_all(&self.hashes.to_le_bytes())?;
+        writer.write_all(&(self.words.len() as u64).to_le_bytes())?;
+        for word in &self.words {
+            writer.write_all(&word.to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    pub fn read_from(mut reader: impl Read) -> io::Result<Self> {
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, "not a breach Bloom filter");
+
+        let mut magic = [0u8; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic != BLOOM_MAGIC {
+            return Err(invalid());
+        }
+
+        let mut hashes = [0u8; 4];
+        reader.read_exact(&mut hashes)?;
+        let hashes = u32::from_le_bytes(hashes);
+        let mut count = [0u8; 8];
+        reader.read_exact(&mut count)?;
+        let count = usize::try_from(u64::from_le_bytes(count)).map_err(|_| invalid())?;
+        if hashes == 0 || count == 0 {
+            return Err(invalid());
+        }
+
+        let mut words = Vec::with_capacity(count);
+        let mut word = [0u8; 8];
+        for _ in 0..count {
+            reader.read_exact(&mut word)?;
+            words.push(u64::from_le_bytes(word));
+        }
+
+        Ok(Self { words, hashes })
+    }
+
+    /// The digest is already uniformly distributed, so its first two
+    /// 64-bit halves give every position by double hashing.
+    fn positions(&self, digest: &[u8; 20]) -> impl Iterator<Item = u64> {
+        let mut half = [0u8; 8];
+        half.copy_from_slice(&digest[..8]);
+        let h1 = u64::from_le_bytes(half);
+        half.copy_from_slice(&digest[8..16]);
+        let h2 = u64::from_le_bytes(half) | 1;
+        let bits = self.words.len() as u64 * 64;
+
+        (0..u64::from(self.hashes)).map(move |i| h1.wrapping_add(i.wrapping_mul(h2)) % bits)
+    }
+}
+
+#[async_trait]
+impl BreachedPasswordChecker for BloomFilter {
+    async fn is_breached(&self, raw: &str) -> Result<bool> {
+        Ok(self.contains(&password_digest(raw)))
+    }
+}
+
+fn read_error(path: &Path, e: io::Error) -> AppError {
+    AppError::internal(format!(
+        "failed to read breached password list {}: {}",
+        path.display(),
+        e
+    ))
+}
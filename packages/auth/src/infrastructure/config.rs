@@ -0,0 +1,439 @@
+use serde::{Deserialize, Serialize};
+
+/// Authentication policy knobs. Every field has a default so the `auth`
+/// section can be omitted from the configuration entirely.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AuthConfig {
+    /// Base URL of the frontend; links in account emails point there.
+    pub frontend_url: String,
+    pub email_verification: EmailVerificationConfig,
+    pub password_reset: PasswordResetConfig,
+    pub email_change: EmailChangeConfig,
+    pub magic_link: MagicLinkConfig,
+    pub invitation: InvitationConfig,
+    pub mfa: MfaConfig,
+    pub lockout: LockoutConfig,
+    pub password_policy: PasswordPolicyConfig,
+    pub password_hashing: PasswordHashingConfig,
+    pub oidc: OidcConfig,
+    pub oauth: OAuthConfig,
+    pub impersonation: ImpersonationConfig,
+    pub tenancy: TenancyConfig,
+    pub session_cookies: SessionCookiesConfig,
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        Self {
+            frontend_url: "http://localhost:3000".to_string(),
+            email_verification: EmailVerificationConfig::default(),
+            password_reset: PasswordResetConfig::default(),
+            email_change: EmailChangeConfig::default(),
+            magic_link: MagicLinkConfig::default(),
+            invitation: InvitationConfig::default(),
+            mfa: MfaConfig::default(),
+            lockout: LockoutConfig::default(),
+            password_policy: PasswordPolicyConfig::default(),
+            password_hashing: PasswordHashingConfig::default(),
+            oidc: OidcConfig::default(),
+            oauth: OAuthConfig::default(),
+            impersonation: ImpersonationConfig::default(),
+            tenancy: TenancyConfig::default(),
+            session_cookies: SessionCookiesConfig::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmailVerificationConfig {
+    pub token_expiration_hours: i64,
+    /// Refuse to log in users who have not confirmed their email address yet.
+    pub required_for_login: bool,
+}
+
+impl Default for EmailVerificationConfig {
+    fn default() -> Self {
+        Self {
+            token_expiration_hours: 24,
+            required_for_login: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PasswordResetConfig {
+    pub token_expiration_minutes: i64,
+}
+
+impl Default for PasswordResetConfig {
+    fn default() -> Self {
+        Self {
+            token_expiration_minutes: 60,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmailChangeConfig {
+    /// Lifetime of the confirmation link sent to the new address.
+    pub token_expiration_hours: i64,
+}
+
+impl Default for EmailChangeConfig {
+    fn default() -> Self {
+        Self {
+            token_expiration_hours: 24,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MagicLinkConfig {
+    /// Passwordless login by emailed link; off unless configured.
+    pub enabled: bool,
+    pub token_expiration_minutes: i64,
+}
+
+impl Default for MagicLinkConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            token_expiration_minutes: 15,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InvitationConfig {
+    /// Lifetime of the link in an invitation email.
+    pub token_expiration_hours: i64,
+}
+
+impl Default for InvitationConfig {
+    fn default() -> Self {
+        Self {
+            token_expiration_hours: 72,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MfaConfig {
+    /// Shown by authenticator apps next to the account name.
+    pub issuer: String,
+    /// Lifetime of the "mfa pending" token returned by login.
+    pub challenge_ttl_seconds: i64,
+    /// Wrong codes accepted per pending token before a new login is needed.
+    pub max_attempts: i32,
+    pub recovery_code_count: usize,
+}
+
+impl Default for MfaConfig {
+    fn default() -> Self {
+        Self {
+            issuer: "Rust Reborn".to_string(),
+            challenge_ttl_seconds: 300,
+            max_attempts: 5,
+            recovery_code_count: 10,
+        }
+    }
+}
+
+/// Brute-force protection for password logins.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LockoutConfig {
+    pub enabled: bool,
+    /// Wrong passwords for one account within `failure_window_seconds` before it is locked.
+    pub max_failed_attempts: i32,
+    pub failure_window_seconds: i64,
+    /// The first lockout lasts this long; every further one doubles it.
+    pub base_lockout_seconds: i64,
+    pub max_lockout_seconds: i64,
+    /// Failed logins from one client address within `ip_window_seconds`
+    /// before it is blocked for the rest of the window.
+    pub ip_max_attempts: i32,
+    pub ip_window_seconds: i64,
+    /// Take the client address from `X-Forwarded-For`; only enable behind a trusted proxy.
+    pub trust_forwarded_for: bool,
+    /// Trusted proxies in front of the service, each appending one entry to
+    /// `X-Forwarded-For`.
+    pub trusted_proxy_hops: usize,
+}
+
+impl Default for LockoutConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_failed_attempts: 5,
+            failure_window_seconds: 900,
+            base_lockout_seconds: 60,
+            max_lockout_seconds: 3600,
+            ip_max_attempts: 20,
+            ip_window_seconds: 300,
+            trust_forwarded_for: false,
+            trusted_proxy_hops: 1,
+        }
+    }
+}
+
+/// Rules for new passwords on registration, invitation, reset and change.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PasswordPolicyConfig {
+    /// Counted in characters, not bytes.
+    pub min_length: usize,
+    /// bcrypt only looks at the first 72 bytes.
+    pub max_length: usize,
+    pub require_uppercase: bool,
+    pub require_lowercase: bool,
+    pub require_digit: bool,
+    pub require_symbol: bool,
+    /// Refuse passwords containing the username or the local part of the email.
+    pub disallow_user_info: bool,
+    /// How many of the latest passwords, the current one included, may not
+    /// be chosen again; 0 turns the check off.
+    pub history_size: usize,
+    /// Days after which a password counts as expired; 0 means never.
+    pub max_age_days: i64,
+    pub breached_passwords: BreachedPasswordsConfig,
+}
+
+impl Default for PasswordPolicyConfig {
+    fn default() -> Self {
+        Self {
+            min_length: 8,
+            max_length: 128,
+            require_uppercase: true,
+            require_lowercase: true,
+            require_digit: true,
+            require_symbol: true,
+            disallow_user_info: true,
+            history_size: 0,
+            max_age_days: 0,
+            breached_passwords: BreachedPasswordsConfig::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BreachListFormat {
+    /// The SHA-1 list ordered by hash (`HASH:COUNT` lines), searched on disk.
+    HashList,
+    /// A filter built from that list with `build_breach_filter`, loaded
+    /// into memory. Rarely flags a password that is not on the list.
+    BloomFilter,
+}
+
+/// Refuse passwords that appear in a breach corpus such as the Have I Been
+/// Pwned download, without calling any external service.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachedPasswordsConfig {
+    pub enabled: bool,
+    pub format: BreachListFormat,
+    pub path: String,
+}
+
+impl Default for BreachedPasswordsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            format: BreachListFormat::BloomFilter,
+            path: "./data/pwned-passwords.bloom".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PasswordAlgorithm {
+    Argon2id,
+    Bcrypt,
+}
+
+/// Algorithm for new password hashes. Hashes made with another algorithm or
+/// other parameters still verify and are upgraded on the next login.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PasswordHashingConfig {
+    pub algorithm: PasswordAlgorithm,
+    pub argon2: Argon2Config,
+    pub bcrypt_cost: u32,
+}
+
+impl Default for PasswordHashingConfig {
+    fn default() -> Self {
+        Self {
+            algorithm: PasswordAlgorithm::Argon2id,
+            argon2: Argon2Config::default(),
+            bcrypt_cost: bcrypt::DEFAULT_COST,
+        }
+    }
+}
+
+/// Defaults follow the OWASP recommendation (19 MiB, 2 iterations, 1 lane).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Argon2Config {
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+}
+
+impl Default for Argon2Config {
+    fn default() -> Self {
+        Self {
+            memory_kib: 19456,
+            iterations: 2,
+            parallelism: 1,
+        }
+    }
+}
+
+/// Tokens issued to registered services with the client-credentials grant.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OAuthConfig {
+    /// Service tokens cannot be revoked, so keep them short-lived.
+    pub access_token_ttl_seconds: i64,
+}
+
+impl Default for OAuthConfig {
+    fn default() -> Self {
+        Self {
+            access_token_ttl_seconds: 3600,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ImpersonationConfig {
+    /// Lifetime of a "login as user" token; it cannot be refreshed.
+    pub token_ttl_minutes: i64,
+}
+
+impl Default for ImpersonationConfig {
+    fn default() -> Self {
+        Self {
+            token_ttl_minutes: 15,
+        }
+    }
+}
+
+/// How requests name the organization (tenant) they act in.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TenancyConfig {
+    /// With `api.example.com`, requests to `acme.api.example.com` act in
+    /// the `acme` organization. The `X-Tenant` header works either way.
+    pub base_domain: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SameSite {
+    Strict,
+    Lax,
+    None,
+}
+
+/// Browser sessions kept in cookies instead of script-readable storage.
+/// Clients opt in per request with `X-Session-Mode: cookie`; every other
+/// client keeps receiving tokens in the response body.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SessionCookiesConfig {
+    pub enabled: bool,
+    pub access_cookie: String,
+    pub refresh_cookie: String,
+    /// Readable by the frontend, which echoes it in `X-CSRF-Token`.
+    pub csrf_cookie: String,
+    /// The refresh cookie is only sent to routes under this path.
+    pub refresh_path: String,
+    pub domain: Option<String>,
+    /// Only turn off for local development over plain HTTP.
+    pub secure: bool,
+    pub same_site: SameSite,
+}
+
+impl Default for SessionCookiesConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            access_cookie: "access_token".to_string(),
+            refresh_cookie: "refresh_token".to_string(),
+            csrf_cookie: "csrf_token".to_string(),
+            refresh_path: "/api/auth".to_string(),
+            domain: None,
+            secure: true,
+            same_site: SameSite::Strict,
+        }
+    }
+}
+
+/// Sign-in through external OpenID Connect providers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OidcConfig {
+    pub providers: Vec<OidcProviderConfig>,
+    /// How long an authorization request may take before its callback is refused.
+    pub state_ttl_seconds: i64,
+}
+
+impl Default for OidcConfig {
+    fn default() -> Self {
+        Self {
+            providers: Vec::new(),
+            state_ttl_seconds: 600,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OidcProviderConfig {
+    /// Used in the login URLs, e.g. `/oidc/{name}/authorize`.
+    pub name: String,
+    /// Discovery runs against `{issuer}/.well-known/openid-configuration`.
+    pub issuer: String,
+    pub client_id: String,
+    /// Omit for public clients; PKCE is used either way.
+    pub client_secret: Option<String>,
+    /// Frontend page the provider redirects back to with `code` and `state`.
+    pub redirect_uri: String,
+    pub scopes: Vec<String>,
+    /// Create a local account for identities that match no existing user.
+    pub auto_provision: bool,
+    /// Link to an existing account with the same address when the provider
+    /// reports the email as verified.
+    pub link_verified_email: bool,
+}
+
+impl Default for OidcProviderConfig {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            issuer: String::new(),
+            client_id: String::new(),
+            client_secret: None,
+            redirect_uri: String::new(),
+            scopes: vec![
+                "openid".to_string(),
+                "email".to_string(),
+                "profile".to_string(),
+            ],
+            auto_provision: true,
+            link_verified_email: true,
+        }
+    }
+}
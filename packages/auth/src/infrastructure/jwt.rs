@@ -1,79 +1,332 @@
-use crate::domain::entity::User;
-use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use crate::domain::entity::{Role, Session, User};
+use crate::infrastructure::jwt_keys::{
+    jwk_set, read_pem, JwtAlgorithm, JwtVerificationKeyConfig, SigningKey, VerificationKey,
+};
+use chrono::{DateTime, Utc};
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{decode, decode_header, encode, Header, Validation};
 use rust_reborn_contracts::{AppError, Result};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claims {
-    pub sub: String, // user_id
+    pub sub: String, // user_id, or the client id of a service token
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub email: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub username: String,
     pub exp: i64,
     pub iat: i64,
+    pub jti: String,
+    #[serde(default)]
+    pub roles: Vec<String>,
+    #[serde(default)]
+    pub permissions: Vec<String>,
+    /// Session (refresh token family) the token belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sid: Option<Uuid>,
+    /// Active tenant (organization) of the session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tid: Option<i64>,
+    /// Set on tokens issued to an OAuth client (client-credentials grant).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    /// Set on impersonation tokens: the administrator acting as `sub` (RFC 8693).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub act: Option<Actor>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Actor {
+    pub sub: String,
+}
+
+impl Claims {
+    pub fn user_id(&self) -> Result<i64> {
+        if self.is_service() {
+            return Err(AppError::unauthorized(
+                "service tokens do not belong to a user",
+            ));
+        }
+        self.sub
+            .parse::<i64>()
+            .map_err(|e| AppError::internal(format!("invalid user ID in token: {}", e)))
+    }
+
+    /// The administrator behind an impersonation token.
+    pub fn actor_id(&self) -> Result<Option<i64>> {
+        self.act
+            .as_ref()
+            .map(|act| {
+                act.sub
+                    .parse::<i64>()
+                    .map_err(|e| AppError::internal(format!("invalid actor ID in token: {}", e)))
+            })
+            .transpose()
+    }
+
+    pub fn is_service(&self) -> bool {
+        self.client_id.is_some()
+    }
+
+    pub fn issued_at(&self) -> DateTime<Utc> {
+        DateTime::from_timestamp(self.iat, 0).unwrap_or_default()
+    }
+
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        DateTime::from_timestamp(self.exp, 0).unwrap_or_default()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JwtConfig {
+    /// Signs HS256 tokens, or only verifies them with `keep_hs256_secret`.
+    #[serde(default)]
     pub secret: String,
     pub expiration_hours: i64,
     pub refresh_expiration_days: i64,
+    #[serde(default)]
+    pub algorithm: JwtAlgorithm,
+    /// `kid` header of issued tokens; required for RS256/EdDSA.
+    #[serde(default)]
+    pub key_id: Option<String>,
+    /// PEM private key for RS256/EdDSA; its public key is derived from it.
+    #[serde(default)]
+    pub private_key_path: Option<String>,
+    /// Additional public keys still accepted, for key rotation.
+    #[serde(default)]
+    pub verification_keys: Vec<JwtVerificationKeyConfig>,
+    /// After switching to RS256/EdDSA, keep accepting HS256 tokens signed
+    /// with `secret` until the access-token lifetime has passed.
+    #[serde(default)]
+    pub keep_hs256_secret: bool,
+}
+
+impl JwtConfig {
+    pub fn hs256(
+        secret: impl Into<String>,
+        expiration_hours: i64,
+        refresh_expiration_days: i64,
+    ) -> Self {
+        Self {
+            secret: secret.into(),
+            expiration_hours,
+            refresh_expiration_days,
+            algorithm: JwtAlgorithm::HS256,
+            key_id: None,
+            private_key_path: None,
+            verification_keys: Vec::new(),
+            keep_hs256_secret: false,
+        }
+    }
 }
 
 pub struct JwtService {
     config: JwtConfig,
-    encoding_key: EncodingKey,
-    decoding_key: DecodingKey,
+    signing_key: SigningKey,
+    /// The signing key's own verification key comes first.
+    verification_keys: Vec<VerificationKey>,
 }
 
 impl JwtService {
-    pub fn new(config: JwtConfig) -> Self {
-        let encoding_key = EncodingKey::from_secret(config.secret.as_bytes());
-        let decoding_key = DecodingKey::from_secret(config.secret.as_bytes());
+    /// Loads the signing key and any extra verification keys named in the config.
+    pub fn new(config: JwtConfig) -> Result<Self> {
+        let (signing_key, own_key) = if config.algorithm.is_asymmetric() {
+            let path = config.private_key_path.as_deref().ok_or_else(|| {
+                AppError::internal("jwt.private_key_path is required for RS256/EdDSA")
+            })?;
+            let kid = config
+                .key_id
+                .clone()
+                .ok_or_else(|| AppError::internal("jwt.key_id is required for RS256/EdDSA"))?;
+            SigningKey::from_private_pem(config.algorithm, &read_pem(path)?, kid)?
+        } else {
+            SigningKey::hmac(&config.secret, config.key_id.clone())
+        };
 
-        Self {
+        let mut verification_keys = vec![own_key];
+        if config.algorithm.is_asymmetric() && config.keep_hs256_secret {
+            if config.secret.is_empty() {
+                return Err(AppError::internal(
+                    "jwt.secret is required with jwt.keep_hs256_secret",
+                ));
+            }
+            let (_, legacy_key) = SigningKey::hmac(&config.secret, None);
+            verification_keys.push(legacy_key);
+        }
+        for extra in &config.verification_keys {
+            if verification_keys
+                .iter()
+                .any(|k| k.kid.as_deref() == Some(extra.kid.as_str()))
+            {
+                return Err(AppError::internal(format!(
+                    "duplicate jwt key id: {}",
+                    extra.kid
+                )));
+            }
+            verification_keys.push(VerificationKey::from_public_pem(
+                extra.algorithm,
+                &read_pem(&extra.public_key_path)?,
+                extra.kid.clone(),
+            )?);
+        }
+
+        Ok(Self {
             config,
-            encoding_key,
-            decoding_key,
+            signing_key,
+            verification_keys,
+        })
+    }
+
+    /// Public verification keys for `/.well-known/jwks.json`.
+    pub fn jwks(&self) -> JwkSet {
+        jwk_set(&self.verification_keys)
+    }
+
+    pub fn expires_in(&self) -> i64 {
+        self.config.expiration_hours * 3600
+    }
+
+    pub fn refresh_expires_in(&self) -> chrono::Duration {
+        chrono::Duration::days(self.config.refresh_expiration_days)
+    }
+
+    /// Role and permission names are embedded as they are now; changes to a
+    /// user's roles apply from the next issued token.
+    pub fn generate_token(
+        &self,
+        user: &User,
+        roles: &[Role],
+        session: Option<&Session>,
+    ) -> Result<String> {
+        let ttl = chrono::Duration::hours(self.config.expiration_hours);
+        let mut claims = Self::user_claims(user, roles, ttl)?;
+        if let Some(session) = session {
+            claims.sid = Some(session.id);
+            claims.tid = session.organization_id;
         }
+
+        self.sign(&claims)
+    }
+
+    /// Token that lets `actor_id` act as `user`. It has no session, so it
+    /// cannot be refreshed and ends at `ttl` or when revoked.
+    pub fn generate_impersonation_token(
+        &self,
+        user: &User,
+        roles: &[Role],
+        actor_id: i64,
+        ttl: chrono::Duration,
+    ) -> Result<String> {
+        let mut claims = Self::user_claims(user, roles, ttl)?;
+        claims.act = Some(Actor {
+            sub: actor_id.to_string(),
+        });
+
+        self.sign(&claims)
     }
 
-    pub fn generate_token(&self, user: &User) -> Result<String> {
+    fn user_claims(user: &User, roles: &[Role], ttl: chrono::Duration) -> Result<Claims> {
         let user_id = user
             .id
             .ok_or_else(|| AppError::internal("cannot generate token: user.id is None"))?;
 
         let now = chrono::Utc::now();
-        let exp = now + chrono::Duration::hours(self.config.expiration_hours);
 
-        let claims = Claims {
+        Ok(Claims {
             sub: user_id.to_string(),
             email: user.email.value().to_string(),
             username: user.username.clone(),
-            exp: exp.timestamp(),
+            exp: (now + ttl).timestamp(),
+            iat: now.timestamp(),
+            jti: Uuid::new_v4().to_string(),
+            roles: roles.iter().map(|r| r.name.clone()).collect(),
+            permissions: Self::permissions_of(roles),
+            sid: None,
+            tid: None,
+            client_id: None,
+            act: None,
+        })
+    }
+
+    /// Token for an OAuth client acting as itself; `scopes` become its permissions.
+    pub fn generate_service_token(
+        &self,
+        client_id: &str,
+        scopes: &[String],
+        ttl: chrono::Duration,
+    ) -> Result<String> {
+        let now = chrono::Utc::now();
+
+        let claims = Claims {
+            sub: client_id.to_string(),
+            email: String::new(),
+            username: String::new(),
+            exp: (now + ttl).timestamp(),
             iat: now.timestamp(),
+            jti: Uuid::new_v4().to_string(),
+            roles: Vec::new(),
+            permissions: scopes.to_vec(),
+            sid: None,
+            tid: None,
+            client_id: Some(client_id.to_string()),
+            act: None,
         };
 
-        encode(&Header::default(), &claims, &self.encoding_key)
-            .map_err(|e| AppError::internal(format!("failed to generate token: {}", e)))
+        self.sign(&claims)
     }
 
-    pub fn verify_token(&self, token: &str) -> Result<i64> {
-        let token_data = decode::<Claims>(token, &self.decoding_key, &Validation::default())
-            .map_err(|e| AppError::unauthorized(format!("invalid token: {}", e)))?;
+    fn sign(&self, claims: &Claims) -> Result<String> {
+        let mut header = Header::new(self.signing_key.algorithm);
+        header.kid = self.signing_key.kid.clone();
 
-        let user_id = token_data
-            .claims
-            .sub
-            .parse::<i64>()
-            .map_err(|e| AppError::internal(format!("invalid user ID in token: {}", e)))?;
+        encode(&header, claims, &self.signing_key.key)
+            .map_err(|e| AppError::internal(format!("failed to generate token: {}", e)))
+    }
+
+    fn permissions_of(roles: &[Role]) -> Vec<String> {
+        let mut permissions: Vec<String> = roles
+            .iter()
+            .flat_map(|r| r.permissions.iter().cloned())
+            .collect();
+        permissions.sort();
+        permissions.dedup();
+        permissions
+    }
 
-        Ok(user_id)
+    pub fn verify_token(&self, token: &str) -> Result<i64> {
+        self.decode_token(token)?.user_id()
     }
 
     pub fn decode_token(&self, token: &str) -> Result<Claims> {
-        let token_data = decode::<Claims>(token, &self.decoding_key, &Validation::default())
+        let key = self.verification_key_for(token)?;
+
+        // Pinning the algorithm to the key stops a token from picking a weaker one.
+        let token_data = decode::<Claims>(token, &key.key, &Validation::new(key.algorithm))
             .map_err(|e| AppError::unauthorized(format!("Invalid token: {}", e)))?;
 
         Ok(token_data.claims)
     }
+
+    /// Tokens without a `kid` (issued before key ids were configured, or
+    /// with the kept HS256 secret) are checked against the first key of
+    /// their algorithm.
+    fn verification_key_for(&self, token: &str) -> Result<&VerificationKey> {
+        let header = decode_header(token)
+            .map_err(|e| AppError::unauthorized(format!("Invalid token: {}", e)))?;
+
+        match header.kid {
+            Some(kid) => self
+                .verification_keys
+                .iter()
+                .find(|k| k.kid.as_deref() == Some(kid.as_str()))
+                .ok_or_else(|| AppError::unauthorized("Invalid token: unknown key id")),
+            None => self
+                .verification_keys
+                .iter()
+                .find(|k| k.algorithm == header.alg)
+                .ok_or_else(|| AppError::unauthorized("Invalid token: unknown key")),
+        }
+    }
 }
@@ -0,0 +1,197 @@
+use data_encoding::BASE64URL_NOPAD;
+use jsonwebtoken::jwk::{
+    AlgorithmParameters, CommonParameters, EllipticCurve, Jwk, JwkSet, KeyAlgorithm,
+    OctetKeyPairParameters, OctetKeyPairType, PublicKeyUse, RSAKeyParameters, RSAKeyType,
+};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey};
+use rsa::pkcs1::{DecodeRsaPrivateKey, DecodeRsaPublicKey};
+use rsa::pkcs8::{DecodePrivateKey, DecodePublicKey};
+use rsa::traits::PublicKeyParts;
+use rsa::{RsaPrivateKey, RsaPublicKey};
+use rust_reborn_contracts::{AppError, Result};
+use serde::{Deserialize, Serialize};
+
+/// Signing algorithms supported for access tokens.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JwtAlgorithm {
+    /// Shared secret from `JwtConfig.secret`; only this service can verify tokens.
+    #[default]
+    HS256,
+    RS256,
+    EdDSA,
+}
+
+impl JwtAlgorithm {
+    pub fn is_asymmetric(self) -> bool {
+        self != Self::HS256
+    }
+
+    fn jwt_algorithm(self) -> Algorithm {
+        match self {
+            Self::HS256 => Algorithm::HS256,
+            Self::RS256 => Algorithm::RS256,
+            Self::EdDSA => Algorithm::EdDSA,
+        }
+    }
+}
+
+/// A retired (or externally managed) public key that is still accepted,
+/// e.g. the previous signing key while its tokens have not expired yet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JwtVerificationKeyConfig {
+    pub kid: String,
+    pub algorithm: JwtAlgorithm,
+    pub public_key_path: String,
+}
+
+/// Key used to sign new tokens.
+pub struct SigningKey {
+    pub kid: Option<String>,
+    pub algorithm: Algorithm,
+    pub key: EncodingKey,
+}
+
+/// Key accepted when verifying tokens. Public keys also appear in the JWKS.
+pub struct VerificationKey {
+    pub kid: Option<String>,
+    pub algorithm: Algorithm,
+    pub key: DecodingKey,
+    pub jwk: Option<Jwk>,
+}
+
+impl SigningKey {
+    pub fn hmac(secret: &str, kid: Option<String>) -> (Self, VerificationKey) {
+        let signing = Self {
+            kid: kid.clone(),
+            algorithm: Algorithm::HS256,
+            key: EncodingKey::from_secret(secret.as_bytes()),
+        };
+        let verification = VerificationKey {
+            kid,
+            algorithm: Algorithm::HS256,
+            key: DecodingKey::from_secret(secret.as_bytes()),
+            jwk: None,
+        };
+
+        (signing, verification)
+    }
+
+    /// Parses a PKCS#8 (or PKCS#1 for RSA) private key and derives the
+    /// matching public key for verification.
+    pub fn from_private_pem(
+        algorithm: JwtAlgorithm,
+        pem: &str,
+        kid: String,
+    ) -> Result<(Self, VerificationKey)> {
+        let (key, jwk) = match algorithm {
+            JwtAlgorithm::RS256 => {
+                let private = RsaPrivateKey::from_pkcs8_pem(pem)
+                    .or_else(|_| RsaPrivateKey::from_pkcs1_pem(pem))
+                    .map_err(|e| key_error("RSA private key", e))?;
+                let key = EncodingKey::from_rsa_pem(pem.as_bytes())
+                    .map_err(|e| key_error("RSA private key", e))?;
+                (key, rsa_jwk(&private.to_public_key(), &kid))
+            }
+            JwtAlgorithm::EdDSA => {
+                let private = ed25519_dalek::SigningKey::from_pkcs8_pem(pem)
+                    .map_err(|e| key_error("Ed25519 private key", e))?;
+                let key = EncodingKey::from_ed_pem(pem.as_bytes())
+                    .map_err(|e| key_error("Ed25519 private key", e))?;
+                (key, ed25519_jwk(&private.verifying_key(), &kid))
+            }
+            JwtAlgorithm::HS256 => {
+                return Err(AppError::internal("HS256 does not use a private key"));
+            }
+        };
+
+        let signing = Self {
+            kid: Some(kid),
+            algorithm: algorithm.jwt_algorithm(),
+            key,
+        };
+
+        Ok((signing, VerificationKey::from_jwk(algorithm, jwk)?))
+    }
+}
+
+impl VerificationKey {
+    /// Parses an SPKI (or PKCS#1 for RSA) public key.
+    pub fn from_public_pem(algorithm: JwtAlgorithm, pem: &str, kid: String) -> Result<Self> {
+        let jwk = match algorithm {
+            JwtAlgorithm::RS256 => {
+                let public = RsaPublicKey::from_public_key_pem(pem)
+                    .or_else(|_| RsaPublicKey::from_pkcs1_pem(pem))
+                    .map_err(|e| key_error("RSA public key", e))?;
+                rsa_jwk(&public, &kid)
+            }
+            JwtAlgorithm::EdDSA => {
+                let public = ed25519_dalek::VerifyingKey::from_public_key_pem(pem)
+                    .map_err(|e| key_error("Ed25519 public key", e))?;
+                ed25519_jwk(&public, &kid)
+            }
+            JwtAlgorithm::HS256 => {
+                return Err(AppError::internal("HS256 does not use a public key"));
+            }
+        };
+
+        Self::from_jwk(algorithm, jwk)
+    }
+
+    fn from_jwk(algorithm: JwtAlgorithm, jwk: Jwk) -> Result<Self> {
+        let key = DecodingKey::from_jwk(&jwk).map_err(|e| key_error("public key", e))?;
+
+        Ok(Self {
+            kid: jwk.common.key_id.clone(),
+            algorithm: algorithm.jwt_algorithm(),
+            key,
+            jwk: Some(jwk),
+        })
+    }
+}
+
+/// Public keys of `keys` as a JWK set; HMAC secrets are never included.
+pub fn jwk_set(keys: &[VerificationKey]) -> JwkSet {
+    JwkSet {
+        keys: keys.iter().filter_map(|k| k.jwk.clone()).collect(),
+    }
+}
+
+pub fn read_pem(path: &str) -> Result<String> {
+    std::fs::read_to_string(path)
+        .map_err(|e| AppError::internal(format!("failed to read key file {}: {}", path, e)))
+}
+
+fn rsa_jwk(public: &RsaPublicKey, kid: &str) -> Jwk {
+    Jwk {
+        common: common_parameters(KeyAlgorithm::RS256, kid),
+        algorithm: AlgorithmParameters::RSA(RSAKeyParameters {
+            key_type: RSAKeyType::RSA,
+            n: BASE64URL_NOPAD.encode(&public.n().to_bytes_be()),
+            e: BASE64URL_NOPAD.encode(&public.e().to_bytes_be()),
+        }),
+    }
+}
+
+fn ed25519_jwk(public: &ed25519_dalek::VerifyingKey, kid: &str) -> Jwk {
+    Jwk {
+        common: common_parameters(KeyAlgorithm::EdDSA, kid),
+        algorithm: AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+            key_type: OctetKeyPairType::OctetKeyPair,
+            curve: EllipticCurve::Ed25519,
+            x: BASE64URL_NOPAD.encode(public.as_bytes()),
+        }),
+    }
+}
+
+fn common_parameters(algorithm: KeyAlgorithm, kid: &str) -> CommonParameters {
+    CommonParameters {
+        public_key_use: Some(PublicKeyUse::Signature),
+        key_algorithm: Some(algorithm),
+        key_id: Some(kid.to_string()),
+        ..Default::default()
+    }
+}
+
+fn key_error(what: &str, err: impl std::fmt::Display) -> AppError {
+    AppError::internal(format!("invalid {}: {}", what, err))
+}
@@ -0,0 +1,147 @@
+use crate::application::port::account_notifier::AccountNotifier;
+use crate::domain::entity::{Invitation, User};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_reborn_contracts::mail::TemplatedMailer;
+use rust_reborn_contracts::{AppError, Result};
+use std::sync::Arc;
+
+/// Sends account notifications through the configured mailer. Links point
+/// at the frontend, which posts the token back to the API.
+pub struct MailAccountNotifier {
+    mailer: Arc<TemplatedMailer>,
+    frontend_url: String,
+}
+
+impl MailAccountNotifier {
+    pub fn new(mailer: Arc<TemplatedMailer>, frontend_url: impl Into<String>) -> Self {
+        Self {
+            mailer,
+            frontend_url: frontend_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    async fn send_link(
+        &self,
+        to: &str,
+        user: &User,
+        template: &str,
+        path: &str,
+        token: &str,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<()> {
+        self.send_link_with(
+            to,
+            template,
+            path,
+            token,
+            expires_at,
+            &[("username", &user.username)],
+        )
+        .await
+    }
+
+    async fn send_link_with(
+        &self,
+        to: &str,
+        template: &str,
+        path: &str,
+        token: &str,
+        expires_at: Option<DateTime<Utc>>,
+        vars: &[(&str, &str)],
+    ) -> Result<()> {
+        let link = format!("{}{}?token={}", self.frontend_url, path, token);
+        let expires_at = expires_at
+            .map(|t| t.format("%Y-%m-%d %H:%M UTC").to_string())
+            .unwrap_or_default();
+
+        let mut vars = vars.to_vec();
+        vars.extend([
+            ("link", link.as_str()),
+            ("token", token),
+            ("expires_at", expires_at.as_str()),
+        ]);
+
+        self.mailer.send_template(to, template, &vars).await
+    }
+}
+
+#[async_trait]
+impl AccountNotifier for MailAccountNotifier {
+    async fn send_email_verification(&self, user: &User, token: &str) -> Result<()> {
+        self.send_link(
+            user.email.value(),
+            user,
+            "email_verification",
+            "/verify-email",
+            token,
+            user.activation_key_expires_at,
+        )
+        .await
+    }
+
+    async fn send_password_reset(&self, user: &User, token: &str) -> Result<()> {
+        self.send_link(
+            user.email.value(),
+            user,
+            "password_reset",
+            "/reset-password",
+            token,
+            user.reset_key_expires_at,
+        )
+        .await
+    }
+
+    async fn send_email_change(&self, user: &User, token: &str) -> Result<()> {
+        let to = user
+            .pending_email
+            .as_ref()
+            .ok_or_else(|| AppError::internal("no pending email to confirm"))?;
+
+        self.send_link(
+            to.value(),
+            user,
+            "email_change",
+            "/confirm-email-change",
+            token,
+            user.email_change_key_expires_at,
+        )
+        .await
+    }
+
+    async fn send_magic_link(
+        &self,
+        user: &User,
+        token: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<()> {
+        self.send_link(
+            user.email.value(),
+            user,
+            "magic_link",
+            "/magic-link",
+            token,
+            Some(expires_at),
+        )
+        .await
+    }
+
+    async fn send_invitation(
+        &self,
+        invitation: &Invitation,
+        inviter: &User,
+        token: &str,
+    ) -> Result<()> {
+        let inviter_name = inviter.full_name.as_deref().unwrap_or(&inviter.username);
+
+        self.send_link_with(
+            &invitation.email,
+            "invitation",
+            "/accept-invitation",
+            token,
+            Some(invitation.expires_at),
+            &[("inviter", inviter_name)],
+        )
+        .await
+    }
+}
@@ -1,4 +1,9 @@
+pub mod breached_passwords;
+pub mod config;
 pub mod jwt;
+pub mod jwt_keys;
+pub mod mail_notifier;
+pub mod oidc;
 pub mod password;
 pub mod repository;
 pub use repository::PostgresUserRepository;
@@ -0,0 +1,356 @@
+use crate::infrastructure::config::OidcProviderConfig;
+use data_encoding::BASE64URL_NOPAD;
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
+use rust_reborn_contracts::{AppError, Result};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+/// Signature algorithms accepted on ID tokens. HMAC is excluded: it would
+/// make the client secret a signing key.
+const ALLOWED_ALGORITHMS: [Algorithm; 9] = [
+    Algorithm::RS256,
+    Algorithm::RS384,
+    Algorithm::RS512,
+    Algorithm::PS256,
+    Algorithm::PS384,
+    Algorithm::PS512,
+    Algorithm::ES256,
+    Algorithm::ES384,
+    Algorithm::EdDSA,
+];
+
+/// The part of the discovery document this client uses.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProviderMetadata {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub jwks_uri: String,
+    #[serde(default)]
+    pub token_endpoint_auth_methods_supported: Vec<String>,
+}
+
+/// Identity asserted by a validated ID token.
+#[derive(Debug, Clone)]
+pub struct OidcIdentity {
+    pub subject: String,
+    pub email: Option<String>,
+    pub email_verified: bool,
+    pub name: Option<String>,
+    pub preferred_username: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenEndpointResponse {
+    id_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IdTokenClaims {
+    sub: String,
+    nonce: Option<String>,
+    email: Option<String>,
+    #[serde(default, deserialize_with = "bool_or_string")]
+    email_verified: bool,
+    name: Option<String>,
+    preferred_username: Option<String>,
+}
+
+/// Client for one OpenID Connect provider. Discovery and the provider's
+/// keys are fetched on first use and cached; the keys are fetched again
+/// when a token names a key id that is not known yet (key rotation).
+pub struct OidcClient {
+    config: OidcProviderConfig,
+    http: reqwest::Client,
+    metadata: RwLock<Option<Arc<ProviderMetadata>>>,
+    jwks: RwLock<Option<Arc<JwkSet>>>,
+}
+
+impl OidcClient {
+    pub fn new(config: OidcProviderConfig, http: reqwest::Client) -> Self {
+        Self {
+            config,
+            http,
+            metadata: RwLock::new(None),
+            jwks: RwLock::new(None),
+        }
+    }
+
+    pub fn config(&self) -> &OidcProviderConfig {
+        &self.config
+    }
+
+    /// URL of the provider's login page for an authorization-code request.
+    pub async fn authorization_url(
+        &self,
+        state: &str,
+        nonce: &str,
+        code_challenge: &str,
+    ) -> Result<String> {
+        let metadata = self.metadata().await?;
+        let scope = self.config.scopes.join(" ");
+
+        let url = reqwest::Url::parse_with_params(
+            &metadata.authorization_endpoint,
+            &[
+                ("response_type", "code"),
+                ("client_id", self.config.client_id.as_str()),
+                ("redirect_uri", self.config.redirect_uri.as_str()),
+                ("scope", scope.as_str()),
+                ("state", state),
+                ("nonce", nonce),
+                ("code_challenge", code_challenge),
+                ("code_challenge_method", "S256"),
+            ],
+        )
+        .map_err(|e| AppError::internal(format!("invalid authorization endpoint: {}", e)))?;
+
+        Ok(url.into())
+    }
+
+    /// Redeems an authorization code and validates the returned ID token.
+    pub async fn exchange_code(
+        &self,
+        code: &str,
+        code_verifier: &str,
+        nonce: &str,
+    ) -> Result<OidcIdentity> {
+        let metadata = self.metadata().await?;
+
+        let mut form = vec![
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", self.config.redirect_uri.as_str()),
+            ("code_verifier", code_verifier),
+            ("client_id", self.config.client_id.as_str()),
+        ];
+        let mut request = self.http.post(&metadata.token_endpoint);
+        if let Some(secret) = &self.config.client_secret {
+            if uses_client_secret_post(&metadata) {
+                form.push(("client_secret", secret.as_str()));
+            } else {
+                request = request.basic_auth(&self.config.client_id, Some(secret));
+            }
+        }
+
+        let response = request
+            .form(&form)
+            .send()
+            .await
+            .map_err(|e| provider_error("token request failed", e))?;
+        if !response.status().is_success() {
+            tracing::warn!(
+                provider = %self.config.name,
+                status = %response.status(),
+                "authorization code was rejected"
+            );
+            return Err(AppError::unauthorized(
+                "authorization code was rejected by the identity provider",
+            ));
+        }
+
+        let tokens: TokenEndpointResponse = response
+            .json()
+            .await
+            .map_err(|e| provider_error("invalid token response", e))?;
+        let id_token = tokens
+            .id_token
+            .ok_or_else(|| AppError::unauthorized("identity provider returned no ID token"))?;
+
+        self.validate_id_token(&id_token, &metadata, nonce).await
+    }
+
+    async fn validate_id_token(
+        &self,
+        id_token: &str,
+        metadata: &ProviderMetadata,
+        nonce: &str,
+    ) -> Result<OidcIdentity> {
+        let header =
+            decode_header(id_token).map_err(|_| AppError::unauthorized("invalid ID token"))?;
+        if !ALLOWED_ALGORITHMS.contains(&header.alg) {
+            return Err(AppError::unauthorized("unsupported ID token algorithm"));
+        }
+
+        let key = self.decoding_key(header.kid.as_deref(), header.alg).await?;
+
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(&[metadata.issuer.as_str()]);
+        validation.set_audience(&[self.config.client_id.as_str()]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+
+        let claims = decode::<IdTokenClaims>(id_token, &key, &validation)
+            .map_err(|e| {
+                tracing::debug!(provider = %self.config.name, "ID token rejected: {}", e);
+                AppError::unauthorized("invalid ID token")
+            })?
+            .claims;
+
+        if claims.nonce.as_deref() != Some(nonce) {
+            return Err(AppError::unauthorized("ID token nonce mismatch"));
+        }
+
+        Ok(OidcIdentity {
+            subject: claims.sub,
+            email: claims.email,
+            email_verified: claims.email_verified,
+            name: claims.name,
+            preferred_username: claims.preferred_username,
+        })
+    }
+
+    async fn decoding_key(&self, kid: Option<&str>, alg: Algorithm) -> Result<DecodingKey> {
+        let jwks = self.jwks(false).await?;
+        let jwks = match find_jwk(&jwks, kid) {
+            Some(_) => jwks,
+            None => self.jwks(true).await?,
+        };
+
+        let jwk =
+            find_jwk(&jwks, kid).ok_or_else(|| AppError::unauthorized("unknown ID token key"))?;
+        if let Some(key_alg) = jwk.common.key_algorithm {
+            if key_alg.to_string().parse::<Algorithm>().ok() != Some(alg) {
+                return Err(AppError::unauthorized("ID token key algorithm mismatch"));
+            }
+        }
+
+        DecodingKey::from_jwk(jwk).map_err(|_| AppError::unauthorized("unusable ID token key"))
+    }
+
+    async fn metadata(&self) -> Result<Arc<ProviderMetadata>> {
+        if let Some(metadata) = self.metadata.read().await.as_ref() {
+            return Ok(metadata.clone());
+        }
+
+        let url = format!(
+            "{}/.well-known/openid-configuration",
+            self.config.issuer.trim_end_matches('/')
+        );
+        let metadata: ProviderMetadata = self.get_json(&url).await?;
+
+        if metadata.issuer.trim_end_matches('/') != self.config.issuer.trim_end_matches('/') {
+            return Err(AppError::internal(format!(
+                "discovery document of {} names issuer {}",
+                self.config.issuer, metadata.issuer
+            )));
+        }
+
+        let metadata = Arc::new(metadata);
+        *self.metadata.write().await = Some(metadata.clone());
+        Ok(metadata)
+    }
+
+    async fn jwks(&self, refresh: bool) -> Result<Arc<JwkSet>> {
+        if !refresh {
+            if let Some(jwks) = self.jwks.read().await.as_ref() {
+                return Ok(jwks.clone());
+            }
+        }
+
+        let metadata = self.metadata().await?;
+        let jwks: Arc<JwkSet> = Arc::new(self.get_json(&metadata.jwks_uri).await?);
+        *self.jwks.write().await = Some(jwks.clone());
+        Ok(jwks)
+    }
+
+    async fn get_json<T: serde::de::DeserializeOwned>(&self, url: &str) -> Result<T> {
+        self.http
+            .get(url)
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| provider_error("request failed", e))?
+            .json()
+            .await
+            .map_err(|e| provider_error("invalid response", e))
+    }
+}
+
+/// Configured providers by name.
+#[derive(Default)]
+pub struct OidcProviders {
+    clients: HashMap<String, Arc<OidcClient>>,
+}
+
+impl OidcProviders {
+    pub fn new(configs: &[OidcProviderConfig]) -> Result<Self> {
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()
+            .map_err(|e| AppError::internal(format!("failed to build HTTP client: {}", e)))?;
+
+        let mut clients = HashMap::new();
+        for config in configs {
+            if config.name.is_empty() || config.issuer.is_empty() || config.client_id.is_empty() {
+                return Err(AppError::internal(
+                    "OIDC providers need a name, issuer and client_id",
+                ));
+            }
+            let name = config.name.clone();
+            let client = Arc::new(OidcClient::new(config.clone(), http.clone()));
+            if clients.insert(name.clone(), client).is_some() {
+                return Err(AppError::internal(format!(
+                    "OIDC provider {} is configured twice",
+                    name
+                )));
+            }
+        }
+
+        Ok(Self { clients })
+    }
+
+    pub fn get(&self, name: &str) -> Result<Arc<OidcClient>> {
+        self.clients
+            .get(name)
+            .cloned()
+            .ok_or_else(|| AppError::not_found("unknown identity provider"))
+    }
+}
+
+/// PKCE `S256` code challenge for a code verifier.
+pub fn pkce_challenge(code_verifier: &str) -> String {
+    BASE64URL_NOPAD.encode(&Sha256::digest(code_verifier.as_bytes()))
+}
+
+fn find_jwk<'a>(jwks: &'a JwkSet, kid: Option<&str>) -> Option<&'a jsonwebtoken::jwk::Jwk> {
+    match kid {
+        Some(kid) => jwks.find(kid),
+        // Without a key id the token is only usable when the choice is unambiguous.
+        None if jwks.keys.len() == 1 => jwks.keys.first(),
+        None => None,
+    }
+}
+
+fn uses_client_secret_post(metadata: &ProviderMetadata) -> bool {
+    let methods = &metadata.token_endpoint_auth_methods_supported;
+    methods.iter().any(|m| m == "client_secret_post")
+        && !methods.iter().any(|m| m == "client_secret_basic")
+}
+
+fn provider_error(what: &str, err: reqwest::Error) -> AppError {
+    AppError::internal(format!("identity provider {}: {}", what, err))
+}
+
+/// Some providers send `email_verified` as the string "true".
+fn bool_or_string<'de, D>(deserializer: D) -> std::result::Result<bool, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum BoolOrString {
+        Bool(bool),
+        String(String),
+    }
+
+    Ok(match Option::<BoolOrString>::deserialize(deserializer)? {
+        Some(BoolOrString::Bool(b)) => b,
+        Some(BoolOrString::String(s)) => s.eq_ignore_ascii_case("true"),
+        None => false,
+    })
+}
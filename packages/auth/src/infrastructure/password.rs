@@ -1,26 +1,113 @@
 use crate::application::port::password_hasher::PasswordHasher;
-use async_trait::async_trait;
-use bcrypt::{hash, verify, DEFAULT_COST};
+use crate::infrastructure::config::{Argon2Config, PasswordAlgorithm, PasswordHashingConfig};
+use argon2::password_hash::{PasswordHash, Salt, SaltString};
+use argon2::{Argon2, Params, PasswordVerifier, Version};
+use rand::RngCore;
 use rust_reborn_contracts::{AppError, Result};
 
+/// Hashes new passwords with the configured algorithm and verifies both
+/// Argon2id and bcrypt hashes, telling them apart by their PHC prefix.
 #[derive(Clone)]
-pub struct PasswordService;
+pub struct PasswordService {
+    algorithm: PasswordAlgorithm,
+    argon2: Argon2<'static>,
+    bcrypt_cost: u32,
+}
 
 impl PasswordService {
-    pub fn new() -> Self {
-        Self
+    pub fn new(config: &PasswordHashingConfig) -> Result<Self> {
+        Ok(Self {
+            algorithm: config.algorithm,
+            argon2: argon2_hasher(&config.argon2)?,
+            bcrypt_cost: config.bcrypt_cost,
+        })
+    }
+
+    fn hash_argon2(&self, raw: &str) -> Result<String> {
+        let mut salt = [0u8; Salt::RECOMMENDED_LENGTH];
+        rand::rng().fill_bytes(&mut salt);
+        let salt = SaltString::encode_b64(&salt).map_err(hash_error)?;
+
+        argon2::PasswordHasher::hash_password(&self.argon2, raw.as_bytes(), &salt)
+            .map(|hash| hash.to_string())
+            .map_err(hash_error)
+    }
+
+    fn argon2_needs_rehash(&self, hash: &PasswordHash) -> bool {
+        let current = self.argon2.params();
+
+        hash.algorithm != argon2::Algorithm::Argon2id.ident()
+            || hash.version != Some(Version::V0x13.into())
+            || Params::try_from(hash).map_or(true, |params| {
+                params.m_cost() != current.m_cost()
+                    || params.t_cost() != current.t_cost()
+                    || params.p_cost() != current.p_cost()
+            })
     }
 }
 
-#[async_trait]
 impl PasswordHasher for PasswordService {
     fn hash(&self, raw: &str) -> Result<String> {
-        hash(raw, DEFAULT_COST)
-            .map_err(|e| AppError::internal(format!("Failed to hash password: {}", e)))
+        match self.algorithm {
+            PasswordAlgorithm::Argon2id => self.hash_argon2(raw),
+            PasswordAlgorithm::Bcrypt => bcrypt::hash(raw, self.bcrypt_cost).map_err(hash_error),
+        }
     }
 
     fn verify(&self, raw: &str, hashed: &str) -> Result<bool> {
-        verify(raw, hashed)
-            .map_err(|e| AppError::internal(format!("Failed to verify password: {}", e)))
+        if is_bcrypt(hashed) {
+            return bcrypt::verify(raw, hashed)
+                .map_err(|e| AppError::internal(format!("Failed to verify password: {}", e)));
+        }
+
+        let hash = PasswordHash::new(hashed)
+            .map_err(|e| AppError::internal(format!("Failed to verify password: {}", e)))?;
+
+        // Parameters are read from the hash itself, so older settings still verify.
+        Ok(Argon2::default()
+            .verify_password(raw.as_bytes(), &hash)
+            .is_ok())
+    }
+
+    fn needs_rehash(&self, hashed: &str) -> bool {
+        match (self.algorithm, is_bcrypt(hashed)) {
+            (PasswordAlgorithm::Bcrypt, true) => bcrypt_cost(hashed) != Some(self.bcrypt_cost),
+            (PasswordAlgorithm::Bcrypt, false) => true,
+            (PasswordAlgorithm::Argon2id, true) => true,
+            (PasswordAlgorithm::Argon2id, false) => {
+                PasswordHash::new(hashed).map_or(true, |hash| self.argon2_needs_rehash(&hash))
+            }
+        }
     }
 }
+
+fn argon2_hasher(config: &Argon2Config) -> Result<Argon2<'static>> {
+    let params = Params::new(
+        config.memory_kib,
+        config.iterations,
+        config.parallelism,
+        None,
+    )
+    .map_err(|e| AppError::internal(format!("invalid argon2 parameters: {}", e)))?;
+
+    Ok(Argon2::new(
+        argon2::Algorithm::Argon2id,
+        Version::V0x13,
+        params,
+    ))
+}
+
+fn is_bcrypt(hashed: &str) -> bool {
+    ["$2a$", "$2b$", "$2x$", "$2y$"]
+        .iter()
+        .any(|prefix| hashed.starts_with(prefix))
+}
+
+/// `$2b$12$...` -> 12
+fn bcrypt_cost(hashed: &str) -> Option<u32> {
+    hashed.get(4..6)?.parse().ok()
+}
+
+fn hash_error(e: impl std::fmt::Display) -> AppError {
+    AppError::internal(format!("Failed to hash password: {}", e))
+}
@@ -0,0 +1,66 @@
+use crate::domain::{entity::LoginThrottle, repository::LoginThrottleRepository};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_reborn_contracts::Result;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Process-local login throttling for tests and single-instance development setups.
+#[derive(Default)]
+pub struct InMemoryLoginThrottleRepository {
+    entries: Mutex<HashMap<String, LoginThrottle>>,
+}
+
+impl InMemoryLoginThrottleRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl LoginThrottleRepository for InMemoryLoginThrottleRepository {
+    async fn find(&self, key: &str) -> Result<Option<LoginThrottle>> {
+        Ok(self.entries.lock().unwrap().get(key).cloned())
+    }
+
+    async fn record_failure(
+        &self,
+        key: &str,
+        now: DateTime<Utc>,
+        window_start: DateTime<Utc>,
+    ) -> Result<LoginThrottle> {
+        let mut entries = self.entries.lock().unwrap();
+        let entry = entries
+            .entry(key.to_string())
+            .or_insert_with(|| LoginThrottle {
+                key: key.to_string(),
+                failed_attempts: 0,
+                lockout_count: 0,
+                window_started_at: now,
+                locked_until: None,
+            });
+
+        if entry.window_started_at < window_start {
+            entry.failed_attempts = 0;
+            entry.window_started_at = now;
+        }
+        entry.failed_attempts += 1;
+
+        Ok(entry.clone())
+    }
+
+    async fn lock(&self, key: &str, until: DateTime<Utc>) -> Result<()> {
+        if let Some(entry) = self.entries.lock().unwrap().get_mut(key) {
+            entry.locked_until = Some(until);
+            entry.lockout_count += 1;
+            entry.failed_attempts = 0;
+            entry.window_started_at = Utc::now();
+        }
+        Ok(())
+    }
+
+    async fn reset(&self, key: &str) -> Result<()> {
+        self.entries.lock().unwrap().remove(key);
+        Ok(())
+    }
+}
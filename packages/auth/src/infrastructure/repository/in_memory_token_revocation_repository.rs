@@ -0,0 +1,56 @@
+use crate::domain::repository::TokenRevocationRepository;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_reborn_contracts::Result;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+/// Process-local revocation list for tests and single-instance development setups.
+#[derive(Default)]
+pub struct InMemoryTokenRevocationRepository {
+    revoked: RwLock<HashMap<String, DateTime<Utc>>>,
+    revoked_before: RwLock<HashMap<i64, DateTime<Utc>>>,
+}
+
+impl InMemoryTokenRevocationRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl TokenRevocationRepository for InMemoryTokenRevocationRepository {
+    async fn revoke(&self, jti: &str, _user_id: i64, expires_at: DateTime<Utc>) -> Result<()> {
+        self.revoked
+            .write()
+            .unwrap()
+            .insert(jti.to_string(), expires_at);
+        Ok(())
+    }
+
+    async fn revoke_all_for_user(&self, user_id: i64, before: DateTime<Utc>) -> Result<()> {
+        let mut revoked_before = self.revoked_before.write().unwrap();
+        let cutoff = revoked_before.entry(user_id).or_insert(before);
+        *cutoff = (*cutoff).max(before);
+        Ok(())
+    }
+
+    async fn is_revoked(
+        &self,
+        jti: &str,
+        user_id: i64,
+        issued_at: Option<DateTime<Utc>>,
+    ) -> Result<bool> {
+        if self.revoked.read().unwrap().contains_key(jti) {
+            return Ok(true);
+        }
+
+        Ok(issued_at.is_some_and(|issued_at| {
+            self.revoked_before
+                .read()
+                .unwrap()
+                .get(&user_id)
+                .is_some_and(|cutoff| *cutoff >= issued_at)
+        }))
+    }
+}
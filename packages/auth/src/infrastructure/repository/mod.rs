@@ -1,3 +1,35 @@
+mod in_memory_login_throttle_repository;
+mod in_memory_token_revocation_repository;
+mod postgres_api_key_repository;
+mod postgres_auth_event_repository;
+mod postgres_external_identity_repository;
+mod postgres_invitation_repository;
+mod postgres_login_throttle_repository;
+mod postgres_magic_link_repository;
+mod postgres_mfa_repository;
+mod postgres_oauth_client_repository;
+mod postgres_organization_repository;
+mod postgres_password_history_repository;
+mod postgres_refresh_token_repository;
+mod postgres_role_repository;
+mod postgres_session_repository;
+mod postgres_token_revocation_repository;
 mod postgres_user_repository;
 
+pub use in_memory_login_throttle_repository::InMemoryLoginThrottleRepository;
+pub use in_memory_token_revocation_repository::InMemoryTokenRevocationRepository;
+pub use postgres_api_key_repository::PostgresApiKeyRepository;
+pub use postgres_auth_event_repository::PostgresAuthEventRepository;
+pub use postgres_external_identity_repository::PostgresExternalIdentityRepository;
+pub use postgres_invitation_repository::PostgresInvitationRepository;
+pub use postgres_login_throttle_repository::PostgresLoginThrottleRepository;
+pub use postgres_magic_link_repository::PostgresMagicLinkRepository;
+pub use postgres_mfa_repository::PostgresMfaRepository;
+pub use postgres_oauth_client_repository::PostgresOAuthClientRepository;
+pub use postgres_organization_repository::PostgresOrganizationRepository;
+pub use postgres_password_history_repository::PostgresPasswordHistoryRepository;
+pub use postgres_refresh_token_repository::PostgresRefreshTokenRepository;
+pub use postgres_role_repository::PostgresRoleRepository;
+pub use postgres_session_repository::PostgresSessionRepository;
+pub use postgres_token_revocation_repository::PostgresTokenRevocationRepository;
 pub use postgres_user_repository::PostgresUserRepository;
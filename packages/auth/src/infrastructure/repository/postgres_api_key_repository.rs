@@ -0,0 +1,135 @@
+use crate::domain::{entity::ApiKey, repository::ApiKeyRepository, value_objects::TokenHash};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_reborn_contracts::Result;
+use sqlx::{query, PgPool};
+
+#[derive(Clone)]
+pub struct PostgresApiKeyRepository {
+    pool: PgPool,
+}
+
+impl PostgresApiKeyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for PostgresApiKeyRepository {
+    async fn save(&self, key: &mut ApiKey) -> Result<()> {
+        let row = query!(
+            r#"
+            INSERT INTO api_keys (user_id, name, prefix, key_hash, scopes, expires_at, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING id
+            "#,
+            key.user_id,
+            key.name,
+            key.prefix,
+            key.key_hash.value(),
+            &key.scopes,
+            key.expires_at,
+            key.created_at,
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        key.id = Some(row.id);
+        Ok(())
+    }
+
+    async fn find_by_prefix(&self, prefix: &str) -> Result<Option<ApiKey>> {
+        let row = query!(
+            r#"
+            SELECT id, user_id, name, prefix, key_hash, scopes, expires_at,
+                   last_used_at, created_at, revoked_at
+            FROM api_keys
+            WHERE prefix = $1
+            "#,
+            prefix
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|r| ApiKey {
+            id: Some(r.id),
+            user_id: r.user_id,
+            name: r.name,
+            prefix: r.prefix,
+            key_hash: TokenHash::from(r.key_hash),
+            scopes: r.scopes,
+            expires_at: r.expires_at,
+            last_used_at: r.last_used_at,
+            created_at: r.created_at,
+            revoked_at: r.revoked_at,
+        }))
+    }
+
+    async fn find_by_user(&self, user_id: i64) -> Result<Vec<ApiKey>> {
+        let rows = query!(
+            r#"
+            SELECT id, user_id, name, prefix, key_hash, scopes, expires_at,
+                   last_used_at, created_at, revoked_at
+            FROM api_keys
+            WHERE user_id = $1 AND revoked_at IS NULL
+            ORDER BY created_at DESC, id DESC
+            "#,
+            user_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| ApiKey {
+                id: Some(r.id),
+                user_id: r.user_id,
+                name: r.name,
+                prefix: r.prefix,
+                key_hash: TokenHash::from(r.key_hash),
+                scopes: r.scopes,
+                expires_at: r.expires_at,
+                last_used_at: r.last_used_at,
+                created_at: r.created_at,
+                revoked_at: r.revoked_at,
+            })
+            .collect())
+    }
+
+    async fn update(&self, key: &ApiKey) -> Result<()> {
+        query!(
+            "UPDATE api_keys SET name = $2, scopes = $3 WHERE id = $1",
+            key.id,
+            key.name,
+            &key.scopes,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn revoke(&self, id: i64) -> Result<()> {
+        query!(
+            "UPDATE api_keys SET revoked_at = NOW() WHERE id = $1 AND revoked_at IS NULL",
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn touch(&self, id: i64, used_at: DateTime<Utc>) -> Result<()> {
+        query!(
+            "UPDATE api_keys SET last_used_at = $2 WHERE id = $1",
+            id,
+            used_at,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
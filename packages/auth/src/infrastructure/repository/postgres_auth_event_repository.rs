@@ -0,0 +1,137 @@
+use crate::domain::{
+    entity::AuthEvent,
+    repository::{AuthEventFilter, AuthEventRepository},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_reborn_contracts::{AppError, Result};
+use sqlx::{query, query_as, PgPool};
+
+#[derive(Clone)]
+pub struct PostgresAuthEventRepository {
+    pool: PgPool,
+}
+
+impl PostgresAuthEventRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+struct AuthEventRow {
+    id: i64,
+    event_type: String,
+    outcome: String,
+    user_id: Option<i64>,
+    actor_id: Option<i64>,
+    ip_address: Option<String>,
+    user_agent: Option<String>,
+    reason: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<AuthEventRow> for AuthEvent {
+    type Error = AppError;
+
+    fn try_from(r: AuthEventRow) -> Result<Self> {
+        let stored =
+            |e: AppError| AppError::internal(format!("invalid auth event stored: {:?}", e));
+
+        Ok(Self {
+            id: Some(r.id),
+            event_type: r.event_type.parse().map_err(stored)?,
+            outcome: r.outcome.parse().map_err(stored)?,
+            user_id: r.user_id,
+            actor_id: r.actor_id,
+            ip_address: r.ip_address,
+            user_agent: r.user_agent,
+            reason: r.reason,
+            created_at: r.created_at,
+        })
+    }
+}
+
+#[async_trait]
+impl AuthEventRepository for PostgresAuthEventRepository {
+    async fn save(&self, event: &mut AuthEvent) -> Result<()> {
+        let row = query!(
+            r#"
+            INSERT INTO auth_events
+                (event_type, outcome, user_id, actor_id, ip_address, user_agent, reason, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING id
+            "#,
+            event.event_type.as_str(),
+            event.outcome.as_str(),
+            event.user_id,
+            event.actor_id,
+            event.ip_address,
+            event.user_agent,
+            event.reason,
+            event.created_at,
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        event.id = Some(row.id);
+        Ok(())
+    }
+
+    async fn find_all(
+        &self,
+        filter: &AuthEventFilter,
+        limit: u32,
+        offset: u32,
+    ) -> Result<Vec<AuthEvent>> {
+        let rows = query_as!(
+            AuthEventRow,
+            r#"
+            SELECT id, event_type, outcome, user_id, actor_id, ip_address, user_agent, reason,
+                   created_at
+            FROM auth_events
+            WHERE ($1::BIGINT IS NULL OR user_id = $1)
+              AND ($2::TEXT IS NULL OR event_type = $2)
+              AND ($3::TEXT IS NULL OR outcome = $3)
+              AND ($4::TIMESTAMPTZ IS NULL OR created_at >= $4)
+              AND ($5::TIMESTAMPTZ IS NULL OR created_at < $5)
+            ORDER BY created_at DESC, id DESC
+            LIMIT $6 OFFSET $7
+            "#,
+            filter.user_id,
+            filter.event_type.map(|t| t.as_str()),
+            filter.outcome.map(|o| o.as_str()),
+            filter.from,
+            filter.to,
+            i64::from(limit),
+            i64::from(offset),
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(AuthEvent::try_from).collect()
+    }
+
+    async fn count(&self, filter: &AuthEventFilter) -> Result<u64> {
+        let total = query!(
+            r#"
+            SELECT COUNT(*) AS "total!"
+            FROM auth_events
+            WHERE ($1::BIGINT IS NULL OR user_id = $1)
+              AND ($2::TEXT IS NULL OR event_type = $2)
+              AND ($3::TEXT IS NULL OR outcome = $3)
+              AND ($4::TIMESTAMPTZ IS NULL OR created_at >= $4)
+              AND ($5::TIMESTAMPTZ IS NULL OR created_at < $5)
+            "#,
+            filter.user_id,
+            filter.event_type.map(|t| t.as_str()),
+            filter.outcome.map(|o| o.as_str()),
+            filter.from,
+            filter.to,
+        )
+        .fetch_one(&self.pool)
+        .await?
+        .total;
+
+        Ok(total as u64)
+    }
+}
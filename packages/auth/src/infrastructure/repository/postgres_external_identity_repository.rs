@@ -0,0 +1,135 @@
+use crate::domain::{
+    entity::{ExternalIdentity, OidcLoginState},
+    repository::ExternalIdentityRepository,
+    value_objects::TokenHash,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_reborn_contracts::Result;
+use sqlx::{query, query_as, PgPool};
+
+#[derive(Clone)]
+pub struct PostgresExternalIdentityRepository {
+    pool: PgPool,
+}
+
+impl PostgresExternalIdentityRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ExternalIdentityRepository for PostgresExternalIdentityRepository {
+    async fn find(&self, provider: &str, subject: &str) -> Result<Option<ExternalIdentity>> {
+        let identity = query_as!(
+            ExternalIdentity,
+            r#"
+            SELECT id AS "id?", user_id, provider, subject, email, created_at, last_login_at
+            FROM external_identities
+            WHERE provider = $1 AND subject = $2
+            "#,
+            provider,
+            subject,
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(identity)
+    }
+
+    async fn find_by_user(&self, user_id: i64) -> Result<Vec<ExternalIdentity>> {
+        let identities = query_as!(
+            ExternalIdentity,
+            r#"
+            SELECT id AS "id?", user_id, provider, subject, email, created_at, last_login_at
+            FROM external_identities
+            WHERE user_id = $1
+            ORDER BY created_at
+            "#,
+            user_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(identities)
+    }
+
+    async fn save(&self, identity: &mut ExternalIdentity) -> Result<()> {
+        let row = query!(
+            r#"
+            INSERT INTO external_identities
+                (user_id, provider, subject, email, created_at, last_login_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id
+            "#,
+            identity.user_id,
+            identity.provider,
+            identity.subject,
+            identity.email,
+            identity.created_at,
+            identity.last_login_at,
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        identity.id = Some(row.id);
+        Ok(())
+    }
+
+    async fn record_login(&self, id: i64, at: DateTime<Utc>) -> Result<()> {
+        query!(
+            "UPDATE external_identities SET last_login_at = $2 WHERE id = $1",
+            id,
+            at,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn save_login_state(&self, state: &OidcLoginState) -> Result<()> {
+        // Abandoned logins are cleared whenever a new one starts.
+        query!("DELETE FROM oidc_login_states WHERE expires_at <= NOW()")
+            .execute(&self.pool)
+            .await?;
+
+        query!(
+            r#"
+            INSERT INTO oidc_login_states (state_hash, provider, code_verifier, nonce, expires_at)
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+            state.state_hash.value(),
+            state.provider,
+            state.code_verifier,
+            state.nonce,
+            state.expires_at,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn take_login_state(&self, state_hash: &str) -> Result<Option<OidcLoginState>> {
+        let row = query!(
+            r#"
+            DELETE FROM oidc_login_states
+            WHERE state_hash = $1
+            RETURNING state_hash, provider, code_verifier, nonce, expires_at
+            "#,
+            state_hash
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|r| OidcLoginState {
+            state_hash: TokenHash::from(r.state_hash),
+            provider: r.provider,
+            code_verifier: r.code_verifier,
+            nonce: r.nonce,
+            expires_at: r.expires_at,
+        }))
+    }
+}
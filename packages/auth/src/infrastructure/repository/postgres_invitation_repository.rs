@@ -0,0 +1,211 @@
+use crate::domain::{
+    entity::{Invitation, User},
+    repository::InvitationRepository,
+    value_objects::TokenHash,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_reborn_contracts::{AppError, Result};
+use sqlx::{query, query_as, PgPool};
+
+#[derive(Clone)]
+pub struct PostgresInvitationRepository {
+    pool: PgPool,
+}
+
+impl PostgresInvitationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+struct InvitationRow {
+    id: i64,
+    email: String,
+    role: String,
+    invited_by: Option<i64>,
+    token_hash: String,
+    expires_at: DateTime<Utc>,
+    accepted_at: Option<DateTime<Utc>>,
+    revoked_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<InvitationRow> for Invitation {
+    fn from(r: InvitationRow) -> Self {
+        Self {
+            id: Some(r.id),
+            email: r.email,
+            role: r.role,
+            invited_by: r.invited_by,
+            token_hash: TokenHash::from(r.token_hash),
+            expires_at: r.expires_at,
+            accepted_at: r.accepted_at,
+            revoked_at: r.revoked_at,
+            created_at: r.created_at,
+        }
+    }
+}
+
+#[async_trait]
+impl InvitationRepository for PostgresInvitationRepository {
+    async fn save(&self, invitation: &mut Invitation) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        let row = query!(
+            r#"
+            INSERT INTO user_invitations (email, role_id, invited_by, token_hash, expires_at, created_at)
+            SELECT $1, r.id, $3, $4, $5, $6
+            FROM roles r
+            WHERE r.name = $2
+            RETURNING id
+            "#,
+            invitation.email,
+            invitation.role,
+            invitation.invited_by,
+            invitation.token_hash.value(),
+            invitation.expires_at,
+            invitation.created_at,
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("role not found: {}", invitation.role)))?;
+
+        query!(
+            r#"
+            UPDATE user_invitations
+            SET revoked_at = NOW()
+            WHERE email = $1 AND id <> $2 AND accepted_at IS NULL AND revoked_at IS NULL
+            "#,
+            invitation.email,
+            row.id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        invitation.id = Some(row.id);
+        Ok(())
+    }
+
+    async fn find_by_token_hash(&self, token_hash: &str) -> Result<Option<Invitation>> {
+        let row = query_as!(
+            InvitationRow,
+            r#"
+            SELECT i.id, i.email, r.name AS role, i.invited_by, i.token_hash,
+                   i.expires_at, i.accepted_at, i.revoked_at, i.created_at
+            FROM user_invitations i
+            JOIN roles r ON r.id = i.role_id
+            WHERE i.token_hash = $1
+            "#,
+            token_hash
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Invitation::from))
+    }
+
+    async fn find_all(&self) -> Result<Vec<Invitation>> {
+        let rows = query_as!(
+            InvitationRow,
+            r#"
+            SELECT i.id, i.email, r.name AS role, i.invited_by, i.token_hash,
+                   i.expires_at, i.accepted_at, i.revoked_at, i.created_at
+            FROM user_invitations i
+            JOIN roles r ON r.id = i.role_id
+            ORDER BY i.created_at DESC, i.id DESC
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Invitation::from).collect())
+    }
+
+    async fn revoke(&self, id: i64) -> Result<bool> {
+        let result = query!(
+            r#"
+            UPDATE user_invitations
+            SET revoked_at = NOW()
+            WHERE id = $1 AND accepted_at IS NULL AND revoked_at IS NULL
+            "#,
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn accept(&self, id: i64, user: &mut User) -> Result<bool> {
+        assert!(user.id.is_none(), "User already persisted");
+
+        let mut tx = self.pool.begin().await?;
+
+        let Some(invitation) = query!(
+            r#"
+            UPDATE user_invitations
+            SET accepted_at = NOW()
+            WHERE id = $1 AND accepted_at IS NULL AND revoked_at IS NULL AND expires_at > NOW()
+            RETURNING role_id
+            "#,
+            id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        else {
+            return Ok(false);
+        };
+
+        let user_id = query!(
+            r#"
+            INSERT INTO users (
+                code,
+                email,
+                username,
+                full_name,
+                password,
+                password_changed_at,
+                is_verified,
+                created_by,
+                created_at,
+                updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING id
+            "#,
+            &user.code,
+            user.email.value(),
+            &user.username,
+            user.full_name,
+            user.password.value(),
+            user.password_changed_at,
+            user.is_verified,
+            user.created_by,
+            user.created_at,
+            user.updated_at,
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(db) if db.is_unique_violation() => {
+                AppError::conflict("email or username already taken")
+            }
+            other => other.into(),
+        })?
+        .id;
+
+        query!(
+            "INSERT INTO user_roles (user_id, role_id) VALUES ($1, $2)",
+            user_id,
+            invitation.role_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        user.id = Some(user_id);
+        Ok(true)
+    }
+}
@@ -0,0 +1,116 @@
+use crate::domain::{entity::LoginThrottle, repository::LoginThrottleRepository};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_reborn_contracts::Result;
+use sqlx::{query, query_as, PgPool};
+
+#[derive(Clone)]
+pub struct PostgresLoginThrottleRepository {
+    pool: PgPool,
+}
+
+impl PostgresLoginThrottleRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+struct LoginThrottleRow {
+    key: String,
+    failed_attempts: i32,
+    lockout_count: i32,
+    window_started_at: DateTime<Utc>,
+    locked_until: Option<DateTime<Utc>>,
+}
+
+impl From<LoginThrottleRow> for LoginThrottle {
+    fn from(row: LoginThrottleRow) -> Self {
+        Self {
+            key: row.key,
+            failed_attempts: row.failed_attempts,
+            lockout_count: row.lockout_count,
+            window_started_at: row.window_started_at,
+            locked_until: row.locked_until,
+        }
+    }
+}
+
+#[async_trait]
+impl LoginThrottleRepository for PostgresLoginThrottleRepository {
+    async fn find(&self, key: &str) -> Result<Option<LoginThrottle>> {
+        let row = query_as!(
+            LoginThrottleRow,
+            r#"
+            SELECT key, failed_attempts, lockout_count, window_started_at, locked_until
+            FROM login_throttles
+            WHERE key = $1
+            "#,
+            key
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn record_failure(
+        &self,
+        key: &str,
+        now: DateTime<Utc>,
+        window_start: DateTime<Utc>,
+    ) -> Result<LoginThrottle> {
+        let row = query_as!(
+            LoginThrottleRow,
+            r#"
+            INSERT INTO login_throttles (key, failed_attempts, window_started_at, updated_at)
+            VALUES ($1, 1, $2, $2)
+            ON CONFLICT (key) DO UPDATE
+            SET failed_attempts = CASE
+                    WHEN login_throttles.window_started_at < $3 THEN 1
+                    ELSE login_throttles.failed_attempts + 1
+                END,
+                window_started_at = CASE
+                    WHEN login_throttles.window_started_at < $3 THEN $2
+                    ELSE login_throttles.window_started_at
+                END,
+                updated_at = $2
+            RETURNING key, failed_attempts, lockout_count, window_started_at, locked_until
+            "#,
+            key,
+            now,
+            window_start
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn lock(&self, key: &str, until: DateTime<Utc>) -> Result<()> {
+        query!(
+            r#"
+            UPDATE login_throttles
+            SET locked_until = $2,
+                lockout_count = lockout_count + 1,
+                failed_attempts = 0,
+                window_started_at = NOW(),
+                updated_at = NOW()
+            WHERE key = $1
+            "#,
+            key,
+            until
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn reset(&self, key: &str) -> Result<()> {
+        query!("DELETE FROM login_throttles WHERE key = $1", key)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
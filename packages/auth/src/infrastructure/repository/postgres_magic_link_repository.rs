@@ -0,0 +1,94 @@
+use crate::domain::{
+    entity::MagicLinkToken, repository::MagicLinkRepository, value_objects::TokenHash,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_reborn_contracts::Result;
+use sqlx::{query, query_as, PgPool};
+
+#[derive(Clone)]
+pub struct PostgresMagicLinkRepository {
+    pool: PgPool,
+}
+
+impl PostgresMagicLinkRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+struct MagicLinkTokenRow {
+    id: i64,
+    user_id: i64,
+    token_hash: String,
+    expires_at: DateTime<Utc>,
+    used_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<MagicLinkTokenRow> for MagicLinkToken {
+    fn from(r: MagicLinkTokenRow) -> Self {
+        Self {
+            id: Some(r.id),
+            user_id: r.user_id,
+            token_hash: TokenHash::from(r.token_hash),
+            expires_at: r.expires_at,
+            used_at: r.used_at,
+            created_at: r.created_at,
+        }
+    }
+}
+
+#[async_trait]
+impl MagicLinkRepository for PostgresMagicLinkRepository {
+    async fn save(&self, token: &mut MagicLinkToken) -> Result<()> {
+        let row = query!(
+            r#"
+            INSERT INTO magic_link_tokens (user_id, token_hash, expires_at, created_at)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id
+            "#,
+            token.user_id,
+            token.token_hash.value(),
+            token.expires_at,
+            token.created_at,
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        token.id = Some(row.id);
+        Ok(())
+    }
+
+    async fn consume(&self, token_hash: &str) -> Result<Option<MagicLinkToken>> {
+        let row = query_as!(
+            MagicLinkTokenRow,
+            r#"
+            UPDATE magic_link_tokens
+            SET used_at = NOW()
+            WHERE token_hash = $1 AND used_at IS NULL AND expires_at > NOW()
+            RETURNING id, user_id, token_hash, expires_at, used_at, created_at
+            "#,
+            token_hash
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(MagicLinkToken::from))
+    }
+
+    async fn invalidate_for_user(&self, user_id: i64) -> Result<()> {
+        query!(
+            r#"
+            UPDATE magic_link_tokens
+            SET used_at = NOW()
+            WHERE user_id = $1 AND used_at IS NULL
+            "#,
+            user_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,208 @@
+use crate::domain::{
+    entity::{MfaChallenge, TotpFactor},
+    repository::MfaRepository,
+    value_objects::TokenHash,
+};
+use async_trait::async_trait;
+use rust_reborn_contracts::Result;
+use sqlx::{query, PgPool};
+
+#[derive(Clone)]
+pub struct PostgresMfaRepository {
+    pool: PgPool,
+}
+
+impl PostgresMfaRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl MfaRepository for PostgresMfaRepository {
+    async fn find_totp(&self, user_id: i64) -> Result<Option<TotpFactor>> {
+        let row = query!(
+            r#"
+            SELECT user_id, secret, confirmed_at, last_used_step, created_at
+            FROM user_totp
+            WHERE user_id = $1
+            "#,
+            user_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|r| TotpFactor {
+            user_id: r.user_id,
+            secret: r.secret,
+            confirmed_at: r.confirmed_at,
+            last_used_step: r.last_used_step,
+            created_at: r.created_at,
+        }))
+    }
+
+    async fn save_totp(&self, factor: &TotpFactor) -> Result<()> {
+        query!(
+            r#"
+            INSERT INTO user_totp (user_id, secret, confirmed_at, last_used_step, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (user_id) DO UPDATE
+            SET secret = EXCLUDED.secret,
+                confirmed_at = EXCLUDED.confirmed_at,
+                last_used_step = EXCLUDED.last_used_step,
+                created_at = EXCLUDED.created_at
+            "#,
+            factor.user_id,
+            factor.secret,
+            factor.confirmed_at,
+            factor.last_used_step,
+            factor.created_at,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn confirm_totp(&self, user_id: i64, step: i64) -> Result<()> {
+        query!(
+            r#"
+            UPDATE user_totp
+            SET confirmed_at = NOW(), last_used_step = $2
+            WHERE user_id = $1
+            "#,
+            user_id,
+            step,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn use_totp_step(&self, user_id: i64, step: i64) -> Result<bool> {
+        let result = query!(
+            r#"
+            UPDATE user_totp
+            SET last_used_step = $2
+            WHERE user_id = $1
+              AND (last_used_step IS NULL OR last_used_step < $2)
+            "#,
+            user_id,
+            step,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn replace_recovery_codes(&self, user_id: i64, code_hashes: &[String]) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        query!("DELETE FROM mfa_recovery_codes WHERE user_id = $1", user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        query!(
+            r#"
+            INSERT INTO mfa_recovery_codes (user_id, code_hash)
+            SELECT $1, UNNEST($2::VARCHAR[])
+            "#,
+            user_id,
+            code_hashes,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn use_recovery_code(&self, user_id: i64, code_hash: &str) -> Result<bool> {
+        let result = query!(
+            r#"
+            UPDATE mfa_recovery_codes
+            SET used_at = NOW()
+            WHERE user_id = $1 AND code_hash = $2 AND used_at IS NULL
+            "#,
+            user_id,
+            code_hash,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn save_challenge(&self, challenge: &mut MfaChallenge) -> Result<()> {
+        let id = query!(
+            r#"
+            INSERT INTO mfa_challenges (user_id, token_hash, expires_at)
+            VALUES ($1, $2, $3)
+            RETURNING id
+            "#,
+            challenge.user_id,
+            challenge.token_hash.value(),
+            challenge.expires_at,
+        )
+        .fetch_one(&self.pool)
+        .await?
+        .id;
+
+        challenge.id = Some(id);
+        Ok(())
+    }
+
+    async fn find_challenge(&self, token_hash: &str) -> Result<Option<MfaChallenge>> {
+        let row = query!(
+            r#"
+            SELECT id, user_id, token_hash, expires_at, failed_attempts, consumed_at
+            FROM mfa_challenges
+            WHERE token_hash = $1
+            "#,
+            token_hash
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|r| MfaChallenge {
+            id: Some(r.id),
+            user_id: r.user_id,
+            token_hash: TokenHash::from(r.token_hash),
+            expires_at: r.expires_at,
+            failed_attempts: r.failed_attempts,
+            consumed_at: r.consumed_at,
+        }))
+    }
+
+    async fn record_failed_attempt(&self, challenge_id: i64) -> Result<()> {
+        query!(
+            r#"
+            UPDATE mfa_challenges
+            SET failed_attempts = failed_attempts + 1
+            WHERE id = $1
+            "#,
+            challenge_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn consume_challenge(&self, challenge_id: i64) -> Result<bool> {
+        let result = query!(
+            r#"
+            UPDATE mfa_challenges
+            SET consumed_at = NOW()
+            WHERE id = $1 AND consumed_at IS NULL
+            "#,
+            challenge_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() == 1)
+    }
+}
@@ -0,0 +1,152 @@
+use crate::domain::{
+    entity::OAuthClient, repository::OAuthClientRepository, value_objects::TokenHash,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_reborn_contracts::{AppError, Result};
+use sqlx::{query, query_as, PgPool};
+
+#[derive(Clone)]
+pub struct PostgresOAuthClientRepository {
+    pool: PgPool,
+}
+
+impl PostgresOAuthClientRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+struct OAuthClientRow {
+    id: i64,
+    client_id: String,
+    name: String,
+    secret_hash: String,
+    scopes: Vec<String>,
+    is_active: bool,
+    created_by: Option<i64>,
+    created_at: DateTime<Utc>,
+    last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<OAuthClientRow> for OAuthClient {
+    fn from(r: OAuthClientRow) -> Self {
+        Self {
+            id: Some(r.id),
+            client_id: r.client_id,
+            name: r.name,
+            secret_hash: TokenHash::from(r.secret_hash),
+            scopes: r.scopes,
+            is_active: r.is_active,
+            created_by: r.created_by,
+            created_at: r.created_at,
+            last_used_at: r.last_used_at,
+        }
+    }
+}
+
+#[async_trait]
+impl OAuthClientRepository for PostgresOAuthClientRepository {
+    async fn save(&self, client: &mut OAuthClient) -> Result<()> {
+        let row = query!(
+            r#"
+            INSERT INTO oauth_clients
+                (client_id, name, secret_hash, scopes, is_active, created_by, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING id
+            "#,
+            client.client_id,
+            client.name,
+            client.secret_hash.value(),
+            &client.scopes,
+            client.is_active,
+            client.created_by,
+            client.created_at,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(db) if db.is_unique_violation() => {
+                AppError::conflict("client_id already registered")
+            }
+            other => other.into(),
+        })?;
+
+        client.id = Some(row.id);
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: i64) -> Result<Option<OAuthClient>> {
+        let row = query_as!(
+            OAuthClientRow,
+            r#"
+            SELECT id, client_id, name, secret_hash, scopes, is_active, created_by,
+                   created_at, last_used_at
+            FROM oauth_clients
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(OAuthClient::from))
+    }
+
+    async fn find_by_client_id(&self, client_id: &str) -> Result<Option<OAuthClient>> {
+        let row = query_as!(
+            OAuthClientRow,
+            r#"
+            SELECT id, client_id, name, secret_hash, scopes, is_active, created_by,
+                   created_at, last_used_at
+            FROM oauth_clients
+            WHERE client_id = $1
+            "#,
+            client_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(OAuthClient::from))
+    }
+
+    async fn find_all(&self) -> Result<Vec<OAuthClient>> {
+        let rows = query_as!(
+            OAuthClientRow,
+            r#"
+            SELECT id, client_id, name, secret_hash, scopes, is_active, created_by,
+                   created_at, last_used_at
+            FROM oauth_clients
+            ORDER BY created_at DESC, id DESC
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(OAuthClient::from).collect())
+    }
+
+    async fn set_active(&self, id: i64, is_active: bool) -> Result<()> {
+        query!(
+            "UPDATE oauth_clients SET is_active = $2 WHERE id = $1",
+            id,
+            is_active,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn touch(&self, id: i64, used_at: DateTime<Utc>) -> Result<()> {
+        query!(
+            "UPDATE oauth_clients SET last_used_at = $2 WHERE id = $1",
+            id,
+            used_at,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
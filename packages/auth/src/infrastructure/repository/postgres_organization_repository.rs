@@ -0,0 +1,282 @@
+use crate::domain::{
+    entity::{Membership, Organization, Role},
+    repository::OrganizationRepository,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_reborn_contracts::{AppError, Result};
+use sqlx::{query, query_as, PgPool};
+
+#[derive(Clone)]
+pub struct PostgresOrganizationRepository {
+    pool: PgPool,
+}
+
+impl PostgresOrganizationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Members matching both filters; `None` matches everything.
+    async fn memberships(
+        &self,
+        organization_id: Option<i64>,
+        user_id: Option<i64>,
+    ) -> Result<Vec<Membership>> {
+        let rows = query_as!(
+            MemberRoleRow,
+            r#"
+            SELECT
+                m.organization_id,
+                m.user_id,
+                m.created_at,
+                r.id AS "role_id?",
+                r.name AS "role_name?",
+                COALESCE(
+                    ARRAY_AGG(p.name ORDER BY p.name) FILTER (WHERE p.name IS NOT NULL),
+                    '{}'
+                ) AS "permissions!"
+            FROM organization_members m
+            LEFT JOIN organization_member_roles mr
+                ON mr.organization_id = m.organization_id AND mr.user_id = m.user_id
+            LEFT JOIN roles r ON r.id = mr.role_id
+            LEFT JOIN role_permissions rp ON rp.role_id = r.id
+            LEFT JOIN permissions p ON p.id = rp.permission_id
+            WHERE ($1::BIGINT IS NULL OR m.organization_id = $1)
+              AND ($2::BIGINT IS NULL OR m.user_id = $2)
+            GROUP BY m.organization_id, m.user_id, m.created_at, r.id, r.name
+            ORDER BY m.organization_id, m.user_id, r.name
+            "#,
+            organization_id,
+            user_id,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut memberships: Vec<Membership> = Vec::new();
+        for row in rows {
+            let role = match (row.role_id, row.role_name) {
+                (Some(id), Some(name)) => Some(Role {
+                    id: Some(id),
+                    name,
+                    permissions: row.permissions,
+                }),
+                _ => None,
+            };
+
+            match memberships.last_mut() {
+                Some(last)
+                    if last.organization_id == row.organization_id
+                        && last.user_id == row.user_id =>
+                {
+                    last.roles.extend(role);
+                }
+                _ => memberships.push(Membership {
+                    organization_id: row.organization_id,
+                    user_id: row.user_id,
+                    roles: role.into_iter().collect(),
+                    created_at: row.created_at,
+                }),
+            }
+        }
+
+        Ok(memberships)
+    }
+}
+
+struct OrganizationRow {
+    id: i64,
+    slug: String,
+    name: String,
+    is_active: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<OrganizationRow> for Organization {
+    fn from(r: OrganizationRow) -> Self {
+        Self {
+            id: Some(r.id),
+            slug: r.slug,
+            name: r.name,
+            is_active: r.is_active,
+            created_at: r.created_at,
+            updated_at: r.updated_at,
+        }
+    }
+}
+
+struct MemberRoleRow {
+    organization_id: i64,
+    user_id: i64,
+    created_at: DateTime<Utc>,
+    role_id: Option<i64>,
+    role_name: Option<String>,
+    permissions: Vec<String>,
+}
+
+#[async_trait]
+impl OrganizationRepository for PostgresOrganizationRepository {
+    async fn save(&self, organization: &mut Organization) -> Result<()> {
+        let row = query!(
+            r#"
+            INSERT INTO organizations (slug, name, is_active, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id
+            "#,
+            organization.slug,
+            organization.name,
+            organization.is_active,
+            organization.created_at,
+            organization.updated_at,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(db) if db.is_unique_violation() => {
+                AppError::conflict("organization slug already taken")
+            }
+            other => other.into(),
+        })?;
+
+        organization.id = Some(row.id);
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: i64) -> Result<Option<Organization>> {
+        let row = query_as!(
+            OrganizationRow,
+            r#"
+            SELECT id, slug, name, is_active, created_at, updated_at
+            FROM organizations
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Option<Organization>> {
+        let row = query_as!(
+            OrganizationRow,
+            r#"
+            SELECT id, slug, name, is_active, created_at, updated_at
+            FROM organizations
+            WHERE slug = $1
+            "#,
+            slug
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_all(&self) -> Result<Vec<Organization>> {
+        let rows = query_as!(
+            OrganizationRow,
+            r#"
+            SELECT id, slug, name, is_active, created_at, updated_at
+            FROM organizations
+            ORDER BY name
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn set_member_roles(
+        &self,
+        organization_id: i64,
+        user_id: i64,
+        role_names: &[String],
+    ) -> Result<()> {
+        let role_ids = query!(
+            "SELECT id, name FROM roles WHERE name = ANY($1::VARCHAR[])",
+            role_names
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        if let Some(missing) = role_names
+            .iter()
+            .find(|name| !role_ids.iter().any(|r| &r.name == *name))
+        {
+            return Err(AppError::not_found(format!("role not found: {}", missing)));
+        }
+        let role_ids: Vec<i64> = role_ids.into_iter().map(|r| r.id).collect();
+
+        let mut tx = self.pool.begin().await?;
+
+        query!(
+            r#"
+            INSERT INTO organization_members (organization_id, user_id)
+            VALUES ($1, $2)
+            ON CONFLICT (organization_id, user_id) DO NOTHING
+            "#,
+            organization_id,
+            user_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        query!(
+            "DELETE FROM organization_member_roles WHERE organization_id = $1 AND user_id = $2",
+            organization_id,
+            user_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        query!(
+            r#"
+            INSERT INTO organization_member_roles (organization_id, user_id, role_id)
+            SELECT $1, $2, UNNEST($3::BIGINT[])
+            "#,
+            organization_id,
+            user_id,
+            &role_ids,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn remove_member(&self, organization_id: i64, user_id: i64) -> Result<bool> {
+        let result = query!(
+            "DELETE FROM organization_members WHERE organization_id = $1 AND user_id = $2",
+            organization_id,
+            user_id,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn find_membership(
+        &self,
+        organization_id: i64,
+        user_id: i64,
+    ) -> Result<Option<Membership>> {
+        Ok(self
+            .memberships(Some(organization_id), Some(user_id))
+            .await?
+            .pop())
+    }
+
+    async fn find_members(&self, organization_id: i64) -> Result<Vec<Membership>> {
+        self.memberships(Some(organization_id), None).await
+    }
+
+    async fn find_memberships_of_user(&self, user_id: i64) -> Result<Vec<Membership>> {
+        self.memberships(None, Some(user_id)).await
+    }
+}
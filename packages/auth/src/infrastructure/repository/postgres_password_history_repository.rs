@@ -0,0 +1,71 @@
+use crate::domain::{repository::PasswordHistoryRepository, value_objects::HashedPassword};
+use async_trait::async_trait;
+use rust_reborn_contracts::Result;
+use sqlx::{query, PgPool};
+
+#[derive(Clone)]
+pub struct PostgresPasswordHistoryRepository {
+    pool: PgPool,
+}
+
+impl PostgresPasswordHistoryRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PasswordHistoryRepository for PostgresPasswordHistoryRepository {
+    async fn add(&self, user_id: i64, password: &HashedPassword, keep: usize) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        query!(
+            "INSERT INTO password_history (user_id, password_hash) VALUES ($1, $2)",
+            user_id,
+            password.value(),
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        query!(
+            r#"
+            DELETE FROM password_history
+            WHERE user_id = $1
+              AND id NOT IN (
+                  SELECT id FROM password_history
+                  WHERE user_id = $1
+                  ORDER BY created_at DESC, id DESC
+                  LIMIT $2
+              )
+            "#,
+            user_id,
+            keep as i64,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_recent(&self, user_id: i64, limit: usize) -> Result<Vec<HashedPassword>> {
+        let rows = query!(
+            r#"
+            SELECT password_hash
+            FROM password_history
+            WHERE user_id = $1
+            ORDER BY created_at DESC, id DESC
+            LIMIT $2
+            "#,
+            user_id,
+            limit as i64,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| HashedPassword::new(r.password_hash))
+            .collect())
+    }
+}
@@ -0,0 +1,154 @@
+use crate::domain::{
+    entity::RefreshToken, repository::RefreshTokenRepository, value_objects::TokenHash,
+};
+use async_trait::async_trait;
+use rust_reborn_contracts::Result;
+use sqlx::{query, PgPool};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresRefreshTokenRepository {
+    pool: PgPool,
+}
+
+impl PostgresRefreshTokenRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl RefreshTokenRepository for PostgresRefreshTokenRepository {
+    async fn save(&self, token: &mut RefreshToken) -> Result<()> {
+        let id = query!(
+            r#"
+            INSERT INTO refresh_tokens (user_id, family_id, token_hash, expires_at, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id
+            "#,
+            token.user_id,
+            token.family_id,
+            token.token_hash.value(),
+            token.expires_at,
+            token.created_at,
+        )
+        .fetch_one(&self.pool)
+        .await?
+        .id;
+
+        token.id = Some(id);
+        Ok(())
+    }
+
+    async fn find_by_hash(&self, token_hash: &str) -> Result<Option<RefreshToken>> {
+        let row = query!(
+            r#"
+            SELECT
+                id,
+                user_id,
+                family_id,
+                token_hash,
+                expires_at,
+                revoked_at,
+                replaced_by,
+                created_at
+            FROM refresh_tokens
+            WHERE token_hash = $1
+            "#,
+            token_hash
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|r| RefreshToken {
+            id: Some(r.id),
+            user_id: r.user_id,
+            family_id: r.family_id,
+            token_hash: TokenHash::from(r.token_hash),
+            expires_at: r.expires_at,
+            revoked_at: r.revoked_at,
+            replaced_by: r.replaced_by,
+            created_at: r.created_at,
+        }))
+    }
+
+    async fn rotate(&self, current: &RefreshToken, next: &mut RefreshToken) -> Result<bool> {
+        let mut tx = self.pool.begin().await?;
+
+        let claimed = query!(
+            r#"
+            UPDATE refresh_tokens
+            SET revoked_at = NOW()
+            WHERE id = $1 AND revoked_at IS NULL
+            "#,
+            current.id,
+        )
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        if claimed == 0 {
+            tx.rollback().await?;
+            return Ok(false);
+        }
+
+        let id = query!(
+            r#"
+            INSERT INTO refresh_tokens (user_id, family_id, token_hash, expires_at, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id
+            "#,
+            next.user_id,
+            next.family_id,
+            next.token_hash.value(),
+            next.expires_at,
+            next.created_at,
+        )
+        .fetch_one(&mut *tx)
+        .await?
+        .id;
+
+        query!(
+            "UPDATE refresh_tokens SET replaced_by = $1 WHERE id = $2",
+            id,
+            current.id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        next.id = Some(id);
+        Ok(true)
+    }
+
+    async fn revoke_family(&self, family_id: Uuid) -> Result<()> {
+        query!(
+            r#"
+            UPDATE refresh_tokens
+            SET revoked_at = NOW()
+            WHERE family_id = $1 AND revoked_at IS NULL
+            "#,
+            family_id,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn revoke_all_for_user(&self, user_id: i64) -> Result<()> {
+        query!(
+            r#"
+            UPDATE refresh_tokens
+            SET revoked_at = NOW()
+            WHERE user_id = $1 AND revoked_at IS NULL
+            "#,
+            user_id,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
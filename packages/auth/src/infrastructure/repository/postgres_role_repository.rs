@@ -0,0 +1,112 @@
+use crate::domain::{entity::Role, repository::RoleRepository};
+use async_trait::async_trait;
+use rust_reborn_contracts::{AppError, Result};
+use sqlx::{query, PgPool};
+
+#[derive(Clone)]
+pub struct PostgresRoleRepository {
+    pool: PgPool,
+}
+
+impl PostgresRoleRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl RoleRepository for PostgresRoleRepository {
+    async fn find_by_user(&self, user_id: i64) -> Result<Vec<Role>> {
+        let rows = query!(
+            r#"
+            SELECT
+                r.id,
+                r.name,
+                COALESCE(
+                    ARRAY_AGG(p.name ORDER BY p.name) FILTER (WHERE p.name IS NOT NULL),
+                    '{}'
+                ) AS "permissions!"
+            FROM user_roles ur
+            JOIN roles r ON r.id = ur.role_id
+            LEFT JOIN role_permissions rp ON rp.role_id = r.id
+            LEFT JOIN permissions p ON p.id = rp.permission_id
+            WHERE ur.user_id = $1
+            GROUP BY r.id, r.name
+            ORDER BY r.name
+            "#,
+            user_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| Role {
+                id: Some(r.id),
+                name: r.name,
+                permissions: r.permissions,
+            })
+            .collect())
+    }
+
+    async fn exists(&self, role_name: &str) -> Result<bool> {
+        let row = query!(
+            r#"SELECT EXISTS (SELECT 1 FROM roles WHERE name = $1) AS "exists!""#,
+            role_name
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.exists)
+    }
+
+    async fn assign_to_user(&self, user_id: i64, role_name: &str) -> Result<()> {
+        let result = query!(
+            r#"
+            INSERT INTO user_roles (user_id, role_id)
+            SELECT $1, id FROM roles WHERE name = $2
+            ON CONFLICT (user_id, role_id) DO NOTHING
+            "#,
+            user_id,
+            role_name,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            // Either the role doesn't exist or it was already assigned.
+            let exists = query!(
+                r#"SELECT EXISTS (SELECT 1 FROM roles WHERE name = $1) AS "exists!""#,
+                role_name
+            )
+            .fetch_one(&self.pool)
+            .await?
+            .exists;
+
+            if !exists {
+                return Err(AppError::not_found(format!(
+                    "role not found: {}",
+                    role_name
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn remove_from_user(&self, user_id: i64, role_name: &str) -> Result<()> {
+        query!(
+            r#"
+            DELETE FROM user_roles
+            WHERE user_id = $1
+              AND role_id = (SELECT id FROM roles WHERE name = $2)
+            "#,
+            user_id,
+            role_name,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
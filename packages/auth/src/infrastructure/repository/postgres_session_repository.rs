@@ -0,0 +1,125 @@
+use crate::domain::{entity::Session, repository::SessionRepository};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_reborn_contracts::Result;
+use sqlx::{query, query_as, PgPool};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresSessionRepository {
+    pool: PgPool,
+}
+
+impl PostgresSessionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SessionRepository for PostgresSessionRepository {
+    async fn save(&self, session: &Session) -> Result<()> {
+        query!(
+            r#"
+            INSERT INTO sessions (id, user_id, user_agent, ip_address, created_at, last_seen_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+            session.id,
+            session.user_id,
+            session.user_agent,
+            session.ip_address,
+            session.created_at,
+            session.last_seen_at,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Session>> {
+        let session = query_as!(
+            Session,
+            r#"
+            SELECT id, user_id, user_agent, ip_address, created_at, last_seen_at, revoked_at,
+                organization_id
+            FROM sessions
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(session)
+    }
+
+    async fn find_active_by_user(
+        &self,
+        user_id: i64,
+        seen_since: DateTime<Utc>,
+    ) -> Result<Vec<Session>> {
+        let sessions = query_as!(
+            Session,
+            r#"
+            SELECT id, user_id, user_agent, ip_address, created_at, last_seen_at, revoked_at,
+                organization_id
+            FROM sessions
+            WHERE user_id = $1 AND revoked_at IS NULL AND last_seen_at > $2
+            ORDER BY last_seen_at DESC
+            "#,
+            user_id,
+            seen_since
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(sessions)
+    }
+
+    async fn touch(&self, id: Uuid, seen_at: DateTime<Utc>) -> Result<()> {
+        query!(
+            "UPDATE sessions SET last_seen_at = $2 WHERE id = $1 AND last_seen_at < $2",
+            id,
+            seen_at
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn set_organization(&self, id: Uuid, organization_id: Option<i64>) -> Result<()> {
+        query!(
+            "UPDATE sessions SET organization_id = $2 WHERE id = $1",
+            id,
+            organization_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn revoke(&self, id: Uuid) -> Result<()> {
+        query!(
+            "UPDATE sessions SET revoked_at = NOW() WHERE id = $1 AND revoked_at IS NULL",
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn revoke_all_for_user(&self, user_id: i64) -> Result<()> {
+        query!(
+            "UPDATE sessions SET revoked_at = NOW() WHERE user_id = $1 AND revoked_at IS NULL",
+            user_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use crate::domain::repository::TokenRevocationRepository;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_reborn_contracts::Result;
+use sqlx::{query, PgPool};
+
+#[derive(Clone)]
+pub struct PostgresTokenRevocationRepository {
+    pool: PgPool,
+}
+
+impl PostgresTokenRevocationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TokenRevocationRepository for PostgresTokenRevocationRepository {
+    async fn revoke(&self, jti: &str, user_id: i64, expires_at: DateTime<Utc>) -> Result<()> {
+        query!(
+            r#"
+            INSERT INTO revoked_tokens (jti, user_id, expires_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (jti) DO NOTHING
+            "#,
+            jti,
+            user_id,
+            expires_at,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn revoke_all_for_user(&self, user_id: i64, before: DateTime<Utc>) -> Result<()> {
+        query!(
+            r#"
+            INSERT INTO user_token_revocations (user_id, revoked_before)
+            VALUES ($1, $2)
+            ON CONFLICT (user_id) DO UPDATE
+            SET revoked_before = GREATEST(user_token_revocations.revoked_before, EXCLUDED.revoked_before)
+            "#,
+            user_id,
+            before,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn is_revoked(
+        &self,
+        jti: &str,
+        user_id: i64,
+        issued_at: Option<DateTime<Utc>>,
+    ) -> Result<bool> {
+        let row = query!(
+            r#"
+            SELECT
+                EXISTS (SELECT 1 FROM revoked_tokens WHERE jti = $1)
+                OR EXISTS (
+                    SELECT 1 FROM user_token_revocations
+                    WHERE user_id = $2 AND revoked_before >= $3
+                ) AS "revoked!"
+            "#,
+            jti,
+            user_id,
+            issued_at,
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.revoked)
+    }
+}
@@ -1,15 +1,15 @@
 use crate::domain::{
     entity::User,
-    repository::UserRepository,
+    repository::{UserFilter, UserRepository},
     value_objects::{Email, HashedPassword},
 };
 use async_trait::async_trait;
-use rust_reborn_contracts::Result;
-use sqlx::{query, PgPool};
+use chrono::{DateTime, Utc};
+use rust_reborn_contracts::{AppError, Result};
+use sqlx::{query, query_as, PgPool};
 
 #[derive(Clone)]
 pub struct PostgresUserRepository {
-    #[allow(dead_code)]
     pool: PgPool,
 }
 
@@ -19,10 +19,100 @@ impl PostgresUserRepository {
     }
 }
 
+struct UserRow {
+    id: i64,
+    code: String,
+    email: String,
+    username: String,
+    password: String,
+    password_changed_at: DateTime<Utc>,
+    full_name: Option<String>,
+    is_verified: bool,
+    is_active: bool,
+    activation_key: Option<String>,
+    activation_key_expires_at: Option<DateTime<Utc>>,
+    reset_key: Option<String>,
+    reset_key_expires_at: Option<DateTime<Utc>>,
+    reset_date: Option<DateTime<Utc>>,
+    pending_email: Option<String>,
+    email_change_key: Option<String>,
+    email_change_key_expires_at: Option<DateTime<Utc>>,
+    status: i32,
+    created_by: Option<String>,
+    updated_by: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    deleted_at: Option<DateTime<Utc>>,
+    last_login_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<UserRow> for User {
+    type Error = AppError;
+
+    fn try_from(r: UserRow) -> Result<Self> {
+        let email = Email::new(r.email)
+            .map_err(|e| AppError::internal(format!("invalid email stored for user: {}", e)))?;
+        let pending_email = r.pending_email.map(Email::new).transpose().map_err(|e| {
+            AppError::internal(format!("invalid pending email stored for user: {}", e))
+        })?;
+
+        Ok(User {
+            id: Some(r.id),
+            code: r.code,
+            email,
+            username: r.username,
+            password: HashedPassword::new(r.password),
+            password_changed_at: r.password_changed_at,
+            full_name: r.full_name,
+            is_active: r.is_active,
+            is_verified: r.is_verified,
+            activation_key: r.activation_key,
+            activation_key_expires_at: r.activation_key_expires_at,
+            reset_key: r.reset_key,
+            reset_key_expires_at: r.reset_key_expires_at,
+            reset_date: r.reset_date,
+            pending_email,
+            email_change_key: r.email_change_key,
+            email_change_key_expires_at: r.email_change_key_expires_at,
+            status: r.status,
+            created_by: r.created_by,
+            updated_by: r.updated_by,
+            created_at: r.created_at,
+            updated_at: r.updated_at,
+            deleted_at: r.deleted_at,
+            last_login_at: r.last_login_at,
+        })
+    }
+}
+
+/// `ILIKE` pattern matching the search term anywhere, with wildcards in the
+/// term itself taken literally.
+fn search_pattern(filter: &UserFilter) -> Option<String> {
+    filter.search.as_deref().map(|term| {
+        let escaped = term
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_");
+        format!("%{}%", escaped)
+    })
+}
+
+/// Lookups skip soft-deleted accounts but their email and username stay
+/// reserved, so a write can still collide with one.
+fn map_unique_violation(err: sqlx::Error) -> AppError {
+    match err {
+        sqlx::Error::Database(db) if db.is_unique_violation() => {
+            AppError::conflict("email or username already taken")
+        }
+        other => other.into(),
+    }
+}
+
 #[async_trait]
 impl UserRepository for PostgresUserRepository {
     async fn find_by_email(&self, email: &str) -> Result<Option<User>> {
-        let row = query!(
+        let row = query_as!(
+            UserRow,
             r#"
             SELECT
                 id,
@@ -30,13 +120,18 @@ impl UserRepository for PostgresUserRepository {
                 email,
                 username,
                 password,
+                password_changed_at,
                 full_name,
                 is_verified,
                 is_active,
                 activation_key,
+                activation_key_expires_at,
                 reset_key,
                 reset_key_expires_at,
                 reset_date,
+                pending_email,
+                email_change_key,
+                email_change_key_expires_at,
                 status,
                 created_by,
                 updated_by,
@@ -45,38 +140,19 @@ impl UserRepository for PostgresUserRepository {
                 deleted_at,
                 last_login_at
             FROM users
-            WHERE email = $1
+            WHERE email = $1 AND deleted_at IS NULL
             "#,
             email
         )
         .fetch_optional(&self.pool)
         .await?;
 
-        Ok(row.map(|r| User {
-            id: Some(r.id),
-            code: r.code,
-            email: Email::new(r.email).unwrap(),
-            username: r.username,
-            password: HashedPassword::new(r.password),
-            full_name: r.full_name,
-            is_active: r.is_active,
-            is_verified: r.is_verified,
-            activation_key: r.activation_key,
-            reset_key: r.reset_key,
-            reset_key_expires_at: r.reset_key_expires_at,
-            reset_date: r.reset_date,
-            status: r.status,
-            created_by: r.created_by,
-            updated_by: r.updated_by,
-            created_at: r.created_at,
-            updated_at: r.created_at,
-            deleted_at: r.deleted_at,
-            last_login_at: r.last_login_at,
-        }))
+        row.map(User::try_from).transpose()
     }
 
     async fn find_by_id(&self, id: i64) -> Result<Option<User>> {
-        let row = query!(
+        let row = query_as!(
+            UserRow,
             r#"
             SELECT
                 id,
@@ -84,13 +160,18 @@ impl UserRepository for PostgresUserRepository {
                 email,
                 username,
                 password,
+                password_changed_at,
                 full_name,
                 is_verified,
                 is_active,
                 activation_key,
+                activation_key_expires_at,
                 reset_key,
                 reset_key_expires_at,
                 reset_date,
+                pending_email,
+                email_change_key,
+                email_change_key_expires_at,
                 status,
                 created_by,
                 updated_by,
@@ -99,52 +180,38 @@ impl UserRepository for PostgresUserRepository {
                 deleted_at,
                 last_login_at
             FROM users
-            WHERE id = $1
+            WHERE id = $1 AND deleted_at IS NULL
             "#,
             id
         )
         .fetch_optional(&self.pool)
         .await?;
 
-        Ok(row.map(|r| User {
-            id: Some(r.id),
-            code: r.code,
-            email: Email::new(r.email).unwrap(),
-            username: r.username,
-            password: HashedPassword::new(r.password),
-            full_name: r.full_name,
-            is_active: r.is_active,
-            is_verified: r.is_verified,
-            activation_key: r.activation_key,
-            reset_key: r.reset_key,
-            reset_key_expires_at: r.reset_key_expires_at,
-            reset_date: r.reset_date,
-            status: r.status,
-            created_by: r.created_by,
-            updated_by: r.updated_by,
-            created_at: r.created_at,
-            updated_at: r.created_at,
-            deleted_at: r.deleted_at,
-            last_login_at: r.last_login_at,
-        }))
+        row.map(User::try_from).transpose()
     }
 
     async fn find_by_username(&self, username: &str) -> Result<Option<User>> {
-        let row = query!(
+        let row = query_as!(
+            UserRow,
             r#"
             SELECT
                 id,
                 code,
                 email,
                 username,
-                full_name,
                 password,
+                password_changed_at,
+                full_name,
                 is_verified,
                 is_active,
                 activation_key,
+                activation_key_expires_at,
                 reset_key,
                 reset_key_expires_at,
                 reset_date,
+                pending_email,
+                email_change_key,
+                email_change_key_expires_at,
                 status,
                 created_by,
                 updated_by,
@@ -153,34 +220,226 @@ impl UserRepository for PostgresUserRepository {
                 deleted_at,
                 last_login_at
             FROM users
-            WHERE username = $1
+            WHERE username = $1 AND deleted_at IS NULL
             "#,
             username
         )
         .fetch_optional(&self.pool)
         .await?;
 
-        Ok(row.map(|r| User {
-            id: Some(r.id),
-            code: r.code,
-            email: Email::new(r.email).unwrap(),
-            username: r.username,
-            full_name: r.full_name,
-            password: HashedPassword::new(r.password),
-            is_active: r.is_active,
-            is_verified: r.is_verified,
-            activation_key: r.activation_key,
-            reset_key: r.reset_key,
-            reset_key_expires_at: r.reset_key_expires_at,
-            reset_date: r.reset_date,
-            status: r.status,
-            created_by: r.created_by,
-            updated_by: r.updated_by,
-            created_at: r.created_at,
-            updated_at: r.updated_at,
-            deleted_at: r.deleted_at,
-            last_login_at: r.last_login_at,
-        }))
+        row.map(User::try_from).transpose()
+    }
+
+    async fn find_by_activation_key(&self, activation_key: &str) -> Result<Option<User>> {
+        let row = query_as!(
+            UserRow,
+            r#"
+            SELECT
+                id,
+                code,
+                email,
+                username,
+                password,
+                password_changed_at,
+                full_name,
+                is_verified,
+                is_active,
+                activation_key,
+                activation_key_expires_at,
+                reset_key,
+                reset_key_expires_at,
+                reset_date,
+                pending_email,
+                email_change_key,
+                email_change_key_expires_at,
+                status,
+                created_by,
+                updated_by,
+                created_at,
+                updated_at,
+                deleted_at,
+                last_login_at
+            FROM users
+            WHERE activation_key = $1 AND deleted_at IS NULL
+            "#,
+            activation_key
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(User::try_from).transpose()
+    }
+
+    async fn find_by_reset_key(&self, reset_key: &str) -> Result<Option<User>> {
+        let row = query_as!(
+            UserRow,
+            r#"
+            SELECT
+                id,
+                code,
+                email,
+                username,
+                password,
+                password_changed_at,
+                full_name,
+                is_verified,
+                is_active,
+                activation_key,
+                activation_key_expires_at,
+                reset_key,
+                reset_key_expires_at,
+                reset_date,
+                pending_email,
+                email_change_key,
+                email_change_key_expires_at,
+                status,
+                created_by,
+                updated_by,
+                created_at,
+                updated_at,
+                deleted_at,
+                last_login_at
+            FROM users
+            WHERE reset_key = $1 AND deleted_at IS NULL
+            "#,
+            reset_key
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(User::try_from).transpose()
+    }
+
+    async fn consume_reset_key(&self, reset_key: &str) -> Result<bool> {
+        let row = query!(
+            r#"
+            UPDATE users
+            SET reset_key = NULL, reset_key_expires_at = NULL
+            WHERE reset_key = $1 AND deleted_at IS NULL
+            RETURNING id
+            "#,
+            reset_key
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.is_some())
+    }
+
+    async fn find_by_email_change_key(&self, email_change_key: &str) -> Result<Option<User>> {
+        let row = query_as!(
+            UserRow,
+            r#"
+            SELECT
+                id,
+                code,
+                email,
+                username,
+                password,
+                password_changed_at,
+                full_name,
+                is_verified,
+                is_active,
+                activation_key,
+                activation_key_expires_at,
+                reset_key,
+                reset_key_expires_at,
+                reset_date,
+                pending_email,
+                email_change_key,
+                email_change_key_expires_at,
+                status,
+                created_by,
+                updated_by,
+                created_at,
+                updated_at,
+                deleted_at,
+                last_login_at
+            FROM users
+            WHERE email_change_key = $1 AND deleted_at IS NULL
+            "#,
+            email_change_key
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(User::try_from).transpose()
+    }
+
+    async fn find_all(&self, filter: &UserFilter, limit: u32, offset: u32) -> Result<Vec<User>> {
+        let search = search_pattern(filter);
+        let rows = query_as!(
+            UserRow,
+            r#"
+            SELECT
+                id,
+                code,
+                email,
+                username,
+                password,
+                password_changed_at,
+                full_name,
+                is_verified,
+                is_active,
+                activation_key,
+                activation_key_expires_at,
+                reset_key,
+                reset_key_expires_at,
+                reset_date,
+                pending_email,
+                email_change_key,
+                email_change_key_expires_at,
+                status,
+                created_by,
+                updated_by,
+                created_at,
+                updated_at,
+                deleted_at,
+                last_login_at
+            FROM users
+            WHERE deleted_at IS NULL
+              AND ($1::INT IS NULL OR status = $1)
+              AND ($2::BOOLEAN IS NULL OR is_verified = $2)
+              AND ($3::BOOLEAN IS NULL OR is_active = $3)
+              AND ($4::TEXT IS NULL OR email ILIKE $4 OR username ILIKE $4)
+            ORDER BY created_at DESC, id DESC
+            LIMIT $5 OFFSET $6
+            "#,
+            filter.status,
+            filter.is_verified,
+            filter.is_active,
+            search.as_deref(),
+            i64::from(limit),
+            i64::from(offset),
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(User::try_from).collect()
+    }
+
+    async fn count(&self, filter: &UserFilter) -> Result<u64> {
+        let search = search_pattern(filter);
+        let total = query!(
+            r#"
+            SELECT COUNT(*) AS "total!"
+            FROM users
+            WHERE deleted_at IS NULL
+              AND ($1::INT IS NULL OR status = $1)
+              AND ($2::BOOLEAN IS NULL OR is_verified = $2)
+              AND ($3::BOOLEAN IS NULL OR is_active = $3)
+              AND ($4::TEXT IS NULL OR email ILIKE $4 OR username ILIKE $4)
+            "#,
+            filter.status,
+            filter.is_verified,
+            filter.is_active,
+            search.as_deref(),
+        )
+        .fetch_one(&self.pool)
+        .await?
+        .total;
+
+        Ok(total as u64)
     }
 
     async fn update(&self, user: &User) -> Result<User> {
@@ -191,17 +450,50 @@ impl UserRepository for PostgresUserRepository {
                 full_name = $1,
                 password = $2,
                 is_verified = $3,
-                last_login_at = $4
-            WHERE id = $5
+                last_login_at = $4,
+                activation_key = $5,
+                activation_key_expires_at = $6,
+                reset_key = $7,
+                reset_key_expires_at = $8,
+                reset_date = $9,
+                is_active = $10,
+                status = $11,
+                deleted_at = $12,
+                updated_at = $13,
+                username = $14,
+                email = $15,
+                pending_email = $16,
+                email_change_key = $17,
+                email_change_key_expires_at = $18,
+                updated_by = $19,
+                password_changed_at = $20
+            WHERE id = $21
             "#,
             user.full_name,
             user.password.value(),
             user.is_verified,
             user.last_login_at,
+            user.activation_key,
+            user.activation_key_expires_at,
+            user.reset_key,
+            user.reset_key_expires_at,
+            user.reset_date,
+            user.is_active,
+            user.status,
+            user.deleted_at,
+            user.updated_at,
+            &user.username,
+            user.email.value(),
+            user.pending_email.as_ref().map(|e| e.value()),
+            user.email_change_key,
+            user.email_change_key_expires_at,
+            user.updated_by,
+            user.password_changed_at,
             user.id,
         )
         .execute(&self.pool)
-        .await?;
+        .await
+        .map_err(map_unique_violation)?;
 
         Ok(user.clone())
     }
@@ -216,11 +508,14 @@ impl UserRepository for PostgresUserRepository {
                 username,
                 full_name,
                 password,
+                password_changed_at,
                 is_verified,
+                activation_key,
+                activation_key_expires_at,
                 created_at,
                 updated_at
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
             RETURNING id
             "#,
             &user.code,
@@ -228,12 +523,16 @@ impl UserRepository for PostgresUserRepository {
             &user.username,
             user.full_name,
             user.password.value(),
+            user.password_changed_at,
             user.is_verified,
+            user.activation_key,
+            user.activation_key_expires_at,
             user.created_at,
             user.updated_at,
         )
         .fetch_one(&self.pool)
-        .await?
+        .await
+        .map_err(map_unique_violation)?
         .id;
 
         user.id = Some(id);
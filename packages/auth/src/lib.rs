@@ -3,20 +3,91 @@ pub mod domain;
 pub mod infrastructure;
 pub mod presentation;
 
+pub use application::auth_context::{AuthContext, Principal};
 pub use application::dto::*;
 pub use domain::entity::User;
-pub use presentation::auth_routes_handler;
-pub use presentation::middleware::{auth_middleware, optional_auth_middleware};
-pub use presentation::AuthApiDoc;
+pub use presentation::middleware::{
+    auth_middleware, optional_auth_middleware, require_permission, require_tenant_permission,
+    RequirePermission, RequireTenantPermission,
+};
+pub use presentation::{admin_routes_handler, auth_routes_handler, well_known_routes_handler};
+pub use presentation::{AdminApiDoc, AuthApiDoc};
 
+use crate::application::accept_invitation::AcceptInvitationUseCase;
+use crate::application::authenticate_api_key::AuthenticateApiKeyUseCase;
+use crate::application::change_password::ChangePasswordUseCase;
+use crate::application::complete_oidc_login::CompleteOidcLoginUseCase;
+use crate::application::confirm_email_change::ConfirmEmailChangeUseCase;
+use crate::application::confirm_totp::ConfirmTotpUseCase;
+use crate::application::create_api_key::CreateApiKeyUseCase;
+use crate::application::create_invitation::CreateInvitationUseCase;
+use crate::application::create_organization::CreateOrganizationUseCase;
+use crate::application::deactivate_user::DeactivateUserUseCase;
+use crate::application::delete_user::DeleteUserUseCase;
+use crate::application::disable_oauth_client::DisableOAuthClientUseCase;
+use crate::application::end_impersonation::EndImpersonationUseCase;
+use crate::application::enroll_totp::EnrollTotpUseCase;
 use crate::application::get_user_detail::GetUserDetailUseCase;
+use crate::application::issue_client_token::IssueClientTokenUseCase;
+use crate::application::list_api_keys::ListApiKeysUseCase;
+use crate::application::list_auth_events::ListAuthEventsUseCase;
+use crate::application::list_invitations::ListInvitationsUseCase;
+use crate::application::list_my_organizations::ListMyOrganizationsUseCase;
+use crate::application::list_oauth_clients::ListOAuthClientsUseCase;
+use crate::application::list_organization_members::ListOrganizationMembersUseCase;
+use crate::application::list_organizations::ListOrganizationsUseCase;
+use crate::application::list_sessions::ListSessionsUseCase;
+use crate::application::list_users::ListUsersUseCase;
+use crate::application::logout::LogoutUseCase;
+use crate::application::logout_all_devices::LogoutAllDevicesUseCase;
+use crate::application::reactivate_user::ReactivateUserUseCase;
+use crate::application::refresh_token::RefreshTokenUseCase;
+use crate::application::register_oauth_client::RegisterOAuthClientUseCase;
+use crate::application::remove_organization_member::RemoveOrganizationMemberUseCase;
+use crate::application::request_email_change::RequestEmailChangeUseCase;
+use crate::application::request_magic_link::RequestMagicLinkUseCase;
+use crate::application::request_password_reset::RequestPasswordResetUseCase;
+use crate::application::resend_verification::ResendVerificationUseCase;
+use crate::application::reset_password::ResetPasswordUseCase;
+use crate::application::revoke_api_key::RevokeApiKeyUseCase;
+use crate::application::revoke_invitation::RevokeInvitationUseCase;
+use crate::application::revoke_session::RevokeSessionUseCase;
+use crate::application::services::auth_event_recorder::AuthEventRecorder;
+use crate::application::services::email_verification::EmailVerificationService;
+use crate::application::services::login_throttle::LoginThrottleService;
+use crate::application::services::mfa_challenge::MfaChallengeService;
+use crate::application::services::password_policy::PasswordPolicyService;
+use crate::application::services::tenant_resolver::TenantResolver;
+use crate::application::services::token_issuer::TokenIssuer;
+use crate::application::services::totp::TotpService;
+use crate::application::set_organization_member::SetOrganizationMemberUseCase;
+use crate::application::start_impersonation::StartImpersonationUseCase;
+use crate::application::start_oidc_login::StartOidcLoginUseCase;
+use crate::application::switch_organization::SwitchOrganizationUseCase;
+use crate::application::update_api_key::UpdateApiKeyUseCase;
+use crate::application::update_profile::UpdateProfileUseCase;
+use crate::application::verify_email::VerifyEmailUseCase;
+use crate::application::verify_magic_link::VerifyMagicLinkUseCase;
+use crate::application::verify_mfa_login::VerifyMfaLoginUseCase;
 use crate::application::verify_token::VerifyTokenUseCase;
 use crate::application::{login_user::LoginUserUseCase, register_user::RegisterUserUseCase};
-use infrastructure::jwt::JwtConfig;
+use infrastructure::breached_passwords::open_breached_password_checker;
+use infrastructure::config::AuthConfig;
 use infrastructure::jwt::JwtService;
+use infrastructure::mail_notifier::MailAccountNotifier;
+use infrastructure::oidc::OidcProviders;
 use infrastructure::password::PasswordService;
-use infrastructure::repository::PostgresUserRepository;
-use rust_reborn_contracts::common::{CodeGenerator, UuidV7CodeGenerator};
+use infrastructure::repository::{
+    PostgresApiKeyRepository, PostgresAuthEventRepository, PostgresExternalIdentityRepository,
+    PostgresInvitationRepository, PostgresLoginThrottleRepository, PostgresMagicLinkRepository,
+    PostgresMfaRepository, PostgresOAuthClientRepository, PostgresOrganizationRepository,
+    PostgresPasswordHistoryRepository, PostgresRefreshTokenRepository, PostgresRoleRepository,
+    PostgresSessionRepository, PostgresTokenRevocationRepository, PostgresUserRepository,
+};
+use presentation::http::session_cookies::SessionCookies;
+use rust_reborn_contracts::common::{CodeGenerator, SecureTokenGenerator, UuidV7CodeGenerator};
+use rust_reborn_contracts::mail::TemplatedMailer;
+use rust_reborn_contracts::Result;
 use sqlx::PgPool;
 use std::sync::Arc;
 
@@ -24,39 +95,496 @@ use std::sync::Arc;
 pub struct AuthState {
     pub register_user_use_case: Arc<RegisterUserUseCase>,
     pub login_user_use_case: Arc<LoginUserUseCase>,
+    pub refresh_token_use_case: Arc<RefreshTokenUseCase>,
     pub get_user_detail_use_case: Arc<GetUserDetailUseCase>,
     pub verify_token_use_case: Arc<VerifyTokenUseCase>,
+    pub logout_use_case: Arc<LogoutUseCase>,
+    pub logout_all_devices_use_case: Arc<LogoutAllDevicesUseCase>,
+    pub verify_email_use_case: Arc<VerifyEmailUseCase>,
+    pub resend_verification_use_case: Arc<ResendVerificationUseCase>,
+    pub request_password_reset_use_case: Arc<RequestPasswordResetUseCase>,
+    pub reset_password_use_case: Arc<ResetPasswordUseCase>,
+    pub change_password_use_case: Arc<ChangePasswordUseCase>,
+    pub enroll_totp_use_case: Arc<EnrollTotpUseCase>,
+    pub confirm_totp_use_case: Arc<ConfirmTotpUseCase>,
+    pub verify_mfa_login_use_case: Arc<VerifyMfaLoginUseCase>,
+    pub list_sessions_use_case: Arc<ListSessionsUseCase>,
+    pub revoke_session_use_case: Arc<RevokeSessionUseCase>,
+    pub list_users_use_case: Arc<ListUsersUseCase>,
+    pub deactivate_user_use_case: Arc<DeactivateUserUseCase>,
+    pub reactivate_user_use_case: Arc<ReactivateUserUseCase>,
+    pub delete_user_use_case: Arc<DeleteUserUseCase>,
+    pub update_profile_use_case: Arc<UpdateProfileUseCase>,
+    pub request_email_change_use_case: Arc<RequestEmailChangeUseCase>,
+    pub confirm_email_change_use_case: Arc<ConfirmEmailChangeUseCase>,
+    pub start_oidc_login_use_case: Arc<StartOidcLoginUseCase>,
+    pub complete_oidc_login_use_case: Arc<CompleteOidcLoginUseCase>,
+    pub create_api_key_use_case: Arc<CreateApiKeyUseCase>,
+    pub list_api_keys_use_case: Arc<ListApiKeysUseCase>,
+    pub update_api_key_use_case: Arc<UpdateApiKeyUseCase>,
+    pub revoke_api_key_use_case: Arc<RevokeApiKeyUseCase>,
+    pub authenticate_api_key_use_case: Arc<AuthenticateApiKeyUseCase>,
+    pub request_magic_link_use_case: Arc<RequestMagicLinkUseCase>,
+    pub verify_magic_link_use_case: Arc<VerifyMagicLinkUseCase>,
+    pub list_auth_events_use_case: Arc<ListAuthEventsUseCase>,
+    pub register_oauth_client_use_case: Arc<RegisterOAuthClientUseCase>,
+    pub list_oauth_clients_use_case: Arc<ListOAuthClientsUseCase>,
+    pub disable_oauth_client_use_case: Arc<DisableOAuthClientUseCase>,
+    pub issue_client_token_use_case: Arc<IssueClientTokenUseCase>,
+    pub start_impersonation_use_case: Arc<StartImpersonationUseCase>,
+    pub end_impersonation_use_case: Arc<EndImpersonationUseCase>,
+    pub create_organization_use_case: Arc<CreateOrganizationUseCase>,
+    pub list_organizations_use_case: Arc<ListOrganizationsUseCase>,
+    pub list_organization_members_use_case: Arc<ListOrganizationMembersUseCase>,
+    pub set_organization_member_use_case: Arc<SetOrganizationMemberUseCase>,
+    pub remove_organization_member_use_case: Arc<RemoveOrganizationMemberUseCase>,
+    pub list_my_organizations_use_case: Arc<ListMyOrganizationsUseCase>,
+    pub switch_organization_use_case: Arc<SwitchOrganizationUseCase>,
+    pub create_invitation_use_case: Arc<CreateInvitationUseCase>,
+    pub list_invitations_use_case: Arc<ListInvitationsUseCase>,
+    pub revoke_invitation_use_case: Arc<RevokeInvitationUseCase>,
+    pub accept_invitation_use_case: Arc<AcceptInvitationUseCase>,
+    pub tenant_resolver: Arc<TenantResolver>,
+    pub jwt_service: Arc<JwtService>,
+    pub session_cookies: Arc<SessionCookies>,
+    /// Trusted proxies that append to `X-Forwarded-For`; 0 ignores the header.
+    pub trusted_proxy_hops: usize,
 }
 
 impl AuthState {
-    pub fn new(pool: PgPool, jwt_config: JwtConfig) -> Self {
+    pub fn new(
+        pool: PgPool,
+        jwt_service: Arc<JwtService>,
+        auth_config: AuthConfig,
+        mailer: Arc<TemplatedMailer>,
+    ) -> Result<Self> {
         // ===== Infrastructure (ONCE) =====
-        let user_repo = Arc::new(PostgresUserRepository::new(pool));
-        let password_service = Arc::new(PasswordService::new());
-        let jwt_service = Arc::new(JwtService::new(jwt_config));
+        let user_repo = Arc::new(PostgresUserRepository::new(pool.clone()));
+        let refresh_token_repo = Arc::new(PostgresRefreshTokenRepository::new(pool.clone()));
+        let revocation_repo = Arc::new(PostgresTokenRevocationRepository::new(pool.clone()));
+        let role_repo = Arc::new(PostgresRoleRepository::new(pool.clone()));
+        let mfa_repo = Arc::new(PostgresMfaRepository::new(pool.clone()));
+        let session_repo = Arc::new(PostgresSessionRepository::new(pool.clone()));
+        let login_throttle_repo = Arc::new(PostgresLoginThrottleRepository::new(pool.clone()));
+        let external_identity_repo =
+            Arc::new(PostgresExternalIdentityRepository::new(pool.clone()));
+        let api_key_repo = Arc::new(PostgresApiKeyRepository::new(pool.clone()));
+        let oauth_client_repo = Arc::new(PostgresOAuthClientRepository::new(pool.clone()));
+        let auth_event_repo = Arc::new(PostgresAuthEventRepository::new(pool.clone()));
+        let organization_repo = Arc::new(PostgresOrganizationRepository::new(pool.clone()));
+        let invitation_repo = Arc::new(PostgresInvitationRepository::new(pool.clone()));
+        let password_history_repo = Arc::new(PostgresPasswordHistoryRepository::new(pool.clone()));
+        let magic_link_repo = Arc::new(PostgresMagicLinkRepository::new(pool));
+        let password_service = Arc::new(PasswordService::new(&auth_config.password_hashing)?);
         let code_generator: Arc<dyn CodeGenerator> = Arc::new(UuidV7CodeGenerator);
+        let token_generator: Arc<dyn CodeGenerator> = Arc::new(SecureTokenGenerator);
+        let notifier = Arc::new(MailAccountNotifier::new(
+            mailer,
+            auth_config.frontend_url.clone(),
+        ));
+
+        let email_verification = Arc::new(EmailVerificationService::new(
+            token_generator.clone(),
+            notifier.clone(),
+            chrono::Duration::hours(auth_config.email_verification.token_expiration_hours),
+        ));
+
+        let token_issuer = Arc::new(TokenIssuer::new(
+            jwt_service.clone(),
+            refresh_token_repo.clone(),
+            role_repo.clone(),
+            session_repo.clone(),
+            token_generator.clone(),
+        ));
+
+        let totp = Arc::new(TotpService::new(auth_config.mfa.issuer.clone()));
+        let mfa_challenges = Arc::new(MfaChallengeService::new(
+            mfa_repo.clone(),
+            token_generator.clone(),
+            chrono::Duration::seconds(auth_config.mfa.challenge_ttl_seconds),
+        ));
+
+        let login_throttle = Arc::new(LoginThrottleService::new(
+            login_throttle_repo,
+            auth_config.lockout.clone(),
+        ));
+
+        let breached_passwords = &auth_config.password_policy.breached_passwords;
+        let breached_password_checker = breached_passwords
+            .enabled
+            .then(|| open_breached_password_checker(breached_passwords))
+            .transpose()?;
+        let password_policy = Arc::new(PasswordPolicyService::new(
+            auth_config.password_policy.clone(),
+            password_history_repo,
+            password_service.clone(),
+            breached_password_checker,
+        ));
+
+        let auth_events = Arc::new(AuthEventRecorder::new(auth_event_repo.clone()));
 
         let register_user_use_case = Arc::new(RegisterUserUseCase::new(
             user_repo.clone(),
             password_service.clone(),
+            password_policy.clone(),
             code_generator.clone(),
+            email_verification.clone(),
+            auth_events.clone(),
         ));
 
         let login_user_use_case = Arc::new(LoginUserUseCase::new(
             user_repo.clone(),
-            jwt_service.clone(),
+            token_issuer.clone(),
             password_service.clone(),
+            password_policy.clone(),
+            mfa_challenges.clone(),
+            login_throttle.clone(),
+            auth_events.clone(),
+            auth_config.email_verification.required_for_login,
+        ));
+
+        let refresh_token_use_case = Arc::new(RefreshTokenUseCase::new(
+            user_repo.clone(),
+            refresh_token_repo.clone(),
+            token_issuer.clone(),
         ));
 
         let get_user_detail_use_case = Arc::new(GetUserDetailUseCase::new(user_repo.clone()));
 
-        let verify_token_use_case = Arc::new(VerifyTokenUseCase::new(jwt_service.clone()));
+        let verify_token_use_case = Arc::new(VerifyTokenUseCase::new(
+            jwt_service.clone(),
+            revocation_repo.clone(),
+            session_repo.clone(),
+            oauth_client_repo.clone(),
+        ));
+
+        let logout_use_case = Arc::new(LogoutUseCase::new(
+            verify_token_use_case.clone(),
+            revocation_repo.clone(),
+            refresh_token_repo.clone(),
+            session_repo.clone(),
+            auth_events.clone(),
+        ));
+
+        let logout_all_devices_use_case = Arc::new(LogoutAllDevicesUseCase::new(
+            revocation_repo.clone(),
+            refresh_token_repo.clone(),
+            session_repo.clone(),
+            auth_events.clone(),
+        ));
+
+        let verify_email_use_case = Arc::new(VerifyEmailUseCase::new(user_repo.clone()));
+
+        let resend_verification_use_case = Arc::new(ResendVerificationUseCase::new(
+            user_repo.clone(),
+            email_verification.clone(),
+        ));
+
+        let request_password_reset_use_case = Arc::new(RequestPasswordResetUseCase::new(
+            user_repo.clone(),
+            token_generator.clone(),
+            notifier.clone(),
+            chrono::Duration::minutes(auth_config.password_reset.token_expiration_minutes),
+        ));
+
+        let reset_password_use_case = Arc::new(ResetPasswordUseCase::new(
+            user_repo.clone(),
+            password_service.clone(),
+            password_policy.clone(),
+            logout_all_devices_use_case.clone(),
+            auth_events.clone(),
+        ));
+
+        let change_password_use_case = Arc::new(ChangePasswordUseCase::new(
+            user_repo.clone(),
+            password_service.clone(),
+            password_policy.clone(),
+            logout_all_devices_use_case.clone(),
+            token_issuer.clone(),
+            auth_events.clone(),
+        ));
+
+        let enroll_totp_use_case = Arc::new(EnrollTotpUseCase::new(
+            user_repo.clone(),
+            mfa_repo.clone(),
+            totp.clone(),
+        ));
+
+        let confirm_totp_use_case = Arc::new(ConfirmTotpUseCase::new(
+            mfa_repo.clone(),
+            totp.clone(),
+            auth_config.mfa.recovery_code_count,
+        ));
+
+        let verify_mfa_login_use_case = Arc::new(VerifyMfaLoginUseCase::new(
+            user_repo.clone(),
+            mfa_repo,
+            totp,
+            token_issuer.clone(),
+            password_policy.clone(),
+            login_throttle,
+            auth_events.clone(),
+            auth_config.mfa.max_attempts,
+        ));
+
+        let list_sessions_use_case = Arc::new(ListSessionsUseCase::new(
+            session_repo.clone(),
+            jwt_service.refresh_expires_in(),
+        ));
+
+        let revoke_session_use_case = Arc::new(RevokeSessionUseCase::new(
+            session_repo.clone(),
+            refresh_token_repo.clone(),
+        ));
+
+        let list_users_use_case = Arc::new(ListUsersUseCase::new(user_repo.clone()));
+
+        let deactivate_user_use_case = Arc::new(DeactivateUserUseCase::new(
+            user_repo.clone(),
+            logout_all_devices_use_case.clone(),
+            auth_events.clone(),
+        ));
+
+        let list_auth_events_use_case = Arc::new(ListAuthEventsUseCase::new(auth_event_repo));
+
+        let reactivate_user_use_case = Arc::new(ReactivateUserUseCase::new(user_repo.clone()));
+
+        let delete_user_use_case = Arc::new(DeleteUserUseCase::new(
+            user_repo.clone(),
+            logout_all_devices_use_case.clone(),
+            auth_events.clone(),
+        ));
+
+        let update_profile_use_case = Arc::new(UpdateProfileUseCase::new(user_repo.clone()));
+
+        let request_email_change_use_case = Arc::new(RequestEmailChangeUseCase::new(
+            user_repo.clone(),
+            password_service.clone(),
+            token_generator.clone(),
+            notifier.clone(),
+            chrono::Duration::hours(auth_config.email_change.token_expiration_hours),
+        ));
+
+        let confirm_email_change_use_case =
+            Arc::new(ConfirmEmailChangeUseCase::new(user_repo.clone()));
+
+        let oidc_providers = Arc::new(OidcProviders::new(&auth_config.oidc.providers)?);
+
+        let start_oidc_login_use_case = Arc::new(StartOidcLoginUseCase::new(
+            oidc_providers.clone(),
+            external_identity_repo.clone(),
+            token_generator.clone(),
+            chrono::Duration::seconds(auth_config.oidc.state_ttl_seconds),
+        ));
+
+        let complete_oidc_login_use_case = Arc::new(CompleteOidcLoginUseCase::new(
+            oidc_providers,
+            external_identity_repo,
+            user_repo.clone(),
+            password_service.clone(),
+            code_generator.clone(),
+            token_generator.clone(),
+            token_issuer.clone(),
+            mfa_challenges.clone(),
+            auth_events.clone(),
+            auth_config.email_verification.required_for_login,
+        ));
+
+        let request_magic_link_use_case = Arc::new(RequestMagicLinkUseCase::new(
+            user_repo.clone(),
+            magic_link_repo.clone(),
+            token_generator.clone(),
+            notifier.clone(),
+            chrono::Duration::minutes(auth_config.magic_link.token_expiration_minutes),
+            auth_config.magic_link.enabled,
+        ));
+
+        let verify_magic_link_use_case = Arc::new(VerifyMagicLinkUseCase::new(
+            user_repo.clone(),
+            magic_link_repo,
+            token_issuer.clone(),
+            mfa_challenges,
+            auth_events.clone(),
+            auth_config.magic_link.enabled,
+        ));
+
+        let create_api_key_use_case = Arc::new(CreateApiKeyUseCase::new(
+            api_key_repo.clone(),
+            role_repo.clone(),
+            token_generator.clone(),
+        ));
+
+        let list_api_keys_use_case = Arc::new(ListApiKeysUseCase::new(api_key_repo.clone()));
+
+        let update_api_key_use_case = Arc::new(UpdateApiKeyUseCase::new(
+            api_key_repo.clone(),
+            role_repo.clone(),
+        ));
+
+        let revoke_api_key_use_case = Arc::new(RevokeApiKeyUseCase::new(api_key_repo.clone()));
+
+        let authenticate_api_key_use_case = Arc::new(AuthenticateApiKeyUseCase::new(
+            api_key_repo,
+            user_repo.clone(),
+            role_repo.clone(),
+        ));
+
+        let register_oauth_client_use_case = Arc::new(RegisterOAuthClientUseCase::new(
+            oauth_client_repo.clone(),
+            code_generator.clone(),
+            token_generator.clone(),
+        ));
+
+        let list_oauth_clients_use_case =
+            Arc::new(ListOAuthClientsUseCase::new(oauth_client_repo.clone()));
+
+        let disable_oauth_client_use_case =
+            Arc::new(DisableOAuthClientUseCase::new(oauth_client_repo.clone()));
+
+        let issue_client_token_use_case = Arc::new(IssueClientTokenUseCase::new(
+            oauth_client_repo,
+            jwt_service.clone(),
+            chrono::Duration::seconds(auth_config.oauth.access_token_ttl_seconds),
+        ));
+
+        let start_impersonation_use_case = Arc::new(StartImpersonationUseCase::new(
+            user_repo.clone(),
+            role_repo.clone(),
+            organization_repo.clone(),
+            jwt_service.clone(),
+            auth_events.clone(),
+            chrono::Duration::minutes(auth_config.impersonation.token_ttl_minutes),
+        ));
+
+        let create_invitation_use_case = Arc::new(CreateInvitationUseCase::new(
+            invitation_repo.clone(),
+            user_repo.clone(),
+            role_repo.clone(),
+            token_generator.clone(),
+            notifier.clone(),
+            chrono::Duration::hours(auth_config.invitation.token_expiration_hours),
+        ));
+
+        let list_invitations_use_case =
+            Arc::new(ListInvitationsUseCase::new(invitation_repo.clone()));
+
+        let revoke_invitation_use_case =
+            Arc::new(RevokeInvitationUseCase::new(invitation_repo.clone()));
+
+        let accept_invitation_use_case = Arc::new(AcceptInvitationUseCase::new(
+            invitation_repo,
+            user_repo.clone(),
+            password_service.clone(),
+            password_policy,
+            code_generator.clone(),
+            auth_events.clone(),
+        ));
+
+        let end_impersonation_use_case = Arc::new(EndImpersonationUseCase::new(
+            verify_token_use_case.clone(),
+            revocation_repo.clone(),
+            auth_events,
+        ));
+
+        let create_organization_use_case =
+            Arc::new(CreateOrganizationUseCase::new(organization_repo.clone()));
+
+        let list_organizations_use_case =
+            Arc::new(ListOrganizationsUseCase::new(organization_repo.clone()));
+
+        let list_organization_members_use_case = Arc::new(ListOrganizationMembersUseCase::new(
+            organization_repo.clone(),
+        ));
+
+        let set_organization_member_use_case = Arc::new(SetOrganizationMemberUseCase::new(
+            organization_repo.clone(),
+            user_repo.clone(),
+        ));
+
+        let remove_organization_member_use_case = Arc::new(RemoveOrganizationMemberUseCase::new(
+            organization_repo.clone(),
+        ));
+
+        let list_my_organizations_use_case =
+            Arc::new(ListMyOrganizationsUseCase::new(organization_repo.clone()));
+
+        let switch_organization_use_case = Arc::new(SwitchOrganizationUseCase::new(
+            organization_repo.clone(),
+            session_repo,
+            user_repo,
+            token_issuer,
+        ));
+
+        let tenant_resolver = Arc::new(TenantResolver::new(
+            organization_repo,
+            auth_config.tenancy.base_domain.clone(),
+        ));
+
+        let session_cookies = Arc::new(SessionCookies::new(
+            auth_config.session_cookies.clone(),
+            jwt_service.refresh_expires_in(),
+        ));
 
-        Self {
+        Ok(Self {
             get_user_detail_use_case,
             register_user_use_case,
             login_user_use_case,
+            refresh_token_use_case,
             verify_token_use_case,
-        }
+            logout_use_case,
+            logout_all_devices_use_case,
+            verify_email_use_case,
+            resend_verification_use_case,
+            request_password_reset_use_case,
+            reset_password_use_case,
+            change_password_use_case,
+            enroll_totp_use_case,
+            confirm_totp_use_case,
+            verify_mfa_login_use_case,
+            list_sessions_use_case,
+            revoke_session_use_case,
+            list_users_use_case,
+            deactivate_user_use_case,
+            reactivate_user_use_case,
+            delete_user_use_case,
+            update_profile_use_case,
+            request_email_change_use_case,
+            confirm_email_change_use_case,
+            start_oidc_login_use_case,
+            complete_oidc_login_use_case,
+            create_api_key_use_case,
+            list_api_keys_use_case,
+            update_api_key_use_case,
+            revoke_api_key_use_case,
+            authenticate_api_key_use_case,
+            request_magic_link_use_case,
+            verify_magic_link_use_case,
+            list_auth_events_use_case,
+            register_oauth_client_use_case,
+            list_oauth_clients_use_case,
+            disable_oauth_client_use_case,
+            issue_client_token_use_case,
+            start_impersonation_use_case,
+            end_impersonation_use_case,
+            create_organization_use_case,
+            list_organizations_use_case,
+            list_organization_members_use_case,
+            set_organization_member_use_case,
+            remove_organization_member_use_case,
+            list_my_organizations_use_case,
+            switch_organization_use_case,
+            create_invitation_use_case,
+            list_invitations_use_case,
+            revoke_invitation_use_case,
+            accept_invitation_use_case,
+            tenant_resolver,
+            session_cookies,
+            jwt_service,
+            trusted_proxy_hops: if auth_config.lockout.trust_forwarded_for {
+                auth_config.lockout.trusted_proxy_hops
+            } else {
+                0
+            },
+        })
     }
 }
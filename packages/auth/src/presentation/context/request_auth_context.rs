@@ -1,19 +1,110 @@
+use crate::application::authenticate_api_key::ApiKeyPrincipal;
 use crate::application::port::auth_context::AuthContext;
+use crate::domain::entity::Membership;
+use crate::infrastructure::jwt::Claims;
+use rust_reborn_contracts::Result;
+use uuid::Uuid;
 
 #[derive(Clone)]
 pub struct RequestAuthContext {
     user_id: Option<i64>,
+    roles: Vec<String>,
+    permissions: Vec<String>,
+    session_id: Option<Uuid>,
+    api_key_id: Option<i64>,
+    client_id: Option<String>,
+    actor_id: Option<i64>,
+    tenant_id: Option<i64>,
+    /// Held in the active tenant only; never satisfy a global check.
+    tenant_roles: Vec<String>,
+    tenant_permissions: Vec<String>,
+    /// Active tenant named by the token, not yet checked against memberships.
+    claimed_tenant: Option<i64>,
 }
 
 impl RequestAuthContext {
-    pub fn authenticated(user_id: i64) -> Self {
+    pub fn authenticated(user_id: i64, roles: Vec<String>, permissions: Vec<String>) -> Self {
         Self {
             user_id: Some(user_id),
+            roles,
+            permissions,
+            session_id: None,
+            api_key_id: None,
+            client_id: None,
+            actor_id: None,
+            tenant_id: None,
+            tenant_roles: Vec::new(),
+            tenant_permissions: Vec::new(),
+            claimed_tenant: None,
+        }
+    }
+
+    pub fn from_claims(claims: Claims) -> Result<Self> {
+        if let Some(client_id) = claims.client_id {
+            return Ok(Self::service(client_id, claims.permissions));
+        }
+
+        let actor_id = claims.actor_id()?;
+        let mut ctx = Self::authenticated(claims.user_id()?, claims.roles, claims.permissions);
+        ctx.session_id = claims.sid;
+        ctx.actor_id = actor_id;
+        ctx.claimed_tenant = claims.tid;
+        Ok(ctx)
+    }
+
+    /// API key requests carry the key's scopes as permissions and no roles.
+    pub fn from_api_key(principal: ApiKeyPrincipal) -> Self {
+        let mut ctx = Self::authenticated(principal.user_id, Vec::new(), principal.permissions);
+        ctx.api_key_id = Some(principal.key_id);
+        ctx
+    }
+
+    pub fn service(client_id: String, permissions: Vec<String>) -> Self {
+        let mut ctx = Self::anonymous();
+        ctx.client_id = Some(client_id);
+        ctx.permissions = permissions;
+        ctx
+    }
+
+    pub fn claimed_tenant(&self) -> Option<i64> {
+        self.claimed_tenant
+    }
+
+    /// Acts in the membership's organization with the roles held there,
+    /// kept apart from the global ones. API keys keep their scopes:
+    /// organization roles never widen them.
+    pub fn enter_tenant(&mut self, membership: &Membership) {
+        self.tenant_id = Some(membership.organization_id);
+        if self.api_key_id.is_some() {
+            return;
+        }
+
+        for role in &membership.roles {
+            if !self.tenant_roles.contains(&role.name) {
+                self.tenant_roles.push(role.name.clone());
+            }
+            for permission in &role.permissions {
+                if !self.tenant_permissions.contains(permission) {
+                    self.tenant_permissions.push(permission.clone());
+                }
+            }
         }
     }
 
     pub fn anonymous() -> Self {
-        Self { user_id: None }
+        Self {
+            user_id: None,
+            roles: Vec::new(),
+            permissions: Vec::new(),
+            session_id: None,
+            api_key_id: None,
+            client_id: None,
+            actor_id: None,
+            tenant_id: None,
+            tenant_roles: Vec::new(),
+            tenant_permissions: Vec::new(),
+            claimed_tenant: None,
+        }
     }
 }
 
@@ -21,4 +112,40 @@ impl AuthContext for RequestAuthContext {
     fn user_id(&self) -> Option<i64> {
         self.user_id
     }
+
+    fn roles(&self) -> &[String] {
+        &self.roles
+    }
+
+    fn permissions(&self) -> &[String] {
+        &self.permissions
+    }
+
+    fn session_id(&self) -> Option<Uuid> {
+        self.session_id
+    }
+
+    fn api_key_id(&self) -> Option<i64> {
+        self.api_key_id
+    }
+
+    fn client_id(&self) -> Option<&str> {
+        self.client_id.as_deref()
+    }
+
+    fn actor_id(&self) -> Option<i64> {
+        self.actor_id
+    }
+
+    fn tenant_id(&self) -> Option<i64> {
+        self.tenant_id
+    }
+
+    fn tenant_roles(&self) -> &[String] {
+        &self.tenant_roles
+    }
+
+    fn tenant_permissions(&self) -> &[String] {
+        &self.tenant_permissions
+    }
 }
@@ -0,0 +1,50 @@
+use crate::application::dto::{
+    AdminUserResponse, AuthEventResponse, CreateInvitationRequest, CreateOrganizationRequest,
+    ImpersonationResponse, InvitationResponse, MembershipResponse, OAuthClientResponse,
+    OrganizationResponse, RegisterOAuthClientRequest, RegisteredOAuthClientResponse,
+    SetMemberRolesRequest,
+};
+use rust_reborn_contracts::common::PaginationMeta;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        crate::presentation::http::handlers::list_users,
+        crate::presentation::http::handlers::deactivate_user,
+        crate::presentation::http::handlers::reactivate_user,
+        crate::presentation::http::handlers::delete_user,
+        crate::presentation::http::handlers::start_impersonation,
+        crate::presentation::http::handlers::create_invitation,
+        crate::presentation::http::handlers::list_invitations,
+        crate::presentation::http::handlers::revoke_invitation,
+        crate::presentation::http::handlers::list_auth_events,
+        crate::presentation::http::handlers::register_oauth_client,
+        crate::presentation::http::handlers::list_oauth_clients,
+        crate::presentation::http::handlers::disable_oauth_client,
+        crate::presentation::http::handlers::create_organization,
+        crate::presentation::http::handlers::list_organizations,
+        crate::presentation::http::handlers::list_organization_members,
+        crate::presentation::http::handlers::set_organization_member,
+        crate::presentation::http::handlers::remove_organization_member
+    ),
+    components(schemas(
+        AdminUserResponse,
+        AuthEventResponse,
+        ImpersonationResponse,
+        CreateInvitationRequest,
+        InvitationResponse,
+        PaginationMeta,
+        OAuthClientResponse,
+        RegisterOAuthClientRequest,
+        RegisteredOAuthClientResponse,
+        CreateOrganizationRequest,
+        OrganizationResponse,
+        MembershipResponse,
+        SetMemberRolesRequest
+    )),
+    tags(
+        (name = "Admin", description = "User accounts, invitations, impersonation, audit log, OAuth clients and organizations, requires users:manage")
+    )
+)]
+pub struct AdminApiDoc;
@@ -1,13 +1,84 @@
-use crate::application::dto::{AuthResponse, LoginRequest};
+use crate::application::dto::{
+    AcceptInvitationRequest, ApiKeyResponse, AuthResponse, ChangeEmailRequest,
+    ChangePasswordRequest, ConfirmEmailChangeRequest, ConfirmTotpRequest, CreateApiKeyRequest,
+    CreatedApiKeyResponse, ForgotPasswordRequest, LoginRequest, LoginResponse, LogoutRequest,
+    MagicLinkRequest, MfaChallengeResponse, MfaLoginRequest, OidcAuthorizationResponse,
+    OidcCallbackRequest, OrganizationResponse, RecoveryCodesResponse, RefreshTokenRequest,
+    ResendVerificationRequest, ResetPasswordRequest, SessionResponse, SwitchOrganizationRequest,
+    TokenResponse, TotpEnrollmentResponse, UpdateApiKeyRequest, UpdateProfileRequest,
+    UserOrganizationResponse, UserResponse, VerifyEmailRequest, VerifyMagicLinkRequest,
+};
 use utoipa::OpenApi;
 
 #[derive(OpenApi)]
 #[openapi(
     paths(
-        crate::presentation::http::handlers::login
+        crate::presentation::http::handlers::accept_invitation,
+        crate::presentation::http::handlers::login,
+        crate::presentation::http::handlers::refresh_token,
+        crate::presentation::http::handlers::logout,
+        crate::presentation::http::handlers::logout_all,
+        crate::presentation::http::handlers::end_impersonation,
+        crate::presentation::http::handlers::verify_email,
+        crate::presentation::http::handlers::resend_verification,
+        crate::presentation::http::handlers::forgot_password,
+        crate::presentation::http::handlers::reset_password,
+        crate::presentation::http::handlers::change_password,
+        crate::presentation::http::handlers::verify_mfa_login,
+        crate::presentation::http::handlers::request_magic_link,
+        crate::presentation::http::handlers::verify_magic_link,
+        crate::presentation::http::handlers::enroll_totp,
+        crate::presentation::http::handlers::confirm_totp,
+        crate::presentation::http::handlers::list_sessions,
+        crate::presentation::http::handlers::revoke_session,
+        crate::presentation::http::handlers::list_my_organizations,
+        crate::presentation::http::handlers::switch_organization,
+        crate::presentation::http::handlers::update_profile,
+        crate::presentation::http::handlers::request_email_change,
+        crate::presentation::http::handlers::confirm_email_change,
+        crate::presentation::http::handlers::start_oidc_login,
+        crate::presentation::http::handlers::complete_oidc_login,
+        crate::presentation::http::handlers::create_api_key,
+        crate::presentation::http::handlers::list_api_keys,
+        crate::presentation::http::handlers::update_api_key,
+        crate::presentation::http::handlers::revoke_api_key
     ),
     components(
-        schemas(LoginRequest, AuthResponse),
+        schemas(
+            AcceptInvitationRequest,
+            LoginRequest,
+            AuthResponse,
+            RefreshTokenRequest,
+            TokenResponse,
+            LogoutRequest,
+            VerifyEmailRequest,
+            ResendVerificationRequest,
+            UserResponse,
+            ForgotPasswordRequest,
+            ResetPasswordRequest,
+            ChangePasswordRequest,
+            LoginResponse,
+            MfaChallengeResponse,
+            MfaLoginRequest,
+            MagicLinkRequest,
+            VerifyMagicLinkRequest,
+            TotpEnrollmentResponse,
+            ConfirmTotpRequest,
+            RecoveryCodesResponse,
+            SessionResponse,
+            OrganizationResponse,
+            UserOrganizationResponse,
+            SwitchOrganizationRequest,
+            UpdateProfileRequest,
+            ChangeEmailRequest,
+            ConfirmEmailChangeRequest,
+            OidcAuthorizationResponse,
+            OidcCallbackRequest,
+            CreateApiKeyRequest,
+            UpdateApiKeyRequest,
+            ApiKeyResponse,
+            CreatedApiKeyResponse,
+        ),
     ),
     tags(
         (name = "Authentication", description = "Authentication management APIs")
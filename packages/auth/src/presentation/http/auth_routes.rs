@@ -1,15 +1,118 @@
-use crate::presentation::http::handlers::{get_current_user, login, logout, register};
+use crate::presentation::http::handlers::{
+    accept_invitation, change_password, complete_oidc_login, confirm_email_change, confirm_totp,
+    create_api_key, create_invitation, create_organization, deactivate_user, delete_user,
+    disable_oauth_client, end_impersonation, enroll_totp, forgot_password, get_current_user, jwks,
+    list_api_keys, list_auth_events, list_invitations, list_my_organizations, list_oauth_clients,
+    list_organization_members, list_organizations, list_sessions, list_users, login, logout,
+    logout_all, oauth_token, reactivate_user, refresh_token, register, register_oauth_client,
+    remove_organization_member, request_email_change, request_magic_link, resend_verification,
+    reset_password, revoke_api_key, revoke_invitation, revoke_session, set_organization_member,
+    start_impersonation, start_oidc_login, switch_organization, update_api_key, update_profile,
+    verify_email, verify_magic_link, verify_mfa_login,
+};
+use crate::presentation::middleware::{
+    auth_middleware, require_permission, require_user, RequirePermission,
+};
 use crate::AuthState;
 use axum::{
-    routing::{get, post},
+    middleware,
+    routing::{delete, get, patch, post, put},
     Router,
 };
 
 pub fn auth_routes_handler(state: AuthState) -> Router {
+    let protected = Router::new()
+        .route("/me", patch(update_profile))
+        .route("/me/email", post(request_email_change))
+        .route("/logout-all", post(logout_all))
+        .route("/impersonation/end", post(end_impersonation))
+        .route("/change-password", post(change_password))
+        .route("/mfa/totp/enroll", post(enroll_totp))
+        .route("/mfa/totp/confirm", post(confirm_totp))
+        .route("/sessions", get(list_sessions))
+        .route("/sessions/{id}", delete(revoke_session))
+        .route("/organizations", get(list_my_organizations))
+        .route("/organizations/active", put(switch_organization))
+        .route("/api-keys", get(list_api_keys).post(create_api_key))
+        .route(
+            "/api-keys/{id}",
+            patch(update_api_key).delete(revoke_api_key),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth_middleware,
+        ));
+
     Router::new()
         .route("/register", post(register))
         .route("/login", post(login))
+        .route("/mfa/verify", post(verify_mfa_login))
+        .route("/magic-link", post(request_magic_link))
+        .route("/magic-link/verify", post(verify_magic_link))
+        .route("/invitations/accept", post(accept_invitation))
+        .route("/refresh", post(refresh_token))
+        .route("/verify-email", post(verify_email))
+        .route("/resend-verification", post(resend_verification))
+        .route("/forgot-password", post(forgot_password))
+        .route("/reset-password", post(reset_password))
+        .route("/confirm-email-change", post(confirm_email_change))
+        .route("/oidc/{provider}/authorize", get(start_oidc_login))
+        .route("/oidc/{provider}/callback", post(complete_oidc_login))
         .route("/me", get(get_current_user))
         .route("/logout", post(logout))
+        .merge(protected)
+        .with_state(state)
+}
+
+/// Account administration, served under `/api/admin`. Every route needs a
+/// user with the `users:manage` permission; OAuth clients are refused even
+/// when granted that scope.
+pub fn admin_routes_handler(state: AuthState) -> Router {
+    Router::new()
+        .route("/users", get(list_users))
+        .route("/auth-events", get(list_auth_events))
+        .route("/users/{id}", delete(delete_user))
+        .route("/users/{id}/deactivate", post(deactivate_user))
+        .route("/users/{id}/reactivate", post(reactivate_user))
+        .route("/users/{id}/impersonate", post(start_impersonation))
+        .route(
+            "/invitations",
+            get(list_invitations).post(create_invitation),
+        )
+        .route("/invitations/{id}", delete(revoke_invitation))
+        .route(
+            "/oauth-clients",
+            get(list_oauth_clients).post(register_oauth_client),
+        )
+        .route("/oauth-clients/{id}", delete(disable_oauth_client))
+        .route(
+            "/organizations",
+            get(list_organizations).post(create_organization),
+        )
+        .route(
+            "/organizations/{id}/members",
+            get(list_organization_members),
+        )
+        .route(
+            "/organizations/{id}/members/{user_id}",
+            put(set_organization_member).delete(remove_organization_member),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            RequirePermission("users:manage"),
+            require_permission,
+        ))
+        .route_layer(middleware::from_fn(require_user))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth_middleware,
+        ))
+        .with_state(state)
+}
+
+/// Routes served at the root of the API, outside `/api/auth`.
+pub fn well_known_routes_handler(state: AuthState) -> Router {
+    Router::new()
+        .route("/.well-known/jwks.json", get(jwks))
+        .route("/oauth/token", post(oauth_token))
         .with_state(state)
 }
@@ -0,0 +1,58 @@
+use crate::domain::value_objects::ClientInfo;
+use crate::AuthState;
+use axum::extract::{ConnectInfo, FromRequestParts};
+use axum::http::{header::USER_AGENT, request::Parts};
+use std::convert::Infallible;
+use std::net::{IpAddr, SocketAddr};
+
+const MAX_USER_AGENT_LENGTH: usize = 512;
+
+/// Address and user agent of the client making the request.
+/// `X-Forwarded-For` is only honoured when the deployment says trusted
+/// proxies set it; otherwise the peer address of the connection is used.
+impl FromRequestParts<AuthState> for ClientInfo {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AuthState,
+    ) -> Result<Self, Self::Rejection> {
+        let user_agent = parts
+            .headers
+            .get(USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.chars().take(MAX_USER_AGENT_LENGTH).collect());
+
+        Ok(Self {
+            ip: client_ip(parts, state.trusted_proxy_hops),
+            user_agent,
+        })
+    }
+}
+
+/// Every trusted proxy appends the address it got the request from, so the
+/// client is `trusted_proxy_hops` entries from the right; anything further
+/// left was written by the client and is ignored.
+pub(crate) fn client_ip(parts: &Parts, trusted_proxy_hops: usize) -> Option<IpAddr> {
+    if trusted_proxy_hops > 0 {
+        let forwarded = parts
+            .headers
+            .get_all("X-Forwarded-For")
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .rev()
+            .take(trusted_proxy_hops)
+            .last()
+            .and_then(|value| value.trim().parse().ok());
+
+        if forwarded.is_some() {
+            return forwarded;
+        }
+    }
+
+    parts
+        .extensions
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip())
+}
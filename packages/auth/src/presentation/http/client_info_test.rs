@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+    use crate::presentation::http::client_info::client_ip;
+    use axum::extract::ConnectInfo;
+    use axum::http::{request::Parts, Request};
+    use std::net::{IpAddr, SocketAddr};
+
+    const PEER: &str = "10.0.0.2:41000";
+
+    fn parts(forwarded_for: &[&str]) -> Parts {
+        let mut builder = Request::builder();
+        for value in forwarded_for {
+            builder = builder.header("X-Forwarded-For", *value);
+        }
+        let (mut parts, _) = builder.body(()).unwrap().into_parts();
+        parts
+            .extensions
+            .insert(ConnectInfo(PEER.parse::<SocketAddr>().unwrap()));
+        parts
+    }
+
+    fn ip(value: &str) -> Option<IpAddr> {
+        Some(value.parse().unwrap())
+    }
+
+    #[test]
+    fn test_spoofed_forwarded_for_entry_is_ignored() {
+        let parts = parts(&["1.2.3.4, 203.0.113.7"]);
+
+        assert_eq!(client_ip(&parts, 1), ip("203.0.113.7"));
+    }
+
+    #[test]
+    fn test_forwarded_for_skips_trusted_proxy_hops() {
+        let parts = parts(&["1.2.3.4, 203.0.113.7", "10.0.0.1"]);
+
+        assert_eq!(client_ip(&parts, 2), ip("203.0.113.7"));
+    }
+
+    #[test]
+    fn test_forwarded_for_is_ignored_unless_trusted() {
+        let parts = parts(&["1.2.3.4"]);
+
+        assert_eq!(client_ip(&parts, 0), ip("10.0.0.2"));
+    }
+}
@@ -1,18 +1,107 @@
-use crate::application::dto::{AuthResponse, LoginRequest, RegisterRequest};
+use crate::application::auth_context::AuthContext;
+use crate::application::dto::{
+    AcceptInvitationRequest, AdminUserResponse, ApiKeyResponse, AuthEventResponse, AuthResponse,
+    ChangeEmailRequest, ChangePasswordRequest, ClientCredentialsRequest, ConfirmEmailChangeRequest,
+    ConfirmTotpRequest, CreateApiKeyRequest, CreateInvitationRequest, CreateOrganizationRequest,
+    CreatedApiKeyResponse, ForgotPasswordRequest, ImpersonationResponse, InvitationResponse,
+    ListAuthEventsQuery, ListUsersQuery, LoginRequest, LoginResponse, LogoutRequest,
+    MagicLinkRequest, MembershipResponse, MfaLoginRequest, OAuthClientResponse, OAuthErrorResponse,
+    OidcAuthorizationResponse, OidcCallbackRequest, OrganizationResponse, RecoveryCodesResponse,
+    RefreshTokenRequest, RegisterOAuthClientRequest, RegisterRequest,
+    RegisteredOAuthClientResponse, ResendVerificationRequest, ResetPasswordRequest,
+    SessionResponse, SetMemberRolesRequest, SwitchOrganizationRequest, TokenResponse,
+    TotpEnrollmentResponse, UpdateApiKeyRequest, UpdateProfileRequest, UserOrganizationResponse,
+    UserResponse, VerifyEmailRequest, VerifyMagicLinkRequest,
+};
+use crate::domain::value_objects::ClientInfo;
 use crate::AuthState;
-use axum::{extract::State, http::HeaderMap, response::IntoResponse, Json};
+use axum::{
+    extract::{rejection::FormRejection, Path, Query, State},
+    http::{header, HeaderMap, Method, StatusCode},
+    response::{IntoResponse, Response},
+    Extension, Form, Json,
+};
+use data_encoding::BASE64;
 use rust_reborn_contracts::{
-    common::response::created_with_message, common::success_with_message, AppError, Result,
+    common::response::{created_with_message, message},
+    common::success_with_message,
+    common::{PaginatedResponse, PaginationParams},
+    AppError, Result,
 };
+use std::sync::Arc;
+use uuid::Uuid;
 use validator::Validate;
 
+/// The bearer token, or the access token cookie of a browser session.
+fn access_token<'a>(state: &AuthState, method: &Method, headers: &'a HeaderMap) -> Result<&'a str> {
+    let Some(auth_header) = headers.get("Authorization") else {
+        let token = state
+            .session_cookies
+            .access_token(headers)
+            .ok_or_else(|| AppError::unauthorized("missing Authorization header"))?;
+        state.session_cookies.verify_csrf(method, headers)?;
+        return Ok(token);
+    };
+
+    auth_header
+        .to_str()
+        .ok()
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or_else(|| AppError::unauthorized("invalid Authorization header"))
+}
+
+/// Replaces the cookies of a browser session whose tokens were reissued.
+fn session_cookies(state: &AuthState, request: &HeaderMap, token: &mut TokenResponse) -> HeaderMap {
+    if state.session_cookies.requested(request) {
+        state.session_cookies.renew(request, token)
+    } else {
+        HeaderMap::new()
+    }
+}
+
+/// Moves the tokens of a completed login into cookies when the client
+/// asked for a cookie session.
+fn login_cookies(
+    state: &AuthState,
+    request: &HeaderMap,
+    response: &mut LoginResponse,
+) -> HeaderMap {
+    match response {
+        LoginResponse::Authenticated(auth) if state.session_cookies.requested(request) => {
+            state.session_cookies.start(&mut auth.token)
+        }
+        _ => HeaderMap::new(),
+    }
+}
+
+/// Credentials and second factors stay under the account owner's control:
+/// neither an API key nor an administrator impersonating the user can
+/// change them.
+fn require_account_owner(ctx: &dyn AuthContext) -> Result<i64> {
+    if ctx.api_key_id().is_some() {
+        return Err(AppError::forbidden("not allowed with an API key"));
+    }
+    if ctx.is_impersonating() {
+        return Err(AppError::forbidden(
+            "not allowed while impersonating a user",
+        ));
+    }
+
+    ctx.user_id()
+        .ok_or_else(|| AppError::unauthorized("authentication required"))
+}
+
 pub async fn register(
     State(state): State<AuthState>,
+    client: ClientInfo,
     Json(payload): Json<RegisterRequest>,
 ) -> Result<impl IntoResponse> {
     payload.validate()?;
 
-    let response = state.register_user_use_case.execute(payload).await?;
+    let response = state
+        .register_user_use_case
+        .execute(payload, client)
+        .await?;
 
     Ok(created_with_message(
         response,
@@ -20,41 +109,208 @@ pub async fn register(
     ))
 }
 
+#[utoipa::path(
+    post,
+    path = "/invitations/accept",
+    request_body = AcceptInvitationRequest,
+    responses(
+        (status = 201, description = "account created with the invited email (already verified) and role", body = UserResponse),
+        (status = 400, description = "Validation error, weak password, or invalid or expired invitation"),
+        (status = 409, description = "Email already registered or username taken")
+    ),
+    tag = "Authentication"
+)]
+pub async fn accept_invitation(
+    State(state): State<AuthState>,
+    client: ClientInfo,
+    Json(payload): Json<AcceptInvitationRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let user = state
+        .accept_invitation_use_case
+        .execute(payload, client)
+        .await?;
+
+    Ok(created_with_message(
+        user,
+        "invitation accepted, you can now log in",
+    ))
+}
+
 #[utoipa::path(
     post,
     path = "/login",
     request_body = LoginRequest,
     responses(
-        (status = 200, description = "login success", body = AuthResponse),
-        (status = 400, description = "Validation error")
+        (status = 200, description = "login success, or an MFA challenge when two-factor authentication is enabled", body = LoginResponse),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Invalid credentials"),
+        (status = 423, description = "Account temporarily locked after repeated failed logins"),
+        (status = 429, description = "Too many failed logins from this client address")
     ),
     tag = "Authentication"
 )]
 pub async fn login(
     State(state): State<AuthState>,
+    headers: HeaderMap,
+    client: ClientInfo,
     Json(payload): Json<LoginRequest>,
 ) -> Result<impl IntoResponse> {
     payload.validate()?;
 
-    let response = state.login_user_use_case.execute(payload).await?;
+    let mut response = state.login_user_use_case.execute(payload, client).await?;
+    let cookies = login_cookies(&state, &headers, &mut response);
 
-    Ok(Json(response))
+    Ok((cookies, Json(response)))
 }
 
-pub async fn get_current_user(
+#[utoipa::path(
+    post,
+    path = "/refresh",
+    request_body(content = Option<RefreshTokenRequest>, description = "left out by cookie sessions, which send the refresh token cookie"),
+    responses(
+        (status = 200, description = "token refreshed", body = TokenResponse),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Invalid, expired or reused refresh token"),
+        (status = 403, description = "Missing or invalid CSRF token")
+    ),
+    tag = "Authentication"
+)]
+pub async fn refresh_token(
     State(state): State<AuthState>,
+    method: Method,
     headers: HeaderMap,
+    payload: Option<Json<RefreshTokenRequest>>,
 ) -> Result<impl IntoResponse> {
-    let auth_header = headers
-        .get("Authorization")
-        .and_then(|value| value.to_str().ok())
-        .ok_or_else(|| AppError::unauthorized("missing Authorization header"))?;
+    // Browser sessions send no body; the refresh token is in its cookie.
+    let (payload, from_cookie) = match payload {
+        Some(Json(payload)) => (payload, false),
+        None => {
+            let refresh_token = state
+                .session_cookies
+                .refresh_token(&headers)
+                .ok_or_else(|| AppError::bad_request("refresh_token is required"))?;
+            state.session_cookies.verify_csrf(&method, &headers)?;
+            let payload = RefreshTokenRequest {
+                refresh_token: refresh_token.to_string(),
+            };
+            (payload, true)
+        }
+    };
+    payload.validate()?;
 
-    if !auth_header.starts_with("Bearer ") {
-        return Err(AppError::unauthorized("invalid Authorization header"));
-    }
+    let mut response = state.refresh_token_use_case.execute(payload).await?;
+    let cookies = if from_cookie || state.session_cookies.requested(&headers) {
+        state.session_cookies.renew(&headers, &mut response)
+    } else {
+        HeaderMap::new()
+    };
+
+    Ok((cookies, Json(response)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/verify-email",
+    request_body = VerifyEmailRequest,
+    responses(
+        (status = 200, description = "email address verified", body = UserResponse),
+        (status = 400, description = "Invalid or expired verification token")
+    ),
+    tag = "Authentication"
+)]
+pub async fn verify_email(
+    State(state): State<AuthState>,
+    Json(payload): Json<VerifyEmailRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let user = state.verify_email_use_case.execute(payload).await?;
+
+    Ok(success_with_message(user, "email verified successfully"))
+}
+
+#[utoipa::path(
+    post,
+    path = "/resend-verification",
+    request_body = ResendVerificationRequest,
+    responses(
+        (status = 200, description = "verification email sent if the account exists and is unverified"),
+        (status = 400, description = "Validation error")
+    ),
+    tag = "Authentication"
+)]
+pub async fn resend_verification(
+    State(state): State<AuthState>,
+    Json(payload): Json<ResendVerificationRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    state.resend_verification_use_case.execute(payload).await?;
+
+    Ok(message(
+        "if the account exists and is not verified yet, a new verification email has been sent",
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/forgot-password",
+    request_body = ForgotPasswordRequest,
+    responses(
+        (status = 200, description = "password reset email sent if the account exists"),
+        (status = 400, description = "Validation error")
+    ),
+    tag = "Authentication"
+)]
+pub async fn forgot_password(
+    State(state): State<AuthState>,
+    Json(payload): Json<ForgotPasswordRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    state
+        .request_password_reset_use_case
+        .execute(payload)
+        .await?;
+
+    Ok(message(
+        "if the account exists, a password reset email has been sent",
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/reset-password",
+    request_body = ResetPasswordRequest,
+    responses(
+        (status = 200, description = "password reset, all sessions signed out"),
+        (status = 400, description = "Invalid or expired reset token, or weak password")
+    ),
+    tag = "Authentication"
+)]
+pub async fn reset_password(
+    State(state): State<AuthState>,
+    client: ClientInfo,
+    Json(payload): Json<ResetPasswordRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    state
+        .reset_password_use_case
+        .execute(payload, client)
+        .await?;
 
-    let token = &auth_header[7..];
+    Ok(message("password has been reset, please log in again"))
+}
+
+pub async fn get_current_user(
+    State(state): State<AuthState>,
+    method: Method,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse> {
+    let token = access_token(&state, &method, &headers)?;
     let user_id = state.verify_token_use_case.execute(token).await?;
 
     let user = state.get_user_detail_use_case.execute(user_id).await?;
@@ -65,11 +321,1334 @@ pub async fn get_current_user(
     ))
 }
 
-pub async fn logout() -> Result<impl IntoResponse> {
-    // In a stateless JWT system, logout is typically handled client-side
-    // by removing the token from storage.
-    // For server-side logout, you'd need to implement token blacklisting.
-    Ok(Json(serde_json::json!({
-        "message": "logged out successfully"
-    })))
+#[utoipa::path(
+    patch,
+    path = "/me",
+    request_body = UpdateProfileRequest,
+    responses(
+        (status = 200, description = "profile updated", body = UserResponse),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Called with an API key or while impersonating a user"),
+        (status = 409, description = "Username already taken")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Authentication"
+)]
+pub async fn update_profile(
+    State(state): State<AuthState>,
+    Extension(ctx): Extension<Arc<dyn AuthContext>>,
+    Json(payload): Json<UpdateProfileRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let user_id = require_account_owner(ctx.as_ref())?;
+
+    let user = state
+        .update_profile_use_case
+        .execute(user_id, payload)
+        .await?;
+
+    Ok(success_with_message(user, "profile updated successfully"))
+}
+
+#[utoipa::path(
+    post,
+    path = "/me/email",
+    request_body = ChangeEmailRequest,
+    responses(
+        (status = 200, description = "confirmation link sent to the new address, current address stays active until then", body = UserResponse),
+        (status = 400, description = "Validation error or unchanged email"),
+        (status = 401, description = "Unauthorized or wrong current password"),
+        (status = 403, description = "Called with an API key or while impersonating a user"),
+        (status = 409, description = "Email already registered")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Authentication"
+)]
+pub async fn request_email_change(
+    State(state): State<AuthState>,
+    Extension(ctx): Extension<Arc<dyn AuthContext>>,
+    Json(payload): Json<ChangeEmailRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let user_id = require_account_owner(ctx.as_ref())?;
+
+    let user = state
+        .request_email_change_use_case
+        .execute(user_id, payload)
+        .await?;
+
+    Ok(success_with_message(
+        user,
+        "check the new email address for a confirmation link",
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/confirm-email-change",
+    request_body = ConfirmEmailChangeRequest,
+    responses(
+        (status = 200, description = "email address changed", body = UserResponse),
+        (status = 400, description = "Invalid or expired email change token"),
+        (status = 409, description = "Email was registered by another account in the meantime")
+    ),
+    tag = "Authentication"
+)]
+pub async fn confirm_email_change(
+    State(state): State<AuthState>,
+    Json(payload): Json<ConfirmEmailChangeRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let user = state.confirm_email_change_use_case.execute(payload).await?;
+
+    Ok(success_with_message(user, "email changed successfully"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/oidc/{provider}/authorize",
+    params(
+        ("provider" = String, Path, description = "Configured provider name")
+    ),
+    responses(
+        (status = 200, description = "provider login URL; redirect the browser there", body = OidcAuthorizationResponse),
+        (status = 404, description = "Unknown provider")
+    ),
+    tag = "Authentication"
+)]
+pub async fn start_oidc_login(
+    State(state): State<AuthState>,
+    Path(provider): Path<String>,
+) -> Result<impl IntoResponse> {
+    let response = state.start_oidc_login_use_case.execute(&provider).await?;
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    post,
+    path = "/oidc/{provider}/callback",
+    params(
+        ("provider" = String, Path, description = "Configured provider name")
+    ),
+    request_body = OidcCallbackRequest,
+    responses(
+        (status = 200, description = "signed in, or an MFA challenge when the account has a second factor", body = LoginResponse),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Invalid or expired state, or the provider rejected the code or ID token"),
+        (status = 403, description = "Account inactive, or no account linked and auto-provisioning is off"),
+        (status = 404, description = "Unknown provider"),
+        (status = 409, description = "An account with the same email exists and cannot be linked automatically")
+    ),
+    tag = "Authentication"
+)]
+pub async fn complete_oidc_login(
+    State(state): State<AuthState>,
+    Path(provider): Path<String>,
+    headers: HeaderMap,
+    client: ClientInfo,
+    Json(payload): Json<OidcCallbackRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let mut response = state
+        .complete_oidc_login_use_case
+        .execute(&provider, payload, client)
+        .await?;
+    let cookies = login_cookies(&state, &headers, &mut response);
+
+    Ok((cookies, Json(response)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/logout",
+    request_body(content = Option<LogoutRequest>, description = "refresh token to revoke along with the access token"),
+    responses(
+        (status = 200, description = "logged out"),
+        (status = 401, description = "Missing, invalid or already revoked token"),
+        (status = 403, description = "Missing or invalid CSRF token")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Authentication"
+)]
+pub async fn logout(
+    State(state): State<AuthState>,
+    method: Method,
+    headers: HeaderMap,
+    client: ClientInfo,
+    payload: Option<Json<LogoutRequest>>,
+) -> Result<impl IntoResponse> {
+    let token = access_token(&state, &method, &headers)?;
+    let refresh_token = payload.and_then(|Json(req)| req.refresh_token).or_else(|| {
+        state
+            .session_cookies
+            .refresh_token(&headers)
+            .map(str::to_string)
+    });
+
+    state
+        .logout_use_case
+        .execute(token, refresh_token, client)
+        .await?;
+
+    Ok((
+        state.session_cookies.clear(),
+        message("logged out successfully"),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/logout-all",
+    responses(
+        (status = 200, description = "every session of the user revoked"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Called with an API key or while impersonating a user")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Authentication"
+)]
+pub async fn logout_all(
+    State(state): State<AuthState>,
+    Extension(ctx): Extension<Arc<dyn AuthContext>>,
+    client: ClientInfo,
+) -> Result<impl IntoResponse> {
+    let user_id = require_account_owner(ctx.as_ref())?;
+
+    state
+        .logout_all_devices_use_case
+        .execute(user_id, client)
+        .await?;
+
+    Ok(message("logged out from all devices"))
+}
+
+#[utoipa::path(
+    post,
+    path = "/impersonation/end",
+    responses(
+        (status = 200, description = "impersonation token revoked"),
+        (status = 400, description = "Not an impersonation token"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Authentication"
+)]
+pub async fn end_impersonation(
+    State(state): State<AuthState>,
+    method: Method,
+    headers: HeaderMap,
+    client: ClientInfo,
+) -> Result<impl IntoResponse> {
+    let token = access_token(&state, &method, &headers)?;
+
+    state
+        .end_impersonation_use_case
+        .execute(token, client)
+        .await?;
+
+    Ok(message("impersonation ended"))
+}
+
+#[utoipa::path(
+    post,
+    path = "/change-password",
+    request_body = ChangePasswordRequest,
+    responses(
+        (status = 200, description = "password changed, other sessions signed out", body = TokenResponse),
+        (status = 400, description = "Weak or unchanged password"),
+        (status = 401, description = "Unauthorized or wrong current password"),
+        (status = 403, description = "Called with an API key or while impersonating a user")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Authentication"
+)]
+pub async fn change_password(
+    State(state): State<AuthState>,
+    Extension(ctx): Extension<Arc<dyn AuthContext>>,
+    headers: HeaderMap,
+    client: ClientInfo,
+    Json(payload): Json<ChangePasswordRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let user_id = require_account_owner(ctx.as_ref())?;
+
+    let mut token = state
+        .change_password_use_case
+        .execute(user_id, payload, client)
+        .await?;
+    let cookies = session_cookies(&state, &headers, &mut token);
+
+    Ok((
+        cookies,
+        success_with_message(token, "password changed successfully"),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/magic-link",
+    request_body = MagicLinkRequest,
+    responses(
+        (status = 200, description = "login link emailed if the account exists"),
+        (status = 400, description = "Validation error"),
+        (status = 403, description = "Magic link login is not enabled")
+    ),
+    tag = "Authentication"
+)]
+pub async fn request_magic_link(
+    State(state): State<AuthState>,
+    Json(payload): Json<MagicLinkRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    state.request_magic_link_use_case.execute(payload).await?;
+
+    Ok(message("if the account exists, a login link has been sent"))
+}
+
+#[utoipa::path(
+    post,
+    path = "/magic-link/verify",
+    request_body = VerifyMagicLinkRequest,
+    responses(
+        (status = 200, description = "login success, or an MFA challenge when two-factor authentication is enabled", body = LoginResponse),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Invalid, used or expired login link"),
+        (status = 403, description = "Account inactive, or magic link login is not enabled")
+    ),
+    tag = "Authentication"
+)]
+pub async fn verify_magic_link(
+    State(state): State<AuthState>,
+    headers: HeaderMap,
+    client: ClientInfo,
+    Json(payload): Json<VerifyMagicLinkRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let mut response = state
+        .verify_magic_link_use_case
+        .execute(payload, client)
+        .await?;
+    let cookies = login_cookies(&state, &headers, &mut response);
+
+    Ok((cookies, Json(response)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/mfa/verify",
+    request_body = MfaLoginRequest,
+    responses(
+        (status = 200, description = "second factor accepted", body = AuthResponse),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Invalid code, or invalid, expired or exhausted mfa token")
+    ),
+    tag = "Authentication"
+)]
+pub async fn verify_mfa_login(
+    State(state): State<AuthState>,
+    headers: HeaderMap,
+    client: ClientInfo,
+    Json(payload): Json<MfaLoginRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let mut response = state
+        .verify_mfa_login_use_case
+        .execute(payload, client)
+        .await?;
+    let cookies = if state.session_cookies.requested(&headers) {
+        state.session_cookies.start(&mut response.token)
+    } else {
+        HeaderMap::new()
+    };
+
+    Ok((cookies, Json(response)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/mfa/totp/enroll",
+    responses(
+        (status = 200, description = "TOTP secret generated, confirm it to enable two-factor authentication", body = TotpEnrollmentResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Called with an API key or while impersonating a user"),
+        (status = 409, description = "Two-factor authentication is already enabled")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Authentication"
+)]
+pub async fn enroll_totp(
+    State(state): State<AuthState>,
+    Extension(ctx): Extension<Arc<dyn AuthContext>>,
+) -> Result<impl IntoResponse> {
+    let user_id = require_account_owner(ctx.as_ref())?;
+
+    let response = state.enroll_totp_use_case.execute(user_id).await?;
+
+    Ok(success_with_message(
+        response,
+        "scan the QR code and confirm with a code",
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/mfa/totp/confirm",
+    request_body = ConfirmTotpRequest,
+    responses(
+        (status = 200, description = "two-factor authentication enabled", body = RecoveryCodesResponse),
+        (status = 400, description = "Invalid code or enrollment not started"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Called with an API key or while impersonating a user"),
+        (status = 409, description = "Two-factor authentication is already enabled")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Authentication"
+)]
+pub async fn confirm_totp(
+    State(state): State<AuthState>,
+    Extension(ctx): Extension<Arc<dyn AuthContext>>,
+    Json(payload): Json<ConfirmTotpRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let user_id = require_account_owner(ctx.as_ref())?;
+
+    let response = state
+        .confirm_totp_use_case
+        .execute(user_id, payload)
+        .await?;
+
+    Ok(success_with_message(
+        response,
+        "two-factor authentication enabled, store the recovery codes safely",
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/sessions",
+    responses(
+        (status = 200, description = "active sessions of the current user", body = [SessionResponse]),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Called with an API key or while impersonating a user")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Authentication"
+)]
+pub async fn list_sessions(
+    State(state): State<AuthState>,
+    Extension(ctx): Extension<Arc<dyn AuthContext>>,
+) -> Result<impl IntoResponse> {
+    let user_id = require_account_owner(ctx.as_ref())?;
+
+    let sessions = state
+        .list_sessions_use_case
+        .execute(user_id, ctx.session_id())
+        .await?;
+
+    Ok(success_with_message(
+        sessions,
+        "sessions fetched successfully",
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/sessions/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Session id")
+    ),
+    responses(
+        (status = 200, description = "session revoked, its tokens stop working"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Called with an API key or while impersonating a user"),
+        (status = 404, description = "Session not found")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Authentication"
+)]
+pub async fn revoke_session(
+    State(state): State<AuthState>,
+    Extension(ctx): Extension<Arc<dyn AuthContext>>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    let user_id = require_account_owner(ctx.as_ref())?;
+
+    state.revoke_session_use_case.execute(user_id, id).await?;
+
+    Ok(message("session revoked"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations",
+    responses(
+        (status = 200, description = "active organizations the current user belongs to, with their roles there", body = [UserOrganizationResponse]),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Called with an API key or while impersonating a user")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Authentication"
+)]
+pub async fn list_my_organizations(
+    State(state): State<AuthState>,
+    Extension(ctx): Extension<Arc<dyn AuthContext>>,
+) -> Result<impl IntoResponse> {
+    let user_id = require_account_owner(ctx.as_ref())?;
+
+    let organizations = state
+        .list_my_organizations_use_case
+        .execute(user_id)
+        .await?;
+
+    Ok(success_with_message(
+        organizations,
+        "organizations fetched successfully",
+    ))
+}
+
+#[utoipa::path(
+    put,
+    path = "/organizations/active",
+    request_body = SwitchOrganizationRequest,
+    responses(
+        (status = 200, description = "active organization changed; use the new access token", body = TokenResponse),
+        (status = 400, description = "The token has no session"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not a member of the organization, or called with an API key or while impersonating a user")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Authentication"
+)]
+pub async fn switch_organization(
+    State(state): State<AuthState>,
+    Extension(ctx): Extension<Arc<dyn AuthContext>>,
+    headers: HeaderMap,
+    Json(payload): Json<SwitchOrganizationRequest>,
+) -> Result<impl IntoResponse> {
+    let user_id = require_account_owner(ctx.as_ref())?;
+    let session_id = ctx
+        .session_id()
+        .ok_or_else(|| AppError::bad_request("the token has no session to switch"))?;
+
+    let mut token = state
+        .switch_organization_use_case
+        .execute(user_id, session_id, payload)
+        .await?;
+    let cookies = session_cookies(&state, &headers, &mut token);
+
+    Ok((
+        cookies,
+        success_with_message(token, "active organization changed"),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api-keys",
+    request_body = CreateApiKeyRequest,
+    responses(
+        (status = 201, description = "API key created; the key is only shown in this response", body = CreatedApiKeyResponse),
+        (status = 400, description = "Validation error or scopes you do not hold"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Called with an API key or while impersonating a user")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Authentication"
+)]
+pub async fn create_api_key(
+    State(state): State<AuthState>,
+    Extension(ctx): Extension<Arc<dyn AuthContext>>,
+    Json(payload): Json<CreateApiKeyRequest>,
+) -> Result<impl IntoResponse> {
+    let user_id = require_account_owner(ctx.as_ref())?;
+    payload.validate()?;
+
+    let response = state
+        .create_api_key_use_case
+        .execute(user_id, payload)
+        .await?;
+
+    Ok(created_with_message(response, "API key created"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api-keys",
+    responses(
+        (status = 200, description = "API keys of the current user that are not revoked", body = [ApiKeyResponse]),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearerAuth" = []),
+        ("apiKeyAuth" = [])
+    ),
+    tag = "Authentication"
+)]
+pub async fn list_api_keys(
+    State(state): State<AuthState>,
+    Extension(ctx): Extension<Arc<dyn AuthContext>>,
+) -> Result<impl IntoResponse> {
+    let user_id = ctx
+        .user_id()
+        .ok_or_else(|| AppError::unauthorized("authentication required"))?;
+
+    let keys = state.list_api_keys_use_case.execute(user_id).await?;
+
+    Ok(success_with_message(keys, "API keys fetched successfully"))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/api-keys/{id}",
+    params(
+        ("id" = i64, Path, description = "API key id")
+    ),
+    request_body = UpdateApiKeyRequest,
+    responses(
+        (status = 200, description = "API key updated", body = ApiKeyResponse),
+        (status = 400, description = "Validation error or scopes you do not hold"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Called with an API key or while impersonating a user"),
+        (status = 404, description = "API key not found")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Authentication"
+)]
+pub async fn update_api_key(
+    State(state): State<AuthState>,
+    Extension(ctx): Extension<Arc<dyn AuthContext>>,
+    Path(id): Path<i64>,
+    Json(payload): Json<UpdateApiKeyRequest>,
+) -> Result<impl IntoResponse> {
+    let user_id = require_account_owner(ctx.as_ref())?;
+    payload.validate()?;
+
+    let key = state
+        .update_api_key_use_case
+        .execute(user_id, id, payload)
+        .await?;
+
+    Ok(success_with_message(key, "API key updated"))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api-keys/{id}",
+    params(
+        ("id" = i64, Path, description = "API key id")
+    ),
+    responses(
+        (status = 200, description = "API key revoked, it stops working immediately"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "API key not found")
+    ),
+    security(
+        ("bearerAuth" = []),
+        ("apiKeyAuth" = [])
+    ),
+    tag = "Authentication"
+)]
+pub async fn revoke_api_key(
+    State(state): State<AuthState>,
+    Extension(ctx): Extension<Arc<dyn AuthContext>>,
+    Path(id): Path<i64>,
+) -> Result<impl IntoResponse> {
+    let user_id = ctx
+        .user_id()
+        .ok_or_else(|| AppError::unauthorized("authentication required"))?;
+
+    state.revoke_api_key_use_case.execute(user_id, id).await?;
+
+    Ok(message("API key revoked"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/users",
+    params(ListUsersQuery, PaginationParams),
+    responses(
+        (status = 200, description = "page of users, newest first", body = PaginatedResponse<AdminUserResponse>),
+        (status = 400, description = "Invalid filter or pagination"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Missing users:manage permission")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn list_users(
+    State(state): State<AuthState>,
+    Query(pagination): Query<PaginationParams>,
+    Query(query): Query<ListUsersQuery>,
+) -> Result<impl IntoResponse> {
+    pagination.validate()?;
+    query.validate()?;
+
+    let users = state.list_users_use_case.execute(query, pagination).await?;
+
+    Ok(success_with_message(users, "users fetched successfully"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/auth-events",
+    params(ListAuthEventsQuery, PaginationParams),
+    responses(
+        (status = 200, description = "page of audit events, newest first", body = PaginatedResponse<AuthEventResponse>),
+        (status = 400, description = "Invalid filter or pagination"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Missing users:manage permission")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn list_auth_events(
+    State(state): State<AuthState>,
+    Query(pagination): Query<PaginationParams>,
+    Query(query): Query<ListAuthEventsQuery>,
+) -> Result<impl IntoResponse> {
+    pagination.validate()?;
+
+    let events = state
+        .list_auth_events_use_case
+        .execute(query, pagination)
+        .await?;
+
+    Ok(success_with_message(
+        events,
+        "auth events fetched successfully",
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/users/{id}/deactivate",
+    params(
+        ("id" = i64, Path, description = "User id")
+    ),
+    responses(
+        (status = 200, description = "user deactivated and signed out everywhere", body = AdminUserResponse),
+        (status = 400, description = "Cannot deactivate your own account"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Missing users:manage permission"),
+        (status = 404, description = "User not found")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn deactivate_user(
+    State(state): State<AuthState>,
+    Extension(ctx): Extension<Arc<dyn AuthContext>>,
+    client: ClientInfo,
+    Path(id): Path<i64>,
+) -> Result<impl IntoResponse> {
+    let actor_id = ctx
+        .user_id()
+        .ok_or_else(|| AppError::unauthorized("authentication required"))?;
+
+    let user = state
+        .deactivate_user_use_case
+        .execute(actor_id, id, client)
+        .await?;
+
+    Ok(success_with_message(user, "user deactivated"))
+}
+
+#[utoipa::path(
+    post,
+    path = "/users/{id}/reactivate",
+    params(
+        ("id" = i64, Path, description = "User id")
+    ),
+    responses(
+        (status = 200, description = "user can log in again", body = AdminUserResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Missing users:manage permission"),
+        (status = 404, description = "User not found")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn reactivate_user(
+    State(state): State<AuthState>,
+    Path(id): Path<i64>,
+) -> Result<impl IntoResponse> {
+    let user = state.reactivate_user_use_case.execute(id).await?;
+
+    Ok(success_with_message(user, "user reactivated"))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/users/{id}",
+    params(
+        ("id" = i64, Path, description = "User id")
+    ),
+    responses(
+        (status = 200, description = "user soft-deleted and signed out everywhere"),
+        (status = 400, description = "Cannot delete your own account"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Missing users:manage permission"),
+        (status = 404, description = "User not found")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn delete_user(
+    State(state): State<AuthState>,
+    Extension(ctx): Extension<Arc<dyn AuthContext>>,
+    client: ClientInfo,
+    Path(id): Path<i64>,
+) -> Result<impl IntoResponse> {
+    let actor_id = ctx
+        .user_id()
+        .ok_or_else(|| AppError::unauthorized("authentication required"))?;
+
+    state
+        .delete_user_use_case
+        .execute(actor_id, id, client)
+        .await?;
+
+    Ok(message("user deleted"))
+}
+
+#[utoipa::path(
+    post,
+    path = "/users/{id}/impersonate",
+    params(
+        ("id" = i64, Path, description = "User id")
+    ),
+    responses(
+        (status = 200, description = "short-lived access token acting as the user", body = ImpersonationResponse),
+        (status = 400, description = "Cannot impersonate yourself or an inactive account"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Missing users:manage permission, called with an API key, or the user is an administrator"),
+        (status = 404, description = "User not found")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn start_impersonation(
+    State(state): State<AuthState>,
+    Extension(ctx): Extension<Arc<dyn AuthContext>>,
+    client: ClientInfo,
+    Path(id): Path<i64>,
+) -> Result<impl IntoResponse> {
+    if ctx.api_key_id().is_some() {
+        return Err(AppError::forbidden(
+            "impersonation cannot be started with an API key",
+        ));
+    }
+    let actor_id = require_account_owner(ctx.as_ref())?;
+
+    let response = state
+        .start_impersonation_use_case
+        .execute(actor_id, id, client)
+        .await?;
+
+    Ok(success_with_message(
+        response,
+        "impersonation started, use the access token to act as the user",
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/oauth-clients",
+    request_body = RegisterOAuthClientRequest,
+    responses(
+        (status = 201, description = "client registered; the secret is only shown once", body = RegisteredOAuthClientResponse),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Missing users:manage permission")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn register_oauth_client(
+    State(state): State<AuthState>,
+    Extension(ctx): Extension<Arc<dyn AuthContext>>,
+    Json(payload): Json<RegisterOAuthClientRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+    let actor_id = ctx
+        .user_id()
+        .ok_or_else(|| AppError::unauthorized("authentication required"))?;
+
+    let client = state
+        .register_oauth_client_use_case
+        .execute(actor_id, payload)
+        .await?;
+
+    Ok(created_with_message(
+        client,
+        "OAuth client registered, store the secret now",
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/oauth-clients",
+    responses(
+        (status = 200, description = "all registered OAuth clients", body = Vec<OAuthClientResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Missing users:manage permission")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn list_oauth_clients(State(state): State<AuthState>) -> Result<impl IntoResponse> {
+    let clients = state.list_oauth_clients_use_case.execute().await?;
+
+    Ok(success_with_message(
+        clients,
+        "OAuth clients fetched successfully",
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/oauth-clients/{id}",
+    params(
+        ("id" = i64, Path, description = "OAuth client id")
+    ),
+    responses(
+        (status = 200, description = "client disabled; tokens already issued stay valid until they expire"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Missing users:manage permission"),
+        (status = 404, description = "OAuth client not found")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn disable_oauth_client(
+    State(state): State<AuthState>,
+    Extension(ctx): Extension<Arc<dyn AuthContext>>,
+    Path(id): Path<i64>,
+) -> Result<impl IntoResponse> {
+    let actor_id = ctx
+        .user_id()
+        .ok_or_else(|| AppError::unauthorized("authentication required"))?;
+
+    state
+        .disable_oauth_client_use_case
+        .execute(actor_id, id)
+        .await?;
+
+    Ok(message("OAuth client disabled"))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations",
+    request_body = CreateOrganizationRequest,
+    responses(
+        (status = 201, description = "organization created", body = OrganizationResponse),
+        (status = 400, description = "Validation error or invalid slug"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Missing users:manage permission"),
+        (status = 409, description = "Slug already taken")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn create_organization(
+    State(state): State<AuthState>,
+    Json(payload): Json<CreateOrganizationRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let organization = state.create_organization_use_case.execute(payload).await?;
+
+    Ok(created_with_message(organization, "organization created"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations",
+    responses(
+        (status = 200, description = "all organizations", body = Vec<OrganizationResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Missing users:manage permission")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn list_organizations(State(state): State<AuthState>) -> Result<impl IntoResponse> {
+    let organizations = state.list_organizations_use_case.execute().await?;
+
+    Ok(success_with_message(
+        organizations,
+        "organizations fetched successfully",
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{id}/members",
+    params(
+        ("id" = i64, Path, description = "Organization id")
+    ),
+    responses(
+        (status = 200, description = "members of the organization with their roles there", body = Vec<MembershipResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Missing users:manage permission"),
+        (status = 404, description = "Organization not found")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn list_organization_members(
+    State(state): State<AuthState>,
+    Path(id): Path<i64>,
+) -> Result<impl IntoResponse> {
+    let members = state.list_organization_members_use_case.execute(id).await?;
+
+    Ok(success_with_message(
+        members,
+        "organization members fetched successfully",
+    ))
+}
+
+#[utoipa::path(
+    put,
+    path = "/organizations/{id}/members/{user_id}",
+    params(
+        ("id" = i64, Path, description = "Organization id"),
+        ("user_id" = i64, Path, description = "User id")
+    ),
+    request_body = SetMemberRolesRequest,
+    responses(
+        (status = 200, description = "user added to the organization or their roles there replaced", body = MembershipResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Missing users:manage permission"),
+        (status = 404, description = "Organization, user or role not found")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn set_organization_member(
+    State(state): State<AuthState>,
+    Path((id, user_id)): Path<(i64, i64)>,
+    Json(payload): Json<SetMemberRolesRequest>,
+) -> Result<impl IntoResponse> {
+    let membership = state
+        .set_organization_member_use_case
+        .execute(id, user_id, payload)
+        .await?;
+
+    Ok(success_with_message(
+        membership,
+        "organization member updated",
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/organizations/{id}/members/{user_id}",
+    params(
+        ("id" = i64, Path, description = "Organization id"),
+        ("user_id" = i64, Path, description = "User id")
+    ),
+    responses(
+        (status = 200, description = "user removed from the organization"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Missing users:manage permission"),
+        (status = 404, description = "Membership not found")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn remove_organization_member(
+    State(state): State<AuthState>,
+    Path((id, user_id)): Path<(i64, i64)>,
+) -> Result<impl IntoResponse> {
+    state
+        .remove_organization_member_use_case
+        .execute(id, user_id)
+        .await?;
+
+    Ok(message("organization member removed"))
+}
+
+#[utoipa::path(
+    post,
+    path = "/invitations",
+    request_body = CreateInvitationRequest,
+    responses(
+        (status = 201, description = "invitation emailed; an earlier open invitation for the address is revoked", body = InvitationResponse),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Missing users:manage permission"),
+        (status = 404, description = "Role not found"),
+        (status = 409, description = "Email already registered")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn create_invitation(
+    State(state): State<AuthState>,
+    Extension(ctx): Extension<Arc<dyn AuthContext>>,
+    Json(payload): Json<CreateInvitationRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+    let actor_id = ctx
+        .user_id()
+        .ok_or_else(|| AppError::unauthorized("authentication required"))?;
+
+    let invitation = state
+        .create_invitation_use_case
+        .execute(actor_id, payload)
+        .await?;
+
+    Ok(created_with_message(invitation, "invitation sent"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/invitations",
+    responses(
+        (status = 200, description = "all invitations, newest first", body = Vec<InvitationResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Missing users:manage permission")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn list_invitations(State(state): State<AuthState>) -> Result<impl IntoResponse> {
+    let invitations = state.list_invitations_use_case.execute().await?;
+
+    Ok(success_with_message(
+        invitations,
+        "invitations fetched successfully",
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/invitations/{id}",
+    params(
+        ("id" = i64, Path, description = "Invitation id")
+    ),
+    responses(
+        (status = 200, description = "invitation revoked, its link stops working"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Missing users:manage permission"),
+        (status = 404, description = "Invitation not found or already accepted")
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+    tag = "Admin"
+)]
+pub async fn revoke_invitation(
+    State(state): State<AuthState>,
+    Path(id): Path<i64>,
+) -> Result<impl IntoResponse> {
+    state.revoke_invitation_use_case.execute(id).await?;
+
+    Ok(message("invitation revoked"))
+}
+
+/// Error of the token endpoint, answered in the shape RFC 6749 prescribes
+/// instead of the usual API envelope.
+pub struct OAuthError {
+    status: StatusCode,
+    error: &'static str,
+    description: String,
+}
+
+impl OAuthError {
+    fn new(status: StatusCode, error: &'static str, description: impl Into<String>) -> Self {
+        Self {
+            status,
+            error,
+            description: description.into(),
+        }
+    }
+}
+
+impl From<AppError> for OAuthError {
+    fn from(err: AppError) -> Self {
+        match err {
+            AppError::Unauthorized(msg) => {
+                Self::new(StatusCode::UNAUTHORIZED, "invalid_client", msg)
+            }
+            AppError::BadRequest(msg) => Self::new(StatusCode::BAD_REQUEST, "invalid_scope", msg),
+            other => {
+                tracing::error!("token endpoint failed: {:?}", other);
+                Self::new(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "server_error",
+                    "internal server error",
+                )
+            }
+        }
+    }
+}
+
+impl IntoResponse for OAuthError {
+    fn into_response(self) -> Response {
+        let body = Json(OAuthErrorResponse {
+            error: self.error,
+            error_description: self.description,
+        });
+        let mut response = (self.status, body).into_response();
+        if self.status == StatusCode::UNAUTHORIZED {
+            response
+                .headers_mut()
+                .insert(header::WWW_AUTHENTICATE, "Basic".parse().unwrap());
+        }
+        response
+    }
+}
+
+/// Client id and secret from HTTP Basic authentication, falling back to
+/// the form body. Basic credentials are form-urlencoded before being
+/// joined (RFC 6749 section 2.3.1).
+fn client_credentials(
+    headers: &HeaderMap,
+    form: &ClientCredentialsRequest,
+) -> std::result::Result<(String, String), OAuthError> {
+    let invalid_client =
+        |msg: &str| OAuthError::new(StatusCode::UNAUTHORIZED, "invalid_client", msg);
+
+    if let Some(value) = headers.get(header::AUTHORIZATION) {
+        let encoded = value
+            .to_str()
+            .ok()
+            .and_then(|v| v.strip_prefix("Basic "))
+            .ok_or_else(|| invalid_client("unsupported client authentication"))?;
+        let decoded = BASE64
+            .decode(encoded.trim().as_bytes())
+            .ok()
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .ok_or_else(|| invalid_client("malformed Basic credentials"))?;
+        let (id, secret) = decoded
+            .split_once(':')
+            .and_then(|(id, secret)| Some((form_urldecode(id)?, form_urldecode(secret)?)))
+            .ok_or_else(|| invalid_client("malformed Basic credentials"))?;
+        return Ok((id, secret));
+    }
+
+    match (&form.client_id, &form.client_secret) {
+        (Some(id), Some(secret)) => Ok((id.clone(), secret.clone())),
+        _ => Err(invalid_client("client authentication required")),
+    }
+}
+
+fn form_urldecode(value: &str) -> Option<String> {
+    percent_encoding::percent_decode_str(&value.replace('+', " "))
+        .decode_utf8()
+        .ok()
+        .map(|decoded| decoded.into_owned())
+}
+
+/// OAuth 2.0 token endpoint, served at `/oauth/token`. Only the
+/// `client_credentials` grant is supported.
+pub async fn oauth_token(
+    State(state): State<AuthState>,
+    headers: HeaderMap,
+    form: std::result::Result<Form<ClientCredentialsRequest>, FormRejection>,
+) -> std::result::Result<impl IntoResponse, OAuthError> {
+    let Form(form) = form
+        .map_err(|e| OAuthError::new(StatusCode::BAD_REQUEST, "invalid_request", e.body_text()))?;
+
+    if form.grant_type != "client_credentials" {
+        return Err(OAuthError::new(
+            StatusCode::BAD_REQUEST,
+            "unsupported_grant_type",
+            "only the client_credentials grant is supported",
+        ));
+    }
+
+    let (client_id, client_secret) = client_credentials(&headers, &form)?;
+    let token = state
+        .issue_client_token_use_case
+        .execute(&client_id, &client_secret, form.scope.as_deref())
+        .await?;
+
+    Ok((
+        [
+            (header::CACHE_CONTROL, "no-store"),
+            (header::PRAGMA, "no-cache"),
+        ],
+        Json(token),
+    ))
+}
+
+/// Public keys for verifying access tokens. Empty when tokens are signed
+/// with the HS256 shared secret.
+pub async fn jwks(State(state): State<AuthState>) -> impl IntoResponse {
+    Json(state.jwt_service.jwks())
 }
@@ -1,3 +1,8 @@
+pub mod admin_openapi;
 pub mod auth_openapi;
 pub mod auth_routes;
+pub mod client_info;
+pub mod client_info_test;
 pub mod handlers;
+pub mod session_cookies;
+pub mod session_cookies_test;
@@ -0,0 +1,201 @@
+use crate::application::dto::TokenResponse;
+use crate::infrastructure::config::{SameSite, SessionCookiesConfig};
+use axum::http::{header, HeaderMap, HeaderValue, Method};
+use rand::RngCore;
+use rust_reborn_contracts::{AppError, Result};
+
+/// Sent by browser clients that want their session in cookies.
+pub const SESSION_MODE_HEADER: &str = "X-Session-Mode";
+/// Echoes the CSRF cookie on unsafe requests authenticated by cookie.
+pub const CSRF_HEADER: &str = "X-CSRF-Token";
+
+/// Access and refresh tokens in HttpOnly cookies, guarded by a
+/// double-submit CSRF token: the frontend copies the readable CSRF cookie
+/// into `X-CSRF-Token`, which a cross-site page cannot do.
+pub struct SessionCookies {
+    config: SessionCookiesConfig,
+    refresh_max_age: chrono::Duration,
+}
+
+impl SessionCookies {
+    pub fn new(config: SessionCookiesConfig, refresh_max_age: chrono::Duration) -> Self {
+        Self {
+            config,
+            refresh_max_age,
+        }
+    }
+
+    /// Whether this request asked for its tokens in cookies.
+    pub fn requested(&self, headers: &HeaderMap) -> bool {
+        self.config.enabled
+            && headers
+                .get(SESSION_MODE_HEADER)
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|mode| mode.eq_ignore_ascii_case("cookie"))
+    }
+
+    /// Cookies for a new session, with a fresh CSRF token. The tokens are
+    /// taken out of the response body.
+    pub fn start(&self, token: &mut TokenResponse) -> HeaderMap {
+        self.issue(token, generate_csrf_token())
+    }
+
+    /// Cookies for tokens reissued within a session; the CSRF token the
+    /// client already holds stays valid.
+    pub fn renew(&self, request: &HeaderMap, token: &mut TokenResponse) -> HeaderMap {
+        let csrf = self
+            .read(request, &self.config.csrf_cookie)
+            .map(str::to_string)
+            .unwrap_or_else(generate_csrf_token);
+        self.issue(token, csrf)
+    }
+
+    /// Expires every session cookie.
+    pub fn clear(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        if !self.config.enabled {
+            return headers;
+        }
+
+        let config = &self.config;
+        self.append(&mut headers, &config.access_cookie, "", "/", 0, true);
+        let refresh_path = config.refresh_path.as_str();
+        self.append(
+            &mut headers,
+            &config.refresh_cookie,
+            "",
+            refresh_path,
+            0,
+            true,
+        );
+        self.append(&mut headers, &config.csrf_cookie, "", "/", 0, false);
+        headers
+    }
+
+    pub fn access_token<'a>(&self, headers: &'a HeaderMap) -> Option<&'a str> {
+        self.read(headers, &self.config.access_cookie)
+    }
+
+    pub fn refresh_token<'a>(&self, headers: &'a HeaderMap) -> Option<&'a str> {
+        self.read(headers, &self.config.refresh_cookie)
+    }
+
+    /// Browsers attach cookies to cross-site requests too, so a request
+    /// authenticated by cookie that changes anything must prove it came
+    /// from the frontend.
+    pub fn verify_csrf(&self, method: &Method, headers: &HeaderMap) -> Result<()> {
+        if matches!(
+            *method,
+            Method::GET | Method::HEAD | Method::OPTIONS | Method::TRACE
+        ) {
+            return Ok(());
+        }
+
+        let cookie = self.read(headers, &self.config.csrf_cookie);
+        let header = headers
+            .get(CSRF_HEADER)
+            .and_then(|value| value.to_str().ok());
+
+        match (cookie, header) {
+            (Some(cookie), Some(header))
+                if constant_time_eq(cookie.as_bytes(), header.as_bytes()) =>
+            {
+                Ok(())
+            }
+            _ => Err(AppError::forbidden("missing or invalid CSRF token")),
+        }
+    }
+
+    fn issue(&self, token: &mut TokenResponse, csrf: String) -> HeaderMap {
+        let config = &self.config;
+        let session_age = self.refresh_max_age.num_seconds();
+        let mut headers = HeaderMap::new();
+
+        let access_token = std::mem::take(&mut token.access_token);
+        self.append(
+            &mut headers,
+            &config.access_cookie,
+            &access_token,
+            "/",
+            token.expires_in,
+            true,
+        );
+        if let Some(refresh_token) = token.refresh_token.take() {
+            self.append(
+                &mut headers,
+                &config.refresh_cookie,
+                &refresh_token,
+                &config.refresh_path,
+                session_age,
+                true,
+            );
+        }
+        self.append(
+            &mut headers,
+            &config.csrf_cookie,
+            &csrf,
+            "/",
+            session_age,
+            false,
+        );
+
+        headers
+    }
+
+    fn append(
+        &self,
+        headers: &mut HeaderMap,
+        name: &str,
+        value: &str,
+        path: &str,
+        max_age: i64,
+        http_only: bool,
+    ) {
+        let mut cookie = format!("{}={}; Path={}; Max-Age={}", name, value, path, max_age);
+        if let Some(domain) = &self.config.domain {
+            cookie.push_str(&format!("; Domain={}", domain));
+        }
+        if http_only {
+            cookie.push_str("; HttpOnly");
+        }
+        if self.config.secure {
+            cookie.push_str("; Secure");
+        }
+        cookie.push_str(match self.config.same_site {
+            SameSite::Strict => "; SameSite=Strict",
+            SameSite::Lax => "; SameSite=Lax",
+            SameSite::None => "; SameSite=None",
+        });
+
+        // Tokens are base64url or hex, so the value is always a valid header.
+        if let Ok(value) = HeaderValue::from_str(&cookie) {
+            headers.append(header::SET_COOKIE, value);
+        }
+    }
+
+    fn read<'a>(&self, headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+        if !self.config.enabled {
+            return None;
+        }
+
+        headers
+            .get_all(header::COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(';'))
+            .filter_map(|pair| pair.trim().split_once('='))
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value)
+            .filter(|value| !value.is_empty())
+    }
+}
+
+fn generate_csrf_token() -> String {
+    let mut bytes = [0u8; 32];
+    rand::rng().fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::dto::TokenResponse;
+    use crate::infrastructure::config::SessionCookiesConfig;
+    use crate::presentation::http::session_cookies::SessionCookies;
+    use axum::http::{header, HeaderMap, HeaderValue, Method};
+    use rust_reborn_contracts::AppError;
+
+    fn cookies(enabled: bool) -> SessionCookies {
+        SessionCookies::new(
+            SessionCookiesConfig {
+                enabled,
+                ..SessionCookiesConfig::default()
+            },
+            chrono::Duration::days(7),
+        )
+    }
+
+    fn token() -> TokenResponse {
+        TokenResponse {
+            access_token: "access.jwt".to_string(),
+            token_type: "Bearer".to_string(),
+            expires_in: 3600,
+            refresh_token: Some("refresh-token".to_string()),
+        }
+    }
+
+    fn set_cookies(headers: &HeaderMap) -> Vec<String> {
+        headers
+            .get_all(header::SET_COOKIE)
+            .iter()
+            .map(|value| value.to_str().unwrap().to_string())
+            .collect()
+    }
+
+    /// What the browser sends back after `set`.
+    fn request(set: &[String], extra: &[(&'static str, &str)]) -> HeaderMap {
+        let cookie = set
+            .iter()
+            .map(|c| c.split(';').next().unwrap())
+            .collect::<Vec<_>>()
+            .join("; ");
+        let mut headers = HeaderMap::new();
+        headers.insert(header::COOKIE, HeaderValue::from_str(&cookie).unwrap());
+        for (name, value) in extra {
+            headers.insert(*name, HeaderValue::from_str(value).unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn test_login_moves_tokens_into_cookies() {
+        let cookies = cookies(true);
+        let mut token = token();
+
+        let set = set_cookies(&cookies.start(&mut token));
+
+        assert!(token.access_token.is_empty());
+        assert!(token.refresh_token.is_none());
+        assert_eq!(token.expires_in, 3600);
+        let body = serde_json::to_value(&token).unwrap();
+        assert!(body.get("access_token").is_none());
+
+        assert_eq!(
+            set[0],
+            "access_token=access.jwt; Path=/; Max-Age=3600; HttpOnly; Secure; SameSite=Strict"
+        );
+        assert_eq!(
+            set[1],
+            "refresh_token=refresh-token; Path=/api/auth; Max-Age=604800; HttpOnly; Secure; SameSite=Strict"
+        );
+        assert!(set[2].starts_with("csrf_token="));
+        assert!(!set[2].contains("HttpOnly"));
+
+        let headers = request(&set, &[]);
+        assert_eq!(cookies.access_token(&headers), Some("access.jwt"));
+        assert_eq!(cookies.refresh_token(&headers), Some("refresh-token"));
+    }
+
+    #[test]
+    fn test_unsafe_methods_need_the_csrf_token() {
+        let cookies = cookies(true);
+        let set = set_cookies(&cookies.start(&mut token()));
+        let csrf = set[2]
+            .split(';')
+            .next()
+            .unwrap()
+            .trim_start_matches("csrf_token=")
+            .to_string();
+
+        let without_header = request(&set, &[]);
+        assert!(cookies.verify_csrf(&Method::GET, &without_header).is_ok());
+        let result = cookies.verify_csrf(&Method::POST, &without_header);
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+
+        let forged = request(&set, &[("X-CSRF-Token", "0123abcd")]);
+        assert!(cookies.verify_csrf(&Method::DELETE, &forged).is_err());
+
+        let echoed = request(&set, &[("X-CSRF-Token", &csrf)]);
+        assert!(cookies.verify_csrf(&Method::DELETE, &echoed).is_ok());
+
+        // Renewing keeps the token the frontend already read.
+        let renewed = set_cookies(&cookies.renew(&echoed, &mut token()));
+        assert!(renewed[2].starts_with(&format!("csrf_token={};", csrf)));
+    }
+
+    #[test]
+    fn test_cookie_mode_is_opt_in() {
+        let opted_in = request(&[], &[("X-Session-Mode", "cookie")]);
+        assert!(cookies(true).requested(&opted_in));
+        assert!(!cookies(false).requested(&opted_in));
+        assert!(!cookies(true).requested(&HeaderMap::new()));
+
+        let set = set_cookies(&cookies(true).start(&mut token()));
+        assert_eq!(cookies(false).access_token(&request(&set, &[])), None);
+        assert!(cookies(false).clear().is_empty());
+
+        let cleared = set_cookies(&cookies(true).clear());
+        assert_eq!(cleared.len(), 3);
+        assert!(cleared
+            .iter()
+            .all(|c| c.contains("=; ") && c.contains("Max-Age=0")));
+    }
+}
@@ -1,35 +1,140 @@
 use crate::application::auth_context::AuthContext;
-use crate::infrastructure::jwt::JwtService;
 use crate::presentation::request_auth_context::RequestAuthContext;
+use crate::AuthState;
 use axum::{
     extract::{Request, State},
-    http::header,
+    http::{header, HeaderMap},
     middleware::Next,
     response::Response,
 };
-use rust_reborn_contracts::AppError;
+use rust_reborn_contracts::{AppError, Result};
 use std::sync::Arc;
 
+/// Names the organization a request acts in, by slug.
+pub(crate) const TENANT_HEADER: &str = "X-Tenant";
+
+/// Credential presented with a request.
+pub(crate) enum Credential<'a> {
+    /// `Authorization: Bearer <access token>`, or the access token cookie
+    Bearer(&'a str),
+    /// `X-API-Key: <key>` or `Authorization: ApiKey <key>`
+    ApiKey(&'a str),
+}
+
+impl<'a> Credential<'a> {
+    /// `Ok(None)` when the request carries no credential at all.
+    pub(crate) fn from_headers(headers: &'a HeaderMap) -> Result<Option<Self>> {
+        if let Some(key) = headers.get("X-API-Key") {
+            let key = key
+                .to_str()
+                .map_err(|_| AppError::unauthorized("invalid X-API-Key header"))?;
+            return Ok(Some(Self::ApiKey(key)));
+        }
+
+        let Some(auth_header) = headers.get(header::AUTHORIZATION) else {
+            return Ok(None);
+        };
+        let auth_header = auth_header
+            .to_str()
+            .map_err(|_| AppError::unauthorized("invalid Authorization header"))?;
+
+        if let Some(token) = auth_header.strip_prefix("Bearer ") {
+            Ok(Some(Self::Bearer(token)))
+        } else if let Some(key) = auth_header.strip_prefix("ApiKey ") {
+            Ok(Some(Self::ApiKey(key)))
+        } else {
+            Err(AppError::unauthorized("invalid Authorization header"))
+        }
+    }
+
+    /// Falls back to the session cookie when the headers carry no
+    /// credential; unsafe methods must then echo the CSRF token.
+    pub(crate) fn from_request(state: &AuthState, request: &'a Request) -> Result<Option<Self>> {
+        if let Some(credential) = Self::from_headers(request.headers())? {
+            return Ok(Some(credential));
+        }
+
+        let Some(token) = state.session_cookies.access_token(request.headers()) else {
+            return Ok(None);
+        };
+        state
+            .session_cookies
+            .verify_csrf(request.method(), request.headers())?;
+        Ok(Some(Self::Bearer(token)))
+    }
+
+    pub(crate) async fn authenticate(self, state: &AuthState) -> Result<RequestAuthContext> {
+        match self {
+            Self::Bearer(token) => {
+                let claims =
+                    state
+                        .verify_token_use_case
+                        .verify(token)
+                        .await
+                        .map_err(|e| match e {
+                            AppError::Unauthorized(_) => {
+                                AppError::unauthorized("invalid or expired token")
+                            }
+                            other => other,
+                        })?;
+                RequestAuthContext::from_claims(claims)
+            }
+            Self::ApiKey(key) => {
+                let principal = state.authenticate_api_key_use_case.execute(key).await?;
+                Ok(RequestAuthContext::from_api_key(principal))
+            }
+        }
+    }
+}
+
+/// Settles the tenant of a user request: the one named by the `X-Tenant`
+/// header or subdomain, else the token's active tenant. Service clients
+/// act outside any organization.
+pub(crate) async fn resolve_tenant(
+    state: &AuthState,
+    headers: &HeaderMap,
+    mut ctx: RequestAuthContext,
+) -> Result<RequestAuthContext> {
+    let Some(user_id) = ctx.user_id() else {
+        return Ok(ctx);
+    };
+
+    let requested = match headers.get(TENANT_HEADER) {
+        Some(value) => Some(
+            value
+                .to_str()
+                .map_err(|_| AppError::bad_request("invalid X-Tenant header"))?
+                .to_string(),
+        ),
+        None => headers
+            .get(header::HOST)
+            .and_then(|host| host.to_str().ok())
+            .and_then(|host| state.tenant_resolver.slug_from_host(host)),
+    };
+
+    if let Some(membership) = state
+        .tenant_resolver
+        .resolve(user_id, requested.as_deref(), ctx.claimed_tenant())
+        .await?
+    {
+        ctx.enter_tenant(&membership);
+    }
+
+    Ok(ctx)
+}
+
 pub async fn auth_middleware(
-    State(jwt): State<Arc<JwtService>>,
+    State(state): State<AuthState>,
     mut request: Request,
     next: Next,
-) -> Result<Response, AppError> {
-    let auth_header = request
-        .headers()
-        .get(header::AUTHORIZATION)
-        .and_then(|value| value.to_str().ok())
-        .ok_or_else(|| AppError::unauthorized("missing Authorization header"))?;
-
-    let token = auth_header
-        .strip_prefix("Bearer ")
-        .ok_or_else(|| AppError::unauthorized("invalid Authorization header"))?;
-
-    let user_id = jwt
-        .verify_token(token)
-        .map_err(|_| AppError::unauthorized("invalid or expired token"))?;
-
-    let ctx: Arc<dyn AuthContext> = Arc::new(RequestAuthContext::authenticated(user_id));
+) -> Result<Response> {
+    let ctx = Credential::from_request(&state, &request)?
+        .ok_or_else(|| AppError::unauthorized("missing Authorization header"))?
+        .authenticate(&state)
+        .await?;
+    let ctx = resolve_tenant(&state, request.headers(), ctx).await?;
+
+    let ctx: Arc<dyn AuthContext> = Arc::new(ctx);
 
     request.extensions_mut().insert(ctx);
 
@@ -1,5 +1,11 @@
 pub mod auth_middleware;
 pub mod optional_auth_middleware;
+pub mod require_permission;
+pub mod require_permission_test;
 
 pub use auth_middleware::auth_middleware;
 pub use optional_auth_middleware::optional_auth_middleware;
+pub use require_permission::{
+    require_permission, require_tenant_permission, require_user, RequirePermission,
+    RequireTenantPermission,
+};
@@ -1,28 +1,31 @@
 use crate::application::auth_context::AuthContext;
-use crate::infrastructure::jwt::JwtService;
+use crate::presentation::middleware::auth_middleware::{resolve_tenant, Credential};
 use crate::presentation::request_auth_context::RequestAuthContext;
+use crate::AuthState;
 use axum::{
     extract::{Request, State},
-    http::header,
     middleware::Next,
     response::Response,
 };
 use std::sync::Arc;
 
 pub async fn optional_auth_middleware(
-    State(jwt): State<Arc<JwtService>>,
+    State(state): State<AuthState>,
     mut request: Request,
     next: Next,
 ) -> Response {
-    let ctx: Arc<dyn AuthContext> = request
-        .headers()
-        .get(header::AUTHORIZATION)
-        .and_then(|header_value| header_value.to_str().ok())
-        .and_then(|value| value.strip_prefix("Bearer "))
-        .and_then(|token| jwt.verify_token(token).ok())
-        .map(RequestAuthContext::authenticated)
-        .map(|ctx| Arc::new(ctx) as Arc<dyn AuthContext>)
-        .unwrap_or_else(|| Arc::new(RequestAuthContext::anonymous()));
+    let ctx = match Credential::from_request(&state, &request) {
+        Ok(Some(credential)) => match credential.authenticate(&state).await {
+            Ok(ctx) => resolve_tenant(&state, request.headers(), ctx).await.ok(),
+            Err(_) => None,
+        },
+        _ => None,
+    };
+
+    let ctx: Arc<dyn AuthContext> = match ctx {
+        Some(ctx) => Arc::new(ctx),
+        None => Arc::new(RequestAuthContext::anonymous()),
+    };
 
     request.extensions_mut().insert(ctx);
 
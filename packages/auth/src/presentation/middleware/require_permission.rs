@@ -0,0 +1,88 @@
+use crate::application::auth_context::{AuthContext, Principal};
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::Response,
+};
+use rust_reborn_contracts::AppError;
+use std::sync::Arc;
+
+/// Global permission a route requires, used as the state of
+/// [`require_permission`]. Roles held in an organization do not count.
+/// Must be layered inside `auth_middleware`, which provides the `AuthContext`.
+///
+/// ```ignore
+/// router.layer(middleware::from_fn_with_state(
+///     RequirePermission("users:manage"),
+///     require_permission,
+/// ))
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RequirePermission(pub &'static str);
+
+/// Permission for resources of the active organization, used as the state
+/// of [`require_tenant_permission`]. Granted globally or by a role held in
+/// that organization.
+#[derive(Debug, Clone, Copy)]
+pub struct RequireTenantPermission(pub &'static str);
+
+pub async fn require_permission(
+    State(RequirePermission(permission)): State<RequirePermission>,
+    request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    check(&request, permission, |ctx| ctx.has_permission(permission))?;
+
+    Ok(next.run(request).await)
+}
+
+pub async fn require_tenant_permission(
+    State(RequireTenantPermission(permission)): State<RequireTenantPermission>,
+    request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    check(&request, permission, |ctx| {
+        ctx.has_tenant_permission(permission)
+    })?;
+
+    Ok(next.run(request).await)
+}
+
+/// Turns away OAuth clients, whatever their scopes: routes behind it act
+/// for a person, such as account administration.
+pub async fn require_user(request: Request, next: Next) -> Result<Response, AppError> {
+    let ctx = request
+        .extensions()
+        .get::<Arc<dyn AuthContext>>()
+        .ok_or_else(|| AppError::unauthorized("authentication required"))?;
+
+    match ctx.principal() {
+        Principal::User(_) => Ok(next.run(request).await),
+        Principal::Service(_) => Err(AppError::forbidden("not allowed for service clients")),
+        Principal::Anonymous => Err(AppError::unauthorized("authentication required")),
+    }
+}
+
+fn check(
+    request: &Request,
+    permission: &str,
+    granted: impl Fn(&dyn AuthContext) -> bool,
+) -> Result<(), AppError> {
+    let ctx = request
+        .extensions()
+        .get::<Arc<dyn AuthContext>>()
+        .ok_or_else(|| AppError::unauthorized("authentication required"))?;
+
+    if ctx.principal() == Principal::Anonymous {
+        return Err(AppError::unauthorized("authentication required"));
+    }
+
+    if !granted(ctx.as_ref()) {
+        return Err(AppError::forbidden(format!(
+            "missing permission: {}",
+            permission
+        )));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::port::auth_context::AuthContext;
+    use crate::presentation::context::request_auth_context::RequestAuthContext;
+    use crate::presentation::middleware::{require_permission, require_user, RequirePermission};
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use axum::routing::get;
+    use axum::{middleware, Extension, Router};
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    /// The admin router's guards, with `ctx` standing in for `auth_middleware`.
+    fn admin_router(ctx: RequestAuthContext) -> Router {
+        let ctx: Arc<dyn AuthContext> = Arc::new(ctx);
+
+        Router::new()
+            .route("/users", get(|| async { "users" }))
+            .route_layer(middleware::from_fn_with_state(
+                RequirePermission("users:manage"),
+                require_permission,
+            ))
+            .route_layer(middleware::from_fn(require_user))
+            .layer(Extension(ctx))
+    }
+
+    async fn status(ctx: RequestAuthContext) -> StatusCode {
+        let request = Request::get("/users").body(Body::empty()).unwrap();
+        admin_router(ctx).oneshot(request).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn test_admin_routes_accept_users_with_permission() {
+        let ctx = RequestAuthContext::authenticated(1, vec![], vec!["users:manage".to_string()]);
+
+        assert_eq!(status(ctx).await, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_admin_routes_refuse_service_clients_with_the_scope() {
+        let ctx = RequestAuthContext::service(
+            "svc-billing".to_string(),
+            vec!["users:manage".to_string()],
+        );
+
+        assert_eq!(status(ctx).await, StatusCode::FORBIDDEN);
+    }
+}
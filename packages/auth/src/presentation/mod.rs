@@ -3,7 +3,11 @@ pub mod http;
 pub mod middleware;
 
 pub use context::request_auth_context;
+pub use http::admin_openapi::AdminApiDoc;
 pub use http::auth_openapi::AuthApiDoc;
-pub use http::auth_routes::auth_routes_handler;
+pub use http::auth_routes::{admin_routes_handler, auth_routes_handler, well_known_routes_handler};
 pub use middleware::auth_middleware;
 pub use middleware::optional_auth_middleware;
+pub use middleware::{
+    require_permission, require_tenant_permission, RequirePermission, RequireTenantPermission,
+};
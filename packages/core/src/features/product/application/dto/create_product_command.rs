@@ -3,6 +3,15 @@ use validator::Validate;
 
 #[derive(Debug, Deserialize, Validate)]
 pub struct CreateProductCommand {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Code must be between 1 and 100 characters"
+    ))]
+    pub code: String,
+
+    pub category_id: Option<i64>,
+
     #[validate(length(
         min = 1,
         max = 255,
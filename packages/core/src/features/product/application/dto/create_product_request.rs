@@ -4,6 +4,11 @@ use validator::Validate;
 
 #[derive(Deserialize, Validate, ToSchema)]
 pub struct CreateProductRequest {
+    /// Unique within the organization.
+    #[validate(length(min = 1, max = 100))]
+    pub code: String,
+    /// A category of the same organization.
+    pub category_id: Option<i64>,
     #[validate(length(min = 3))]
     pub name: String,
     pub description: String,
@@ -1,11 +1,12 @@
 use crate::features::product::domain::Product;
 use serde::Serialize;
 use utoipa::ToSchema;
-use uuid::Uuid;
 
 #[derive(Debug, Serialize, ToSchema)]
 pub struct CreateProductResult {
-    pub id: Uuid,
+    pub id: i64,
+    pub code: String,
+    pub category_id: Option<i64>,
     pub name: String,
     pub description: String,
     pub price: f64,
@@ -14,7 +15,9 @@ pub struct CreateProductResult {
 impl From<Product> for CreateProductResult {
     fn from(product: Product) -> Self {
         Self {
-            id: product.id,
+            id: product.id.unwrap_or_default(),
+            code: product.code,
+            category_id: product.category_id,
             name: product.name,
             description: product.description,
             price: product.price,
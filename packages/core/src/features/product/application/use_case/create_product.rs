@@ -1,15 +1,31 @@
 use crate::features::product::application::CreateProductCommand;
-use crate::features::product::domain::{Product, ProductRepository};
-use rust_reborn_contracts::Result;
-use uuid::Uuid;
+use crate::features::product::domain::{CategoryRepository, Product, ProductRepository};
+use rust_reborn_contracts::{AppError, Result};
 
 pub async fn create_product(
     service: &dyn ProductRepository,
+    categories: &dyn CategoryRepository,
+    organization_id: i64,
     cmd: CreateProductCommand,
 ) -> Result<Product> {
-    let product = Product::new(Uuid::new_v4(), cmd.name, cmd.description, cmd.price)?;
+    // A category of another tenant is as good as missing.
+    if let Some(category_id) = cmd.category_id {
+        categories
+            .find_by_id(organization_id, category_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("category not found"))?;
+    }
 
-    service.save(product.clone()).await?;
+    let mut product = Product::new(
+        organization_id,
+        cmd.category_id,
+        cmd.code,
+        cmd.name,
+        cmd.description,
+        cmd.price,
+    )?;
+
+    service.save(&mut product).await?;
 
     Ok(product)
 }
@@ -0,0 +1,28 @@
+#[derive(Debug, Clone)]
+pub struct Category {
+    /// Assigned by the repository on save.
+    pub id: Option<i64>,
+    /// Tenant that owns the category.
+    pub organization_id: i64,
+    /// Unique within the tenant.
+    pub code: String,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+impl Category {
+    pub fn new(
+        organization_id: i64,
+        code: String,
+        name: String,
+        description: Option<String>,
+    ) -> Self {
+        Self {
+            id: None,
+            organization_id,
+            code,
+            name,
+            description,
+        }
+    }
+}
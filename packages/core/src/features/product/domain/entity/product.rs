@@ -1,22 +1,38 @@
 use rust_reborn_contracts::{AppError, Result};
-use uuid::Uuid;
 
 #[derive(Debug, Clone)]
 pub struct Product {
-    pub id: Uuid,
+    /// Assigned by the repository on save.
+    pub id: Option<i64>,
+    /// Tenant that owns the product.
+    pub organization_id: i64,
+    /// A category of the same tenant.
+    pub category_id: Option<i64>,
+    /// Unique within the tenant.
+    pub code: String,
     pub name: String,
     pub description: String,
     pub price: f64,
 }
 
 impl Product {
-    pub fn new(id: Uuid, name: String, description: String, price: f64) -> Result<Self> {
+    pub fn new(
+        organization_id: i64,
+        category_id: Option<i64>,
+        code: String,
+        name: String,
+        description: String,
+        price: f64,
+    ) -> Result<Self> {
         if price <= 0.0 {
             return Err(AppError::bad_request("Price must be greater than 0"));
         }
 
         Ok(Self {
-            id,
+            id: None,
+            organization_id,
+            category_id,
+            code,
             name,
             description,
             price,
@@ -1,5 +1,7 @@
 pub mod entity;
 pub mod service;
 
+pub use entity::category::Category;
 pub use entity::product::Product;
+pub use service::category_repository::CategoryRepository;
 pub use service::product_repository::ProductRepository;
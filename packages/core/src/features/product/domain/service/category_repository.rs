@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use rust_reborn_contracts::Result;
+
+use crate::features::product::domain::Category;
+
+#[async_trait]
+/// Scoped to a tenant like [`ProductRepository`](super::product_repository::ProductRepository).
+pub trait CategoryRepository: Send + Sync {
+    async fn find_by_id(&self, organization_id: i64, id: i64) -> Result<Option<Category>>;
+    async fn save(&self, category: &mut Category) -> Result<()>;
+}
@@ -1 +1,2 @@
+pub mod category_repository;
 pub mod product_repository;
@@ -1,12 +1,14 @@
 use async_trait::async_trait;
 use rust_reborn_contracts::Result;
-use uuid::Uuid;
 
 use crate::features::product::domain::Product;
 
 #[async_trait]
+/// Every lookup is scoped to a tenant; a product of another organization
+/// is reported as missing, never returned.
 pub trait ProductRepository: Send + Sync {
-    async fn find_by_id(&self, id: Uuid) -> Result<Option<Product>>;
+    async fn find_by_id(&self, organization_id: i64, id: i64) -> Result<Option<Product>>;
+    /// Only updates the product within `product.organization_id`.
     async fn update(&self, product: &Product) -> Result<Product>;
-    async fn save(&self, product: Product) -> Result<()>;
+    async fn save(&self, product: &mut Product) -> Result<()>;
 }
@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod tests {
+    use crate::features::product::domain::{
+        Category, CategoryRepository, Product, ProductRepository,
+    };
+    use crate::features::product::infrastructure::category_repository_impl::PostgresCategoryRepository;
+    use crate::features::product::infrastructure::product_repository_impl::PostgresProductRepository;
+    use rust_reborn_contracts::AppError;
+    use sqlx::PgPool;
+
+    /// Runs against `DATABASE_URL`, the database the query macros are
+    /// checked against, and removes what it created. Ignored by default;
+    /// run with `cargo test -- --ignored`.
+    async fn pool() -> PgPool {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        PgPool::connect(&url).await.unwrap()
+    }
+
+    async fn create_organization(pool: &PgPool, slug: &str) -> i64 {
+        sqlx::query_scalar("INSERT INTO organizations (slug, name) VALUES ($1, $1) RETURNING id")
+            .bind(slug)
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    async fn remove_organizations(pool: &PgPool, ids: &[i64]) {
+        for table in ["products", "categories", "organizations"] {
+            let column = if table == "organizations" {
+                "id"
+            } else {
+                "organization_id"
+            };
+            sqlx::query(&format!("DELETE FROM {} WHERE {} = ANY($1)", table, column))
+                .bind(ids)
+                .execute(pool)
+                .await
+                .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "needs DATABASE_URL"]
+    async fn test_catalog_rows_are_only_visible_to_their_tenant() {
+        let pool = pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+        let acme = create_organization(&pool, &format!("acme-{}", suffix)).await;
+        let globex = create_organization(&pool, &format!("globex-{}", suffix)).await;
+
+        let categories = PostgresCategoryRepository::new(pool.clone());
+        let products = PostgresProductRepository::new(pool.clone());
+
+        let mut tools = Category::new(acme, "TOOLS".to_string(), "Tools".to_string(), None);
+        categories.save(&mut tools).await.unwrap();
+        let tools_id = tools.id.unwrap();
+        assert!(categories
+            .find_by_id(acme, tools_id)
+            .await
+            .unwrap()
+            .is_some());
+        assert!(categories
+            .find_by_id(globex, tools_id)
+            .await
+            .unwrap()
+            .is_none());
+
+        let mut hammer = Product::new(
+            acme,
+            Some(tools_id),
+            "HAMMER".to_string(),
+            "Hammer".to_string(),
+            "Claw hammer".to_string(),
+            12.5,
+        )
+        .unwrap();
+        products.save(&mut hammer).await.unwrap();
+        let hammer_id = hammer.id.unwrap();
+
+        let found = products.find_by_id(acme, hammer_id).await.unwrap().unwrap();
+        assert_eq!(found.code, "HAMMER");
+        assert_eq!(found.category_id, Some(tools_id));
+        assert_eq!(found.price, 12.5);
+        assert!(products
+            .find_by_id(globex, hammer_id)
+            .await
+            .unwrap()
+            .is_none());
+
+        // Another tenant can neither update the product nor see its code.
+        let mut stolen = found.clone();
+        stolen.organization_id = globex;
+        stolen.name = "Stolen".to_string();
+        let result = products.update(&stolen).await;
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+
+        let mut duplicate = Product::new(
+            acme,
+            None,
+            "HAMMER".to_string(),
+            "Hammer".to_string(),
+            String::new(),
+            1.0,
+        )
+        .unwrap();
+        let result = products.save(&mut duplicate).await;
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+        duplicate.organization_id = globex;
+        products.save(&mut duplicate).await.unwrap();
+
+        let mut renamed = found;
+        renamed.name = "Framing hammer".to_string();
+        let updated = products.update(&renamed).await.unwrap();
+        assert_eq!(updated.name, "Framing hammer");
+
+        remove_organizations(&pool, &[acme, globex]).await;
+    }
+}
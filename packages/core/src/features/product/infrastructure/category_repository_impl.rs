@@ -0,0 +1,80 @@
+use crate::features::product::domain::{Category, CategoryRepository};
+use async_trait::async_trait;
+use rust_reborn_contracts::{AppError, Result};
+use sqlx::{query, query_as, PgPool};
+
+#[derive(Clone)]
+pub struct PostgresCategoryRepository {
+    pool: PgPool,
+}
+
+impl PostgresCategoryRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+struct CategoryRow {
+    id: i64,
+    organization_id: i64,
+    code: String,
+    name: String,
+    description: Option<String>,
+}
+
+impl From<CategoryRow> for Category {
+    fn from(r: CategoryRow) -> Self {
+        Self {
+            id: Some(r.id),
+            organization_id: r.organization_id,
+            code: r.code,
+            name: r.name,
+            description: r.description,
+        }
+    }
+}
+
+#[async_trait]
+impl CategoryRepository for PostgresCategoryRepository {
+    async fn find_by_id(&self, organization_id: i64, id: i64) -> Result<Option<Category>> {
+        let row = query_as!(
+            CategoryRow,
+            r#"
+            SELECT id, organization_id AS "organization_id!", code, name, description
+            FROM categories
+            WHERE id = $1 AND organization_id = $2 AND deleted_at IS NULL
+            "#,
+            id,
+            organization_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn save(&self, category: &mut Category) -> Result<()> {
+        let row = query!(
+            r#"
+            INSERT INTO categories (organization_id, code, name, description)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id
+            "#,
+            category.organization_id,
+            category.code,
+            category.name,
+            category.description,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(db) if db.is_unique_violation() => {
+                AppError::conflict("category code already exists")
+            }
+            other => other.into(),
+        })?;
+
+        category.id = Some(row.id);
+        Ok(())
+    }
+}
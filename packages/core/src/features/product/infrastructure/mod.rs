@@ -1 +1,3 @@
+pub mod catalog_repository_test;
+pub mod category_repository_impl;
 pub mod product_repository_impl;
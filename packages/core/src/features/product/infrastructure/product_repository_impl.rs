@@ -1,12 +1,10 @@
 use crate::features::product::domain::{Product, ProductRepository};
 use async_trait::async_trait;
-use rust_reborn_contracts::Result;
-use sqlx::PgPool;
-use uuid::Uuid;
+use rust_reborn_contracts::{AppError, Result};
+use sqlx::{query, query_as, PgPool};
 
 #[derive(Clone)]
 pub struct PostgresProductRepository {
-    #[allow(dead_code)]
     pool: PgPool,
 }
 
@@ -16,18 +14,121 @@ impl PostgresProductRepository {
     }
 }
 
+struct ProductRow {
+    id: i64,
+    organization_id: i64,
+    category_id: Option<i64>,
+    code: String,
+    name: String,
+    description: String,
+    price: f64,
+}
+
+impl From<ProductRow> for Product {
+    fn from(r: ProductRow) -> Self {
+        Self {
+            id: Some(r.id),
+            organization_id: r.organization_id,
+            category_id: r.category_id,
+            code: r.code,
+            name: r.name,
+            description: r.description,
+            price: r.price,
+        }
+    }
+}
+
+fn write_error(e: sqlx::Error) -> AppError {
+    match e {
+        sqlx::Error::Database(db) if db.is_unique_violation() => {
+            AppError::conflict("product code already exists")
+        }
+        other => other.into(),
+    }
+}
+
 #[async_trait]
 impl ProductRepository for PostgresProductRepository {
-    async fn find_by_id(&self, _id: Uuid) -> Result<Option<Product>> {
-        // TODO: Implement actual DB query
-        Ok(None)
+    async fn find_by_id(&self, organization_id: i64, id: i64) -> Result<Option<Product>> {
+        let row = query_as!(
+            ProductRow,
+            r#"
+            SELECT
+                id,
+                organization_id AS "organization_id!",
+                category_id,
+                code,
+                name,
+                COALESCE(description, '') AS "description!",
+                price::FLOAT8 AS "price!"
+            FROM products
+            WHERE id = $1 AND organization_id = $2 AND deleted_at IS NULL
+            "#,
+            id,
+            organization_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
     }
 
-    async fn update(&self, _product: &Product) -> Result<Product> {
-        Ok(_product.clone())
+    async fn update(&self, product: &Product) -> Result<Product> {
+        let id = product
+            .id
+            .ok_or_else(|| AppError::internal("product must be persisted"))?;
+
+        let row = query_as!(
+            ProductRow,
+            r#"
+            UPDATE products
+            SET category_id = $3, code = $4, name = $5, description = $6,
+                price = $7::FLOAT8, updated_at = NOW()
+            WHERE id = $1 AND organization_id = $2 AND deleted_at IS NULL
+            RETURNING
+                id,
+                organization_id AS "organization_id!",
+                category_id,
+                code,
+                name,
+                COALESCE(description, '') AS "description!",
+                price::FLOAT8 AS "price!"
+            "#,
+            id,
+            product.organization_id,
+            product.category_id,
+            product.code,
+            product.name,
+            product.description,
+            product.price,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(write_error)?
+        .ok_or_else(|| AppError::not_found("product not found"))?;
+
+        Ok(row.into())
     }
 
-    async fn save(&self, _product: Product) -> Result<()> {
+    async fn save(&self, product: &mut Product) -> Result<()> {
+        let row = query!(
+            r#"
+            INSERT INTO products (organization_id, category_id, code, name, description, price)
+            VALUES ($1, $2, $3, $4, $5, $6::FLOAT8)
+            RETURNING id
+            "#,
+            product.organization_id,
+            product.category_id,
+            product.code,
+            product.name,
+            product.description,
+            product.price,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(write_error)?;
+
+        product.id = Some(row.id);
         Ok(())
     }
 }
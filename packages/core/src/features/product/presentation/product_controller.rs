@@ -7,10 +7,11 @@ use crate::features::product::{
             create_product_result::CreateProductResult,
         },
     },
-    domain::ProductRepository,
+    presentation::product_routes::ProductState,
 };
-use axum::{extract::State, response::IntoResponse, Json};
-use rust_reborn_contracts::{common::response::created, validation::validate, Result};
+use axum::{extract::State, response::IntoResponse, Extension, Json};
+use rust_reborn_auth::AuthContext;
+use rust_reborn_contracts::{common::response::created, validation::validate, AppError, Result};
 use std::sync::Arc;
 
 #[utoipa::path(
@@ -20,26 +21,42 @@ use std::sync::Arc;
     responses(
         (status = 201, description = "Product created", body = CreateProductResult),
         (status = 401, description = "Unauthorized"),
-        (status = 400, description = "Validation error")
+        (status = 403, description = "Missing products:write permission"),
+        (status = 400, description = "Validation error or no active organization"),
+        (status = 404, description = "Category not found in the organization"),
+        (status = 409, description = "Product code already exists in the organization")
     ),
     security(
-        ("bearerAuth" = [])
+        ("bearerAuth" = []),
+        ("apiKeyAuth" = [])
     ),
     tag = "Products"
 )]
 pub async fn create_product_handler(
-    State(repo): State<Arc<dyn ProductRepository>>,
+    State(state): State<ProductState>,
+    Extension(ctx): Extension<Arc<dyn AuthContext>>,
     Json(req): Json<CreateProductRequest>,
 ) -> Result<impl IntoResponse> {
     validate(&req)?;
+    let organization_id = ctx
+        .tenant_id()
+        .ok_or_else(|| AppError::bad_request("no active organization"))?;
 
     let command = CreateProductCommand {
+        code: req.code,
+        category_id: req.category_id,
         name: req.name,
         description: req.description,
         price: req.price,
     };
 
-    let product = create_product(repo.as_ref(), command).await?;
+    let product = create_product(
+        state.products.as_ref(),
+        state.categories.as_ref(),
+        organization_id,
+        command,
+    )
+    .await?;
 
     Ok(created(CreateProductResult::from(product)))
 }
@@ -1,10 +1,22 @@
-use crate::features::product::domain::ProductRepository;
+use crate::features::product::domain::{CategoryRepository, ProductRepository};
 use crate::features::product::presentation::product_controller::create_product_handler;
 use axum::{routing::post, Router};
 use std::sync::Arc;
 
-pub fn product_routes_handler(repo: Arc<dyn ProductRepository>) -> Router {
+#[derive(Clone)]
+pub struct ProductState {
+    pub products: Arc<dyn ProductRepository>,
+    pub categories: Arc<dyn CategoryRepository>,
+}
+
+pub fn product_routes_handler(
+    products: Arc<dyn ProductRepository>,
+    categories: Arc<dyn CategoryRepository>,
+) -> Router {
     Router::new()
         .route("/", post(create_product_handler))
-        .with_state(repo)
+        .with_state(ProductState {
+            products,
+            categories,
+        })
 }
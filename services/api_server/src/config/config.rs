@@ -1,5 +1,7 @@
 use config::{Config, ConfigError, Environment, File};
+use rust_reborn_auth::infrastructure::config::AuthConfig;
 use rust_reborn_auth::infrastructure::jwt::JwtConfig;
+use rust_reborn_contracts::mail::{MailBackend, MailConfig};
 use serde::Deserialize;
 use std::env;
 
@@ -8,6 +10,10 @@ pub struct AppConfig {
     pub server: ServerConfig,
     pub database: DatabaseConfig,
     pub jwt: JwtConfig,
+    #[serde(default)]
+    pub auth: AuthConfig,
+    #[serde(default)]
+    pub mail: MailConfig,
     pub media: MediaConfig,
 }
 
@@ -85,6 +91,33 @@ impl AppConfig {
         if let Some(ref_exp) = Self::get_env_parse("APP_JWT_REFRESH_EXPIRATION_DAYS") {
             config.jwt.refresh_expiration_days = ref_exp;
         }
+        if let Ok(kid) = env::var("APP_JWT_KEY_ID") {
+            config.jwt.key_id = Some(kid);
+        }
+        if let Ok(path) = env::var("APP_JWT_PRIVATE_KEY_PATH") {
+            config.jwt.private_key_path = Some(path);
+        }
+        if let Some(required) = Self::get_env_parse("APP_AUTH_EMAIL_VERIFICATION_REQUIRED") {
+            config.auth.email_verification.required_for_login = required;
+        }
+        if let Ok(url) = env::var("APP_AUTH_FRONTEND_URL") {
+            config.auth.frontend_url = url;
+        }
+        if let Ok(from) = env::var("APP_MAIL_FROM") {
+            config.mail.from = from;
+        }
+        if let Ok(host) = env::var("APP_MAIL_SMTP_HOST") {
+            config.mail.smtp.host = host;
+        }
+        if let Some(port) = Self::get_env_parse("APP_MAIL_SMTP_PORT") {
+            config.mail.smtp.port = port;
+        }
+        if let Ok(username) = env::var("APP_MAIL_SMTP_USERNAME") {
+            config.mail.smtp.username = Some(username);
+        }
+        if let Ok(password) = env::var("APP_MAIL_SMTP_PASSWORD") {
+            config.mail.smtp.password = Some(password);
+        }
         if let Some(size) = Self::get_env_parse("APP_MEDIA_MAX_FILE_SIZE") {
             config.media.max_file_size = size;
         }
@@ -134,20 +167,35 @@ impl AppConfig {
             ));
         }
 
-        if self.jwt.secret.contains("${") {
+        let uses_secret = !self.jwt.algorithm.is_asymmetric();
+
+        if uses_secret && (self.jwt.secret.is_empty() || self.jwt.secret.contains("${")) {
             return Err(ConfigError::Message(
                 "JWT_SECRET environment variable is required".into(),
             ));
         }
 
+        if !uses_secret && self.jwt.private_key_path.is_none() {
+            return Err(ConfigError::Message(
+                "jwt.private_key_path is required for RS256/EdDSA".into(),
+            ));
+        }
+
         // Production checks
         if self.server.environment == "production" {
-            if self.jwt.secret.len() < 32 {
+            if uses_secret && self.jwt.secret.len() < 32 {
                 return Err(ConfigError::Message(
                     "JWT secret must be at least 32 characters in production".into(),
                 ));
             }
 
+            if self.mail.backend != MailBackend::Smtp {
+                tracing::warn!(
+                    "⚠️  Mail backend is {:?}, emails will not be delivered!",
+                    self.mail.backend
+                );
+            }
+
             if self.database.url.contains("localhost") {
                 tracing::warn!("⚠️  Using localhost database in production mode!");
             }
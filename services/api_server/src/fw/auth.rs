@@ -1,16 +1,24 @@
 use crate::config::config::AppConfig;
-use rust_reborn_auth::infrastructure::jwt::JwtConfig;
+use rust_reborn_auth::infrastructure::jwt::JwtService;
 use rust_reborn_auth::AuthState;
+use rust_reborn_contracts::mail::TemplatedMailer;
 use sqlx::PgPool;
+use std::sync::Arc;
 
-pub fn build_auth_state(pool: &PgPool, config: &AppConfig) -> AuthState {
-    AuthState::new(pool.clone(), config.jwt.clone())
-}
+pub fn build_auth_state(
+    pool: &PgPool,
+    config: &AppConfig,
+    mailer: Arc<TemplatedMailer>,
+) -> anyhow::Result<AuthState> {
+    let jwt_service =
+        JwtService::new(config.jwt.clone()).map_err(|e| anyhow::anyhow!("{:?}", e))?;
+    tracing::info!("🔑 JWT signing algorithm: {:?}", config.jwt.algorithm);
 
-pub fn build_jwt_config(config: &AppConfig) -> JwtConfig {
-    JwtConfig {
-        secret: config.jwt.secret.clone(),
-        expiration_hours: config.jwt.expiration_hours,
-        refresh_expiration_days: config.jwt.refresh_expiration_days,
-    }
+    AuthState::new(
+        pool.clone(),
+        Arc::new(jwt_service),
+        config.auth.clone(),
+        mailer,
+    )
+    .map_err(|e| anyhow::anyhow!("{:?}", e))
 }
@@ -0,0 +1,19 @@
+use crate::config::config::AppConfig;
+use rust_reborn_contracts::mail::{build_mailer, MailTemplates, TemplatedMailer};
+use std::sync::Arc;
+
+pub fn build_templated_mailer(config: &AppConfig) -> anyhow::Result<Arc<TemplatedMailer>> {
+    let mail = &config.mail;
+
+    let mut templates = MailTemplates::with_defaults(&mail.default_locale);
+    if let Some(dir) = &mail.templates_dir {
+        templates
+            .load_dir(dir)
+            .map_err(|e| anyhow::anyhow!("{:?}", e))?;
+    }
+
+    let mailer = build_mailer(mail).map_err(|e| anyhow::anyhow!("{:?}", e))?;
+    tracing::info!("📧 Mail backend: {:?}", mail.backend);
+
+    Ok(Arc::new(TemplatedMailer::new(mailer, templates)))
+}
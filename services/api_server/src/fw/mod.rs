@@ -2,6 +2,7 @@ pub mod auth;
 pub mod build_app;
 pub mod db;
 pub mod load_config;
+pub mod mail;
 pub mod router;
 
 pub use build_app::build_app;
@@ -21,7 +21,13 @@ async fn main() -> anyhow::Result<()> {
     println!("   → Api Url: http://{}/api", addr);
     println!("\n   → Press Ctrl+C to stop\n");
 
-    axum::serve(listener, app.router).await?;
+    // Connection info gives handlers the peer address, e.g. for login throttling.
+    axum::serve(
+        listener,
+        app.router
+            .into_make_service_with_connect_info::<std::net::SocketAddr>(),
+    )
+    .await?;
 
     Ok(())
 }
@@ -1,6 +1,6 @@
-use rust_reborn_auth::AuthApiDoc;
+use rust_reborn_auth::{AdminApiDoc, AuthApiDoc};
 use rust_reborn_core::features::product::presentation::ProductApiDoc;
-use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme};
 use utoipa::OpenApi;
 
 #[derive(OpenApi)]
@@ -12,6 +12,7 @@ use utoipa::OpenApi;
     ),
     nest(
         (path = "/api/auth", api = AuthApiDoc),
+        (path = "/api/admin", api = AdminApiDoc),
         (path = "/api", api = ProductApiDoc)
     ),
     modifiers(&SecurityAddon)
@@ -36,6 +37,10 @@ impl utoipa::Modify for SecurityAddon {
                         .build(),
                 ),
             );
+            components.add_security_scheme(
+                "apiKeyAuth",
+                SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("X-API-Key"))),
+            );
         }
     }
 }
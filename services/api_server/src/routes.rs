@@ -1,15 +1,33 @@
 use axum::{middleware, Router};
-use rust_reborn_auth::infrastructure::jwt::JwtService;
-use rust_reborn_auth::{auth_middleware, AuthState};
-use rust_reborn_core::{product_routes_handler, ProductRepository};
+use rust_reborn_auth::{
+    auth_middleware, require_tenant_permission, AuthState, RequireTenantPermission,
+};
+use rust_reborn_core::{product_routes_handler, CategoryRepository, ProductRepository};
 use std::sync::Arc;
 
 pub fn auth_routes(state: AuthState) -> Router {
     rust_reborn_auth::auth_routes_handler(state)
 }
 
-pub fn product_routes(repo: Arc<dyn ProductRepository>, jwt: Arc<JwtService>) -> Router {
-    product_routes_handler(repo).layer(middleware::from_fn_with_state(jwt, auth_middleware))
+pub fn admin_routes(state: AuthState) -> Router {
+    rust_reborn_auth::admin_routes_handler(state)
+}
+
+pub fn well_known_routes(state: AuthState) -> Router {
+    rust_reborn_auth::well_known_routes_handler(state)
+}
+
+pub fn product_routes(
+    products: Arc<dyn ProductRepository>,
+    categories: Arc<dyn CategoryRepository>,
+    auth_state: AuthState,
+) -> Router {
+    product_routes_handler(products, categories)
+        .layer(middleware::from_fn_with_state(
+            RequireTenantPermission("products:write"),
+            require_tenant_permission,
+        ))
+        .layer(middleware::from_fn_with_state(auth_state, auth_middleware))
 }
 
 // Future: media routes
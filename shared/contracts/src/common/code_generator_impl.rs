@@ -1,4 +1,5 @@
 use crate::common::CodeGenerator;
+use rand::RngCore;
 use uuid::Uuid;
 
 pub struct UuidV7CodeGenerator;
@@ -8,3 +9,15 @@ impl CodeGenerator for UuidV7CodeGenerator {
         format!("{}-{}", prefix, Uuid::now_v7())
     }
 }
+
+/// Generates unguessable, prefixed secrets (256 bits of OS randomness, hex encoded).
+/// Use this for anything handed to a client as a bearer credential.
+pub struct SecureTokenGenerator;
+
+impl CodeGenerator for SecureTokenGenerator {
+    fn generate(&self, prefix: &str) -> String {
+        let mut bytes = [0u8; 32];
+        rand::rng().fill_bytes(&mut bytes);
+        format!("{}-{}", prefix, hex::encode(bytes))
+    }
+}
@@ -1,10 +1,15 @@
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct PaginationParams {
     #[serde(default = "default_page")]
+    #[validate(range(min = 1))]
     pub page: u32,
     #[serde(default = "default_per_page")]
+    #[validate(range(min = 1, max = 100))]
     pub per_page: u32,
 }
 
@@ -17,20 +22,35 @@ fn default_per_page() -> u32 {
 
 impl PaginationParams {
     pub fn offset(&self) -> u32 {
-        (self.page - 1) * self.per_page
+        self.page.saturating_sub(1) * self.per_page
     }
     pub fn limit(&self) -> u32 {
         self.per_page
     }
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct PaginatedResponse<T> {
     pub data: Vec<T>,
     pub pagination: PaginationMeta,
 }
 
-#[derive(Debug, Serialize)]
+impl<T> PaginatedResponse<T> {
+    pub fn new(data: Vec<T>, params: &PaginationParams, total_items: u64) -> Self {
+        let per_page = params.per_page.max(1);
+        Self {
+            data,
+            pagination: PaginationMeta {
+                current_page: params.page,
+                per_page,
+                total_items,
+                total_pages: total_items.div_ceil(per_page as u64) as u32,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
 pub struct PaginationMeta {
     pub current_page: u32,
     pub per_page: u32,
@@ -15,6 +15,9 @@ pub enum AppError {
     Unauthorized(String),
     Forbidden(String),
     Conflict(String),
+    /// Account temporarily locked after repeated failed logins.
+    AccountLocked(String),
+    TooManyRequests(String),
     ValidationError(validator::ValidationErrors),
     DatabaseError(String),
 }
@@ -38,16 +41,30 @@ impl AppError {
     pub fn conflict(msg: impl Into<String>) -> Self {
         Self::Conflict(msg.into())
     }
+    pub fn account_locked(msg: impl Into<String>) -> Self {
+        Self::AccountLocked(msg.into())
+    }
+    pub fn too_many_requests(msg: impl Into<String>) -> Self {
+        Self::TooManyRequests(msg.into())
+    }
 }
 
 #[derive(Serialize, Deserialize)]
 pub struct ErrorResponse {
     pub error: String,
     pub message: String,
+    /// Per-field rule violations (`code`, `message`, `params`) for validation errors.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<serde_json::Value>,
 }
 
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
+        let details = match &self {
+            Self::ValidationError(e) => serde_json::to_value(e).ok(),
+            _ => None,
+        };
+
         let (status, error_type, message) = match self {
             Self::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, "INTERNAL_ERROR", msg),
             Self::NotFound(msg) => (StatusCode::NOT_FOUND, "NOT_FOUND", msg),
@@ -55,6 +72,8 @@ impl IntoResponse for AppError {
             Self::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, "UNAUTHORIZED", msg),
             Self::Forbidden(msg) => (StatusCode::FORBIDDEN, "FORBIDDEN", msg),
             Self::Conflict(msg) => (StatusCode::CONFLICT, "CONFLICT", msg),
+            Self::AccountLocked(msg) => (StatusCode::LOCKED, "ACCOUNT_LOCKED", msg),
+            Self::TooManyRequests(msg) => (StatusCode::TOO_MANY_REQUESTS, "TOO_MANY_REQUESTS", msg),
             Self::ValidationError(e) => {
                 (StatusCode::BAD_REQUEST, "VALIDATION_ERROR", e.to_string())
             }
@@ -66,6 +85,7 @@ impl IntoResponse for AppError {
             Json(ErrorResponse {
                 error: error_type.to_string(),
                 message,
+                details,
             }),
         )
             .into_response()
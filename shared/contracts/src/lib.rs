@@ -1,6 +1,7 @@
 pub mod auth;
 pub mod common;
 pub mod error;
+pub mod mail;
 pub mod validation;
 
 pub use error::{AppError, Result};
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MailBackend {
+    Smtp,
+    /// Writes every message as a JSON file into `file_dir`.
+    File,
+    /// Only logs messages; the default for local development.
+    Log,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SmtpSecurity {
+    /// Implicit TLS, usually port 465.
+    Tls,
+    /// STARTTLS upgrade, usually port 587.
+    StartTls,
+    /// Plain text; only for local catch-all servers such as MailHog.
+    None,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MailConfig {
+    pub backend: MailBackend,
+    pub from: String,
+    pub default_locale: String,
+    /// Optional directory overriding the built-in templates,
+    /// laid out as `<locale>/<name>.subject`, `.txt` and `.html`.
+    pub templates_dir: Option<String>,
+    pub file_dir: String,
+    pub smtp: SmtpConfig,
+}
+
+impl Default for MailConfig {
+    fn default() -> Self {
+        Self {
+            backend: MailBackend::Log,
+            from: "Rust Reborn <no-reply@localhost>".to_string(),
+            default_locale: "en".to_string(),
+            templates_dir: None,
+            file_dir: "./mail".to_string(),
+            smtp: SmtpConfig::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: u16,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub security: SmtpSecurity,
+}
+
+impl Default for SmtpConfig {
+    fn default() -> Self {
+        Self {
+            host: "localhost".to_string(),
+            port: 587,
+            username: None,
+            password: None,
+            security: SmtpSecurity::StartTls,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::mail::mailer::{EmailMessage, Mailer};
+use crate::{AppError, Result};
+use async_trait::async_trait;
+use std::path::PathBuf;
+
+/// Drops every message as a JSON file into a directory, so development
+/// setups and tests can inspect outgoing mail without a mail server.
+#[derive(Clone)]
+pub struct FileMailer {
+    dir: PathBuf,
+}
+
+impl FileMailer {
+    pub fn new(dir: impl Into<PathBuf>) -> Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir).map_err(|e| {
+            AppError::internal(format!("cannot create mail dir {}: {}", dir.display(), e))
+        })?;
+        Ok(Self { dir })
+    }
+
+    pub fn dir(&self) -> &PathBuf {
+        &self.dir
+    }
+}
+
+#[async_trait]
+impl Mailer for FileMailer {
+    async fn send(&self, message: &EmailMessage) -> Result<()> {
+        let file_name = format!(
+            "{}-{}.json",
+            chrono::Utc::now().format("%Y%m%dT%H%M%S%.6f"),
+            uuid::Uuid::new_v4()
+        );
+        let content = serde_json::to_vec_pretty(message)
+            .map_err(|e| AppError::internal(format!("cannot serialize email: {}", e)))?;
+
+        tokio::fs::write(self.dir.join(file_name), content)
+            .await
+            .map_err(|e| AppError::internal(format!("cannot write email: {}", e)))
+    }
+}
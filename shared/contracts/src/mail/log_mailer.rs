@@ -0,0 +1,27 @@
+use crate::mail::mailer::{EmailMessage, Mailer};
+use crate::Result;
+use async_trait::async_trait;
+
+/// Writes messages to the application log instead of sending them.
+/// Only meant for local development: the log then contains live links.
+#[derive(Clone, Default)]
+pub struct LogMailer;
+
+impl LogMailer {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl Mailer for LogMailer {
+    async fn send(&self, message: &EmailMessage) -> Result<()> {
+        tracing::info!(
+            to = %message.to,
+            subject = %message.subject,
+            body = %message.text_body,
+            "email (log backend)"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use crate::mail::template::{MailTemplates, RenderedTemplate};
+use crate::Result;
+use async_trait::async_trait;
+use serde::Serialize;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EmailMessage {
+    pub to: String,
+    pub subject: String,
+    pub text_body: String,
+    pub html_body: String,
+}
+
+impl EmailMessage {
+    pub fn from_template(to: impl Into<String>, rendered: RenderedTemplate) -> Self {
+        Self {
+            to: to.into(),
+            subject: rendered.subject,
+            text_body: rendered.text,
+            html_body: rendered.html,
+        }
+    }
+}
+
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, message: &EmailMessage) -> Result<()>;
+}
+
+/// Renders a named template and hands the result to a [`Mailer`].
+pub struct TemplatedMailer {
+    mailer: Arc<dyn Mailer>,
+    templates: MailTemplates,
+}
+
+impl TemplatedMailer {
+    pub fn new(mailer: Arc<dyn Mailer>, templates: MailTemplates) -> Self {
+        Self { mailer, templates }
+    }
+
+    pub async fn send_template(
+        &self,
+        to: &str,
+        template: &str,
+        vars: &[(&str, &str)],
+    ) -> Result<()> {
+        let rendered = self.templates.render(template, vars)?;
+        self.mailer
+            .send(&EmailMessage::from_template(to, rendered))
+            .await
+    }
+}
@@ -0,0 +1,27 @@
+pub mod config;
+pub mod file_mailer;
+pub mod log_mailer;
+pub mod mailer;
+pub mod smtp_mailer;
+pub mod template;
+
+pub use config::*;
+pub use file_mailer::FileMailer;
+pub use log_mailer::LogMailer;
+pub use mailer::*;
+pub use smtp_mailer::SmtpMailer;
+pub use template::*;
+
+use crate::Result;
+use std::sync::Arc;
+
+/// Builds the backend selected by `config.backend`.
+pub fn build_mailer(config: &MailConfig) -> Result<Arc<dyn Mailer>> {
+    let mailer: Arc<dyn Mailer> = match config.backend {
+        MailBackend::Smtp => Arc::new(SmtpMailer::new(&config.smtp, &config.from)?),
+        MailBackend::File => Arc::new(FileMailer::new(&config.file_dir)?),
+        MailBackend::Log => Arc::new(LogMailer::new()),
+    };
+
+    Ok(mailer)
+}
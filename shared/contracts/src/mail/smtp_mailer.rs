@@ -0,0 +1,69 @@
+use crate::mail::config::{SmtpConfig, SmtpSecurity};
+use crate::mail::mailer::{EmailMessage, Mailer};
+use crate::{AppError, Result};
+use async_trait::async_trait;
+use lettre::message::{Mailbox, MultiPart};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+
+#[derive(Clone)]
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpMailer {
+    pub fn new(config: &SmtpConfig, from: &str) -> Result<Self> {
+        let from = from
+            .parse::<Mailbox>()
+            .map_err(|e| AppError::internal(format!("invalid mail sender address: {}", e)))?;
+
+        let builder = match config.security {
+            SmtpSecurity::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&config.host),
+            SmtpSecurity::StartTls => {
+                AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host)
+            }
+            SmtpSecurity::None => Ok(AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(
+                &config.host,
+            )),
+        }
+        .map_err(|e| AppError::internal(format!("invalid SMTP relay: {}", e)))?;
+
+        let mut builder = builder.port(config.port);
+        if let (Some(username), Some(password)) = (&config.username, &config.password) {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+
+        Ok(Self {
+            transport: builder.build(),
+            from,
+        })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, message: &EmailMessage) -> Result<()> {
+        let to = message
+            .to
+            .parse::<Mailbox>()
+            .map_err(|e| AppError::bad_request(format!("invalid recipient address: {}", e)))?;
+
+        let email = Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(&message.subject)
+            .multipart(MultiPart::alternative_plain_html(
+                message.text_body.clone(),
+                message.html_body.clone(),
+            ))
+            .map_err(|e| AppError::internal(format!("cannot build email: {}", e)))?;
+
+        self.transport
+            .send(email)
+            .await
+            .map_err(|e| AppError::internal(format!("SMTP delivery failed: {}", e)))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,230 @@
+use crate::{AppError, Result};
+use std::collections::HashMap;
+use std::path::Path;
+
+#[derive(Debug, Clone)]
+pub struct EmailTemplate {
+    pub subject: String,
+    pub text: String,
+    pub html: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct RenderedTemplate {
+    pub subject: String,
+    pub text: String,
+    pub html: String,
+}
+
+/// Templates keyed by name and locale; every message is rendered in the
+/// default locale. Placeholders are written `{{name}}`; values are
+/// HTML-escaped in the HTML part only.
+#[derive(Debug, Clone)]
+pub struct MailTemplates {
+    default_locale: String,
+    templates: HashMap<(String, String), EmailTemplate>,
+}
+
+impl MailTemplates {
+    pub fn new(default_locale: impl Into<String>) -> Self {
+        Self {
+            default_locale: default_locale.into(),
+            templates: HashMap::new(),
+        }
+    }
+
+    /// Built-in account templates in English and Indonesian.
+    pub fn with_defaults(default_locale: impl Into<String>) -> Self {
+        let mut templates = Self::new(default_locale);
+        for (name, locale, subject, text, html) in BUILT_IN {
+            templates.register(
+                name,
+                locale,
+                EmailTemplate {
+                    subject: subject.to_string(),
+                    text: text.to_string(),
+                    html: html.to_string(),
+                },
+            );
+        }
+        templates
+    }
+
+    pub fn register(&mut self, name: &str, locale: &str, template: EmailTemplate) {
+        self.templates
+            .insert((name.to_string(), locale.to_string()), template);
+    }
+
+    /// Loads `<dir>/<locale>/<name>.subject`, `<name>.txt` and `<name>.html`,
+    /// replacing templates with the same name and locale.
+    pub fn load_dir(&mut self, dir: impl AsRef<Path>) -> Result<()> {
+        let read_err = |e: std::io::Error| AppError::internal(format!("mail templates: {}", e));
+
+        for locale_entry in std::fs::read_dir(dir).map_err(read_err)? {
+            let locale_path = locale_entry.map_err(read_err)?.path();
+            if !locale_path.is_dir() {
+                continue;
+            }
+            let locale = file_name(&locale_path);
+
+            for entry in std::fs::read_dir(&locale_path).map_err(read_err)? {
+                let path = entry.map_err(read_err)?.path();
+                if path.extension().and_then(|e| e.to_str()) != Some("subject") {
+                    continue;
+                }
+                let name = path
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or_default()
+                    .to_string();
+
+                let template = EmailTemplate {
+                    subject: std::fs::read_to_string(&path)
+                        .map_err(read_err)?
+                        .trim()
+                        .to_string(),
+                    text: std::fs::read_to_string(path.with_extension("txt")).map_err(read_err)?,
+                    html: std::fs::read_to_string(path.with_extension("html")).map_err(read_err)?,
+                };
+                self.register(&name, &locale, template);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn render(&self, name: &str, vars: &[(&str, &str)]) -> Result<RenderedTemplate> {
+        let template = self
+            .templates
+            .get(&(name.to_string(), self.default_locale.clone()))
+            .ok_or_else(|| AppError::internal(format!("mail template not found: {}", name)))?;
+
+        Ok(RenderedTemplate {
+            subject: substitute(&template.subject, vars, false),
+            text: substitute(&template.text, vars, false),
+            html: substitute(&template.html, vars, true),
+        })
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Single pass over the template, so a value that itself contains
+/// `{{...}}` is copied as is rather than expanded.
+fn substitute(template: &str, vars: &[(&str, &str)], escape: bool) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        output.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            rest = &rest[start..];
+            break;
+        };
+
+        let key = &after[..end];
+        match vars.iter().find(|(name, _)| *name == key) {
+            Some((_, value)) if escape => output.push_str(&escape_html(value)),
+            Some((_, value)) => output.push_str(value),
+            None => output.push_str(&rest[start..start + end + 4]),
+        }
+        rest = &after[end + 2..];
+    }
+
+    output.push_str(rest);
+    output
+}
+
+fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+const BUILT_IN: &[(&str, &str, &str, &str, &str)] = &[
+    (
+        "email_verification",
+        "en",
+        "Verify your email address",
+        "Hi {{username}},\n\nConfirm your email address by opening the link below:\n{{link}}\n\nThe link is valid until {{expires_at}}.\n",
+        "<p>Hi {{username}},</p><p>Confirm your email address by opening the link below:</p><p><a href=\"{{link}}\">Verify email</a></p><p>The link is valid until {{expires_at}}.</p>",
+    ),
+    (
+        "email_verification",
+        "id",
+        "Verifikasi alamat email Anda",
+        "Halo {{username}},\n\nKonfirmasi alamat email Anda melalui tautan berikut:\n{{link}}\n\nTautan berlaku hingga {{expires_at}}.\n",
+        "<p>Halo {{username}},</p><p>Konfirmasi alamat email Anda melalui tautan berikut:</p><p><a href=\"{{link}}\">Verifikasi email</a></p><p>Tautan berlaku hingga {{expires_at}}.</p>",
+    ),
+    (
+        "password_reset",
+        "en",
+        "Reset your password",
+        "Hi {{username}},\n\nSomeone asked to reset the password of your account. If it was you, open the link below:\n{{link}}\n\nThe link is valid until {{expires_at}}. If you did not ask for this, ignore this email.\n",
+        "<p>Hi {{username}},</p><p>Someone asked to reset the password of your account. If it was you, open the link below:</p><p><a href=\"{{link}}\">Reset password</a></p><p>The link is valid until {{expires_at}}. If you did not ask for this, ignore this email.</p>",
+    ),
+    (
+        "password_reset",
+        "id",
+        "Atur ulang password Anda",
+        "Halo {{username}},\n\nAda permintaan untuk mengatur ulang password akun Anda. Jika itu Anda, buka tautan berikut:\n{{link}}\n\nTautan berlaku hingga {{expires_at}}. Abaikan email ini jika Anda tidak memintanya.\n",
+        "<p>Halo {{username}},</p><p>Ada permintaan untuk mengatur ulang password akun Anda. Jika itu Anda, buka tautan berikut:</p><p><a href=\"{{link}}\">Atur ulang password</a></p><p>Tautan berlaku hingga {{expires_at}}. Abaikan email ini jika Anda tidak memintanya.</p>",
+    ),
+    (
+        "email_change",
+        "en",
+        "Confirm your new email address",
+        "Hi {{username}},\n\nConfirm that this is the new email address of your account by opening the link below:\n{{link}}\n\nThe link is valid until {{expires_at}}. Your current address stays in use until you confirm.\n",
+        "<p>Hi {{username}},</p><p>Confirm that this is the new email address of your account by opening the link below:</p><p><a href=\"{{link}}\">Confirm email address</a></p><p>The link is valid until {{expires_at}}. Your current address stays in use until you confirm.</p>",
+    ),
+    (
+        "email_change",
+        "id",
+        "Konfirmasi alamat email baru Anda",
+        "Halo {{username}},\n\nKonfirmasi bahwa ini adalah alamat email baru akun Anda melalui tautan berikut:\n{{link}}\n\nTautan berlaku hingga {{expires_at}}. Alamat lama tetap digunakan sampai Anda mengonfirmasi.\n",
+        "<p>Halo {{username}},</p><p>Konfirmasi bahwa ini adalah alamat email baru akun Anda melalui tautan berikut:</p><p><a href=\"{{link}}\">Konfirmasi alamat email</a></p><p>Tautan berlaku hingga {{expires_at}}. Alamat lama tetap digunakan sampai Anda mengonfirmasi.</p>",
+    ),
+    (
+        "magic_link",
+        "en",
+        "Your sign-in link",
+        "Hi {{username}},\n\nOpen the link below to sign in:\n{{link}}\n\nThe link works once and is valid until {{expires_at}}. If you did not ask for it, ignore this email.\n",
+        "<p>Hi {{username}},</p><p>Open the link below to sign in:</p><p><a href=\"{{link}}\">Sign in</a></p><p>The link works once and is valid until {{expires_at}}. If you did not ask for it, ignore this email.</p>",
+    ),
+    (
+        "magic_link",
+        "id",
+        "Tautan masuk Anda",
+        "Halo {{username}},\n\nBuka tautan berikut untuk masuk:\n{{link}}\n\nTautan hanya bisa dipakai sekali dan berlaku hingga {{expires_at}}. Abaikan email ini jika Anda tidak memintanya.\n",
+        "<p>Halo {{username}},</p><p>Buka tautan berikut untuk masuk:</p><p><a href=\"{{link}}\">Masuk</a></p><p>Tautan hanya bisa dipakai sekali dan berlaku hingga {{expires_at}}. Abaikan email ini jika Anda tidak memintanya.</p>",
+    ),
+    (
+        "invitation",
+        "en",
+        "You have been invited",
+        "Hi,\n\n{{inviter}} invited you to create an account. Open the link below to choose a username and password:\n{{link}}\n\nThe invitation is valid until {{expires_at}}.\n",
+        "<p>Hi,</p><p>{{inviter}} invited you to create an account. Open the link below to choose a username and password:</p><p><a href=\"{{link}}\">Accept invitation</a></p><p>The invitation is valid until {{expires_at}}.</p>",
+    ),
+    (
+        "invitation",
+        "id",
+        "Anda diundang",
+        "Halo,\n\n{{inviter}} mengundang Anda untuk membuat akun. Buka tautan berikut untuk memilih username dan password:\n{{link}}\n\nUndangan berlaku hingga {{expires_at}}.\n",
+        "<p>Halo,</p><p>{{inviter}} mengundang Anda untuk membuat akun. Buka tautan berikut untuk memilih username dan password:</p><p><a href=\"{{link}}\">Terima undangan</a></p><p>Undangan berlaku hingga {{expires_at}}.</p>",
+    ),
+];